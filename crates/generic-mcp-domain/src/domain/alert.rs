@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{Priority, Ticket};
+
+/// Ticket changes worth interrupting someone for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    /// A ticket was assigned to the user the provider's token belongs to.
+    AssignedToMe,
+    /// A ticket's priority was raised.
+    PriorityEscalated,
+    /// A ticket's SLA deadline passed without being met.
+    SlaBreached,
+}
+
+/// A ticket change an `AlertSink` is told of.
+#[derive(Debug, Clone, Serialize)]
+pub struct TicketAlert {
+    pub provider: String,
+    pub kind: AlertKind,
+    pub ticket_id: String,
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    pub priority: Priority,
+    /// The priority before an escalation.
+    pub previous_priority: Option<Priority>,
+    /// The deadline a breached SLA missed.
+    pub sla_due: Option<DateTime<Utc>>,
+}
+
+impl TicketAlert {
+    pub fn new(provider: &str, kind: AlertKind, ticket: &Ticket) -> Self {
+        Self {
+            provider: provider.to_string(),
+            kind,
+            ticket_id: ticket.id.clone(),
+            identifier: ticket.identifier.clone(),
+            title: ticket.title.clone(),
+            url: ticket.url.clone(),
+            priority: ticket.priority.clone(),
+            previous_priority: None,
+            sla_due: None,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self.kind {
+            AlertKind::AssignedToMe => format!("{} \"{}\" was assigned to you", self.identifier, self.title),
+            AlertKind::PriorityEscalated => format!(
+                "{} \"{}\" was escalated from {} to {}",
+                self.identifier,
+                self.title,
+                self.previous_priority.as_ref().map_or("none", Priority::name),
+                self.priority.name()
+            ),
+            AlertKind::SlaBreached => match self.sla_due {
+                Some(due) => format!("{} \"{}\" breached its SLA, due {}", self.identifier, self.title, due.format("%Y-%m-%d %H:%M UTC")),
+                None => format!("{} \"{}\" breached its SLA", self.identifier, self.title),
+            },
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A file or link attached to a ticket. Metadata the provider does not
+/// expose (Linear has no size for linked attachments) is left empty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: String,
+    pub title: String,
+    pub url: String,
+    pub mime_type: Option<String>,
+    pub size_bytes: Option<u64>,
+    pub uploader_id: Option<String>,
+    /// The integration a link came from (`github`, `slack`, ...); `None` for
+    /// uploads and plain links.
+    pub source: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Attaches a link to a ticket. Without a title, providers that recognize
+/// the URL (pull requests, docs, threads) fill one in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAttachmentRequest {
+    pub ticket_id: String,
+    pub url: String,
+    pub title: Option<String>,
+}
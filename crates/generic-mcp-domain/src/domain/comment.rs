@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::Reaction;
+
+/// A comment on a ticket. Replies point at the comment they answer through
+/// `parent_comment_id`; top-level comments have none.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: String,
+    pub body: String,
+    pub author_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub parent_comment_id: Option<String>,
+    pub reactions: Vec<Reaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCommentRequest {
+    pub ticket_id: String,
+    pub body: String,
+    pub parent_comment_id: Option<String>,
+}
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A time-boxed iteration (Linear cycle, Jira sprint, Shortcut iteration).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cycle {
+    pub id: String,
+    pub team_id: String,
+    pub number: u32,
+    pub name: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// Completed share of the cycle's scope, `0.0..=1.0`, when the provider
+    /// reports it.
+    pub progress: Option<f32>,
+    pub ticket_ids: Vec<String>,
+}
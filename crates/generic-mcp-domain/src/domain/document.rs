@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::RichText;
+
+/// A long-form document (spec, RFC, meeting notes) kept in the tracker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Document {
+    pub id: String,
+    pub title: String,
+    /// Only populated when a single document is fetched.
+    pub content: Option<RichText>,
+    pub project_id: Option<String>,
+    pub creator_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub url: String,
+}
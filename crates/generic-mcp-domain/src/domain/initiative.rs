@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A roadmap-level goal grouping several projects (Linear initiatives, Jira
+/// initiatives above epics).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Initiative {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Provider status text, e.g. `Planned`, `Active`, `Completed`.
+    pub status: Option<String>,
+    pub owner_id: Option<String>,
+    pub target_date: Option<DateTime<Utc>>,
+    pub project_ids: Vec<String>,
+}
@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
+
+use crate::domain::{Attachment, Reaction, Sla, TicketRelations};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Issue {
@@ -13,11 +14,20 @@ pub struct Issue {
     pub assignee_id: Option<String>,
     pub creator_id: String,
     pub project_id: Option<String>,
+    pub cycle_id: Option<String>,
     pub labels: Vec<String>,
+    pub subscriber_ids: Vec<String>,
+    pub attachments: Vec<Attachment>,
+    pub relations: TicketRelations,
+    pub reactions: Vec<Reaction>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub due_date: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub snoozed_until: Option<DateTime<Utc>>,
     pub estimate: Option<f32>,
+    pub sla: Option<Sla>,
     pub url: String,
 }
 
@@ -29,7 +39,7 @@ pub struct IssueState {
     pub position: f32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IssueStateType {
     Unstarted,
     Started,
@@ -37,7 +47,7 @@ pub enum IssueStateType {
     Canceled,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IssuePriority {
     NoPriority,
     Urgent,
@@ -46,17 +56,28 @@ pub enum IssuePriority {
     Low,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct IssueFilter {
     pub assignee_id: Option<String>,
     pub project_id: Option<String>,
+    pub team_id: Option<String>,
+    pub cycle_id: Option<String>,
+    pub blocked: Option<bool>,
     pub state_type: Option<IssueStateType>,
     pub priority: Option<IssuePriority>,
     pub labels: Option<Vec<String>>,
     pub search_query: Option<String>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub updated_after: Option<DateTime<Utc>>,
+    pub updated_before: Option<DateTime<Utc>>,
+    pub due_before: Option<DateTime<Utc>>,
+    pub include_archived: bool,
+    pub triage: Option<bool>,
+    pub snoozed: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CreateIssueRequest {
     pub title: String,
     pub description: Option<String>,
@@ -64,6 +85,7 @@ pub struct CreateIssueRequest {
     pub assignee_id: Option<String>,
     pub team_id: Option<String>,
     pub project_id: Option<String>,
+    pub parent_id: Option<String>,
     pub label_ids: Option<Vec<String>>,
     pub due_date: Option<DateTime<Utc>>,
     pub estimate: Option<f32>,
@@ -0,0 +1,54 @@
+// Generic domain models
+pub mod ticket;
+pub mod ticket_ref;
+pub mod rich_text;
+pub mod workspace;
+pub mod label;
+pub mod project;
+pub mod project_update;
+pub mod document;
+pub mod initiative;
+pub mod cycle;
+pub mod sla;
+pub mod time_entry;
+pub mod comment;
+pub mod reaction;
+pub mod attachment;
+pub mod relation;
+pub mod ticket_event;
+pub mod workflow;
+pub mod saved_view;
+pub mod notification;
+pub mod template;
+pub mod webhook;
+pub mod alert;
+
+pub use ticket::*;
+pub use ticket_ref::*;
+pub use rich_text::*;
+pub use workspace::*;
+pub use label::*;
+pub use project::*;
+pub use project_update::*;
+pub use document::*;
+pub use initiative::*;
+pub use cycle::*;
+pub use sla::*;
+pub use time_entry::*;
+pub use comment::*;
+pub use reaction::*;
+pub use attachment::*;
+pub use relation::*;
+pub use ticket_event::*;
+pub use workflow::*;
+pub use saved_view::*;
+pub use notification::*;
+pub use template::*;
+pub use webhook::*;
+pub use alert::*;
+
+// Legacy Linear-specific types (for backward compatibility)
+pub mod issue;
+pub mod user;
+
+pub use issue::*;
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// An entry in the current user's tracker inbox.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: String,
+    pub kind: NotificationKind,
+    pub ticket_id: Option<String>,
+    pub ticket_identifier: Option<String>,
+    pub ticket_title: Option<String>,
+    pub actor_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub read_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    Mention,
+    Assignment,
+    StateChange,
+    Comment,
+    /// Provider-specific kinds without a generic equivalent.
+    Other(String),
+}
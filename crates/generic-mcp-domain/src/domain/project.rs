@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub key: String,
+    pub state: ProjectState,
+    pub target_date: Option<DateTime<Utc>>,
+    pub lead_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub progress: f32,
+    /// Only populated when a single project is fetched in detail.
+    pub milestones: Vec<ProjectMilestone>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ProjectState {
+    Planned,
+    Started,
+    Completed,
+    Canceled,
+    Paused,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectMilestone {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub target_date: Option<DateTime<Utc>>,
+    pub project_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateProjectRequest {
+    pub name: String,
+    pub description: Option<String>,
+    pub team_ids: Vec<String>,
+    pub lead_id: Option<String>,
+    pub target_date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateProjectRequest {
+    pub id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub state: Option<ProjectState>,
+    pub lead_id: Option<String>,
+    pub target_date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateMilestoneRequest {
+    pub project_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub target_date: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::RichText;
+
+/// A status post on a project: the narrative of how it is going, which
+/// ticket counts alone do not tell.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectUpdate {
+    pub id: String,
+    pub project_id: String,
+    pub body: RichText,
+    pub health: Option<ProjectHealth>,
+    pub author_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectHealth {
+    OnTrack,
+    AtRisk,
+    OffTrack,
+}
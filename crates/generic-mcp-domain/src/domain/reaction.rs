@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// An emoji reaction. `emoji` is whatever the provider uses: Linear takes
+/// shortcode names such as `thumbsup`, GitHub a fixed set like `+1`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reaction {
+    pub id: String,
+    pub emoji: String,
+    pub user_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// What a reaction is attached to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "snake_case")]
+pub enum ReactionTarget {
+    Ticket(String),
+    Comment(String),
+}
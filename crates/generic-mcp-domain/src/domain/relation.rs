@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// Another ticket referenced from a relation, with the identifier people use.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelatedTicket {
+    pub id: String,
+    pub identifier: String,
+}
+
+/// Typed links between tickets. Only populated by fetches that request
+/// relations; list endpoints may leave it empty.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TicketRelations {
+    pub parent: Option<RelatedTicket>,
+    pub children: Vec<RelatedTicket>,
+    pub blocks: Vec<RelatedTicket>,
+    pub blocked_by: Vec<RelatedTicket>,
+    pub duplicates: Vec<RelatedTicket>,
+    pub duplicated_by: Vec<RelatedTicket>,
+    pub relates_to: Vec<RelatedTicket>,
+}
+
+impl TicketRelations {
+    pub fn is_blocked(&self) -> bool {
+        !self.blocked_by.is_empty()
+    }
+}
+
+/// The link to create, read from the source ticket: `Blocks` means the
+/// source blocks the related ticket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationKind {
+    Blocks,
+    BlockedBy,
+    Duplicates,
+    RelatesTo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRelationRequest {
+    pub ticket_id: String,
+    pub related_ticket_id: String,
+    pub kind: RelationKind,
+}
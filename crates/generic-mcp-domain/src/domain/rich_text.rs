@@ -0,0 +1,1083 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+/// Formatted text in whichever format the provider speaks. Conversions go
+/// through a small shared document model (headings, paragraphs, lists, code,
+/// quotes, rules, and bold/italic/code/link inlines); anything richer, such
+/// as tables or panels, degrades to its text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "format", content = "content", rename_all = "snake_case")]
+pub enum RichText {
+    Markdown(String),
+    Html(String),
+    /// Atlassian Document Format, as used by Jira Cloud.
+    Adf(Value),
+}
+
+impl RichText {
+    pub fn markdown(text: impl Into<String>) -> Self {
+        RichText::Markdown(text.into())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.to_plain_text().trim().is_empty()
+    }
+
+    pub fn to_markdown(&self) -> String {
+        match self {
+            RichText::Markdown(text) => text.clone(),
+            other => render_markdown(&other.blocks()),
+        }
+    }
+
+    pub fn to_html(&self) -> String {
+        match self {
+            RichText::Html(html) => html.clone(),
+            other => render_html(&other.blocks()),
+        }
+    }
+
+    pub fn to_adf(&self) -> Value {
+        match self {
+            RichText::Adf(doc) => doc.clone(),
+            other => render_adf(&other.blocks()),
+        }
+    }
+
+    /// Text without formatting, one line per block; for search and previews.
+    pub fn to_plain_text(&self) -> String {
+        let mut lines = Vec::new();
+        collect_plain_text(&self.blocks(), &mut lines);
+        lines.join("\n")
+    }
+
+    fn blocks(&self) -> Vec<Block> {
+        match self {
+            RichText::Markdown(text) => parse_markdown(text),
+            RichText::Html(html) => parse_html(html),
+            RichText::Adf(doc) => parse_adf_blocks(&doc["content"]),
+        }
+    }
+}
+
+impl From<String> for RichText {
+    fn from(text: String) -> Self {
+        RichText::Markdown(text)
+    }
+}
+
+impl From<&str> for RichText {
+    fn from(text: &str) -> Self {
+        RichText::Markdown(text.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Block {
+    Heading(u8, Vec<Inline>),
+    Paragraph(Vec<Inline>),
+    Code { language: Option<String>, code: String },
+    List { ordered: bool, items: Vec<Vec<Block>> },
+    Quote(Vec<Block>),
+    Rule,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Inline {
+    Text(String),
+    Code(String),
+    Strong(Vec<Inline>),
+    Emphasis(Vec<Inline>),
+    Link { href: String, children: Vec<Inline> },
+    Break,
+}
+
+// ---- Markdown ----
+
+fn list_marker(line: &str) -> Option<(bool, usize)> {
+    if line.starts_with("- ") || line.starts_with("* ") || line.starts_with("+ ") {
+        return Some((false, 2));
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 && line[digits..].starts_with(". ") {
+        return Some((true, digits + 2));
+    }
+    None
+}
+
+fn is_rule(line: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3 && ["-", "*", "_"].iter().any(|c| compact.chars().all(|ch| ch.to_string() == *c))
+}
+
+fn parse_markdown(text: &str) -> Vec<Block> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut i = 0;
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>| {
+        if !paragraph.is_empty() {
+            let mut inlines = Vec::new();
+            for (index, line) in paragraph.iter().enumerate() {
+                if index > 0 {
+                    inlines.push(Inline::Break);
+                }
+                inlines.extend(parse_inlines(line.trim()));
+            }
+            blocks.push(Block::Paragraph(inlines));
+            paragraph.clear();
+        }
+    };
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim_start();
+
+        if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+            i += 1;
+            continue;
+        }
+
+        if let Some(fence) = ["```", "~~~"].into_iter().find(|f| trimmed.starts_with(f)) {
+            flush(&mut paragraph, &mut blocks);
+            let language = trimmed[3..].trim();
+            let mut code = Vec::new();
+            i += 1;
+            while i < lines.len() && !lines[i].trim_start().starts_with(fence) {
+                code.push(lines[i]);
+                i += 1;
+            }
+            blocks.push(Block::Code {
+                language: (!language.is_empty()).then(|| language.to_string()),
+                code: code.join("\n"),
+            });
+            i += 1;
+            continue;
+        }
+
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Heading(level as u8, parse_inlines(trimmed[level..].trim())));
+            i += 1;
+            continue;
+        }
+
+        if is_rule(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Rule);
+            i += 1;
+            continue;
+        }
+
+        if trimmed.starts_with('>') {
+            flush(&mut paragraph, &mut blocks);
+            let mut quoted = Vec::new();
+            while i < lines.len() && lines[i].trim_start().starts_with('>') {
+                let inner = &lines[i].trim_start()[1..];
+                quoted.push(inner.strip_prefix(' ').unwrap_or(inner));
+                i += 1;
+            }
+            blocks.push(Block::Quote(parse_markdown(&quoted.join("\n"))));
+            continue;
+        }
+
+        if let Some((ordered, _)) = list_marker(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            let indent = line.len() - trimmed.len();
+            let mut items = Vec::new();
+
+            while i < lines.len() {
+                let item_line = lines[i];
+                let item_trimmed = item_line.trim_start();
+                let item_indent = item_line.len() - item_trimmed.len();
+                let Some((item_ordered, width)) = list_marker(item_trimmed) else { break };
+                if item_indent != indent || item_ordered != ordered {
+                    break;
+                }
+
+                // Continuation lines are indented past the marker
+                let mut body = vec![item_trimmed[width..].to_string()];
+                i += 1;
+                while i < lines.len() {
+                    let next = lines[i];
+                    let next_indent = next.len() - next.trim_start().len();
+                    if next.trim().is_empty() {
+                        let continues = lines.get(i + 1)
+                            .is_some_and(|after| after.len() - after.trim_start().len() > indent);
+                        if !continues {
+                            break;
+                        }
+                        body.push(String::new());
+                    } else if next_indent > indent {
+                        body.push(next.chars().skip((indent + width).min(next_indent)).collect());
+                    } else {
+                        break;
+                    }
+                    i += 1;
+                }
+                items.push(parse_markdown(&body.join("\n")));
+            }
+
+            blocks.push(Block::List { ordered, items });
+            continue;
+        }
+
+        paragraph.push(line);
+        i += 1;
+    }
+
+    flush(&mut paragraph, &mut blocks);
+    blocks
+}
+
+fn parse_inlines(text: &str) -> Vec<Inline> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut inlines = Vec::new();
+    let mut buffer = String::new();
+    let mut i = 0;
+
+    let find = |from: usize, pattern: &[char]| -> Option<usize> {
+        (from..chars.len().saturating_sub(pattern.len() - 1))
+            .find(|&j| chars[j..j + pattern.len()] == *pattern)
+    };
+    let flush = |buffer: &mut String, inlines: &mut Vec<Inline>| {
+        if !buffer.is_empty() {
+            inlines.push(Inline::Text(std::mem::take(buffer)));
+        }
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '\\' && i + 1 < chars.len() {
+            buffer.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+
+        if c == '`' {
+            if let Some(end) = find(i + 1, &['`']) {
+                flush(&mut buffer, &mut inlines);
+                inlines.push(Inline::Code(chars[i + 1..end].iter().collect()));
+                i = end + 1;
+                continue;
+            }
+        }
+
+        if (c == '*' || c == '_') && chars.get(i + 1) == Some(&c) {
+            if let Some(end) = find(i + 2, &[c, c]) {
+                flush(&mut buffer, &mut inlines);
+                let inner: String = chars[i + 2..end].iter().collect();
+                inlines.push(Inline::Strong(parse_inlines(&inner)));
+                i = end + 2;
+                continue;
+            }
+        }
+
+        let word_start = i == 0 || !chars[i - 1].is_alphanumeric();
+        if (c == '*' || (c == '_' && word_start)) && chars.get(i + 1).is_some_and(|n| !n.is_whitespace()) {
+            // Never empty, so an unclosed `**` stays text
+            if let Some(end) = find(i + 2, &[c]) {
+                flush(&mut buffer, &mut inlines);
+                let inner: String = chars[i + 1..end].iter().collect();
+                inlines.push(Inline::Emphasis(parse_inlines(&inner)));
+                i = end + 1;
+                continue;
+            }
+        }
+
+        if c == '[' {
+            if let Some(close) = find(i + 1, &[']', '(']) {
+                if let Some(end) = find(close + 2, &[')']) {
+                    flush(&mut buffer, &mut inlines);
+                    let label: String = chars[i + 1..close].iter().collect();
+                    inlines.push(Inline::Link {
+                        href: chars[close + 2..end].iter().collect(),
+                        children: parse_inlines(&label),
+                    });
+                    i = end + 1;
+                    continue;
+                }
+            }
+        }
+
+        buffer.push(c);
+        i += 1;
+    }
+
+    flush(&mut buffer, &mut inlines);
+    inlines
+}
+
+fn render_markdown_inlines(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => text.clone(),
+            Inline::Code(code) => format!("`{}`", code),
+            Inline::Strong(children) => format!("**{}**", render_markdown_inlines(children)),
+            Inline::Emphasis(children) => format!("*{}*", render_markdown_inlines(children)),
+            Inline::Link { href, children } => format!("[{}]({})", render_markdown_inlines(children), href),
+            Inline::Break => "\n".to_string(),
+        })
+        .collect()
+}
+
+fn render_markdown(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(|block| match block {
+            Block::Heading(level, inlines) => format!("{} {}", "#".repeat(*level as usize), render_markdown_inlines(inlines)),
+            Block::Paragraph(inlines) => render_markdown_inlines(inlines),
+            Block::Code { language, code } => format!("```{}\n{}\n```", language.as_deref().unwrap_or(""), code),
+            Block::Rule => "---".to_string(),
+            Block::Quote(children) => render_markdown(children)
+                .lines()
+                .map(|line| if line.is_empty() { ">".to_string() } else { format!("> {}", line) })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Block::List { ordered, items } => items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    let marker = if *ordered { format!("{}. ", index + 1) } else { "- ".to_string() };
+                    let padding = " ".repeat(marker.len());
+                    render_list_item(item)
+                        .lines()
+                        .enumerate()
+                        .map(|(n, line)| match (n, line.is_empty()) {
+                            (0, _) => format!("{}{}", marker, line),
+                            (_, true) => String::new(),
+                            _ => format!("{}{}", padding, line),
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// A list item's blocks, with nested lists kept tight against the text
+/// before them.
+fn render_list_item(blocks: &[Block]) -> String {
+    let mut rendered = String::new();
+    for (index, block) in blocks.iter().enumerate() {
+        if index > 0 {
+            rendered.push_str(if matches!(block, Block::List { .. }) { "\n" } else { "\n\n" });
+        }
+        rendered.push_str(&render_markdown(std::slice::from_ref(block)));
+    }
+    rendered
+}
+
+// ---- HTML ----
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn unescape_html(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn render_html_inlines(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => escape_html(text),
+            Inline::Code(code) => format!("<code>{}</code>", escape_html(code)),
+            Inline::Strong(children) => format!("<strong>{}</strong>", render_html_inlines(children)),
+            Inline::Emphasis(children) => format!("<em>{}</em>", render_html_inlines(children)),
+            Inline::Link { href, children } => format!("<a href=\"{}\">{}</a>", escape_html(href), render_html_inlines(children)),
+            Inline::Break => "<br>".to_string(),
+        })
+        .collect()
+}
+
+fn render_html(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(|block| match block {
+            Block::Heading(level, inlines) => format!("<h{0}>{1}</h{0}>", level, render_html_inlines(inlines)),
+            Block::Paragraph(inlines) => format!("<p>{}</p>", render_html_inlines(inlines)),
+            Block::Code { language, code } => match language {
+                Some(language) => format!("<pre><code class=\"language-{}\">{}</code></pre>", escape_html(language), escape_html(code)),
+                None => format!("<pre><code>{}</code></pre>", escape_html(code)),
+            },
+            Block::Rule => "<hr>".to_string(),
+            Block::Quote(children) => format!("<blockquote>{}</blockquote>", render_html(children)),
+            Block::List { ordered, items } => {
+                let tag = if *ordered { "ol" } else { "ul" };
+                let items: String = items.iter().map(|item| format!("<li>{}</li>", render_html(item))).collect();
+                format!("<{0}>{1}</{0}>", tag, items)
+            }
+        })
+        .collect()
+}
+
+/// A lenient HTML element tree; enough for the tags descriptions use.
+#[derive(Debug, Clone)]
+enum HtmlNode {
+    Element { tag: String, attrs: Vec<(String, String)>, children: Vec<HtmlNode> },
+    Text(String),
+}
+
+/// An element still waiting for its closing tag: name, attributes, children.
+type OpenElement = (String, Vec<(String, String)>, Vec<HtmlNode>);
+
+const VOID_TAGS: [&str; 5] = ["br", "hr", "img", "input", "meta"];
+
+fn parse_attrs(source: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let mut rest = source;
+    while let Some(eq) = rest.find('=') {
+        let name = rest[..eq].split_whitespace().last().unwrap_or_default().to_lowercase();
+        let after = rest[eq + 1..].trim_start();
+        let (value, remaining) = match after.chars().next() {
+            Some(quote @ ('"' | '\'')) => match after[1..].find(quote) {
+                Some(end) => (&after[1..end + 1], &after[end + 2..]),
+                None => (&after[1..], ""),
+            },
+            _ => {
+                let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                (&after[..end], &after[end..])
+            }
+        };
+        attrs.push((name, unescape_html(value)));
+        rest = remaining;
+    }
+    attrs
+}
+
+fn parse_html_tree(html: &str) -> Vec<HtmlNode> {
+    // Stack of open elements; index 0 is the synthetic root
+    let mut stack: Vec<OpenElement> = vec![(String::new(), Vec::new(), Vec::new())];
+    let mut rest = html;
+
+    let close = |stack: &mut Vec<OpenElement>| {
+        let (tag, attrs, children) = stack.pop().expect("root is never closed");
+        stack.last_mut().expect("root is never closed").2.push(HtmlNode::Element { tag, attrs, children });
+    };
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            stack.last_mut().expect("root").2.push(HtmlNode::Text(unescape_html(rest)));
+            break;
+        };
+        if start > 0 {
+            stack.last_mut().expect("root").2.push(HtmlNode::Text(unescape_html(&rest[..start])));
+        }
+        let Some(end) = rest[start..].find('>') else {
+            stack.last_mut().expect("root").2.push(HtmlNode::Text(unescape_html(&rest[start..])));
+            break;
+        };
+        let tag_source = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        if tag_source.starts_with('!') || tag_source.starts_with('?') {
+            continue;
+        }
+
+        if let Some(name) = tag_source.strip_prefix('/') {
+            let name = name.trim().to_lowercase();
+            if let Some(position) = stack.iter().rposition(|(tag, _, _)| *tag == name) {
+                if position > 0 {
+                    while stack.len() > position {
+                        close(&mut stack);
+                    }
+                }
+            }
+            continue;
+        }
+
+        let self_closing = tag_source.ends_with('/');
+        let tag_source = tag_source.trim_end_matches('/');
+        let name_end = tag_source.find(char::is_whitespace).unwrap_or(tag_source.len());
+        let name = tag_source[..name_end].to_lowercase();
+        let attrs = parse_attrs(&tag_source[name_end..]);
+
+        // As in HTML, a new item ends the previous one left open in the same list
+        if name == "li" {
+            let list = stack.iter().rposition(|(tag, _, _)| tag == "ul" || tag == "ol").unwrap_or(0);
+            if let Some(position) = stack.iter().rposition(|(tag, _, _)| tag == "li").filter(|&p| p > list) {
+                while stack.len() > position {
+                    close(&mut stack);
+                }
+            }
+        }
+
+        if self_closing || VOID_TAGS.contains(&name.as_str()) {
+            stack.last_mut().expect("root").2.push(HtmlNode::Element { tag: name, attrs, children: Vec::new() });
+        } else {
+            stack.push((name, attrs, Vec::new()));
+        }
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+    stack.pop().map(|(_, _, children)| children).unwrap_or_default()
+}
+
+fn html_text(nodes: &[HtmlNode]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            HtmlNode::Text(text) => text.clone(),
+            HtmlNode::Element { tag, .. } if tag == "br" => "\n".to_string(),
+            HtmlNode::Element { children, .. } => html_text(children),
+        })
+        .collect()
+}
+
+fn html_inlines(nodes: &[HtmlNode]) -> Vec<Inline> {
+    let mut inlines = Vec::new();
+    for node in nodes {
+        match node {
+            HtmlNode::Text(text) => {
+                let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                let leading = text.starts_with(char::is_whitespace) && !collapsed.is_empty();
+                let trailing = text.ends_with(char::is_whitespace) && !collapsed.is_empty();
+                let text = format!("{}{}{}", if leading { " " } else { "" }, collapsed, if trailing { " " } else { "" });
+                if !text.is_empty() {
+                    inlines.push(Inline::Text(text));
+                }
+            }
+            HtmlNode::Element { tag, attrs, children } => match tag.as_str() {
+                "strong" | "b" => inlines.push(Inline::Strong(html_inlines(children))),
+                "em" | "i" => inlines.push(Inline::Emphasis(html_inlines(children))),
+                "code" => inlines.push(Inline::Code(html_text(children))),
+                "br" => inlines.push(Inline::Break),
+                "a" => match attrs.iter().find(|(name, _)| name == "href") {
+                    Some((_, href)) => inlines.push(Inline::Link { href: href.clone(), children: html_inlines(children) }),
+                    None => inlines.extend(html_inlines(children)),
+                },
+                "img" => {
+                    if let Some((_, alt)) = attrs.iter().find(|(name, _)| name == "alt") {
+                        inlines.push(Inline::Text(alt.clone()));
+                    }
+                }
+                _ => inlines.extend(html_inlines(children)),
+            },
+        }
+    }
+    inlines
+}
+
+fn is_html_block(node: &HtmlNode) -> bool {
+    matches!(node, HtmlNode::Element { tag, .. } if matches!(
+        tag.as_str(),
+        "p" | "div" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "pre" | "ul" | "ol" | "blockquote" | "hr" | "table" | "section" | "article"
+    ))
+}
+
+fn html_blocks(nodes: &[HtmlNode]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut pending: Vec<&HtmlNode> = Vec::new();
+
+    let flush = |pending: &mut Vec<&HtmlNode>, blocks: &mut Vec<Block>| {
+        if pending.is_empty() {
+            return;
+        }
+        let nodes: Vec<HtmlNode> = pending.drain(..).cloned().collect();
+        let inlines = trim_inlines(html_inlines(&nodes));
+        if !inlines.is_empty() {
+            blocks.push(Block::Paragraph(inlines));
+        }
+    };
+
+    for node in nodes {
+        if !is_html_block(node) {
+            pending.push(node);
+            continue;
+        }
+        flush(&mut pending, &mut blocks);
+
+        let HtmlNode::Element { tag, attrs: _, children } = node else { continue };
+        match tag.as_str() {
+            "p" => {
+                let inlines = trim_inlines(html_inlines(children));
+                if !inlines.is_empty() {
+                    blocks.push(Block::Paragraph(inlines));
+                }
+            }
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = tag[1..].parse().unwrap_or(1);
+                blocks.push(Block::Heading(level, trim_inlines(html_inlines(children))));
+            }
+            "pre" => {
+                let language = children.iter().find_map(|child| match child {
+                    HtmlNode::Element { tag, attrs, .. } if tag == "code" => attrs
+                        .iter()
+                        .find(|(name, _)| name == "class")
+                        .and_then(|(_, class)| class.split_whitespace().find_map(|c| c.strip_prefix("language-")))
+                        .map(|l| l.to_string()),
+                    _ => None,
+                });
+                blocks.push(Block::Code { language, code: html_text(children).trim_end_matches('\n').to_string() });
+            }
+            "ul" | "ol" => {
+                let items = children
+                    .iter()
+                    .filter_map(|child| match child {
+                        HtmlNode::Element { tag, children, .. } if tag == "li" => Some(html_blocks(children)),
+                        _ => None,
+                    })
+                    .collect();
+                blocks.push(Block::List { ordered: tag == "ol", items });
+            }
+            "blockquote" => blocks.push(Block::Quote(html_blocks(children))),
+            "hr" => blocks.push(Block::Rule),
+            _ => blocks.extend(html_blocks(children)),
+        }
+    }
+
+    flush(&mut pending, &mut blocks);
+    blocks
+}
+
+fn trim_inlines(mut inlines: Vec<Inline>) -> Vec<Inline> {
+    if let Some(Inline::Text(text)) = inlines.first_mut() {
+        *text = text.trim_start().to_string();
+    }
+    if let Some(Inline::Text(text)) = inlines.last_mut() {
+        *text = text.trim_end().to_string();
+    }
+    inlines.retain(|inline| !matches!(inline, Inline::Text(text) if text.is_empty()));
+    inlines
+}
+
+fn parse_html(html: &str) -> Vec<Block> {
+    html_blocks(&parse_html_tree(html))
+}
+
+// ---- Atlassian Document Format ----
+
+fn adf_text_nodes(inlines: &[Inline], marks: &[Value], out: &mut Vec<Value>) {
+    for inline in inlines {
+        let with = |mark: Value| {
+            let mut marks = marks.to_vec();
+            marks.push(mark);
+            marks
+        };
+        match inline {
+            Inline::Text(text) => {
+                let mut node = json!({ "type": "text", "text": text });
+                if !marks.is_empty() {
+                    node["marks"] = json!(marks);
+                }
+                out.push(node);
+            }
+            Inline::Code(code) => adf_text_nodes(&[Inline::Text(code.clone())], &with(json!({ "type": "code" })), out),
+            Inline::Strong(children) => adf_text_nodes(children, &with(json!({ "type": "strong" })), out),
+            Inline::Emphasis(children) => adf_text_nodes(children, &with(json!({ "type": "em" })), out),
+            Inline::Link { href, children } => {
+                adf_text_nodes(children, &with(json!({ "type": "link", "attrs": { "href": href } })), out)
+            }
+            Inline::Break => out.push(json!({ "type": "hardBreak" })),
+        }
+    }
+}
+
+fn adf_blocks(blocks: &[Block]) -> Vec<Value> {
+    blocks
+        .iter()
+        .map(|block| match block {
+            Block::Heading(level, inlines) => {
+                let mut content = Vec::new();
+                adf_text_nodes(inlines, &[], &mut content);
+                json!({ "type": "heading", "attrs": { "level": level }, "content": content })
+            }
+            Block::Paragraph(inlines) => {
+                let mut content = Vec::new();
+                adf_text_nodes(inlines, &[], &mut content);
+                json!({ "type": "paragraph", "content": content })
+            }
+            Block::Code { language, code } => {
+                let mut node = json!({ "type": "codeBlock", "content": [{ "type": "text", "text": code }] });
+                if let Some(language) = language {
+                    node["attrs"] = json!({ "language": language });
+                }
+                node
+            }
+            Block::Rule => json!({ "type": "rule" }),
+            Block::Quote(children) => json!({ "type": "blockquote", "content": adf_blocks(children) }),
+            Block::List { ordered, items } => json!({
+                "type": if *ordered { "orderedList" } else { "bulletList" },
+                "content": items
+                    .iter()
+                    .map(|item| json!({ "type": "listItem", "content": adf_blocks(item) }))
+                    .collect::<Vec<_>>()
+            }),
+        })
+        .collect()
+}
+
+fn render_adf(blocks: &[Block]) -> Value {
+    json!({ "type": "doc", "version": 1, "content": adf_blocks(blocks) })
+}
+
+fn parse_adf_inlines(content: &Value) -> Vec<Inline> {
+    content
+        .as_array()
+        .unwrap_or(&Vec::new())
+        .iter()
+        .filter_map(|node| match node["type"].as_str()? {
+            "text" => {
+                let text = node["text"].as_str().unwrap_or_default().to_string();
+                let marks = node["marks"].as_array().cloned().unwrap_or_default();
+                if marks.iter().any(|m| m["type"] == "code") {
+                    return Some(Inline::Code(text));
+                }
+
+                let mut inline = Inline::Text(text);
+                for mark in &marks {
+                    inline = match mark["type"].as_str() {
+                        Some("strong") => Inline::Strong(vec![inline]),
+                        Some("em") => Inline::Emphasis(vec![inline]),
+                        Some("link") => Inline::Link {
+                            href: mark["attrs"]["href"].as_str().unwrap_or_default().to_string(),
+                            children: vec![inline],
+                        },
+                        _ => inline,
+                    };
+                }
+                Some(inline)
+            }
+            "hardBreak" => Some(Inline::Break),
+            "mention" | "emoji" | "status" | "date" => node["attrs"]["text"].as_str()
+                .or(node["attrs"]["shortName"].as_str())
+                .map(|text| Inline::Text(text.to_string())),
+            "inlineCard" => node["attrs"]["url"].as_str().map(|url| Inline::Link {
+                href: url.to_string(),
+                children: vec![Inline::Text(url.to_string())],
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+fn adf_plain_text(node: &Value) -> String {
+    match node["text"].as_str() {
+        Some(text) => text.to_string(),
+        None => node["content"]
+            .as_array()
+            .unwrap_or(&Vec::new())
+            .iter()
+            .map(adf_plain_text)
+            .collect(),
+    }
+}
+
+fn parse_adf_blocks(content: &Value) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    for node in content.as_array().unwrap_or(&Vec::new()) {
+        match node["type"].as_str().unwrap_or_default() {
+            "paragraph" => blocks.push(Block::Paragraph(parse_adf_inlines(&node["content"]))),
+            "heading" => blocks.push(Block::Heading(
+                node["attrs"]["level"].as_u64().unwrap_or(1).clamp(1, 6) as u8,
+                parse_adf_inlines(&node["content"]),
+            )),
+            "codeBlock" => blocks.push(Block::Code {
+                language: node["attrs"]["language"].as_str().map(|l| l.to_string()),
+                code: adf_plain_text(node),
+            }),
+            "bulletList" | "orderedList" => blocks.push(Block::List {
+                ordered: node["type"] == "orderedList",
+                items: node["content"]
+                    .as_array()
+                    .unwrap_or(&Vec::new())
+                    .iter()
+                    .map(|item| parse_adf_blocks(&item["content"]))
+                    .collect(),
+            }),
+            "blockquote" => blocks.push(Block::Quote(parse_adf_blocks(&node["content"]))),
+            "rule" => blocks.push(Block::Rule),
+            // Panels, tables, expands and the like keep their content
+            _ if node["content"].is_array() => {
+                let inner = parse_adf_blocks(&node["content"]);
+                if inner.is_empty() {
+                    let text = adf_plain_text(node);
+                    if !text.trim().is_empty() {
+                        blocks.push(Block::Paragraph(vec![Inline::Text(text)]));
+                    }
+                } else {
+                    blocks.extend(inner);
+                }
+            }
+            _ => {}
+        }
+    }
+    blocks
+}
+
+// ---- Plain text ----
+
+fn inline_text(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) | Inline::Code(text) => text.clone(),
+            Inline::Strong(children) | Inline::Emphasis(children) | Inline::Link { children, .. } => inline_text(children),
+            Inline::Break => "\n".to_string(),
+        })
+        .collect()
+}
+
+fn collect_plain_text(blocks: &[Block], lines: &mut Vec<String>) {
+    for block in blocks {
+        match block {
+            Block::Heading(_, inlines) | Block::Paragraph(inlines) => lines.push(inline_text(inlines)),
+            Block::Code { code, .. } => lines.push(code.clone()),
+            Block::List { items, .. } => items.iter().for_each(|item| collect_plain_text(item, lines)),
+            Block::Quote(children) => collect_plain_text(children, lines),
+            Block::Rule => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(text: &str) -> Inline {
+        Inline::Text(text.to_string())
+    }
+
+    fn paragraph(content: &str) -> Block {
+        Block::Paragraph(vec![text(content)])
+    }
+
+    /// Markdown in the form `render_markdown` writes, so it survives a
+    /// round trip unchanged.
+    const DOCUMENT: &str = "\
+# Fix **IPMI** auth
+
+The BMC rejects *rotated* passwords; see [the runbook](https://example.com/runbook) and `ipmitool`.
+Second line of the paragraph.
+
+- Check the firmware
+  - On `gpu-01`
+  - On `gpu-02`
+- Rotate again
+
+1. Drain the node
+2. Reboot
+
+```bash
+ipmitool -I lanplus user list
+# not a heading
+```
+
+> Quoted **note**
+>
+> - with a list
+
+---";
+
+    #[test]
+    fn markdown_round_trips() {
+        assert_eq!(RichText::markdown(DOCUMENT).to_html(), RichText::Html(RichText::markdown(DOCUMENT).to_html()).to_html());
+        assert_eq!(render_markdown(&parse_markdown(DOCUMENT)), DOCUMENT);
+    }
+
+    #[test]
+    fn html_round_trips() {
+        let html = RichText::markdown(DOCUMENT).to_html();
+        assert_eq!(RichText::Html(html).to_markdown(), DOCUMENT);
+    }
+
+    #[test]
+    fn adf_round_trips() {
+        let adf = RichText::markdown(DOCUMENT).to_adf();
+        assert_eq!(adf["type"], "doc");
+        assert_eq!(RichText::Adf(adf).to_markdown(), DOCUMENT);
+    }
+
+    #[test]
+    fn parses_nested_lists() {
+        let blocks = parse_markdown("- a\n  - b\n  - c\n- d");
+        assert_eq!(blocks, vec![Block::List {
+            ordered: false,
+            items: vec![
+                vec![paragraph("a"), Block::List { ordered: false, items: vec![vec![paragraph("b")], vec![paragraph("c")]] }],
+                vec![paragraph("d")],
+            ],
+        }]);
+    }
+
+    #[test]
+    fn parses_ordered_lists_inside_bullets() {
+        let blocks = parse_markdown("* a\n  1. b\n  2. c");
+        assert_eq!(blocks, vec![Block::List {
+            ordered: false,
+            items: vec![vec![
+                paragraph("a"),
+                Block::List { ordered: true, items: vec![vec![paragraph("b")], vec![paragraph("c")]] },
+            ]],
+        }]);
+    }
+
+    #[test]
+    fn keeps_loose_list_items_together() {
+        let blocks = parse_markdown("- a\n\n  more of a\n- b");
+        assert_eq!(blocks, vec![Block::List {
+            ordered: false,
+            items: vec![vec![paragraph("a"), paragraph("more of a")], vec![paragraph("b")]],
+        }]);
+    }
+
+    #[test]
+    fn starts_a_new_list_when_the_marker_kind_changes() {
+        let blocks = parse_markdown("1. a\n- b");
+        assert_eq!(blocks, vec![
+            Block::List { ordered: true, items: vec![vec![paragraph("a")]] },
+            Block::List { ordered: false, items: vec![vec![paragraph("b")]] },
+        ]);
+    }
+
+    #[test]
+    fn keeps_code_fences_verbatim() {
+        let blocks = parse_markdown("```rust\nlet x = **y**;\n\n- not a list\n```\nafter");
+        assert_eq!(blocks, vec![
+            Block::Code { language: Some("rust".to_string()), code: "let x = **y**;\n\n- not a list".to_string() },
+            paragraph("after"),
+        ]);
+        let blocks = parse_markdown("~~~\n```\n~~~");
+        assert_eq!(blocks, vec![Block::Code { language: None, code: "```".to_string() }]);
+    }
+
+    #[test]
+    fn escapes_code_in_html() {
+        let html = RichText::markdown("```html\n<b>&</b>\n```").to_html();
+        assert_eq!(html, "<pre><code class=\"language-html\">&lt;b&gt;&amp;&lt;/b&gt;</code></pre>");
+        assert_eq!(RichText::Html(html).to_markdown(), "```html\n<b>&</b>\n```");
+    }
+
+    #[test]
+    fn parses_inline_marks() {
+        assert_eq!(parse_inlines("a **b** *c* `d` [e](f) _g_ snake_case_word"), vec![
+            text("a "),
+            Inline::Strong(vec![text("b")]),
+            text(" "),
+            Inline::Emphasis(vec![text("c")]),
+            text(" "),
+            Inline::Code("d".to_string()),
+            text(" "),
+            Inline::Link { href: "f".to_string(), children: vec![text("e")] },
+            text(" "),
+            Inline::Emphasis(vec![text("g")]),
+            text(" snake_case_word"),
+        ]);
+    }
+
+    #[test]
+    fn nests_inline_marks() {
+        assert_eq!(parse_inlines("**bold *and italic* too**"), vec![
+            Inline::Strong(vec![text("bold "), Inline::Emphasis(vec![text("and italic")]), text(" too")]),
+        ]);
+        assert_eq!(parse_inlines("[**bold** link](https://example.com)"), vec![Inline::Link {
+            href: "https://example.com".to_string(),
+            children: vec![Inline::Strong(vec![text("bold")]), text(" link")],
+        }]);
+        // Code spans keep their markers
+        assert_eq!(parse_inlines("`**x**`"), vec![Inline::Code("**x**".to_string())]);
+    }
+
+    #[test]
+    fn honours_backslash_escapes() {
+        assert_eq!(parse_inlines(r"\*not emphasis\* and \`not code\`"), vec![text("*not emphasis* and `not code`")]);
+    }
+
+    #[test]
+    fn treats_unclosed_markdown_as_text() {
+        for input in ["**bold", "**", "*emphasis", "`code", "[label](", "[label]", "* ", "a * b", "\\"] {
+            assert_eq!(parse_inlines(input), vec![text(input)], "{:?}", input);
+        }
+        assert_eq!(parse_inlines("**bold*"), vec![Inline::Emphasis(vec![text("*bold")])]);
+    }
+
+    #[test]
+    fn handles_malformed_markdown_blocks() {
+        // Seven hashes, or none followed by a space, are not headings
+        assert_eq!(parse_markdown("####### seven"), vec![paragraph("####### seven")]);
+        assert_eq!(parse_markdown("#tag"), vec![paragraph("#tag")]);
+        // An unterminated fence runs to the end
+        assert_eq!(parse_markdown("```\nno end"), vec![Block::Code { language: None, code: "no end".to_string() }]);
+        assert_eq!(parse_markdown("- "), vec![Block::List { ordered: false, items: vec![vec![]] }]);
+        assert_eq!(parse_markdown(""), vec![]);
+        assert_eq!(parse_markdown("\n\n  \n"), vec![]);
+        // Multi-byte text next to markers
+        assert_eq!(parse_inlines("é**ü**ß"), vec![text("é"), Inline::Strong(vec![text("ü")]), text("ß")]);
+    }
+
+    #[test]
+    fn recovers_from_malformed_html() {
+        assert_eq!(parse_html("<p>a <strong>b"), vec![Block::Paragraph(vec![text("a "), Inline::Strong(vec![text("b")])])]);
+        assert_eq!(parse_html("</div>text</span>"), vec![paragraph("text")]);
+        assert_eq!(RichText::Html("a <b".to_string()).to_markdown(), "a <b");
+        assert_eq!(parse_html("<!-- comment --><p>x</p>"), vec![paragraph("x")]);
+        assert_eq!(parse_html("<a>no href</a>"), vec![paragraph("no href")]);
+        assert_eq!(parse_html("<p>a&nbsp;&amp;&lt;b&gt;</p>"), vec![paragraph("a &<b>")]);
+        assert_eq!(parse_html("<ul><li>one<li>two<ol><li>three<li>four</ol></ul>"), vec![Block::List {
+            ordered: false,
+            items: vec![
+                vec![paragraph("one")],
+                vec![paragraph("two"), Block::List { ordered: true, items: vec![vec![paragraph("three")], vec![paragraph("four")]] }],
+            ],
+        }]);
+    }
+
+    #[test]
+    fn reads_html_attributes_in_any_quoting() {
+        assert_eq!(parse_attrs(r#" href="a b" class='c' data-x=y"#), vec![
+            ("href".to_string(), "a b".to_string()),
+            ("class".to_string(), "c".to_string()),
+            ("data-x".to_string(), "y".to_string()),
+        ]);
+        assert_eq!(parse_attrs(r#" href="unterminated"#), vec![("href".to_string(), "unterminated".to_string())]);
+    }
+
+    #[test]
+    fn ignores_malformed_adf() {
+        assert!(RichText::Adf(json!({})).to_markdown().is_empty());
+        assert!(RichText::Adf(json!({ "content": "not an array" })).to_markdown().is_empty());
+        assert!(RichText::Adf(json!({ "content": [{ "type": "paragraph" }] })).is_empty());
+        let doc = json!({ "content": [
+            { "type": "panel", "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": "in a panel" }] }] },
+            { "type": "mediaSingle" },
+            { "type": "paragraph", "content": [
+                { "type": "text", "text": "x", "marks": [{ "type": "strong" }, { "type": "unknown" }] },
+                { "type": "mention", "attrs": { "text": "@ann" } },
+                { "type": "inlineCard", "attrs": { "url": "https://example.com" } },
+            ] },
+        ] });
+        assert_eq!(RichText::Adf(doc).to_markdown(), "in a panel\n\n**x**@ann[https://example.com](https://example.com)");
+    }
+
+    #[test]
+    fn flattens_to_plain_text() {
+        let plain = RichText::markdown("# Title\n\n- **a**\n- [b](c)\n\n```\ncode\n```\n\n---").to_plain_text();
+        assert_eq!(plain, "Title\na\nb\ncode");
+        assert!(RichText::markdown("  \n\n").is_empty());
+        assert!(RichText::Html("<p> </p>".to_string()).is_empty());
+    }
+
+    #[test]
+    fn serializes_with_its_format() {
+        let value = serde_json::to_value(RichText::markdown("x")).unwrap();
+        assert_eq!(value, json!({ "format": "markdown", "content": "x" }));
+        assert_eq!(serde_json::from_value::<RichText>(value).unwrap(), RichText::markdown("x"));
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::TicketFilter;
+
+/// A named, reusable ticket query such as "on-call queue".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedView {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub filter: TicketFilter,
+    #[serde(default)]
+    pub sort: Option<TicketSort>,
+    /// Maximum number of tickets to return after sorting.
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketSort {
+    pub field: SortField,
+    #[serde(default)]
+    pub descending: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortField {
+    Priority,
+    CreatedAt,
+    UpdatedAt,
+    DueDate,
+    Title,
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Service-level deadlines on a ticket (Zendesk, Jira Service Management,
+/// Linear triage SLAs). Providers clear a deadline once it has been met.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Sla {
+    pub started_at: Option<DateTime<Utc>>,
+    pub first_response_due: Option<DateTime<Utc>>,
+    pub resolution_due: Option<DateTime<Utc>>,
+    /// Set by providers that track breaches themselves.
+    pub breached: bool,
+}
+
+impl Sla {
+    pub fn is_breached(&self, now: DateTime<Utc>) -> bool {
+        self.breached || self.next_due().is_some_and(|due| due <= now)
+    }
+
+    /// The earliest pending deadline.
+    pub fn next_due(&self) -> Option<DateTime<Utc>> {
+        match (self.first_response_due, self.resolution_due) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::Priority;
+
+/// A reusable ticket shape. `title` and `body` may contain `{{variable}}`
+/// placeholders that are filled in when a ticket is created from it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketTemplate {
+    pub name: String,
+    pub title: String,
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Label names or ids.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub priority: Option<Priority>,
+    #[serde(default)]
+    pub estimate: Option<f32>,
+    /// Team id or key; falls back to the server's default team.
+    #[serde(default)]
+    pub team: Option<String>,
+}
@@ -0,0 +1,179 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::domain::{Attachment, Reaction, RichText, Sla, TicketRelations};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ticket {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub description: Option<RichText>,
+    pub priority: Priority,
+    pub state: State,
+    pub assignee_id: Option<String>,
+    pub creator_id: String,
+    pub project_id: Option<String>,
+    pub cycle_id: Option<String>,
+    pub labels: Vec<String>,
+    pub watcher_ids: Vec<String>,
+    /// Only populated when a single ticket is fetched in detail.
+    pub attachments: Vec<Attachment>,
+    pub relations: TicketRelations,
+    pub reactions: Vec<Reaction>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Set once the ticket is archived; archived tickets only show up in
+    /// searches that ask for them.
+    pub archived_at: Option<DateTime<Utc>>,
+    /// When a snoozed ticket returns to the triage queue.
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub estimate: Option<f32>,
+    pub sla: Option<Sla>,
+    pub url: String,
+    pub custom_fields: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct State {
+    pub id: String,
+    pub name: String,
+    pub type_: StateType,
+    pub position: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StateType {
+    Open,
+    InProgress,
+    Closed,
+    Cancelled,
+    Custom(String),
+}
+
+impl StateType {
+    /// Reads a state category by name, e.g. `todo`, `in progress`, or
+    /// `done`; any other name is kept as `Custom`.
+    pub fn parse(value: &str) -> Self {
+        match value.to_lowercase().replace([' ', '-'], "_").as_str() {
+            "open" | "todo" | "unstarted" | "backlog" => StateType::Open,
+            "in_progress" | "started" => StateType::InProgress,
+            "closed" | "done" | "completed" => StateType::Closed,
+            "cancelled" | "canceled" => StateType::Cancelled,
+            _ => StateType::Custom(value.to_string()),
+        }
+    }
+
+    /// The name `parse` reads back as this category.
+    pub fn name(&self) -> &str {
+        match self {
+            StateType::Open => "open",
+            StateType::InProgress => "in_progress",
+            StateType::Closed => "closed",
+            StateType::Cancelled => "cancelled",
+            StateType::Custom(name) => name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Priority {
+    None,
+    Lowest,
+    Low,
+    Medium,
+    High,
+    Highest,
+    Custom(String),
+}
+
+impl Priority {
+    /// Reads a priority by name, case-insensitively, with `urgent` for
+    /// `Highest`; any other name is kept as `Custom`.
+    pub fn parse(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "none" | "no_priority" => Priority::None,
+            "lowest" => Priority::Lowest,
+            "low" => Priority::Low,
+            "medium" => Priority::Medium,
+            "high" => Priority::High,
+            "highest" | "urgent" => Priority::Highest,
+            _ => Priority::Custom(value.to_string()),
+        }
+    }
+
+    /// The name `parse` reads back as this priority.
+    pub fn name(&self) -> &str {
+        match self {
+            Priority::None => "none",
+            Priority::Lowest => "lowest",
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+            Priority::Highest => "highest",
+            Priority::Custom(name) => name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TicketFilter {
+    pub assignee_id: Option<String>,
+    pub project_id: Option<String>,
+    pub team_id: Option<String>,
+    pub cycle_id: Option<String>,
+    /// `Some(true)` keeps only tickets blocked by another ticket,
+    /// `Some(false)` only unblocked ones.
+    pub blocked: Option<bool>,
+    pub state_type: Option<StateType>,
+    pub priority: Option<Priority>,
+    pub labels: Option<Vec<String>>,
+    pub search_query: Option<String>,
+    /// Date bounds: `*_after` is inclusive, `*_before` exclusive. Combine
+    /// `updated_before` with an open state to find stale tickets.
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub updated_after: Option<DateTime<Utc>>,
+    pub updated_before: Option<DateTime<Utc>>,
+    pub due_before: Option<DateTime<Utc>>,
+    /// Archived tickets are excluded unless this is set.
+    pub include_archived: bool,
+    /// `Some(true)` keeps only tickets awaiting triage, `Some(false)` drops them.
+    pub triage: Option<bool>,
+    /// `Some(true)` keeps only snoozed tickets, `Some(false)` drops them.
+    pub snoozed: Option<bool>,
+    pub custom_filters: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateTicketRequest {
+    pub title: String,
+    pub description: Option<RichText>,
+    pub priority: Option<Priority>,
+    pub assignee_id: Option<String>,
+    pub team_id: Option<String>,
+    pub project_id: Option<String>,
+    pub parent_id: Option<String>,
+    pub label_ids: Option<Vec<String>>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub estimate: Option<f32>,
+    pub custom_fields: Option<HashMap<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTicketRequest {
+    pub id: String,
+    pub title: Option<String>,
+    pub description: Option<RichText>,
+    pub priority: Option<Priority>,
+    pub assignee_id: Option<String>,
+    pub state_id: Option<String>,
+    pub label_ids: Option<Vec<String>>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub estimate: Option<f32>,
+    pub custom_fields: Option<HashMap<String, serde_json::Value>>,
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// One entry in a ticket's history. Field values are the provider's display
+/// values (state names, user names) rather than ids, since history is read by
+/// people and agents rather than fed back into the API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketEvent {
+    pub id: String,
+    pub ticket_id: String,
+    pub actor_id: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+    pub kind: TicketEventKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TicketEventKind {
+    StateTransition {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    Assignment {
+        from: Option<String>,
+        to: Option<String>,
+    },
+    FieldChanged {
+        field: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+    Comment {
+        comment_id: String,
+        body: String,
+    },
+}
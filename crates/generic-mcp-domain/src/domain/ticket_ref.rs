@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How a user referred to a ticket: a provider id, a team-scoped key such as
+/// `METAL-37` (Linear, Jira), or a bare number such as `#123` (GitHub).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TicketRef {
+    Id(String),
+    Key { prefix: String, number: u32 },
+    Number(u32),
+}
+
+impl TicketRef {
+    /// Parses a user-supplied reference. Ticket URLs are reduced to the key
+    /// they contain; anything unrecognised is treated as a provider id.
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim();
+
+        if let Some(number) = value.strip_prefix('#').and_then(|n| n.parse().ok()) {
+            return TicketRef::Number(number);
+        }
+        if let Ok(number) = value.parse() {
+            return TicketRef::Number(number);
+        }
+        if let Some(key) = Self::parse_key(value) {
+            return key;
+        }
+        if value.contains("://") {
+            if let Some(key) = value.split(['/', '?', '#']).find_map(Self::parse_key) {
+                return key;
+            }
+        }
+
+        TicketRef::Id(value.to_string())
+    }
+
+    fn parse_key(value: &str) -> Option<Self> {
+        let (prefix, number) = value.split_once('-')?;
+        let valid_prefix = prefix.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+            && prefix.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_prefix || !number.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        Some(TicketRef::Key {
+            prefix: prefix.to_uppercase(),
+            number: number.parse().ok()?,
+        })
+    }
+}
+
+impl fmt::Display for TicketRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TicketRef::Id(id) => write!(f, "{}", id),
+            TicketRef::Key { prefix, number } => write!(f, "{}-{}", prefix, number),
+            TicketRef::Number(number) => write!(f, "#{}", number),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Time logged against a ticket (Jira worklog, YouTrack work item).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntry {
+    pub id: String,
+    pub ticket_id: String,
+    pub user_id: String,
+    pub duration_minutes: u32,
+    pub started_at: DateTime<Utc>,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogTimeRequest {
+    pub ticket_id: String,
+    pub duration_minutes: u32,
+    pub started_at: Option<DateTime<Utc>>,
+    pub comment: Option<String>,
+}
@@ -0,0 +1,5 @@
+//! Former home of a second, Linear-only `User`/`Team` pair. The workspace
+//! types are the only definitions now; these re-exports keep old
+//! `domain::user::*` paths compiling.
+
+pub use crate::domain::workspace::{Team, User};
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A provider-side subscription that pushes change events to `url`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    pub id: String,
+    pub url: String,
+    pub label: Option<String>,
+    pub enabled: bool,
+    /// Entity types that trigger a delivery, e.g. `Issue` or `Comment`.
+    pub resource_types: Vec<String>,
+    /// `None` when the webhook covers every public team.
+    pub team_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub label: Option<String>,
+    pub resource_types: Vec<String>,
+    /// Limit deliveries to one team; `None` subscribes all public teams.
+    pub team_id: Option<String>,
+    /// Shared secret the provider signs deliveries with.
+    pub secret: Option<String>,
+}
+
+/// What kind of entity a webhook delivery is about.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEntity {
+    Ticket,
+    Comment,
+    Project,
+    ProjectUpdate,
+    Document,
+    Label,
+    Cycle,
+    Other(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookAction {
+    Created,
+    Updated,
+    Removed,
+}
+
+/// A verified webhook delivery reduced to what changed, so caches and
+/// subscribers can refresh without knowing the provider's payload format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    pub provider: String,
+    pub entity: WebhookEntity,
+    pub action: WebhookAction,
+    pub entity_id: String,
+    /// The ticket a comment (or the ticket itself) belongs to.
+    pub ticket_id: Option<String>,
+    pub project_id: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::StateType;
+
+/// A state a team's tickets can be moved into.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowState {
+    pub id: String,
+    pub name: String,
+    pub type_: StateType,
+    pub position: f32,
+    pub color: Option<String>,
+}
+
+/// The ordered set of states available to one team.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workflow {
+    pub team_id: String,
+    pub states: Vec<WorkflowState>,
+}
+
+impl Workflow {
+    pub fn new(team_id: impl Into<String>, mut states: Vec<WorkflowState>) -> Self {
+        states.sort_by(|a, b| a.position.total_cmp(&b.position));
+        Self {
+            team_id: team_id.into(),
+            states,
+        }
+    }
+
+    /// Finds the state a user most likely means: an exact id, then a name
+    /// (case-insensitive), then the first state of a matching category such
+    /// as "done" or "in progress".
+    pub fn resolve(&self, target: &str) -> Option<&WorkflowState> {
+        if let Some(state) = self.states.iter().find(|s| s.id == target || s.name.eq_ignore_ascii_case(target)) {
+            return Some(state);
+        }
+
+        let category = match target.to_lowercase().replace([' ', '-'], "_").as_str() {
+            "open" | "todo" | "unstarted" | "backlog" => StateType::Open,
+            "in_progress" | "started" => StateType::InProgress,
+            "closed" | "done" | "completed" => StateType::Closed,
+            "cancelled" | "canceled" => StateType::Cancelled,
+            _ => return None,
+        };
+        self.states.iter().find(|s| std::mem::discriminant(&s.type_) == std::mem::discriminant(&category))
+    }
+}
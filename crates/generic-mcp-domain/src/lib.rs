@@ -0,0 +1,9 @@
+//! Domain models and port traits shared by generic-mcp and anything that wants
+//! to speak its types (provider crates, contract tests) without pulling in
+//! tokio or hyper.
+
+pub mod domain;
+pub mod ports;
+
+pub use domain::*;
+pub use ports::*;
@@ -0,0 +1,10 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::TicketAlert;
+
+/// Delivers alerts outside the MCP session, e.g. to a chat channel.
+#[async_trait]
+pub trait AlertSink: Send + Sync {
+    async fn send(&self, alert: &TicketAlert) -> Result<()>;
+}
@@ -0,0 +1,22 @@
+use anyhow::Result;
+use std::time::SystemTime;
+
+/// A cached provider response, serialized as JSON.
+#[derive(Debug, Clone)]
+pub struct StoredEntry {
+    pub key: String,
+    pub value: String,
+    pub fetched_at: SystemTime,
+    pub expires_at: SystemTime,
+}
+
+/// Where the response cache is kept across restarts. Entries are grouped
+/// by namespace, e.g. `linear/teams`, so each provider's caches load and
+/// clear on their own.
+pub trait CacheStore: Send + Sync {
+    fn load(&self, namespace: &str) -> Result<Vec<StoredEntry>>;
+    /// Adds the entry, replacing one with the same key.
+    fn put(&self, namespace: &str, entry: &StoredEntry) -> Result<()>;
+    fn remove(&self, namespace: &str, key: &str) -> Result<()>;
+    fn clear(&self, namespace: &str) -> Result<()>;
+}
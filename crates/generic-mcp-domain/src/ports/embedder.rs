@@ -0,0 +1,13 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// Turns text into vectors whose cosine similarity tracks how close their
+/// meanings are; backs semantic search.
+#[async_trait]
+pub trait Embedder: Send + Sync {
+    /// Names the model, since vectors from different models cannot be compared.
+    fn model(&self) -> &str;
+
+    /// One vector per text, in order.
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>>;
+}
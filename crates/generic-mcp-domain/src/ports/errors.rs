@@ -0,0 +1,103 @@
+use std::fmt;
+use std::time::Duration;
+
+/// Returned by optional `TicketService` operations a provider does not support,
+/// so callers can tell "not available here" apart from a failed request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedOperation {
+    pub operation: &'static str,
+}
+
+impl UnsupportedOperation {
+    pub fn new(operation: &'static str) -> Self {
+        Self { operation }
+    }
+}
+
+impl fmt::Display for UnsupportedOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not supported by this provider", self.operation)
+    }
+}
+
+impl std::error::Error for UnsupportedOperation {}
+
+/// Why a `TicketService` call failed, so callers can react without parsing
+/// messages: re-authenticate, back off, fix their input, or try elsewhere.
+#[derive(Debug, thiserror::Error)]
+pub enum TicketServiceError {
+    /// The ticket, project, or other entity does not exist.
+    #[error("{0}")]
+    NotFound(String),
+    /// The credentials were rejected or lack a needed permission.
+    #[error("{0}")]
+    Unauthorized(String),
+    /// The provider is throttling requests; retry after the given delay if
+    /// it said.
+    #[error("{message}")]
+    RateLimited {
+        message: String,
+        retry_after: Option<Duration>,
+    },
+    /// The request itself is invalid, e.g. an unknown team or a bad field.
+    #[error("{0}")]
+    Validation(String),
+    /// The provider could not be reached or failed on its side; the same
+    /// request may succeed later.
+    #[error("{0}")]
+    ProviderUnavailable(String),
+    #[error(transparent)]
+    Unsupported(#[from] UnsupportedOperation),
+    /// Any other failure.
+    #[error(transparent)]
+    Other(anyhow::Error),
+}
+
+/// What `TicketService` calls return.
+pub type TicketResult<T> = std::result::Result<T, TicketServiceError>;
+
+impl TicketServiceError {
+    /// A stable name for the variant, e.g. `not_found`, for logs and
+    /// structured error responses.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::NotFound(_) => "not_found",
+            Self::Unauthorized(_) => "unauthorized",
+            Self::RateLimited { .. } => "rate_limited",
+            Self::Validation(_) => "validation",
+            Self::ProviderUnavailable(_) => "provider_unavailable",
+            Self::Unsupported(_) => "unsupported",
+            Self::Other(_) => "error",
+        }
+    }
+
+    /// Whether retrying the same request later may succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::RateLimited { .. } | Self::ProviderUnavailable(_))
+    }
+}
+
+/// Keeps typed errors that travelled through `anyhow`, e.g. from helpers
+/// shared with the rest of an adapter; anything else becomes `Other`.
+impl From<anyhow::Error> for TicketServiceError {
+    fn from(error: anyhow::Error) -> Self {
+        let error = match error.downcast::<TicketServiceError>() {
+            Ok(typed) => return typed,
+            Err(error) => error,
+        };
+        match error.downcast::<UnsupportedOperation>() {
+            Ok(unsupported) => Self::Unsupported(unsupported),
+            Err(error) => Self::Other(error),
+        }
+    }
+}
+
+/// The `UnsupportedOperation` behind an error, whether it came straight
+/// from a provider or wrapped in a `TicketServiceError`.
+pub fn unsupported_operation(error: &anyhow::Error) -> Option<&UnsupportedOperation> {
+    match error.downcast_ref::<TicketServiceError>() {
+        Some(TicketServiceError::Unsupported(unsupported)) => Some(unsupported),
+        Some(_) => None,
+        None => error.downcast_ref::<UnsupportedOperation>(),
+    }
+}
@@ -0,0 +1,22 @@
+use anyhow::Result;
+
+use crate::domain::Ticket;
+
+/// Field names full-text queries can target, e.g. `state:"in progress"`.
+pub const LOCAL_SEARCH_FIELDS: &[&str] = &["identifier", "title", "description", "state", "label", "priority", "assignee"];
+
+/// A full-text index of tickets from any provider, searched without calling
+/// them; backs local search.
+pub trait FullTextIndex: Send + Sync {
+    /// Adds or replaces `provider`'s tickets.
+    fn index_tickets(&self, provider: &str, tickets: &[Ticket]) -> Result<()>;
+
+    /// Drops a ticket the provider no longer returns.
+    fn remove_ticket(&self, provider: &str, ticket_id: &str) -> Result<()>;
+
+    /// `(provider, ticket)` pairs matching `query`, best match first. Terms
+    /// match any text field and must all be present, `"..."` matches a
+    /// phrase, and `field:value` targets one of `LOCAL_SEARCH_FIELDS`. With
+    /// `fuzzy`, terms in titles and descriptions match within one typo.
+    fn search(&self, query: &str, provider: Option<&str>, fuzzy: bool, limit: usize) -> Result<Vec<(String, Ticket)>>;
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use bytes::Bytes;
+
+/// Sends an HTTP request and reads the whole response. Provider adapters
+/// talk to their APIs through this rather than a concrete client, so each
+/// provider can use its own backend and tests can answer with canned
+/// responses.
+#[async_trait]
+pub trait HttpTransport: Send + Sync {
+    /// Fails only when no response arrived, e.g. a connection error or a
+    /// timeout; error statuses come back as responses.
+    async fn execute(&self, request: http::Request<Bytes>) -> Result<http::Response<Bytes>>;
+}
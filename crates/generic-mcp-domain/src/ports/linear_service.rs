@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use anyhow::Result;
+
+use crate::domain::{
+    Issue, IssueFilter, CreateIssueRequest, UpdateIssueRequest,
+    Label, CreateLabelRequest, Project, ProjectMilestone,
+    CreateProjectRequest, UpdateProjectRequest, CreateMilestoneRequest, Cycle, TicketEvent,
+    WorkflowState, Reaction, ReactionTarget, Initiative, Notification,
+    Comment, CreateCommentRequest, CreateRelationRequest, Attachment, CreateAttachmentRequest,
+    Webhook, CreateWebhookRequest, ProjectUpdate, Document,
+};
+use crate::domain::{User, Team};
+use crate::ports::{Page, PageRequest};
+
+#[async_trait]
+pub trait LinearService {
+    async fn get_assigned_issues(&self, user_id: &str, page: &PageRequest) -> Result<Page<Issue>>;
+    
+    async fn search_issues(&self, filter: &IssueFilter, page: &PageRequest) -> Result<Page<Issue>>;
+    
+    async fn get_issue(&self, issue_id: &str) -> Result<Option<Issue>>;
+    
+    /// Fetches several issues in one round trip. Linear accepts identifiers
+    /// such as `ENG-12` wherever it accepts an issue id.
+    async fn get_issues(&self, issue_ids: &[String]) -> Result<Vec<Option<Issue>>>;
+    
+    async fn get_issue_by_identifier(&self, team_key: &str, number: u32) -> Result<Option<Issue>>;
+    
+    async fn get_issues_by_number(&self, number: u32) -> Result<Vec<Issue>>;
+    
+    async fn get_issue_history(&self, issue_id: &str) -> Result<Vec<TicketEvent>>;
+    
+    /// Comments on an issue, oldest first.
+    async fn get_comments(&self, issue_id: &str, page: &PageRequest) -> Result<Page<Comment>>;
+    
+    async fn create_comment(&self, request: &CreateCommentRequest) -> Result<Comment>;
+    
+    async fn create_issue(&self, request: &CreateIssueRequest) -> Result<Issue>;
+    
+    async fn update_issue(&self, request: &UpdateIssueRequest) -> Result<Issue>;
+    
+    async fn create_issue_relation(&self, request: &CreateRelationRequest) -> Result<()>;
+    
+    /// Sets or clears (`None`) an issue's parent.
+    async fn set_issue_parent(&self, issue_id: &str, parent_id: Option<&str>) -> Result<Issue>;
+    
+    async fn get_attachments(&self, issue_id: &str) -> Result<Vec<Attachment>>;
+    
+    async fn create_attachment(&self, request: &CreateAttachmentRequest) -> Result<Attachment>;
+    
+    async fn archive_issue(&self, issue_id: &str) -> Result<()>;
+    
+    async fn delete_issue(&self, issue_id: &str) -> Result<()>;
+    
+    async fn subscribe_to_issue(&self, issue_id: &str, user_id: &str) -> Result<()>;
+    
+    async fn unsubscribe_from_issue(&self, issue_id: &str, user_id: &str) -> Result<()>;
+    
+    async fn add_reaction(&self, target: &ReactionTarget, emoji: &str) -> Result<Reaction>;
+    
+    async fn remove_reaction(&self, reaction_id: &str) -> Result<()>;
+    
+    async fn get_current_user(&self) -> Result<User>;
+    
+    async fn get_notifications(&self, unread_only: bool, page: &PageRequest) -> Result<Page<Notification>>;
+    
+    async fn get_teams(&self) -> Result<Vec<Team>>;
+    
+    async fn get_team_members(&self, team_id: &str) -> Result<Vec<User>>;
+    
+    async fn get_workflow_states(&self, team_id: &str) -> Result<Vec<WorkflowState>>;
+    
+    async fn get_labels(&self) -> Result<Vec<Label>>;
+    
+    async fn create_label(&self, request: &CreateLabelRequest) -> Result<Label>;
+    
+    async fn get_projects(&self, page: &PageRequest) -> Result<Page<Project>>;
+    
+    async fn get_project(&self, project_id: &str) -> Result<Option<Project>>;
+    
+    async fn get_project_milestones(&self, project_id: &str) -> Result<Vec<ProjectMilestone>>;
+    
+    async fn create_project(&self, request: &CreateProjectRequest) -> Result<Project>;
+    
+    async fn update_project(&self, request: &UpdateProjectRequest) -> Result<Project>;
+    
+    async fn create_milestone(&self, request: &CreateMilestoneRequest) -> Result<ProjectMilestone>;
+    
+    async fn get_project_updates(&self, project_id: &str, page: &PageRequest) -> Result<Page<ProjectUpdate>>;
+    
+    async fn get_documents(&self, project_id: Option<&str>, page: &PageRequest) -> Result<Page<Document>>;
+    
+    async fn get_document(&self, document_id: &str) -> Result<Option<Document>>;
+    
+    async fn get_initiatives(&self) -> Result<Vec<Initiative>>;
+    
+    async fn get_initiative_projects(&self, initiative_id: &str) -> Result<Vec<Project>>;
+    
+    async fn get_cycles(&self, team_id: &str) -> Result<Vec<Cycle>>;
+    
+    async fn get_active_cycle(&self, team_id: &str) -> Result<Option<Cycle>>;
+    
+    async fn set_issue_cycle(&self, issue_id: &str, cycle_id: &str) -> Result<Issue>;
+    
+    async fn get_webhooks(&self) -> Result<Vec<Webhook>>;
+    
+    async fn create_webhook(&self, request: &CreateWebhookRequest) -> Result<Webhook>;
+    
+    async fn delete_webhook(&self, webhook_id: &str) -> Result<()>;
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::ports::TicketService;
+
+/// A decorator that can wrap any provider: caching, rate limiting, retries,
+/// and the like. Each middleware returns a `TicketService` that adds its
+/// behavior and delegates to `inner`, so they compose in any order.
+pub trait TicketServiceMiddleware: Send + Sync {
+    fn wrap(&self, inner: Arc<dyn TicketService + Send + Sync>) -> Arc<dyn TicketService + Send + Sync>;
+}
+
+/// Closures work as one-off middlewares.
+impl<F> TicketServiceMiddleware for F
+where
+    F: Fn(Arc<dyn TicketService + Send + Sync>) -> Arc<dyn TicketService + Send + Sync> + Send + Sync,
+{
+    fn wrap(&self, inner: Arc<dyn TicketService + Send + Sync>) -> Arc<dyn TicketService + Send + Sync> {
+        self(inner)
+    }
+}
@@ -0,0 +1,30 @@
+// Generic service interfaces
+pub mod ticket_service;
+pub mod mcp_server;
+pub mod errors;
+pub mod pagination;
+pub mod secret_store;
+pub mod webhook_decoder;
+pub mod embedder;
+pub mod middleware;
+pub mod http_transport;
+pub mod cache_store;
+pub mod alert_sink;
+pub mod full_text_index;
+
+pub use ticket_service::*;
+pub use mcp_server::*;
+pub use errors::*;
+pub use pagination::*;
+pub use secret_store::*;
+pub use webhook_decoder::*;
+pub use embedder::*;
+pub use middleware::*;
+pub use http_transport::*;
+pub use cache_store::*;
+pub use alert_sink::*;
+pub use full_text_index::*;
+
+// Legacy Linear-specific interface (for backward compatibility)
+pub mod linear_service;
+pub use linear_service::*;
\ No newline at end of file
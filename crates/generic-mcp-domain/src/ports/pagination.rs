@@ -0,0 +1,95 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+
+/// Where a listing should resume and how many items to return. The default
+/// asks for the first page at the provider's default page size.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageRequest {
+    /// Opaque cursor from a previous page's `next_cursor`.
+    pub cursor: Option<String>,
+    pub limit: Option<usize>,
+}
+
+impl PageRequest {
+    pub fn first(limit: usize) -> Self {
+        Self {
+            cursor: None,
+            limit: Some(limit),
+        }
+    }
+
+    pub fn after(&self, cursor: impl Into<String>) -> Self {
+        Self {
+            cursor: Some(cursor.into()),
+            limit: self.limit,
+        }
+    }
+}
+
+/// One page of a listing. `next_cursor` is `None` on the last page; `total`
+/// is only set when the provider reports it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub total: Option<usize>,
+}
+
+impl<T> Page<T> {
+    pub fn new(items: Vec<T>, next_cursor: Option<String>) -> Self {
+        Self {
+            items,
+            next_cursor,
+            total: None,
+        }
+    }
+
+    /// A listing that fits in a single page.
+    pub fn complete(items: Vec<T>) -> Self {
+        let total = items.len();
+        Self {
+            items,
+            next_cursor: None,
+            total: Some(total),
+        }
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+            total: self.total,
+        }
+    }
+}
+
+/// Follows `next_cursor` from the first page until the listing is exhausted,
+/// for callers that genuinely need every item (reports, exports, sorting).
+/// Works with `anyhow` and `TicketServiceError` results alike.
+pub async fn collect_pages<T, E, F, Fut>(mut fetch: F) -> Result<Vec<T>, E>
+where
+    E: From<anyhow::Error>,
+    F: FnMut(PageRequest) -> Fut,
+    Fut: Future<Output = Result<Page<T>, E>>,
+{
+    let mut items = Vec::new();
+    let mut request = PageRequest::default();
+
+    loop {
+        let page = fetch(request.clone()).await?;
+        items.extend(page.items);
+
+        match page.next_cursor {
+            Some(cursor) if request.cursor.as_deref() == Some(cursor.as_str()) => {
+                return Err(anyhow!("Pagination did not advance past cursor {}", cursor).into());
+            }
+            Some(cursor) => request = request.after(cursor),
+            None => return Ok(items),
+        }
+    }
+}
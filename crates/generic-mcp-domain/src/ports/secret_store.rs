@@ -0,0 +1,10 @@
+use anyhow::Result;
+
+/// Where credentials that must outlive the process (OAuth refresh tokens,
+/// saved API tokens) are kept. Keys are namespaced by provider, e.g.
+/// `linear.oauth`.
+pub trait SecretStore: Send + Sync {
+    fn get(&self, key: &str) -> Result<Option<String>>;
+    fn set(&self, key: &str, value: &str) -> Result<()>;
+    fn delete(&self, key: &str) -> Result<()>;
+}
@@ -0,0 +1,22 @@
+use anyhow::Result;
+use std::collections::HashMap;
+
+use crate::domain::WebhookEvent;
+
+/// Checks and parses one provider's webhook deliveries. `headers` holds the
+/// request headers with lowercase names; `body` is the raw payload the
+/// signature was computed over.
+pub trait WebhookDecoder: Send + Sync {
+    fn provider(&self) -> &str;
+
+    /// Whether `verify` checks a signature; false when no secret is
+    /// configured and every delivery passes.
+    fn signed(&self) -> bool;
+
+    /// Rejects deliveries whose signature does not match the shared secret.
+    fn verify(&self, headers: &HashMap<String, String>, body: &[u8]) -> Result<()>;
+
+    /// The changes a verified delivery reports; empty for pings and event
+    /// types that do not map to domain entities.
+    fn decode(&self, headers: &HashMap<String, String>, body: &[u8]) -> Result<Vec<WebhookEvent>>;
+}
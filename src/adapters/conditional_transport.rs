@@ -0,0 +1,145 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use bytes::Bytes;
+use hyper::header::{ACCEPT, AUTHORIZATION, ETAG, HeaderMap, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use hyper::{Method, Request, Response, StatusCode, Version};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::debug;
+
+use crate::ports::HttpTransport;
+
+/// How many GET responses are kept for conditional requests by default.
+pub const DEFAULT_CONDITIONAL_CACHE_ENTRIES: usize = 500;
+
+/// A response kept to answer `304 Not Modified` with.
+struct Validated {
+    version: Version,
+    headers: HeaderMap,
+    body: Bytes,
+}
+
+#[derive(Default)]
+struct Remembered {
+    responses: HashMap<u64, Validated>,
+    /// Keys in the order they were first stored, for eviction.
+    order: VecDeque<u64>,
+}
+
+/// Sends GET requests conditionally: remembers the `ETag` and
+/// `Last-Modified` of each response, sends them back as `If-None-Match` and
+/// `If-Modified-Since`, and answers a `304 Not Modified` with the remembered
+/// response. REST APIs such as GitHub's and GitLab's do not count 304s
+/// against the rate limit, so polling unchanged resources costs almost
+/// nothing. Other methods, and requests that are already conditional, pass
+/// through. Responses are kept per URL and credentials, the oldest dropped
+/// beyond `capacity`.
+pub struct ConditionalTransport {
+    inner: Arc<dyn HttpTransport>,
+    capacity: usize,
+    remembered: Mutex<Remembered>,
+    hits: AtomicU64,
+}
+
+impl ConditionalTransport {
+    pub fn new(inner: Arc<dyn HttpTransport>, capacity: usize) -> Self {
+        Self {
+            inner,
+            capacity,
+            remembered: Mutex::new(Remembered::default()),
+            hits: AtomicU64::new(0),
+        }
+    }
+
+    /// Requests answered from a remembered response after a 304.
+    pub fn not_modified_hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Identifies a request by URL and what changes its response: the
+    /// credentials and the requested representation.
+    fn key(request: &Request<Bytes>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        request.uri().hash(&mut hasher);
+        for name in [AUTHORIZATION, ACCEPT] {
+            request.headers().get(name).map(|value| value.as_bytes()).hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    fn store(&self, key: u64, response: &Response<Bytes>) {
+        let Ok(mut remembered) = self.remembered.lock() else {
+            return;
+        };
+        let Remembered { responses, order } = &mut *remembered;
+        let validated = Validated {
+            version: response.version(),
+            headers: response.headers().clone(),
+            body: response.body().clone(),
+        };
+        if responses.insert(key, validated).is_none() {
+            order.push_back(key);
+        }
+        while responses.len() > self.capacity {
+            match order.pop_front() {
+                Some(oldest) => responses.remove(&oldest),
+                None => break,
+            };
+        }
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ConditionalTransport {
+    async fn execute(&self, mut request: Request<Bytes>) -> Result<Response<Bytes>> {
+        let conditional = request.headers().contains_key(IF_NONE_MATCH) || request.headers().contains_key(IF_MODIFIED_SINCE);
+        if request.method() != Method::GET || conditional {
+            return self.inner.execute(request).await;
+        }
+
+        let key = Self::key(&request);
+        let validators = self.remembered.lock().ok().and_then(|remembered| {
+            let validated = remembered.responses.get(&key)?;
+            Some((validated.headers.get(ETAG).cloned(), validated.headers.get(LAST_MODIFIED).cloned()))
+        });
+        if let Some((etag, last_modified)) = validators {
+            if let Some(etag) = etag {
+                request.headers_mut().insert(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = last_modified {
+                request.headers_mut().insert(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let uri = request.uri().clone();
+        let response = self.inner.execute(request).await?;
+        match response.status() {
+            StatusCode::NOT_MODIFIED => {
+                let cached = self.remembered.lock().ok().and_then(|remembered| {
+                    let validated = remembered.responses.get(&key)?;
+                    let mut cached = Response::new(validated.body.clone());
+                    *cached.version_mut() = validated.version;
+                    *cached.headers_mut() = validated.headers.clone();
+                    Some(cached)
+                });
+                match cached {
+                    Some(cached) => {
+                        self.hits.fetch_add(1, Ordering::Relaxed);
+                        debug!("{} not modified; answered from the remembered response", uri);
+                        Ok(cached)
+                    }
+                    // Evicted while the request was in flight
+                    None => Ok(response),
+                }
+            }
+            StatusCode::OK if response.headers().contains_key(ETAG) || response.headers().contains_key(LAST_MODIFIED) => {
+                self.store(key, &response);
+                Ok(response)
+            }
+            _ => Ok(response),
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use bytes::Bytes;
+use http_body_util::Full;
+use hyper::{Request, Method, header::{AUTHORIZATION, CONTENT_TYPE}};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::adapters::{HttpsClient, default_https_client};
+use crate::ports::Embedder;
+
+pub const DEFAULT_HASHING_DIMENSIONS: usize = 512;
+pub const OPENAI_EMBEDDINGS_URL: &str = "https://api.openai.com/v1";
+pub const DEFAULT_EMBEDDINGS_MODEL: &str = "text-embedding-3-small";
+
+/// Embeds text without a model by hashing its words, word pairs, and word
+/// trigrams into a fixed number of dimensions. Matches shared vocabulary
+/// and close spellings rather than meaning, but needs nothing installed.
+pub struct HashingEmbedder {
+    dimensions: usize,
+    model: String,
+}
+
+impl HashingEmbedder {
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            dimensions: dimensions.max(1),
+            model: format!("hashing-{}", dimensions),
+        }
+    }
+
+    /// FNV-1a, so vectors stay the same across builds.
+    fn hash(feature: &str) -> u64 {
+        feature.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+    }
+
+    fn embed_one(&self, text: &str) -> Vec<f32> {
+        let words: Vec<String> = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| word.to_lowercase())
+            .collect();
+
+        let mut features = Vec::new();
+        for (i, word) in words.iter().enumerate() {
+            features.push(word.clone());
+            if let Some(next) = words.get(i + 1) {
+                features.push(format!("{} {}", word, next));
+            }
+            let chars: Vec<char> = format!("<{}>", word).chars().collect();
+            features.extend(chars.windows(3).map(|w| w.iter().collect::<String>()));
+        }
+
+        let mut vector = vec![0.0f32; self.dimensions];
+        for feature in features {
+            let hash = Self::hash(&feature);
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            vector[(hash % self.dimensions as u64) as usize] += sign;
+        }
+        let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            vector.iter_mut().for_each(|v| *v /= norm);
+        }
+        vector
+    }
+}
+
+impl Default for HashingEmbedder {
+    fn default() -> Self {
+        Self::new(DEFAULT_HASHING_DIMENSIONS)
+    }
+}
+
+#[async_trait]
+impl Embedder for HashingEmbedder {
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        Ok(texts.iter().map(|text| self.embed_one(text)).collect())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingsResponse {
+    data: Vec<EmbeddingData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingData {
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+/// Embeds text through an OpenAI-compatible `/embeddings` endpoint: OpenAI
+/// itself, or a local model served by Ollama, llama.cpp, or vLLM.
+pub struct OpenAiEmbedder {
+    client: HttpsClient,
+    base_url: String,
+    model: String,
+    api_key: Option<String>,
+}
+
+impl OpenAiEmbedder {
+    /// `base_url` is the API root, e.g. `https://api.openai.com/v1` or
+    /// `http://localhost:11434/v1`; local servers usually need no key.
+    pub fn new(base_url: impl Into<String>, model: impl Into<String>, api_key: Option<String>) -> Self {
+        Self {
+            client: default_https_client(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            model: model.into(),
+            api_key,
+        }
+    }
+
+    /// Sends requests through another client, e.g. one with proxy settings.
+    pub fn with_http_client(mut self, client: HttpsClient) -> Self {
+        self.client = client;
+        self
+    }
+}
+
+#[async_trait]
+impl Embedder for OpenAiEmbedder {
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let body = json!({ "model": self.model, "input": texts });
+        let mut request = Request::builder()
+            .method(Method::POST)
+            .uri(format!("{}/embeddings", self.base_url))
+            .header(CONTENT_TYPE, "application/json");
+        if let Some(api_key) = &self.api_key {
+            request = request.header(AUTHORIZATION, format!("Bearer {}", api_key));
+        }
+        let request = request.body(Full::new(Bytes::from(serde_json::to_vec(&body)?)))?;
+
+        let response = self.client.request(request).await?;
+        let status = response.status();
+        let response_bytes = response.into_body();
+        if !status.is_success() {
+            return Err(anyhow!(
+                "Embeddings request to {} failed with {}: {}",
+                self.base_url, status, String::from_utf8_lossy(&response_bytes)
+            ));
+        }
+
+        let mut response: EmbeddingsResponse = serde_json::from_slice(&response_bytes)
+            .map_err(|e| anyhow!("Unexpected embeddings response: {}", e))?;
+        if response.data.len() != texts.len() {
+            return Err(anyhow!("Asked for {} embeddings, got {}", texts.len(), response.data.len()));
+        }
+        response.data.sort_by_key(|data| data.index);
+        Ok(response.data.into_iter().map(|data| data.embedding).collect())
+    }
+}
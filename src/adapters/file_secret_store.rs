@@ -0,0 +1,82 @@
+use anyhow::{Result, anyhow};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::ports::SecretStore;
+
+/// Secrets kept in a JSON file only its owner can read, for hosts without
+/// an OS keychain.
+pub struct FileSecretStore {
+    path: PathBuf,
+    // Serializes read-modify-write cycles within this process
+    lock: Mutex<()>,
+}
+
+impl FileSecretStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// `$XDG_CONFIG_HOME/generic-mcp/secrets.json`, falling back to
+    /// `~/.config`.
+    pub fn default_path() -> Option<PathBuf> {
+        let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+        Some(config_dir.join("generic-mcp").join("secrets.json"))
+    }
+
+    fn read(&self) -> Result<BTreeMap<String, String>> {
+        match fs::read_to_string(&self.path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| anyhow!("Invalid secrets file {}: {}", self.path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(anyhow!("Failed to read secrets file {}: {}", self.path.display(), e)),
+        }
+    }
+
+    fn write(&self, secrets: &BTreeMap<String, String>) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Write then rename so a crash never leaves a truncated file
+        let tmp = self.path.with_extension("json.tmp");
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let file = options.open(&tmp)?;
+        serde_json::to_writer_pretty(file, secrets)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+impl SecretStore for FileSecretStore {
+    fn get(&self, key: &str) -> Result<Option<String>> {
+        let _guard = self.lock.lock().map_err(|_| anyhow!("Secret store lock poisoned"))?;
+        Ok(self.read()?.remove(key))
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<()> {
+        let _guard = self.lock.lock().map_err(|_| anyhow!("Secret store lock poisoned"))?;
+        let mut secrets = self.read()?;
+        secrets.insert(key.to_string(), value.to_string());
+        self.write(&secrets)
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        let _guard = self.lock.lock().map_err(|_| anyhow!("Secret store lock poisoned"))?;
+        let mut secrets = self.read()?;
+        if secrets.remove(key).is_some() {
+            self.write(&secrets)?;
+        }
+        Ok(())
+    }
+}
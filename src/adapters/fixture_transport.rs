@@ -0,0 +1,272 @@
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+use bytes::Bytes;
+use hyper::header::{AUTHORIZATION, HeaderName, HeaderValue};
+use hyper::{Request, Response, StatusCode};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+use tracing::debug;
+
+use crate::core::redaction::EMAIL_PATTERN;
+use crate::ports::{FixtureMode, FixtureSettings, HttpTransport};
+
+/// What secrets and credentials are replaced with in fixtures.
+const REDACTED: &str = "REDACTED";
+
+/// JSON fields and query parameters whose values are always redacted,
+/// compared without case, `_`, or `-`.
+const SECRET_FIELDS: &[&str] = &[
+    "token", "accesstoken", "refreshtoken", "privatetoken", "apikey", "apitoken",
+    "password", "secret", "clientsecret",
+];
+
+/// Response headers kept in fixtures; the rest may carry cookies or
+/// per-session identifiers.
+const KEPT_HEADERS: &[&str] = &["content-type", "link", "retry-after", "etag", "last-modified"];
+
+static EMAIL: LazyLock<Regex> = LazyLock::new(|| Regex::new(EMAIL_PATTERN).unwrap());
+
+/// A request as it is kept in a fixture, sanitized.
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    /// Path and query; the host is the fixture's directory.
+    url: String,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    body: Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+    /// JSON bodies as JSON, anything else as a string.
+    #[serde(default)]
+    body: Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+/// Records provider responses to fixture files, or replays them in place
+/// of the network, so adapters can be developed and tested without API
+/// tokens. Fixtures are sanitized before they are written: credentials and
+/// secret fields are redacted wherever they appear, email addresses are
+/// replaced with stable placeholders, and only a few response headers are
+/// kept. Requests are matched by method, path, query, and body after the
+/// same sanitizing, so a replayed run needs no real token either.
+pub struct FixtureTransport {
+    /// Where real requests go when recording; `None` replays.
+    inner: Option<Arc<dyn HttpTransport>>,
+    dir: PathBuf,
+}
+
+impl FixtureTransport {
+    /// Sends requests through `inner` and writes each response to `dir`.
+    pub fn record(inner: Arc<dyn HttpTransport>, dir: impl Into<PathBuf>) -> Self {
+        Self { inner: Some(inner), dir: dir.into() }
+    }
+
+    /// Answers requests from the fixtures in `dir` alone.
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        Self { inner: None, dir: dir.into() }
+    }
+
+    /// `inner` wrapped as `settings` ask: recording through it, or replaced
+    /// by replaying.
+    pub fn from_settings(settings: &FixtureSettings, inner: Arc<dyn HttpTransport>) -> Self {
+        match settings.mode {
+            FixtureMode::Record => Self::record(inner, &settings.dir),
+            FixtureMode::Replay => Self::replay(&settings.dir),
+        }
+    }
+
+    /// The file a sanitized request is kept in.
+    fn path(&self, host: &str, request: &RecordedRequest) -> PathBuf {
+        let mut hasher = Sha256::new();
+        hasher.update(request.method.as_bytes());
+        hasher.update(b"\n");
+        hasher.update(request.url.as_bytes());
+        hasher.update(b"\n");
+        if !request.body.is_null() {
+            hasher.update(request.body.to_string().as_bytes());
+        }
+        let digest = hex::encode(hasher.finalize());
+        self.dir
+            .join(host)
+            .join(format!("{}-{}.json", request.method.to_lowercase(), &digest[..16]))
+    }
+}
+
+#[async_trait]
+impl HttpTransport for FixtureTransport {
+    async fn execute(&self, request: Request<Bytes>) -> Result<Response<Bytes>> {
+        let sanitizer = Sanitizer::for_request(&request);
+        let host = request.uri().host().unwrap_or("localhost").to_string();
+        let recorded = RecordedRequest {
+            method: request.method().to_string(),
+            url: sanitizer.url(request.uri().path_and_query().map_or("/", |pq| pq.as_str())),
+            body: sanitizer.body(request.body()),
+        };
+        let path = self.path(&host, &recorded);
+
+        let Some(inner) = &self.inner else {
+            debug!("Replaying {} {}{} from {}", recorded.method, host, recorded.url, path.display());
+            return read_fixture(&path)
+                .with_context(|| format!(
+                    "No recorded response for {} {}{} (expected {}); record it with MCP_HTTP_FIXTURES=record",
+                    recorded.method, host, recorded.url, path.display()
+                ));
+        };
+
+        let response = inner.execute(request).await?;
+        let headers = response.headers()
+            .iter()
+            .filter(|(name, _)| {
+                let name = name.as_str();
+                KEPT_HEADERS.contains(&name) || name.starts_with("x-ratelimit-") || name.starts_with("ratelimit-")
+            })
+            .filter_map(|(name, value)| Some((name.to_string(), sanitizer.text(value.to_str().ok()?))))
+            .collect();
+        let fixture = Fixture {
+            request: recorded,
+            response: RecordedResponse {
+                status: response.status().as_u16(),
+                headers,
+                body: sanitizer.body(response.body()),
+            },
+        };
+        write_fixture(&path, &fixture)?;
+        debug!("Recorded {} {}{} to {}", fixture.request.method, host, fixture.request.url, path.display());
+        Ok(response)
+    }
+}
+
+fn read_fixture(path: &Path) -> Result<Response<Bytes>> {
+    let contents = std::fs::read_to_string(path)?;
+    let fixture: Fixture = serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid fixture in {}", path.display()))?;
+    let recorded = fixture.response;
+    let body = match recorded.body {
+        Value::Null => Bytes::new(),
+        Value::String(text) => Bytes::from(text),
+        json => Bytes::from(serde_json::to_vec(&json)?),
+    };
+    let mut response = Response::new(body);
+    *response.status_mut() = StatusCode::from_u16(recorded.status)
+        .map_err(|_| anyhow!("Invalid status {} in {}", recorded.status, path.display()))?;
+    for (name, value) in recorded.headers {
+        response.headers_mut().insert(HeaderName::try_from(name)?, HeaderValue::try_from(value)?);
+    }
+    Ok(response)
+}
+
+fn write_fixture(path: &Path, fixture: &Fixture) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, serde_json::to_string_pretty(fixture)? + "\n")
+        .with_context(|| format!("Failed to write fixture {}", path.display()))
+}
+
+fn is_secret_field(name: &str) -> bool {
+    let name: String = name.chars().filter(|c| *c != '_' && *c != '-').collect::<String>().to_lowercase();
+    SECRET_FIELDS.contains(&name.as_str())
+}
+
+/// Scrubs one exchange, knowing the credentials its request was sent with.
+struct Sanitizer {
+    secrets: Vec<String>,
+}
+
+impl Sanitizer {
+    fn for_request(request: &Request<Bytes>) -> Self {
+        let mut secrets = Vec::new();
+        if let Some(authorization) = request.headers().get(AUTHORIZATION).and_then(|value| value.to_str().ok()) {
+            secrets.push(authorization.to_string());
+            // The token alone, without a `Bearer` or `token` scheme
+            if let Some((_, token)) = authorization.rsplit_once(' ') {
+                secrets.push(token.to_string());
+            }
+        }
+        if let Some(query) = request.uri().query() {
+            secrets.extend(
+                url::form_urlencoded::parse(query.as_bytes())
+                    .filter(|(name, _)| is_secret_field(name))
+                    .map(|(_, value)| value.into_owned()),
+            );
+        }
+        // Short values would redact unrelated text
+        secrets.retain(|secret| secret.len() >= 8);
+        secrets.sort_by_key(|secret| std::cmp::Reverse(secret.len()));
+        Self { secrets }
+    }
+
+    fn text(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        for secret in &self.secrets {
+            text = text.replace(secret.as_str(), REDACTED);
+        }
+        EMAIL.replace_all(&text, |captures: &regex::Captures<'_>| placeholder_email(&captures[0])).into_owned()
+    }
+
+    fn url(&self, url: &str) -> String {
+        let Some((path, query)) = url.split_once('?') else {
+            return self.text(url);
+        };
+        let query: String = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(url::form_urlencoded::parse(query.as_bytes()).map(|(name, value)| {
+                let value = if is_secret_field(&name) { REDACTED.to_string() } else { value.into_owned() };
+                (name.into_owned(), value)
+            }))
+            .finish();
+        self.text(&format!("{}?{}", path, query))
+    }
+
+    fn body(&self, body: &Bytes) -> Value {
+        if body.is_empty() {
+            return Value::Null;
+        }
+        match serde_json::from_slice::<Value>(body) {
+            Ok(json) => self.json(json),
+            Err(_) => Value::String(self.text(&String::from_utf8_lossy(body))),
+        }
+    }
+
+    fn json(&self, value: Value) -> Value {
+        match value {
+            Value::String(text) => Value::String(self.text(&text)),
+            Value::Array(items) => Value::Array(items.into_iter().map(|item| self.json(item)).collect()),
+            Value::Object(fields) => Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(name, value)| {
+                        let value = if is_secret_field(&name) && value.is_string() {
+                            Value::String(REDACTED.to_string())
+                        } else {
+                            self.json(value)
+                        };
+                        (name, value)
+                    })
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+}
+
+/// The same made-up address for the same real one, so people can still be
+/// told apart in recorded data.
+fn placeholder_email(email: &str) -> String {
+    let digest = hex::encode(Sha256::digest(email.to_lowercase().as_bytes()));
+    format!("user-{}@example.com", &digest[..8])
+}
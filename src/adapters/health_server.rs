@@ -0,0 +1,81 @@
+use anyhow::Result;
+use bytes::Bytes;
+use http_body_util::Full;
+use hyper::body::Incoming;
+use hyper::header::{CONTENT_TYPE, HeaderValue};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use serde_json::{Value, json};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tracing::{debug, info};
+
+use crate::adapters::McpServerImpl;
+use crate::core::ReadinessCheck;
+
+/// Serves `/healthz` (the MCP server is running) and `/readyz` (every
+/// provider is reachable) for Kubernetes probes or process supervisors.
+/// Both answer 200 when healthy and 503 otherwise.
+pub struct HealthServer {
+    addr: SocketAddr,
+    server: McpServerImpl,
+    readiness: Arc<ReadinessCheck>,
+}
+
+impl HealthServer {
+    pub fn new(addr: SocketAddr, server: McpServerImpl, readiness: Arc<ReadinessCheck>) -> Self {
+        Self { addr, server, readiness }
+    }
+
+    /// Accepts connections until the task is dropped.
+    pub async fn run(self) -> Result<()> {
+        let listener = TcpListener::bind(self.addr).await?;
+        info!("Serving health checks on {} (/healthz, /readyz)", self.addr);
+
+        let server = Arc::new(self);
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let server = server.clone();
+            tokio::spawn(async move {
+                let service = service_fn(|request| {
+                    let server = server.clone();
+                    async move { Ok::<_, hyper::Error>(server.handle(request).await) }
+                });
+                if let Err(e) = http1::Builder::new().serve_connection(TokioIo::new(stream), service).await {
+                    debug!("Health check connection from {} ended: {}", peer, e);
+                }
+            });
+        }
+    }
+
+    async fn handle(&self, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        if request.method() != Method::GET && request.method() != Method::HEAD {
+            return Self::respond(StatusCode::METHOD_NOT_ALLOWED, json!({ "error": "Use GET" }));
+        }
+        match request.uri().path() {
+            "/healthz" => match self.server.is_running() {
+                true => Self::respond(StatusCode::OK, json!({ "status": "ok" })),
+                false => Self::respond(StatusCode::SERVICE_UNAVAILABLE, json!({ "status": "stopped" })),
+            },
+            "/readyz" => {
+                let readiness = self.readiness.readiness().await;
+                let status = match readiness.ready && self.server.is_running() {
+                    true => StatusCode::OK,
+                    false => StatusCode::SERVICE_UNAVAILABLE,
+                };
+                Self::respond(status, serde_json::to_value(&readiness).unwrap_or_default())
+            }
+            _ => Self::respond(StatusCode::NOT_FOUND, json!({ "error": "Unknown path" })),
+        }
+    }
+
+    fn respond(status: StatusCode, body: Value) -> Response<Full<Bytes>> {
+        let mut response = Response::new(Full::new(Bytes::from(body.to_string())));
+        *response.status_mut() = status;
+        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        response
+    }
+}
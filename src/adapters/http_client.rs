@@ -0,0 +1,229 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::future::{self, Either};
+use http_body_util::{BodyExt, Full};
+use hyper::{Request, Response, Uri};
+use hyper::rt::Timer;
+use hyper_tls::HttpsConnector;
+use hyper_tls::native_tls::{Certificate, TlsConnector};
+use hyper_util::client::legacy::Client;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::connect::proxy::Tunnel;
+use hyper_util::client::proxy::matcher::Matcher;
+use hyper_util::rt::{TokioExecutor, TokioTimer};
+use std::future::Future;
+use std::pin::{Pin, pin};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tower_service::Service;
+
+use crate::adapters::{ConditionalTransport, DEFAULT_CONDITIONAL_CACHE_ENTRIES, FixtureTransport};
+use crate::ports::{HttpSettings, HttpTransport};
+
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+pub const DEFAULT_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// The HTTP client provider adapters send requests with. Cloning shares
+/// the connection pool and the in-flight limit.
+#[derive(Clone)]
+pub struct HttpsClient {
+    client: Client<HttpsConnector<ProxyConnector>, Full<Bytes>>,
+    request_timeout: Option<Duration>,
+    in_flight: Option<Arc<Semaphore>>,
+}
+
+impl HttpsClient {
+    fn new(settings: &HttpSettings, matcher: Matcher, tls: Option<TlsConnector>) -> Self {
+        let connector = ProxyConnector::new(matcher, settings);
+        let https = match tls {
+            Some(tls) => HttpsConnector::from((connector, tls.into())),
+            None => HttpsConnector::new_with_connector(connector),
+        };
+
+        let mut builder = Client::builder(TokioExecutor::new());
+        builder.pool_timer(TokioTimer::new());
+        match settings.pool_idle_timeout.unwrap_or(DEFAULT_POOL_IDLE_TIMEOUT) {
+            Duration::ZERO => builder.pool_max_idle_per_host(0),
+            idle => builder.pool_idle_timeout(idle),
+        };
+
+        Self {
+            client: builder.build(https),
+            request_timeout: enabled(settings.request_timeout, DEFAULT_REQUEST_TIMEOUT),
+            in_flight: settings.max_in_flight.map(|max| Arc::new(Semaphore::new(max.max(1)))),
+        }
+    }
+
+    /// Sends `request` and reads the whole response, giving up once that
+    /// takes longer than the request timeout. Waiting for an in-flight slot
+    /// does not count against it.
+    pub async fn request(&self, request: Request<Full<Bytes>>) -> Result<Response<Bytes>> {
+        let _permit = match &self.in_flight {
+            Some(in_flight) => in_flight.acquire().await.ok(),
+            None => None,
+        };
+        let host = request.uri().host().unwrap_or_default().to_string();
+        let exchange = async {
+            let (parts, body) = self.client.request(request).await?.into_parts();
+            Ok(Response::from_parts(parts, body.collect().await?.to_bytes()))
+        };
+        let Some(timeout) = self.request_timeout else {
+            return exchange.await;
+        };
+        match future::select(pin!(exchange), TokioTimer::new().sleep(timeout)).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(anyhow!("Request to {} timed out after {}s", host, timeout.as_secs_f64())),
+        }
+    }
+}
+
+#[async_trait]
+impl HttpTransport for HttpsClient {
+    async fn execute(&self, request: Request<Bytes>) -> Result<Response<Bytes>> {
+        self.request(request.map(Full::new)).await
+    }
+}
+
+/// The transport `settings.backend` names: `hyper` (the default), or
+/// `reqwest` when built with the reqwest feature. GET requests go out
+/// conditionally unless `conditional_cache_entries` is zero, or fixtures
+/// are recorded or replayed, so each fixture holds a whole response.
+pub fn http_transport(settings: &HttpSettings) -> Result<Arc<dyn HttpTransport>> {
+    let transport: Arc<dyn HttpTransport> = match settings.backend.as_deref() {
+        None | Some("hyper") => Arc::new(https_client(settings)?),
+        #[cfg(feature = "reqwest")]
+        Some("reqwest") => Arc::new(crate::adapters::ReqwestTransport::new(settings)?),
+        Some(other) => return Err(anyhow!(
+            "Unknown HTTP backend {}: expected hyper, or reqwest when built with the reqwest feature", other
+        )),
+    };
+    if let Some(fixtures) = &settings.fixtures {
+        return Ok(Arc::new(FixtureTransport::from_settings(fixtures, transport)));
+    }
+    match settings.conditional_cache_entries.unwrap_or(DEFAULT_CONDITIONAL_CACHE_ENTRIES) {
+        0 => Ok(transport),
+        capacity => Ok(Arc::new(ConditionalTransport::new(transport, capacity))),
+    }
+}
+
+/// A timeout setting: unset takes the default, and zero disables it.
+pub(crate) fn enabled(timeout: Option<Duration>, default: Duration) -> Option<Duration> {
+    Some(timeout.unwrap_or(default)).filter(|timeout| !timeout.is_zero())
+}
+
+/// Builds a client that goes through the configured proxy, or the one the
+/// environment names, except for hosts the no-proxy list exempts, and
+/// trusts the configured certificates.
+pub fn https_client(settings: &HttpSettings) -> Result<HttpsClient> {
+    let matcher = if settings.proxy.is_none() && settings.no_proxy.is_none() {
+        Matcher::from_system()
+    } else {
+        proxy_matcher(settings)?
+    };
+    let tls = if settings.ca_certificates.is_empty() && !settings.accept_invalid_certs {
+        None
+    } else {
+        Some(tls_connector(settings)?)
+    };
+    Ok(HttpsClient::new(settings, matcher, tls))
+}
+
+/// A client with the default timeouts, using the proxy environment
+/// variables, then the macOS or Windows system proxy settings.
+pub fn default_https_client() -> HttpsClient {
+    HttpsClient::new(&HttpSettings::default(), Matcher::from_system(), None)
+}
+
+fn tls_connector(settings: &HttpSettings) -> Result<TlsConnector> {
+    let mut builder = TlsConnector::builder();
+    for path in &settings.ca_certificates {
+        let pem = std::fs::read(path)
+            .map_err(|e| anyhow!("Failed to read CA certificate {}: {}", path, e))?;
+        let certificates = Certificate::stack_from_pem(&pem)
+            .map_err(|e| anyhow!("Invalid CA certificate {}: {}", path, e))?;
+        if certificates.is_empty() {
+            return Err(anyhow!("No PEM certificates in {}", path));
+        }
+        for certificate in certificates {
+            builder.add_root_certificate(certificate);
+        }
+    }
+    if settings.accept_invalid_certs {
+        builder.danger_accept_invalid_certs(true);
+        builder.danger_accept_invalid_hostnames(true);
+    }
+    builder.build().map_err(|e| anyhow!("Failed to set up TLS: {}", e))
+}
+
+fn proxy_matcher(settings: &HttpSettings) -> Result<Matcher> {
+    let builder = match &settings.proxy {
+        Some(proxy) => {
+            let uri: Uri = proxy.parse().map_err(|e| anyhow!("Invalid proxy URL {}: {}", proxy, e))?;
+            if uri.scheme_str() != Some("http") {
+                return Err(anyhow!("Unsupported proxy URL {}: only http:// proxies are supported", proxy));
+            }
+            Matcher::builder().all(proxy)
+        }
+        None => Matcher::builder()
+            .all(env_var(&["ALL_PROXY", "all_proxy"]))
+            .http(env_var(&["HTTP_PROXY", "http_proxy"]))
+            .https(env_var(&["HTTPS_PROXY", "https_proxy"])),
+    };
+    let no_proxy = settings.no_proxy.clone().unwrap_or_else(|| env_var(&["NO_PROXY", "no_proxy"]));
+    Ok(builder.no(no_proxy).build())
+}
+
+pub(crate) fn env_var(names: &[&str]) -> String {
+    names.iter().find_map(|name| std::env::var(name).ok()).unwrap_or_default()
+}
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Opens TCP connections directly, or through a proxy with HTTP `CONNECT`
+/// tunnels so TLS runs end to end with the provider.
+#[derive(Clone)]
+pub struct ProxyConnector {
+    http: HttpConnector,
+    matcher: Arc<Matcher>,
+}
+
+impl ProxyConnector {
+    fn new(matcher: Matcher, settings: &HttpSettings) -> Self {
+        let mut http = HttpConnector::new();
+        // TLS is layered on top for https:// destinations
+        http.enforce_http(false);
+        http.set_connect_timeout(enabled(settings.connect_timeout, DEFAULT_CONNECT_TIMEOUT));
+        http.set_keepalive(settings.tcp_keepalive.filter(|keepalive| !keepalive.is_zero()));
+        Self { http, matcher: Arc::new(matcher) }
+    }
+}
+
+impl Service<Uri> for ProxyConnector {
+    type Response = <HttpConnector as Service<Uri>>::Response;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.http.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, dst: Uri) -> Self::Future {
+        match self.matcher.intercept(&dst) {
+            Some(intercept) => {
+                let mut tunnel = Tunnel::new(intercept.uri().clone(), self.http.clone());
+                if let Some(auth) = intercept.basic_auth() {
+                    tunnel = tunnel.with_auth(auth.clone());
+                }
+                Box::pin(async move { tunnel.call(dst).await.map_err(Into::into) })
+            }
+            None => {
+                let connecting = self.http.call(dst);
+                Box::pin(async move { connecting.await.map_err(Into::into) })
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::{Result, anyhow};
+use keyring::Entry;
+
+use crate::ports::SecretStore;
+
+/// Service name secrets are filed under in the OS keychain.
+pub const DEFAULT_KEYRING_SERVICE: &str = "generic-mcp";
+
+/// Secrets kept in the OS keychain: the macOS Keychain, the Windows
+/// Credential Manager, or the Secret Service (GNOME Keyring, KWallet) on
+/// Linux. Each key is one entry of the service.
+///
+/// Keychain calls block, and the Secret Service client deadlocks on a tokio
+/// worker thread, so each call runs on a thread of its own.
+pub struct KeyringSecretStore {
+    service: String,
+}
+
+impl KeyringSecretStore {
+    pub fn new(service: impl Into<String>) -> Self {
+        Self { service: service.into() }
+    }
+
+    fn with_entry<T: Send>(&self, key: &str, f: impl FnOnce(Entry) -> Result<T> + Send) -> Result<T> {
+        let entry = Entry::new(&self.service, key)
+            .map_err(|e| anyhow!("Invalid keychain entry {}: {}", key, e))?;
+        std::thread::scope(|scope| {
+            scope.spawn(|| f(entry))
+                .join()
+                .map_err(|_| anyhow!("Keychain access for {} panicked", key))?
+        })
+    }
+}
+
+impl Default for KeyringSecretStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_KEYRING_SERVICE)
+    }
+}
+
+impl SecretStore for KeyringSecretStore {
+    fn get(&self, key: &str) -> Result<Option<String>> {
+        self.with_entry(key, |entry| match entry.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(anyhow!("Failed to read {} from the keychain: {}", key, e)),
+        })
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<()> {
+        self.with_entry(key, |entry| entry.set_password(value)
+            .map_err(|e| anyhow!("Failed to save {} to the keychain: {}", key, e)))
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        self.with_entry(key, |entry| match entry.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(anyhow!("Failed to delete {} from the keychain: {}", key, e)),
+        })
+    }
+}
@@ -1,42 +1,350 @@
 use async_trait::async_trait;
 use anyhow::{Result, anyhow};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
+use chrono::NaiveDate;
 use std::collections::HashMap;
+use std::sync::Arc;
 use bytes::Bytes;
-use http_body_util::{BodyExt, Full};
-use hyper::{Request, Response, Method, Uri, header::{HeaderValue, AUTHORIZATION, CONTENT_TYPE}};
-use hyper_util::rt::TokioExecutor;
-use hyper_tls::HttpsConnector;
-use hyper_util::client::legacy::Client;
+use hyper::{Request, Method, StatusCode, Uri, header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER}};
+use hyper::rt::Timer;
+use hyper_util::rt::TokioTimer;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{debug, warn};
 
 use crate::domain::{
     Issue, IssueFilter, CreateIssueRequest, UpdateIssueRequest,
     Label, CreateLabelRequest, Project, ProjectMilestone,
-    IssuePriority, IssueState, IssueStateType, ProjectState
+    IssuePriority, IssueState, IssueStateType, ProjectState, Attachment,
+    RelatedTicket, TicketRelations, TicketEvent, TicketEventKind, WorkflowState, StateType,
+    Reaction, ReactionTarget, Initiative, Notification, NotificationKind, Sla,
+    CreateProjectRequest, UpdateProjectRequest, CreateMilestoneRequest, Cycle,
+    Comment, CreateCommentRequest, CreateRelationRequest, RelationKind, CreateAttachmentRequest,
+    Webhook, CreateWebhookRequest, ProjectUpdate, ProjectHealth, Document, RichText,
 };
-use crate::domain::workspace::{User, Team};
-use crate::ports::LinearService;
+use crate::domain::{User, Team};
+use crate::ports::{HttpTransport, LinearService, Page, PageRequest, TicketServiceError};
+use crate::adapters::{OAuthClient, default_https_client};
+use crate::adapters::linear_schema::{
+    AttachmentNode, CommentNode, CycleNode, IssueNode, IssueRef, MilestoneNode, ProjectNode, ReactionNode, UserNode,
+    WebhookNode, ProjectUpdateNode, DocumentNode, HistoryNode, LabelRef, NameRef, NotificationNode, TeamNode, StateNode, LabelNode,
+    InitiativeNode,
+};
+
+/// Linear limits query complexity, so large batches are split into chunks.
+const MAX_ALIASED_ISSUES: usize = 50;
+
+/// Linear's page size when none is requested, and the most it will return.
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+pub const MAX_PAGE_SIZE: usize = 250;
+
+/// Stops exhaustive listings from walking an unexpectedly huge result set.
+pub const DEFAULT_MAX_PAGES: usize = 20;
+
+/// Retries for rate limits, 5xx responses, and dropped connections. Delays
+/// double from the base with up to 50% jitter, and never exceed the cap.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+const BASE_RETRY_DELAY: Duration = Duration::from_millis(500);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+const ISSUE_FIELDS: &str = r#"
+    id
+    identifier
+    title
+    description
+    priority
+    url
+    createdAt
+    updatedAt
+    dueDate
+    completedAt
+    archivedAt
+    snoozedUntilAt
+    estimate
+    slaStartedAt
+    slaBreachesAt
+    state { id name type position }
+    assignee { id name }
+    creator { id name }
+    project { id name }
+    cycle { id }
+    labels { nodes { id name } }
+    subscribers { nodes { id } }
+    parent { id identifier }
+    children { nodes { id identifier } }
+    relations { nodes { type relatedIssue { id identifier } } }
+    inverseRelations { nodes { type issue { id identifier } } }
+"#;
+
+const ATTACHMENT_FIELDS: &str = r#"
+    id
+    title
+    url
+    createdAt
+    creator { id }
+    metadata
+    sourceType
+"#;
+
+const WEBHOOK_FIELDS: &str = r#"
+    id
+    url
+    label
+    enabled
+    resourceTypes
+    team { id }
+    createdAt
+"#;
+
+/// Resource types a provisioned webhook subscribes to.
+pub const WEBHOOK_RESOURCE_TYPES: &[&str] = &["Issue", "Comment", "IssueLabel", "Reaction", "Project", "Cycle"];
+
+/// Cycle selection. `issues` is capped at the largest page Linear serves;
+/// a cycle's `ticket_ids` beyond that are not listed.
+const CYCLE_FIELDS: &str = r#"
+    id
+    number
+    name
+    startsAt
+    endsAt
+    progress
+    issues(first: 250) { nodes { id } }
+"#;
+
+/// How requests authenticate: a personal API key, sent as is, or an OAuth
+/// access token, sent as a bearer token and refreshed as needed.
+enum LinearAuth {
+    ApiKey(String),
+    OAuth(Arc<OAuthClient>),
+}
+
+/// The lighter selection for listings: no description, subscribers, or
+/// relations, which dominate payload size in large workspaces.
+const ISSUE_SUMMARY_FIELDS: &str = r#"
+    id
+    identifier
+    title
+    priority
+    url
+    createdAt
+    updatedAt
+    dueDate
+    completedAt
+    archivedAt
+    snoozedUntilAt
+    estimate
+    slaStartedAt
+    slaBreachesAt
+    state { id name type position }
+    assignee { id name }
+    creator { id name }
+    project { id name }
+    cycle { id }
+    labels { nodes { id name } }
+"#;
+
+/// How much of each issue list queries fetch. Single-issue lookups always
+/// fetch everything.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FieldSelection {
+    /// Titles, states, people, dates, and labels.
+    Summary,
+    #[default]
+    Full,
+}
+
+impl FieldSelection {
+    fn selection(self) -> &'static str {
+        match self {
+            FieldSelection::Summary => ISSUE_SUMMARY_FIELDS,
+            FieldSelection::Full => ISSUE_FIELDS,
+        }
+    }
+}
+
+impl std::str::FromStr for FieldSelection {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "summary" => Ok(FieldSelection::Summary),
+            "full" => Ok(FieldSelection::Full),
+            other => Err(anyhow!("Unknown field selection: {}. Expected summary or full", other)),
+        }
+    }
+}
+
+/// Several top-level fields combined into one aliased query so they share a
+/// round trip: `b0: issue(id: $b0_id) { ... } b1: team(id: $b1_id) { ... }`.
+pub struct QueryBatch {
+    operation: String,
+    params: Vec<String>,
+    fields: Vec<String>,
+    variables: serde_json::Map<String, Value>,
+}
+
+impl QueryBatch {
+    pub fn new(operation: &str) -> Self {
+        Self {
+            operation: operation.to_string(),
+            params: Vec::new(),
+            fields: Vec::new(),
+            variables: serde_json::Map::new(),
+        }
+    }
+
+    /// Adds `field(args) { selection }`, where each argument is a name, its
+    /// GraphQL type, and its value. Returns the entry's index in the results.
+    pub fn add(&mut self, field: &str, args: &[(&str, &str, Value)], selection: &str) -> usize {
+        let index = self.fields.len();
+        let mut arguments = Vec::with_capacity(args.len());
+        for (name, type_, value) in args {
+            let variable = format!("b{}_{}", index, name);
+            self.params.push(format!("${}: {}", variable, type_));
+            arguments.push(format!("{}: ${}", name, variable));
+            self.variables.insert(variable, value.clone());
+        }
+
+        let arguments = if arguments.is_empty() { String::new() } else { format!("({})", arguments.join(", ")) };
+        self.fields.push(format!("b{}: {}{} {{ {} }}", index, field, arguments, selection));
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    fn query(&self) -> String {
+        let params = if self.params.is_empty() { String::new() } else { format!("({})", self.params.join(", ")) };
+        format!("query {}{} {{ {} }}", self.operation, params, self.fields.join("\n"))
+    }
+}
 
 pub struct LinearClient {
-    client: Client<HttpsConnector<hyper_util::client::legacy::connect::HttpConnector>, Full<Bytes>>,
-    api_token: String,
+    client: Arc<dyn HttpTransport>,
+    auth: LinearAuth,
     base_url: String,
+    page_size: usize,
+    max_pages: usize,
+    max_retries: u32,
+    list_fields: FieldSelection,
 }
 
 impl LinearClient {
     pub fn new(api_token: String) -> Result<Self> {
-        let https = HttpsConnector::new();
-        let client = Client::builder(TokioExecutor::new()).build(https);
+        let client = Arc::new(default_https_client());
         let base_url = "https://api.linear.app/graphql".to_string();
         
         Ok(Self {
             client,
-            api_token,
+            auth: LinearAuth::ApiKey(api_token),
             base_url,
+            page_size: DEFAULT_PAGE_SIZE,
+            max_pages: DEFAULT_MAX_PAGES,
+            max_retries: DEFAULT_MAX_RETRIES,
+            list_fields: FieldSelection::default(),
         })
     }
 
+    /// Sends requests to another GraphQL endpoint, e.g. a proxy.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Sends requests through another transport, e.g. one with proxy
+    /// settings or a different HTTP library.
+    pub fn with_transport(mut self, client: Arc<dyn HttpTransport>) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Authenticates with OAuth tokens instead of the API key.
+    pub fn with_oauth(mut self, oauth: Arc<OAuthClient>) -> Self {
+        self.auth = LinearAuth::OAuth(oauth);
+        self
+    }
+
+    /// How much of each issue assigned-issue and search listings fetch.
+    pub fn with_list_fields(mut self, list_fields: FieldSelection) -> Self {
+        self.list_fields = list_fields;
+        self
+    }
+
+    /// How many times a transient failure is retried; 0 disables retries.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Page size used when a caller does not ask for one (capped at 250).
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+        self
+    }
+
+    /// Most pages an exhaustive listing such as `get_all_assigned_issues` fetches.
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages.max(1);
+        self
+    }
+
+    /// Every issue assigned to a user, following cursors up to the page limit.
+    pub async fn get_all_assigned_issues(&self, user_id: &str) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+        let mut request = PageRequest::default();
+
+        for _ in 0..self.max_pages {
+            let page = self.get_assigned_issues(user_id, &request).await?;
+            issues.extend(page.items);
+            match page.next_cursor {
+                Some(cursor) => request = request.after(cursor),
+                None => return Ok(issues),
+            }
+        }
+
+        warn!(
+            "Stopped after {} pages ({} issues) of assigned issues for {}; raise LINEAR_MAX_PAGES for more",
+            self.max_pages, issues.len(), user_id
+        );
+        Ok(issues)
+    }
+
+    /// Sends a batch in one request and returns each entry's data in the
+    /// order added. An entry that fails or does not exist comes back null,
+    /// so one bad id does not fail the rest.
+    pub async fn execute_batch(&self, batch: &QueryBatch) -> Result<Vec<Value>> {
+        if batch.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let response = self.post_query(&batch.query(), Some(Value::Object(batch.variables.clone()))).await?;
+        let data = &response["data"];
+        if data.is_null() {
+            return Err(Self::graphql_error(&response["errors"], None));
+        }
+
+        Ok((0..batch.len()).map(|i| data[format!("b{}", i)].clone()).collect())
+    }
+
     async fn execute_query(&self, query: &str, variables: Option<Value>) -> Result<Value> {
+        let json = self.post_query(query, variables).await?;
+
+        if let Some(errors) = json.get("errors") {
+            return Err(Self::graphql_error(errors, None));
+        }
+
+        Ok(json.get("data").unwrap_or(&Value::Null).clone())
+    }
+
+    /// Sends a query and returns the whole response, leaving `errors` to the
+    /// caller. Aliased batch queries use this because one missing issue
+    /// should not fail the rest.
+    async fn post_query(&self, query: &str, variables: Option<Value>) -> Result<Value> {
         let mut body = serde_json::json!({
             "query": query
         });
@@ -45,151 +353,1090 @@ impl LinearClient {
             body["variables"] = vars;
         }
 
-        let body_bytes = serde_json::to_vec(&body)?;
+        let body_bytes = Bytes::from(serde_json::to_vec(&body)?);
         let uri: Uri = self.base_url.parse()?;
-        
-        let request = Request::builder()
-            .method(Method::POST)
-            .uri(uri)
-            .header(AUTHORIZATION, HeaderValue::from_str(&self.api_token)?)
-            .header(CONTENT_TYPE, "application/json")
-            .body(Full::new(Bytes::from(body_bytes)))?;
-
-        let response = self.client.request(request).await?;
-        let status = response.status();
-        
-        if !status.is_success() {
-            let body_bytes = response.collect().await?.to_bytes();
-            let error_text = String::from_utf8_lossy(&body_bytes);
-            return Err(anyhow!("GraphQL request failed: {} - {}", status, error_text));
+        let mut attempt = 0;
+        let mut token = match &self.auth {
+            LinearAuth::ApiKey(key) => key.clone(),
+            LinearAuth::OAuth(oauth) => oauth.access_token().await?,
+        };
+        let mut reauthorized = false;
+
+        loop {
+            let authorization = match &self.auth {
+                LinearAuth::ApiKey(_) => HeaderValue::from_str(&token)?,
+                LinearAuth::OAuth(_) => HeaderValue::from_str(&format!("Bearer {}", token))?,
+            };
+            let request = Request::builder()
+                .method(Method::POST)
+                .uri(uri.clone())
+                .header(AUTHORIZATION, authorization)
+                .header(CONTENT_TYPE, "application/json")
+                .body(body_bytes.clone())?;
+
+            let response = match self.client.execute(request).await {
+                Ok(response) => response,
+                Err(error) if attempt < self.max_retries => {
+                    let delay = Self::backoff(attempt);
+                    warn!("Linear request failed ({}); retrying in {:?}", error, delay);
+                    TokioTimer::new().sleep(delay).await;
+                    attempt += 1;
+                    continue;
+                }
+                Err(error) => return Err(TicketServiceError::ProviderUnavailable(format!("Linear request failed: {:#}", error)).into()),
+            };
+
+            let status = response.status();
+
+            // A token revoked or expired early gets one refresh before failing
+            if let (StatusCode::UNAUTHORIZED, LinearAuth::OAuth(oauth), false) = (status, &self.auth, reauthorized) {
+                warn!("Linear rejected the OAuth access token; refreshing it");
+                token = oauth.refresh(&token).await?;
+                reauthorized = true;
+                continue;
+            }
+
+            let retry_delay = Self::log_rate_limit(response.headers());
+            let response_bytes = response.into_body();
+            let json: Option<Value> = serde_json::from_slice(&response_bytes).ok();
+
+            let rate_limited = status == StatusCode::TOO_MANY_REQUESTS
+                || json.as_ref().is_some_and(Self::is_rate_limited);
+            if (rate_limited || status.is_server_error()) && attempt < self.max_retries {
+                let delay = match (rate_limited, retry_delay) {
+                    (true, Some(delay)) => delay.min(MAX_RETRY_DELAY),
+                    _ => Self::backoff(attempt),
+                };
+                if rate_limited {
+                    warn!("Linear rate limit hit; retrying in {:?} (attempt {} of {})", delay, attempt + 1, self.max_retries);
+                } else {
+                    warn!("Linear returned {}; retrying in {:?} (attempt {} of {})", status, delay, attempt + 1, self.max_retries);
+                }
+                TokioTimer::new().sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            if rate_limited {
+                return Err(TicketServiceError::RateLimited {
+                    message: format!("Linear rate limit exceeded after {} retries", attempt),
+                    retry_after: retry_delay,
+                }.into());
+            }
+            if !status.is_success() {
+                let error_text = String::from_utf8_lossy(&response_bytes);
+                let message = format!("GraphQL request failed: {} - {}", status, error_text);
+                return Err(match status {
+                    StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => TicketServiceError::Unauthorized(message).into(),
+                    status if status.is_server_error() => TicketServiceError::ProviderUnavailable(message).into(),
+                    // Linear reports bad tokens and input as GraphQL errors in a 400
+                    _ => match json.as_ref().and_then(|json| json.get("errors")) {
+                        Some(errors) => Self::graphql_error(errors, Some(message)),
+                        None => anyhow!(message),
+                    },
+                });
+            }
+
+            return json.ok_or_else(|| anyhow!("Linear returned a response that is not JSON"));
         }
+    }
 
-        let body_bytes = response.collect().await?.to_bytes();
-        let json: Value = serde_json::from_slice(&body_bytes)?;
-        
-        if let Some(errors) = json.get("errors") {
-            return Err(anyhow!("GraphQL errors: {}", errors));
+    /// Exponential backoff with jitter for the given zero-based attempt.
+    fn backoff(attempt: u32) -> Duration {
+        let delay = BASE_RETRY_DELAY.saturating_mul(2u32.saturating_pow(attempt)).min(MAX_RETRY_DELAY);
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().subsec_nanos();
+        delay + delay.mul_f64(f64::from(nanos % 1000) / 2000.0)
+    }
+
+    /// A typed error for a GraphQL `errors` array, by the first error's
+    /// `extensions.code`; `message` replaces the default description.
+    fn graphql_error(errors: &Value, message: Option<String>) -> anyhow::Error {
+        let message = message.unwrap_or_else(|| format!("GraphQL errors: {}", errors));
+        let first = &errors[0];
+        let error = match first["extensions"]["code"].as_str().unwrap_or_default() {
+            "AUTHENTICATION_ERROR" | "FORBIDDEN" => TicketServiceError::Unauthorized(message),
+            "RATELIMITED" => TicketServiceError::RateLimited { message, retry_after: None },
+            "INVALID_INPUT" | "BAD_USER_INPUT" | "GRAPHQL_VALIDATION_FAILED" => TicketServiceError::Validation(message),
+            _ if first["message"].as_str().is_some_and(|m| m.to_lowercase().contains("not found")) => {
+                TicketServiceError::NotFound(message)
+            }
+            _ => return anyhow!(message),
+        };
+        error.into()
+    }
+
+    /// Linear signals rate limiting with a `RATELIMITED` GraphQL error code.
+    fn is_rate_limited(response: &Value) -> bool {
+        response["errors"]
+            .as_array()
+            .is_some_and(|errors| errors.iter().any(|e| e["extensions"]["code"] == "RATELIMITED"))
+    }
+
+    /// Traces Linear's request and complexity budgets, and returns how long
+    /// to wait before retrying, from `Retry-After` or the reset timestamp.
+    fn log_rate_limit(headers: &HeaderMap) -> Option<Duration> {
+        let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).and_then(|v| v.parse::<u64>().ok());
+
+        let requests_remaining = header("x-ratelimit-requests-remaining");
+        let requests_limit = header("x-ratelimit-requests-limit");
+        let complexity_remaining = header("x-ratelimit-complexity-remaining");
+        let complexity_limit = header("x-ratelimit-complexity-limit");
+        debug!(
+            "Linear rate limit: {:?}/{:?} requests, {:?}/{:?} complexity remaining (query complexity {:?})",
+            requests_remaining, requests_limit, complexity_remaining, complexity_limit, header("x-complexity")
+        );
+
+        let low = |remaining: Option<u64>, limit: Option<u64>| matches!((remaining, limit), (Some(r), Some(l)) if r * 10 < l);
+        if low(requests_remaining, requests_limit) || low(complexity_remaining, complexity_limit) {
+            warn!(
+                "Linear rate limit nearly exhausted: {:?} requests and {:?} complexity remaining",
+                requests_remaining, complexity_remaining
+            );
         }
 
-        Ok(json.get("data").unwrap_or(&Value::Null).clone())
+        if let Some(seconds) = headers.get(RETRY_AFTER).and_then(|v| v.to_str().ok()).and_then(|v| v.parse::<u64>().ok()) {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        // Reset headers are epoch milliseconds
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+        header("x-ratelimit-requests-reset")
+            .into_iter()
+            .chain(header("x-ratelimit-complexity-reset"))
+            .filter(|&reset| reset > now)
+            .max()
+            .map(|reset| Duration::from_millis(reset - now))
     }
 
-    fn parse_issue(&self, issue_data: &Value) -> Result<Issue> {
-        let id = issue_data["id"].as_str().unwrap_or_default().to_string();
-        let identifier = issue_data["identifier"].as_str().unwrap_or_default().to_string();
-        let title = issue_data["title"].as_str().unwrap_or_default().to_string();
-        let description = issue_data["description"].as_str().map(|s| s.to_string());
-        let url = issue_data["url"].as_str().unwrap_or_default().to_string();
-        
-        let priority = match issue_data["priority"].as_u64() {
-            Some(0) => IssuePriority::NoPriority,
-            Some(1) => IssuePriority::Urgent,
-            Some(2) => IssuePriority::High,
-            Some(3) => IssuePriority::Medium,
-            Some(4) => IssuePriority::Low,
-            _ => IssuePriority::NoPriority,
+    fn build_issue_filter(&self, filter: &IssueFilter) -> Value {
+        let mut linear_filter = serde_json::json!({});
+
+        if let Some(assignee_id) = &filter.assignee_id {
+            linear_filter["assignee"] = serde_json::json!({ "id": { "eq": assignee_id } });
+        }
+
+        if let Some(project_id) = &filter.project_id {
+            linear_filter["project"] = serde_json::json!({ "id": { "eq": project_id } });
+        }
+
+        if let Some(team_id) = &filter.team_id {
+            linear_filter["team"] = serde_json::json!({ "id": { "eq": team_id } });
+        }
+
+        if let Some(cycle_id) = &filter.cycle_id {
+            linear_filter["cycle"] = serde_json::json!({ "id": { "eq": cycle_id } });
+        }
+
+        if let Some(blocked) = filter.blocked {
+            linear_filter["hasBlockedByRelations"] = serde_json::json!({ "eq": blocked });
+        }
+
+        if let Some(state_type) = &filter.state_type {
+            // parse_issue folds Linear's triage and backlog states into Unstarted
+            let state_types = match state_type {
+                IssueStateType::Unstarted => vec!["triage", "backlog", "unstarted"],
+                IssueStateType::Started => vec!["started"],
+                IssueStateType::Completed => vec!["completed"],
+                IssueStateType::Canceled => vec!["canceled"],
+            };
+            linear_filter["state"] = serde_json::json!({ "type": { "in": state_types } });
+        }
+
+        if let Some(triage) = filter.triage {
+            // Merged into any state-type comparator above; both must hold
+            let comparator = if triage { "eq" } else { "neq" };
+            linear_filter["state"]["type"][comparator] = serde_json::json!("triage");
+        }
+
+        if let Some(snoozed) = filter.snoozed {
+            linear_filter["snoozedUntilAt"] = serde_json::json!({ "null": !snoozed });
+        }
+
+        if let Some(priority) = &filter.priority {
+            linear_filter["priority"] = serde_json::json!({ "eq": Self::priority_value(priority) });
+        }
+
+        if let Some(labels) = &filter.labels {
+            linear_filter["labels"] = serde_json::json!({ "name": { "in": labels } });
+        }
+
+        let date_range = |after: Option<chrono::DateTime<chrono::Utc>>, before: Option<chrono::DateTime<chrono::Utc>>| {
+            let mut comparator = serde_json::json!({});
+            if let Some(after) = after {
+                comparator["gte"] = serde_json::json!(after.to_rfc3339());
+            }
+            if let Some(before) = before {
+                comparator["lt"] = serde_json::json!(before.to_rfc3339());
+            }
+            (after.is_some() || before.is_some()).then_some(comparator)
         };
 
-        let state = IssueState {
-            id: issue_data["state"]["id"].as_str().unwrap_or_default().to_string(),
-            name: issue_data["state"]["name"].as_str().unwrap_or_default().to_string(),
-            type_: match issue_data["state"]["type"].as_str() {
-                Some("unstarted") => IssueStateType::Unstarted,
-                Some("started") => IssueStateType::Started,
-                Some("completed") => IssueStateType::Completed,
-                Some("canceled") => IssueStateType::Canceled,
-                _ => IssueStateType::Unstarted,
+        if let Some(created) = date_range(filter.created_after, filter.created_before) {
+            linear_filter["createdAt"] = created;
+        }
+
+        if let Some(updated) = date_range(filter.updated_after, filter.updated_before) {
+            linear_filter["updatedAt"] = updated;
+        }
+
+        if let Some(due_before) = filter.due_before {
+            // dueDate is a timeless date
+            linear_filter["dueDate"] = serde_json::json!({ "lt": due_before.format("%Y-%m-%d").to_string() });
+        }
+
+        if let Some(search_query) = &filter.search_query {
+            if !search_query.is_empty() {
+                linear_filter["or"] = serde_json::json!([
+                    { "title": { "containsIgnoreCase": search_query } },
+                    { "description": { "containsIgnoreCase": search_query } }
+                ]);
+            }
+        }
+
+        linear_filter
+    }
+
+    fn project_state_value(state: &ProjectState) -> &'static str {
+        match state {
+            ProjectState::Planned => "planned",
+            ProjectState::Started => "started",
+            ProjectState::Paused => "paused",
+            ProjectState::Completed => "completed",
+            ProjectState::Canceled => "canceled",
+        }
+    }
+
+    /// Decodes a response object into its typed shape; schema drift fails
+    /// here with the offending field's name.
+    fn decode<T: DeserializeOwned>(value: &Value, what: &str) -> Result<T> {
+        T::deserialize(value).map_err(|e| anyhow!("Unexpected Linear {} response: {}", what, e))
+    }
+
+    fn timeless(date: NaiveDate) -> chrono::DateTime<chrono::Utc> {
+        date.and_time(chrono::NaiveTime::MIN).and_utc()
+    }
+
+    fn parse_project(&self, project_data: &Value) -> Result<Project> {
+        let project: ProjectNode = Self::decode(project_data, "project")?;
+
+        let state = match project.state.as_str() {
+            "started" => ProjectState::Started,
+            "paused" => ProjectState::Paused,
+            "completed" => ProjectState::Completed,
+            "canceled" => ProjectState::Canceled,
+            _ => ProjectState::Planned,
+        };
+
+        let milestones = project.project_milestones.nodes
+            .into_iter()
+            .map(|milestone| Self::milestone_from_node(milestone, &project.id))
+            .collect();
+
+        Ok(Project {
+            id: project.id,
+            name: project.name,
+            description: project.description,
+            key: project.slug_id,
+            state,
+            target_date: project.target_date.map(Self::timeless),
+            lead_id: project.lead.map(|lead| lead.id),
+            created_at: project.created_at,
+            updated_at: project.updated_at,
+            progress: project.progress as f32,
+            milestones,
+        })
+    }
+
+    fn milestone_from_node(milestone: MilestoneNode, project_id: &str) -> ProjectMilestone {
+        ProjectMilestone {
+            id: milestone.id,
+            name: milestone.name,
+            description: milestone.description,
+            target_date: milestone.target_date.map(Self::timeless),
+            project_id: project_id.to_string(),
+        }
+    }
+
+    fn parse_milestone(&self, milestone_data: &Value, project_id: &str) -> Result<ProjectMilestone> {
+        let milestone = Self::decode(milestone_data, "project milestone")?;
+        Ok(Self::milestone_from_node(milestone, project_id))
+    }
+
+    fn parse_cycle(&self, cycle_data: &Value, team_id: &str) -> Result<Cycle> {
+        let cycle: CycleNode = Self::decode(cycle_data, "cycle")?;
+
+        Ok(Cycle {
+            id: cycle.id,
+            team_id: team_id.to_string(),
+            number: cycle.number as u32,
+            name: cycle.name,
+            starts_at: cycle.starts_at,
+            ends_at: cycle.ends_at,
+            progress: cycle.progress.map(|p| p as f32),
+            ticket_ids: cycle.issues.nodes.into_iter().map(|issue| issue.id).collect(),
+        })
+    }
+
+    fn parse_attachment(attachment_data: &Value) -> Result<Attachment> {
+        Ok(Self::attachment_from_node(Self::decode(attachment_data, "attachment")?))
+    }
+
+    fn attachment_from_node(attachment: AttachmentNode) -> Attachment {
+        Attachment {
+            mime_type: attachment.metadata["contentType"].as_str().map(|s| s.to_string()),
+            size_bytes: attachment.metadata["size"].as_u64(),
+            id: attachment.id,
+            title: attachment.title,
+            url: attachment.url,
+            uploader_id: attachment.creator.map(|creator| creator.id),
+            // Linear reports uploads and unrecognized links as plain "url"
+            source: attachment.source_type.filter(|source| source != "url"),
+            created_at: attachment.created_at,
+        }
+    }
+
+    fn reaction_from_node(reaction: ReactionNode) -> Reaction {
+        Reaction {
+            id: reaction.id,
+            emoji: reaction.emoji,
+            user_id: reaction.user.map(|user| user.id),
+            created_at: reaction.created_at,
+        }
+    }
+
+    fn parse_comment(comment_data: &Value) -> Result<Comment> {
+        let comment: CommentNode = Self::decode(comment_data, "comment")?;
+
+        Ok(Comment {
+            id: comment.id,
+            body: comment.body,
+            author_id: comment.user.map(|user| user.id),
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+            parent_comment_id: comment.parent.map(|parent| parent.id),
+            reactions: comment.reactions.into_iter().map(Self::reaction_from_node).collect(),
+        })
+    }
+
+    fn parse_webhook(webhook_data: &Value) -> Result<Webhook> {
+        let webhook: WebhookNode = Self::decode(webhook_data, "webhook")?;
+
+        Ok(Webhook {
+            id: webhook.id,
+            url: webhook.url,
+            label: webhook.label,
+            enabled: webhook.enabled,
+            resource_types: webhook.resource_types,
+            team_id: webhook.team.map(|team| team.id),
+            created_at: webhook.created_at,
+        })
+    }
+
+    fn parse_project_update(update_data: &Value, project_id: &str) -> Result<ProjectUpdate> {
+        let update: ProjectUpdateNode = Self::decode(update_data, "project update")?;
+
+        Ok(ProjectUpdate {
+            id: update.id,
+            project_id: project_id.to_string(),
+            body: RichText::Markdown(update.body),
+            health: match update.health.as_deref() {
+                Some("onTrack") => Some(ProjectHealth::OnTrack),
+                Some("atRisk") => Some(ProjectHealth::AtRisk),
+                Some("offTrack") => Some(ProjectHealth::OffTrack),
+                _ => None,
             },
-            position: issue_data["state"]["position"].as_f64().unwrap_or(0.0) as f32,
+            author_id: update.user.map(|user| user.id),
+            created_at: update.created_at,
+            updated_at: update.updated_at,
+            url: update.url,
+        })
+    }
+
+    fn parse_document(document_data: &Value) -> Result<Document> {
+        let document: DocumentNode = Self::decode(document_data, "document")?;
+
+        Ok(Document {
+            id: document.id,
+            title: document.title,
+            content: document.content.map(RichText::Markdown),
+            project_id: document.project.map(|project| project.id),
+            creator_id: document.creator.map(|creator| creator.id),
+            created_at: document.created_at,
+            updated_at: document.updated_at,
+            url: document.url,
+        })
+    }
+
+    fn parse_reaction(reaction_data: &Value) -> Result<Reaction> {
+        Ok(Self::reaction_from_node(Self::decode(reaction_data, "reaction")?))
+    }
+
+    fn related(issue: IssueRef) -> RelatedTicket {
+        RelatedTicket {
+            id: issue.id,
+            identifier: issue.identifier,
+        }
+    }
+
+    /// Maps Linear's directed relations onto both sides: `relations` are
+    /// links from this issue, `inverseRelations` links pointing at it.
+    fn relations_from_node(issue: &mut IssueNode) -> TicketRelations {
+        let mut relations = TicketRelations {
+            parent: issue.parent.take().map(Self::related),
+            children: std::mem::take(&mut issue.children.nodes).into_iter().map(Self::related).collect(),
+            ..Default::default()
         };
 
-        let assignee_id = issue_data["assignee"]["id"].as_str().map(|s| s.to_string());
-        let creator_id = issue_data["creator"]["id"].as_str().unwrap_or_default().to_string();
-        let project_id = issue_data["project"]["id"].as_str().map(|s| s.to_string());
+        for relation in std::mem::take(&mut issue.relations.nodes) {
+            let related = Self::related(relation.related_issue);
+            match relation.type_.as_str() {
+                "blocks" => relations.blocks.push(related),
+                "duplicate" => relations.duplicates.push(related),
+                _ => relations.relates_to.push(related),
+            }
+        }
 
-        let labels: Vec<String> = issue_data["labels"]["nodes"]
-            .as_array()
-            .unwrap_or(&vec![])
-            .iter()
-            .filter_map(|label| label["name"].as_str())
-            .map(|s| s.to_string())
-            .collect();
+        for relation in std::mem::take(&mut issue.inverse_relations.nodes) {
+            let related = Self::related(relation.issue);
+            match relation.type_.as_str() {
+                "blocks" => relations.blocked_by.push(related),
+                "duplicate" => relations.duplicated_by.push(related),
+                _ => relations.relates_to.push(related),
+            }
+        }
+
+        relations
+    }
+
+    fn priority_name(value: Option<f64>) -> Option<String> {
+        let name = match value? as u8 {
+            1 => "Urgent",
+            2 => "High",
+            3 => "Medium",
+            4 => "Low",
+            _ => "No priority",
+        };
+        Some(name.to_string())
+    }
+
+    /// Splits one Linear history entry, which may record several changes at
+    /// once, into one event per change.
+    fn parse_history_entry(entry_data: &Value, ticket_id: &str) -> Result<Vec<TicketEvent>> {
+        let entry: HistoryNode = Self::decode(entry_data, "issue history")?;
+        let name = |named: Option<NameRef>| named.map(|named| named.name);
+        let number = |number: Option<f64>| number.map(|number| number.to_string());
+
+        let mut kinds = Vec::new();
+        if entry.to_state.is_some() {
+            kinds.push(("state", TicketEventKind::StateTransition {
+                from: name(entry.from_state),
+                to: name(entry.to_state),
+            }));
+        }
+        if entry.from_assignee.is_some() || entry.to_assignee.is_some() {
+            kinds.push(("assignee", TicketEventKind::Assignment {
+                from: name(entry.from_assignee),
+                to: name(entry.to_assignee),
+            }));
+        }
+
+        let fields = [
+            ("title", entry.from_title, entry.to_title),
+            ("priority", Self::priority_name(entry.from_priority), Self::priority_name(entry.to_priority)),
+            ("estimate", number(entry.from_estimate), number(entry.to_estimate)),
+            ("due_date", entry.from_due_date, entry.to_due_date),
+            ("project", name(entry.from_project), name(entry.to_project)),
+            ("cycle", number(entry.from_cycle.map(|cycle| cycle.number)), number(entry.to_cycle.map(|cycle| cycle.number))),
+            ("parent", entry.from_parent.map(|parent| parent.identifier), entry.to_parent.map(|parent| parent.identifier)),
+        ];
+        for (field, from, to) in fields {
+            if from.is_some() || to.is_some() {
+                kinds.push((field, TicketEventKind::FieldChanged { field: field.to_string(), from, to }));
+            }
+        }
+
+        let label_names = |labels: Option<Vec<LabelRef>>| -> Option<String> {
+            let names: Vec<String> = labels?.into_iter().map(|label| label.name).collect();
+            (!names.is_empty()).then(|| names.join(", "))
+        };
+        let (removed, added) = (label_names(entry.removed_labels), label_names(entry.added_labels));
+        if removed.is_some() || added.is_some() {
+            kinds.push(("labels", TicketEventKind::FieldChanged { field: "labels".to_string(), from: removed, to: added }));
+        }
+        if entry.updated_description == Some(true) {
+            kinds.push(("description", TicketEventKind::FieldChanged { field: "description".to_string(), from: None, to: None }));
+        }
+
+        let actor_id = entry.actor.map(|actor| actor.id);
+        Ok(kinds
+            .into_iter()
+            .map(|(suffix, kind)| TicketEvent {
+                id: format!("{}:{}", entry.id, suffix),
+                ticket_id: ticket_id.to_string(),
+                actor_id: actor_id.clone(),
+                occurred_at: entry.created_at,
+                kind,
+            })
+            .collect())
+    }
+
+    fn priority_value(priority: &IssuePriority) -> u8 {
+        match priority {
+            IssuePriority::NoPriority => 0,
+            IssuePriority::Urgent => 1,
+            IssuePriority::High => 2,
+            IssuePriority::Medium => 3,
+            IssuePriority::Low => 4,
+        }
+    }
 
-        let created_at = chrono::DateTime::parse_from_rfc3339(
-            issue_data["createdAt"].as_str().unwrap_or("1970-01-01T00:00:00Z")
-        )?.with_timezone(&chrono::Utc);
+    fn parse_issue(&self, issue_data: &Value) -> Result<Issue> {
+        let mut issue: IssueNode = Self::decode(issue_data, "issue")?;
+        let relations = Self::relations_from_node(&mut issue);
 
-        let updated_at = chrono::DateTime::parse_from_rfc3339(
-            issue_data["updatedAt"].as_str().unwrap_or("1970-01-01T00:00:00Z")
-        )?.with_timezone(&chrono::Utc);
+        let priority = match issue.priority as u8 {
+            1 => IssuePriority::Urgent,
+            2 => IssuePriority::High,
+            3 => IssuePriority::Medium,
+            4 => IssuePriority::Low,
+            _ => IssuePriority::NoPriority,
+        };
 
-        let due_date = issue_data["dueDate"].as_str()
-            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
-            .map(|dt| dt.with_timezone(&chrono::Utc));
+        let state = IssueState {
+            id: issue.state.id,
+            name: issue.state.name,
+            type_: match issue.state.type_.as_str() {
+                "started" => IssueStateType::Started,
+                "completed" => IssueStateType::Completed,
+                "canceled" => IssueStateType::Canceled,
+                // Triage and backlog fold into Unstarted
+                _ => IssueStateType::Unstarted,
+            },
+            position: issue.state.position as f32,
+        };
 
-        let estimate = issue_data["estimate"].as_f64().map(|e| e as f32);
+        // Linear's triage SLAs have a single resolution deadline
+        let completed_at = issue.completed_at;
+        let sla = issue.sla_breaches_at.map(|breaches_at| Sla {
+            started_at: issue.sla_started_at,
+            first_response_due: None,
+            resolution_due: completed_at.is_none().then_some(breaches_at),
+            breached: completed_at.map_or(breaches_at <= chrono::Utc::now(), |done| done > breaches_at),
+        });
 
         Ok(Issue {
-            id,
-            identifier,
-            title,
-            description,
+            id: issue.id,
+            identifier: issue.identifier,
+            title: issue.title,
+            description: issue.description,
             priority,
             state,
-            assignee_id,
-            creator_id,
-            project_id,
-            labels,
-            created_at,
-            updated_at,
-            due_date,
-            estimate,
-            url,
+            assignee_id: issue.assignee.map(|assignee| assignee.id),
+            creator_id: issue.creator.map(|creator| creator.id).unwrap_or_default(),
+            project_id: issue.project.map(|project| project.id),
+            cycle_id: issue.cycle.map(|cycle| cycle.id),
+            labels: issue.labels.nodes.into_iter().map(|label| label.name).collect(),
+            subscriber_ids: issue.subscribers.nodes.into_iter().map(|subscriber| subscriber.id).collect(),
+            attachments: issue.attachments.nodes.into_iter().map(Self::attachment_from_node).collect(),
+            relations,
+            reactions: issue.reactions.into_iter().map(Self::reaction_from_node).collect(),
+            created_at: issue.created_at,
+            updated_at: issue.updated_at,
+            due_date: issue.due_date.map(Self::timeless),
+            completed_at,
+            archived_at: issue.archived_at,
+            snoozed_until: issue.snoozed_until_at,
+            estimate: issue.estimate.map(|e| e as f32),
+            sla,
+            url: issue.url,
         })
     }
-}
 
-#[async_trait]
-impl LinearService for LinearClient {
-    async fn get_assigned_issues(&self, user_id: &str) -> Result<Vec<Issue>> {
-        let query = r#"
-            query GetAssignedIssues($userId: String!) {
-                user(id: $userId) {
-                    assignedIssues {
-                        nodes {
-                            id
-                            identifier
-                            title
-                            description
-                            priority
-                            url
-                            createdAt
-                            updatedAt
-                            dueDate
-                            estimate
-                            state {
+    /// Maps a Linear `User` node onto the workspace user type.
+    fn parse_notification(notification_data: &Value) -> Result<Notification> {
+        let notification: NotificationNode = Self::decode(notification_data, "notification")?;
+        let kind = match notification.type_.as_str() {
+            "issueMention" | "issueCommentMention" | "issueDescriptionMention" => NotificationKind::Mention,
+            "issueAssignedToYou" | "issueUnassignedFromYou" => NotificationKind::Assignment,
+            "issueStatusChanged" | "issueStatusChangedAll" => NotificationKind::StateChange,
+            "issueNewComment" | "issueCommentReaction" => NotificationKind::Comment,
+            other => NotificationKind::Other(other.to_string()),
+        };
+        let (ticket_id, ticket_identifier, ticket_title) = match notification.issue {
+            Some(issue) => (Some(issue.id), Some(issue.identifier), Some(issue.title)),
+            None => (None, None, None),
+        };
+        Ok(Notification {
+            id: notification.id,
+            kind,
+            ticket_id,
+            ticket_identifier,
+            ticket_title,
+            actor_id: notification.actor.map(|actor| actor.id),
+            created_at: notification.created_at,
+            read_at: notification.read_at,
+        })
+    }
+
+    fn parse_team(team_data: &Value) -> Result<Team> {
+        let team: TeamNode = Self::decode(team_data, "team")?;
+        Ok(Team {
+            id: team.id,
+            name: team.name,
+            key: team.key,
+            description: team.description,
+            members: Vec::new(), // We'll populate this separately if needed
+            custom_fields: HashMap::new(),
+        })
+    }
+
+    fn parse_workflow_state(state_data: &Value) -> Result<WorkflowState> {
+        let state: StateNode = Self::decode(state_data, "workflow state")?;
+        Ok(WorkflowState {
+            id: state.id,
+            name: state.name,
+            type_: match state.type_.as_str() {
+                "triage" | "backlog" | "unstarted" => StateType::Open,
+                "started" => StateType::InProgress,
+                "completed" => StateType::Closed,
+                "canceled" => StateType::Cancelled,
+                other => StateType::Custom(other.to_string()),
+            },
+            position: state.position as f32,
+            color: state.color,
+        })
+    }
+
+    fn parse_label(label_data: &Value) -> Result<Label> {
+        let label: LabelNode = Self::decode(label_data, "label")?;
+        Ok(Label {
+            id: label.id,
+            name: label.name,
+            color: label.color,
+            description: label.description,
+        })
+    }
+
+    fn parse_initiative(initiative_data: &Value) -> Result<Initiative> {
+        let initiative: InitiativeNode = Self::decode(initiative_data, "initiative")?;
+        Ok(Initiative {
+            id: initiative.id,
+            name: initiative.name,
+            description: initiative.description,
+            status: initiative.status,
+            owner_id: initiative.owner.map(|owner| owner.id),
+            target_date: initiative.target_date.map(Self::timeless),
+            project_ids: initiative.projects.nodes.into_iter().map(|project| project.id).collect(),
+        })
+    }
+
+    fn parse_user(user_data: &Value) -> Result<User> {
+        let user: UserNode = Self::decode(user_data, "user")?;
+        Ok(User {
+            id: user.id,
+            name: user.name,
+            email: user.email,
+            avatar_url: user.avatar_url,
+            display_name: user.display_name,
+            active: user.active,
+            custom_fields: HashMap::new(),
+        })
+    }
+
+    /// `first`/`after` variables for a connection query.
+    fn page_variables(&self, page: &PageRequest) -> (usize, Option<String>) {
+        let first = page.limit.unwrap_or(self.page_size).clamp(1, MAX_PAGE_SIZE);
+        (first, page.cursor.clone())
+    }
+
+    /// The cursor of the next page, if the connection has one.
+    fn next_cursor(connection: &Value) -> Option<String> {
+        let page_info = &connection["pageInfo"];
+        if page_info["hasNextPage"].as_bool().unwrap_or(false) {
+            page_info["endCursor"].as_str().map(|s| s.to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Runs an `issues` query with a raw Linear filter object. Archived
+    /// issues are left out unless `include_archived` is set.
+    async fn query_issues(&self, filter: Value, include_archived: bool, page: &PageRequest) -> Result<Page<Issue>> {
+        let query = format!(r#"
+            query SearchIssues($filter: IssueFilter, $includeArchived: Boolean, $first: Int, $after: String) {{
+                issues(filter: $filter, includeArchived: $includeArchived, first: $first, after: $after) {{
+                    pageInfo {{
+                        hasNextPage
+                        endCursor
+                    }}
+                    nodes {{ {fields} }}
+                }}
+            }}
+        "#, fields = self.list_fields.selection());
+
+        let (first, after) = self.page_variables(page);
+        let variables = serde_json::json!({
+            "filter": filter,
+            "includeArchived": include_archived,
+            "first": first,
+            "after": after
+        });
+
+        let data = self.execute_query(&query, Some(variables)).await?;
+        let issues_data = data["issues"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid issues response format"))?;
+
+        let mut issues = Vec::new();
+        for issue_data in issues_data {
+            issues.push(self.parse_issue(issue_data)?);
+        }
+
+        Ok(Page::new(issues, Self::next_cursor(&data["issues"])))
+    }
+}
+
+#[async_trait]
+impl LinearService for LinearClient {
+    async fn get_assigned_issues(&self, user_id: &str, page: &PageRequest) -> Result<Page<Issue>> {
+        let query = format!(r#"
+            query GetAssignedIssues($userId: String!, $first: Int, $after: String) {{
+                user(id: $userId) {{
+                    assignedIssues(first: $first, after: $after) {{
+                        pageInfo {{
+                            hasNextPage
+                            endCursor
+                        }}
+                        nodes {{ {fields} }}
+                    }}
+                }}
+            }}
+        "#, fields = self.list_fields.selection());
+
+        let (first, after) = self.page_variables(page);
+        let variables = serde_json::json!({
+            "userId": user_id,
+            "first": first,
+            "after": after
+        });
+
+        let data = self.execute_query(&query, Some(variables)).await?;
+        let assigned = &data["user"]["assignedIssues"];
+        let issues_data = assigned["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid response format"))?;
+
+        let mut issues = Vec::new();
+        for issue_data in issues_data {
+            issues.push(self.parse_issue(issue_data)?);
+        }
+
+        Ok(Page::new(issues, Self::next_cursor(assigned)))
+    }
+
+    async fn search_issues(&self, filter: &IssueFilter, page: &PageRequest) -> Result<Page<Issue>> {
+        self.query_issues(self.build_issue_filter(filter), filter.include_archived, page).await
+    }
+
+    async fn get_issues(&self, issue_ids: &[String]) -> Result<Vec<Option<Issue>>> {
+        let mut issues = Vec::with_capacity(issue_ids.len());
+
+        for chunk in issue_ids.chunks(MAX_ALIASED_ISSUES) {
+            let mut batch = QueryBatch::new("GetIssues");
+            for id in chunk {
+                batch.add("issue", &[("id", "String!", serde_json::json!(id))], ISSUE_FIELDS);
+            }
+
+            for issue_data in self.execute_batch(&batch).await? {
+                if issue_data.is_null() {
+                    issues.push(None);
+                } else {
+                    issues.push(Some(self.parse_issue(&issue_data)?));
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+
+    async fn get_issue_by_identifier(&self, team_key: &str, number: u32) -> Result<Option<Issue>> {
+        let issues = self.query_issues(serde_json::json!({
+            "team": { "key": { "eq": team_key.to_uppercase() } },
+            "number": { "eq": number }
+        }), true, &PageRequest::first(1)).await?;
+        Ok(issues.items.into_iter().next())
+    }
+
+    async fn get_issues_by_number(&self, number: u32) -> Result<Vec<Issue>> {
+        let page = self.query_issues(serde_json::json!({
+            "number": { "eq": number }
+        }), true, &PageRequest::default()).await?;
+        Ok(page.items)
+    }
+
+    async fn get_issue(&self, issue_id: &str) -> Result<Option<Issue>> {
+        let query = r#"
+            query GetIssue($id: String!) {
+                issue(id: $id) {
+                    id
+                    identifier
+                    title
+                    description
+                    priority
+                    url
+                    createdAt
+                    updatedAt
+                    dueDate
+                    completedAt
+                    archivedAt
+                    snoozedUntilAt
+                    estimate
+                    slaStartedAt
+                    slaBreachesAt
+                    state {
+                        id
+                        name
+                        type
+                        position
+                    }
+                    assignee {
+                        id
+                        name
+                    }
+                    creator {
+                        id
+                        name
+                    }
+                    project {
+                        id
+                        name
+                    }
+                    cycle {
+                        id
+                    }
+                    labels {
+                        nodes {
+                            id
+                            name
+                        }
+                    }
+                    subscribers {
+                        nodes {
+                            id
+                        }
+                    }
+                    parent {
+                        id
+                        identifier
+                    }
+                    children {
+                        nodes {
+                            id
+                            identifier
+                        }
+                    }
+                    relations {
+                        nodes {
+                            type
+                            relatedIssue {
                                 id
-                                name
-                                type
-                                position
+                                identifier
                             }
-                            assignee {
+                        }
+                    }
+                    inverseRelations {
+                        nodes {
+                            type
+                            issue {
                                 id
-                                name
+                                identifier
                             }
+                        }
+                    }
+                    reactions {
+                        id
+                        emoji
+                        createdAt
+                        user {
+                            id
+                        }
+                    }
+                    attachments {
+                        nodes {
+                            id
+                            title
+                            url
+                            createdAt
                             creator {
                                 id
-                                name
                             }
-                            project {
+                            metadata
+                            sourceType
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": issue_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        
+        if data["issue"].is_null() {
+            return Ok(None);
+        }
+
+        let issue = self.parse_issue(&data["issue"])?;
+        Ok(Some(issue))
+    }
+
+    async fn get_issue_history(&self, issue_id: &str) -> Result<Vec<TicketEvent>> {
+        let query = r#"
+            query GetIssueHistory(
+                $id: String!, $first: Int, $historyAfter: String, $commentsAfter: String,
+                $withHistory: Boolean!, $withComments: Boolean!
+            ) {
+                issue(id: $id) {
+                    id
+                    history(first: $first, after: $historyAfter) @include(if: $withHistory) {
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                        nodes {
+                            id
+                            createdAt
+                            actor { id }
+                            fromState { name }
+                            toState { name }
+                            fromAssignee { name }
+                            toAssignee { name }
+                            fromTitle
+                            toTitle
+                            fromPriority
+                            toPriority
+                            fromEstimate
+                            toEstimate
+                            fromDueDate
+                            toDueDate
+                            fromProject { name }
+                            toProject { name }
+                            fromCycle { number }
+                            toCycle { number }
+                            fromParent { id identifier }
+                            toParent { id identifier }
+                            addedLabels { name }
+                            removedLabels { name }
+                            updatedDescription
+                        }
+                    }
+                    comments(first: $first, after: $commentsAfter) @include(if: $withComments) {
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                        nodes {
+                            id
+                            body
+                            createdAt
+                            updatedAt
+                            user { id }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        // Both connections are paged together; one that runs out is left out
+        // of later requests
+        let mut events = Vec::new();
+        let (mut history_after, mut comments_after) = (None, None);
+        let (mut with_history, mut with_comments) = (true, true);
+        for _ in 0..self.max_pages {
+            let variables = serde_json::json!({
+                "id": issue_id,
+                "first": MAX_PAGE_SIZE,
+                "historyAfter": history_after,
+                "commentsAfter": comments_after,
+                "withHistory": with_history,
+                "withComments": with_comments
+            });
+
+            let data = self.execute_query(query, Some(variables)).await?;
+            let issue_data = &data["issue"];
+            if issue_data.is_null() {
+                return Err(anyhow!("Issue not found: {}", issue_id));
+            }
+            let ticket_id = issue_data["id"].as_str().unwrap_or(issue_id);
+
+            if with_history {
+                let history = &issue_data["history"];
+                let history_data = history["nodes"].as_array()
+                    .ok_or_else(|| anyhow!("Invalid issue history response format"))?;
+                for entry_data in history_data {
+                    events.extend(Self::parse_history_entry(entry_data, ticket_id)?);
+                }
+                history_after = Self::next_cursor(history);
+                with_history = history_after.is_some();
+            }
+
+            if with_comments {
+                let comments = &issue_data["comments"];
+                let comments_data = comments["nodes"].as_array()
+                    .ok_or_else(|| anyhow!("Invalid comments response format"))?;
+                for comment_data in comments_data {
+                    let comment: CommentNode = Self::decode(comment_data, "comment")?;
+                    events.push(TicketEvent {
+                        id: comment.id.clone(),
+                        ticket_id: ticket_id.to_string(),
+                        actor_id: comment.user.map(|user| user.id),
+                        occurred_at: comment.created_at,
+                        kind: TicketEventKind::Comment {
+                            comment_id: comment.id,
+                            body: comment.body,
+                        },
+                    });
+                }
+                comments_after = Self::next_cursor(comments);
+                with_comments = comments_after.is_some();
+            }
+
+            if !with_history && !with_comments {
+                break;
+            }
+        }
+        if with_history || with_comments {
+            warn!(
+                "Stopped after {} pages ({} events) of history for {}; raise LINEAR_MAX_PAGES for more",
+                self.max_pages, events.len(), issue_id
+            );
+        }
+
+        events.sort_by_key(|event| event.occurred_at);
+        Ok(events)
+    }
+
+    async fn get_comments(&self, issue_id: &str, page: &PageRequest) -> Result<Page<Comment>> {
+        let query = r#"
+            query GetComments($id: String!, $first: Int, $after: String) {
+                issue(id: $id) {
+                    comments(first: $first, after: $after) {
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                        nodes {
+                            id
+                            body
+                            createdAt
+                            updatedAt
+                            user { id }
+                            parent { id }
+                            reactions {
                                 id
-                                name
-                            }
-                            labels {
-                                nodes {
-                                    id
-                                    name
-                                }
+                                emoji
+                                createdAt
+                                user { id }
                             }
                         }
                     }
@@ -197,266 +1444,1317 @@ impl LinearService for LinearClient {
             }
         "#;
 
+        let (first, after) = self.page_variables(page);
+        let variables = serde_json::json!({
+            "id": issue_id,
+            "first": first,
+            "after": after
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        if data["issue"].is_null() {
+            return Err(anyhow!("Issue not found: {}", issue_id));
+        }
+        let connection = &data["issue"]["comments"];
+        let comments_data = connection["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid comments response format"))?;
+
+        let mut comments = comments_data
+            .iter()
+            .map(Self::parse_comment)
+            .collect::<Result<Vec<_>>>()?;
+        comments.sort_by_key(|comment| comment.created_at);
+
+        Ok(Page::new(comments, Self::next_cursor(connection)))
+    }
+
+    async fn create_comment(&self, request: &CreateCommentRequest) -> Result<Comment> {
+        let query = r#"
+            mutation CreateComment($input: CommentCreateInput!) {
+                commentCreate(input: $input) {
+                    success
+                    comment {
+                        id
+                        body
+                        createdAt
+                        updatedAt
+                        user { id }
+                        parent { id }
+                    }
+                }
+            }
+        "#;
+
+        let mut input = serde_json::json!({
+            "issueId": request.ticket_id,
+            "body": request.body
+        });
+        if let Some(parent_id) = &request.parent_comment_id {
+            input["parentId"] = serde_json::json!(parent_id);
+        }
+
+        let data = self.execute_query(query, Some(serde_json::json!({ "input": input }))).await?;
+        if !data["commentCreate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to comment on issue {}", request.ticket_id));
+        }
+
+        Self::parse_comment(&data["commentCreate"]["comment"])
+    }
+
+    async fn create_issue(&self, request: &CreateIssueRequest) -> Result<Issue> {
+        let priority = Self::priority_value(request.priority.as_ref().unwrap_or(&IssuePriority::Medium));
+
+        let mut variables = serde_json::json!({
+            "title": request.title,
+            "priority": priority
+        });
+
+        if let Some(description) = &request.description {
+            variables["description"] = serde_json::Value::String(description.clone());
+        }
+
+        if let Some(assignee_id) = &request.assignee_id {
+            variables["assigneeId"] = serde_json::Value::String(assignee_id.clone());
+        }
+
+        let team_id = request.team_id.as_ref()
+            .ok_or_else(|| anyhow!("team_id is required for issue creation"))?;
+        variables["teamId"] = serde_json::Value::String(team_id.clone());
+
+        if let Some(project_id) = &request.project_id {
+            variables["projectId"] = serde_json::Value::String(project_id.clone());
+        }
+
+        if let Some(parent_id) = &request.parent_id {
+            variables["parentId"] = serde_json::Value::String(parent_id.clone());
+        }
+
+        if let Some(due_date) = &request.due_date {
+            variables["dueDate"] = serde_json::Value::String(due_date.format("%Y-%m-%d").to_string());
+        }
+
+        if let Some(estimate) = request.estimate {
+            variables["estimate"] = serde_json::json!(estimate.round() as i64);
+        }
+
+        if let Some(label_ids) = &request.label_ids {
+            variables["labelIds"] = serde_json::Value::Array(
+                label_ids.iter().map(|id| serde_json::Value::String(id.clone())).collect()
+            );
+        }
+
+        let query = r#"
+            mutation CreateIssue($title: String!, $description: String, $priority: Int, $assigneeId: String, $teamId: String!, $projectId: String, $parentId: String, $labelIds: [String!], $dueDate: TimelessDate, $estimate: Int) {
+                issueCreate(input: {
+                    title: $title
+                    description: $description
+                    priority: $priority
+                    assigneeId: $assigneeId
+                    teamId: $teamId
+                    projectId: $projectId
+                    parentId: $parentId
+                    labelIds: $labelIds
+                    dueDate: $dueDate
+                    estimate: $estimate
+                }) {
+                    success
+                    issue {
+                        id
+                        identifier
+                        title
+                        description
+                        priority
+                        url
+                        createdAt
+                        updatedAt
+                        dueDate
+                        completedAt
+                        archivedAt
+                        snoozedUntilAt
+                        estimate
+                        state {
+                            id
+                            name
+                            type
+                            position
+                        }
+                        assignee {
+                            id
+                            name
+                        }
+                        creator {
+                            id
+                            name
+                        }
+                        project {
+                            id
+                            name
+                        }
+                        cycle {
+                            id
+                        }
+                        labels {
+                            nodes {
+                                id
+                                name
+                            }
+                        }
+                        subscribers {
+                            nodes {
+                                id
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        
+        if !data["issueCreate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to create issue"));
+        }
+
+        let issue_data = &data["issueCreate"]["issue"];
+        self.parse_issue(issue_data)
+    }
+
+    async fn update_issue(&self, request: &UpdateIssueRequest) -> Result<Issue> {
+        // Only the fields being changed go in the input; a null would clear them
+        let mut input = serde_json::Map::new();
+        if let Some(title) = &request.title {
+            input.insert("title".to_string(), serde_json::json!(title));
+        }
+        if let Some(description) = &request.description {
+            input.insert("description".to_string(), serde_json::json!(description));
+        }
+        if let Some(priority) = &request.priority {
+            input.insert("priority".to_string(), serde_json::json!(Self::priority_value(priority)));
+        }
+        if let Some(assignee_id) = &request.assignee_id {
+            input.insert("assigneeId".to_string(), serde_json::json!(assignee_id));
+        }
+        if let Some(state_id) = &request.state_id {
+            input.insert("stateId".to_string(), serde_json::json!(state_id));
+        }
+        if let Some(label_ids) = &request.label_ids {
+            input.insert("labelIds".to_string(), serde_json::json!(label_ids));
+        }
+        if let Some(due_date) = &request.due_date {
+            input.insert("dueDate".to_string(), serde_json::json!(due_date.format("%Y-%m-%d").to_string()));
+        }
+        if let Some(estimate) = request.estimate {
+            input.insert("estimate".to_string(), serde_json::json!(estimate.round() as i64));
+        }
+
+        let query = format!(r#"
+            mutation UpdateIssue($id: String!, $input: IssueUpdateInput!) {{
+                issueUpdate(id: $id, input: $input) {{
+                    success
+                    issue {{ {fields} }}
+                }}
+            }}
+        "#, fields = ISSUE_FIELDS);
+
+        let variables = serde_json::json!({
+            "id": request.id,
+            "input": input
+        });
+
+        let data = self.execute_query(&query, Some(variables)).await?;
+        if !data["issueUpdate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to update issue {}", request.id));
+        }
+
+        self.parse_issue(&data["issueUpdate"]["issue"])
+    }
+
+    async fn create_issue_relation(&self, request: &CreateRelationRequest) -> Result<()> {
+        let query = r#"
+            mutation CreateIssueRelation($input: IssueRelationCreateInput!) {
+                issueRelationCreate(input: $input) {
+                    success
+                }
+            }
+        "#;
+
+        // Linear has no "blocked by"; it is "blocks" from the other side
+        let (issue_id, related_id, relation_type) = match request.kind {
+            RelationKind::Blocks => (&request.ticket_id, &request.related_ticket_id, "blocks"),
+            RelationKind::BlockedBy => (&request.related_ticket_id, &request.ticket_id, "blocks"),
+            RelationKind::Duplicates => (&request.ticket_id, &request.related_ticket_id, "duplicate"),
+            RelationKind::RelatesTo => (&request.ticket_id, &request.related_ticket_id, "related"),
+        };
+
+        let variables = serde_json::json!({
+            "input": {
+                "issueId": issue_id,
+                "relatedIssueId": related_id,
+                "type": relation_type
+            }
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        if !data["issueRelationCreate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!(
+                "Failed to link issue {} to {}", request.ticket_id, request.related_ticket_id
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn set_issue_parent(&self, issue_id: &str, parent_id: Option<&str>) -> Result<Issue> {
+        let query = format!(r#"
+            mutation SetIssueParent($id: String!, $parentId: String) {{
+                issueUpdate(id: $id, input: {{ parentId: $parentId }}) {{
+                    success
+                    issue {{ {fields} }}
+                }}
+            }}
+        "#, fields = ISSUE_FIELDS);
+
+        let variables = serde_json::json!({
+            "id": issue_id,
+            "parentId": parent_id
+        });
+
+        let data = self.execute_query(&query, Some(variables)).await?;
+        if !data["issueUpdate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to set the parent of issue {}", issue_id));
+        }
+
+        self.parse_issue(&data["issueUpdate"]["issue"])
+    }
+
+    async fn get_attachments(&self, issue_id: &str) -> Result<Vec<Attachment>> {
+        let query = format!(r#"
+            query GetAttachments($id: String!) {{
+                issue(id: $id) {{
+                    attachments(first: 250) {{
+                        nodes {{ {fields} }}
+                    }}
+                }}
+            }}
+        "#, fields = ATTACHMENT_FIELDS);
+
+        let variables = serde_json::json!({
+            "id": issue_id
+        });
+
+        let data = self.execute_query(&query, Some(variables)).await?;
+        if data["issue"].is_null() {
+            return Err(anyhow!("Issue not found: {}", issue_id));
+        }
+        let attachments_data = data["issue"]["attachments"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid attachments response format"))?;
+
+        let mut attachments = attachments_data
+            .iter()
+            .map(Self::parse_attachment)
+            .collect::<Result<Vec<_>>>()?;
+        attachments.sort_by_key(|attachment| attachment.created_at);
+
+        Ok(attachments)
+    }
+
+    async fn create_attachment(&self, request: &CreateAttachmentRequest) -> Result<Attachment> {
+        // Untitled links go through attachmentLinkURL, which recognizes
+        // GitHub, Slack, Figma and similar URLs and titles them itself
+        let (mutation, query) = match &request.title {
+            Some(_) => ("attachmentCreate", format!(r#"
+                mutation CreateAttachment($issueId: String!, $url: String!, $title: String!) {{
+                    attachmentCreate(input: {{ issueId: $issueId, url: $url, title: $title }}) {{
+                        success
+                        attachment {{ {fields} }}
+                    }}
+                }}
+            "#, fields = ATTACHMENT_FIELDS)),
+            None => ("attachmentLinkURL", format!(r#"
+                mutation LinkAttachment($issueId: String!, $url: String!) {{
+                    attachmentLinkURL(issueId: $issueId, url: $url) {{
+                        success
+                        attachment {{ {fields} }}
+                    }}
+                }}
+            "#, fields = ATTACHMENT_FIELDS)),
+        };
+
+        let mut variables = serde_json::json!({
+            "issueId": request.ticket_id,
+            "url": request.url
+        });
+        if let Some(title) = &request.title {
+            variables["title"] = serde_json::json!(title);
+        }
+
+        let data = self.execute_query(&query, Some(variables)).await?;
+        if !data[mutation]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to attach {} to issue {}", request.url, request.ticket_id));
+        }
+
+        Self::parse_attachment(&data[mutation]["attachment"])
+    }
+
+    async fn archive_issue(&self, issue_id: &str) -> Result<()> {
+        let query = r#"
+            mutation ArchiveIssue($id: String!) {
+                issueArchive(id: $id) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": issue_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+
+        if !data["issueArchive"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to archive issue {}", issue_id));
+        }
+
+        Ok(())
+    }
+
+    async fn delete_issue(&self, issue_id: &str) -> Result<()> {
+        let query = r#"
+            mutation DeleteIssue($id: String!) {
+                issueDelete(id: $id) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": issue_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+
+        if !data["issueDelete"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to delete issue {}", issue_id));
+        }
+
+        Ok(())
+    }
+
+    async fn subscribe_to_issue(&self, issue_id: &str, user_id: &str) -> Result<()> {
+        let query = r#"
+            mutation SubscribeToIssue($id: String!, $userId: String) {
+                issueSubscribe(id: $id, userId: $userId) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": issue_id,
+            "userId": user_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+
+        if !data["issueSubscribe"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to subscribe user {} to issue {}", user_id, issue_id));
+        }
+
+        Ok(())
+    }
+
+    async fn unsubscribe_from_issue(&self, issue_id: &str, user_id: &str) -> Result<()> {
+        let query = r#"
+            mutation UnsubscribeFromIssue($id: String!, $userId: String) {
+                issueUnsubscribe(id: $id, userId: $userId) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": issue_id,
+            "userId": user_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+
+        if !data["issueUnsubscribe"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to unsubscribe user {} from issue {}", user_id, issue_id));
+        }
+
+        Ok(())
+    }
+
+    async fn add_reaction(&self, target: &ReactionTarget, emoji: &str) -> Result<Reaction> {
+        let query = r#"
+            mutation AddReaction($input: ReactionCreateInput!) {
+                reactionCreate(input: $input) {
+                    success
+                    reaction {
+                        id
+                        emoji
+                        createdAt
+                        user {
+                            id
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let mut input = serde_json::json!({ "emoji": emoji });
+        match target {
+            ReactionTarget::Ticket(id) => input["issueId"] = serde_json::json!(id),
+            ReactionTarget::Comment(id) => input["commentId"] = serde_json::json!(id),
+        }
+
+        let data = self.execute_query(query, Some(serde_json::json!({ "input": input }))).await?;
+        if !data["reactionCreate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to add reaction {}", emoji));
+        }
+
+        Self::parse_reaction(&data["reactionCreate"]["reaction"])
+    }
+
+    async fn remove_reaction(&self, reaction_id: &str) -> Result<()> {
+        let query = r#"
+            mutation RemoveReaction($id: String!) {
+                reactionDelete(id: $id) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": reaction_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        if !data["reactionDelete"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to remove reaction {}", reaction_id));
+        }
+
+        Ok(())
+    }
+
+    async fn get_current_user(&self) -> Result<User> {
+        let query = r#"
+            query GetCurrentUser {
+                viewer {
+                    id
+                    name
+                    email
+                    avatarUrl
+                    displayName
+                    active
+                }
+            }
+        "#;
+
+        let data = self.execute_query(query, None).await?;
+        let user_data = &data["viewer"];
+
+        Self::parse_user(user_data)
+    }
+
+    async fn get_notifications(&self, unread_only: bool, page: &PageRequest) -> Result<Page<Notification>> {
+        let query = r#"
+            query GetNotifications($first: Int, $after: String) {
+                notifications(first: $first, after: $after) {
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                    nodes {
+                        id
+                        type
+                        createdAt
+                        readAt
+                        actor {
+                            id
+                        }
+                        ... on IssueNotification {
+                            issue {
+                                id
+                                identifier
+                                title
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let (first, after) = self.page_variables(page);
+        let variables = serde_json::json!({
+            "first": first,
+            "after": after
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        let notifications_data = data["notifications"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid notifications response format"))?;
+
+        let mut notifications = notifications_data
+            .iter()
+            .map(Self::parse_notification)
+            .collect::<Result<Vec<_>>>()?;
+        notifications.retain(|notification| !unread_only || notification.read_at.is_none());
+        notifications.sort_by_key(|n| std::cmp::Reverse(n.created_at));
+
+        Ok(Page::new(notifications, Self::next_cursor(&data["notifications"])))
+    }
+
+    async fn get_teams(&self) -> Result<Vec<Team>> {
+        let query = r#"
+            query GetTeams {
+                teams {
+                    nodes {
+                        id
+                        name
+                        key
+                        description
+                    }
+                }
+            }
+        "#;
+
+        let data = self.execute_query(query, None).await?;
+        let teams_data = data["teams"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid teams response format"))?;
+
+        teams_data.iter().map(Self::parse_team).collect()
+    }
+
+    async fn get_team_members(&self, team_id: &str) -> Result<Vec<User>> {
+        let query = r#"
+            query GetTeamMembers($id: String!) {
+                team(id: $id) {
+                    members {
+                        nodes {
+                            id
+                            name
+                            email
+                            avatarUrl
+                            displayName
+                            active
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": team_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        let members_data = data["team"]["members"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid team members response format"))?;
+
+        let mut members = Vec::new();
+        for user_data in members_data {
+            members.push(Self::parse_user(user_data)?);
+        }
+
+        Ok(members)
+    }
+
+    async fn get_workflow_states(&self, team_id: &str) -> Result<Vec<WorkflowState>> {
+        let query = r#"
+            query GetWorkflowStates($id: String!) {
+                team(id: $id) {
+                    states {
+                        nodes {
+                            id
+                            name
+                            type
+                            position
+                            color
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": team_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        let states_data = data["team"]["states"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid workflow states response format"))?;
+
+        states_data.iter().map(Self::parse_workflow_state).collect()
+    }
+
+    async fn get_labels(&self) -> Result<Vec<Label>> {
+        let query = r#"
+            query GetLabels {
+                issueLabels(first: 250) {
+                    nodes {
+                        id
+                        name
+                        color
+                        description
+                    }
+                }
+            }
+        "#;
+
+        let data = self.execute_query(query, None).await?;
+        let labels_data = data["issueLabels"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid labels response format"))?;
+
+        labels_data.iter().map(Self::parse_label).collect()
+    }
+
+    async fn create_label(&self, request: &CreateLabelRequest) -> Result<Label> {
+        let query = r#"
+            mutation CreateLabel($input: IssueLabelCreateInput!) {
+                issueLabelCreate(input: $input) {
+                    success
+                    issueLabel {
+                        id
+                        name
+                        color
+                        description
+                    }
+                }
+            }
+        "#;
+
+        let mut input = serde_json::json!({
+            "name": request.name,
+            "color": request.color
+        });
+        if let Some(description) = &request.description {
+            input["description"] = serde_json::Value::String(description.clone());
+        }
+
+        let data = self.execute_query(query, Some(serde_json::json!({ "input": input }))).await?;
+        if !data["issueLabelCreate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to create label {}", request.name));
+        }
+
+        Self::parse_label(&data["issueLabelCreate"]["issueLabel"])
+    }
+
+    async fn get_projects(&self, page: &PageRequest) -> Result<Page<Project>> {
+        let query = r#"
+            query GetProjects($first: Int, $after: String) {
+                projects(first: $first, after: $after) {
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                    nodes {
+                        id
+                        name
+                        description
+                        slugId
+                        state
+                        targetDate
+                        progress
+                        createdAt
+                        updatedAt
+                        lead {
+                            id
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let (first, after) = self.page_variables(page);
+        let variables = serde_json::json!({
+            "first": first,
+            "after": after
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        let projects_data = data["projects"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid projects response format"))?;
+
+        let mut projects = Vec::new();
+        for project_data in projects_data {
+            projects.push(self.parse_project(project_data)?);
+        }
+
+        Ok(Page::new(projects, Self::next_cursor(&data["projects"])))
+    }
+
+    async fn get_project(&self, project_id: &str) -> Result<Option<Project>> {
+        let query = r#"
+            query GetProject($id: String!) {
+                project(id: $id) {
+                    id
+                    name
+                    description
+                    slugId
+                    state
+                    targetDate
+                    progress
+                    createdAt
+                    updatedAt
+                    lead {
+                        id
+                    }
+                    projectMilestones {
+                        nodes {
+                            id
+                            name
+                            description
+                            targetDate
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": project_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+
+        if data["project"].is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(self.parse_project(&data["project"])?))
+    }
+
+    async fn get_project_updates(&self, project_id: &str, page: &PageRequest) -> Result<Page<ProjectUpdate>> {
+        let query = r#"
+            query GetProjectUpdates($id: String!, $first: Int, $after: String) {
+                project(id: $id) {
+                    projectUpdates(first: $first, after: $after) {
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                        nodes {
+                            id
+                            body
+                            health
+                            createdAt
+                            updatedAt
+                            url
+                            user { id }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let (first, after) = self.page_variables(page);
+        let variables = serde_json::json!({
+            "id": project_id,
+            "first": first,
+            "after": after
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        if data["project"].is_null() {
+            return Err(anyhow!("Project not found: {}", project_id));
+        }
+        let connection = &data["project"]["projectUpdates"];
+        let updates_data = connection["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid project updates response format"))?;
+
+        let mut updates = updates_data
+            .iter()
+            .map(|update_data| Self::parse_project_update(update_data, project_id))
+            .collect::<Result<Vec<_>>>()?;
+        updates.sort_by_key(|update| std::cmp::Reverse(update.created_at));
+
+        Ok(Page::new(updates, Self::next_cursor(connection)))
+    }
+
+    async fn get_documents(&self, project_id: Option<&str>, page: &PageRequest) -> Result<Page<Document>> {
+        let query = r#"
+            query GetDocuments($filter: DocumentFilter, $first: Int, $after: String) {
+                documents(filter: $filter, first: $first, after: $after) {
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                    nodes {
+                        id
+                        title
+                        url
+                        createdAt
+                        updatedAt
+                        creator { id }
+                        project { id }
+                    }
+                }
+            }
+        "#;
+
+        let filter = project_id.map(|id| serde_json::json!({ "project": { "id": { "eq": id } } }));
+        let (first, after) = self.page_variables(page);
+        let variables = serde_json::json!({
+            "filter": filter,
+            "first": first,
+            "after": after
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        let documents_data = data["documents"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid documents response format"))?;
+
+        let documents = documents_data
+            .iter()
+            .map(Self::parse_document)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Page::new(documents, Self::next_cursor(&data["documents"])))
+    }
+
+    async fn get_document(&self, document_id: &str) -> Result<Option<Document>> {
+        let query = r#"
+            query GetDocument($id: String!) {
+                document(id: $id) {
+                    id
+                    title
+                    content
+                    url
+                    createdAt
+                    updatedAt
+                    creator { id }
+                    project { id }
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": document_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+
+        if data["document"].is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::parse_document(&data["document"])?))
+    }
+
+    async fn get_project_milestones(&self, project_id: &str) -> Result<Vec<ProjectMilestone>> {
+        let query = r#"
+            query GetProjectMilestones($id: String!) {
+                project(id: $id) {
+                    projectMilestones {
+                        nodes {
+                            id
+                            name
+                            description
+                            targetDate
+                        }
+                    }
+                }
+            }
+        "#;
+
         let variables = serde_json::json!({
-            "userId": user_id
+            "id": project_id
         });
 
         let data = self.execute_query(query, Some(variables)).await?;
-        let issues_data = data["user"]["assignedIssues"]["nodes"].as_array()
-            .ok_or_else(|| anyhow!("Invalid response format"))?;
+        let milestones_data = data["project"]["projectMilestones"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid project milestones response format"))?;
 
-        let mut issues = Vec::new();
-        for issue_data in issues_data {
-            issues.push(self.parse_issue(issue_data)?);
+        milestones_data.iter()
+            .map(|milestone_data| self.parse_milestone(milestone_data, project_id))
+            .collect()
+    }
+
+    async fn create_project(&self, request: &CreateProjectRequest) -> Result<Project> {
+        if request.team_ids.is_empty() {
+            return Err(anyhow!("At least one team is required for project creation"));
         }
 
-        Ok(issues)
-    }
+        let mut input = serde_json::json!({
+            "name": request.name,
+            "teamIds": request.team_ids
+        });
 
-    async fn search_issues(&self, _filter: &IssueFilter) -> Result<Vec<Issue>> {
-        todo!("Implement search_issues")
-    }
+        if let Some(description) = &request.description {
+            input["description"] = Value::String(description.clone());
+        }
+
+        if let Some(lead_id) = &request.lead_id {
+            input["leadId"] = Value::String(lead_id.clone());
+        }
+
+        if let Some(target_date) = &request.target_date {
+            input["targetDate"] = Value::String(target_date.format("%Y-%m-%d").to_string());
+        }
 
-    async fn get_issue(&self, issue_id: &str) -> Result<Option<Issue>> {
         let query = r#"
-            query GetIssue($id: String!) {
-                issue(id: $id) {
-                    id
-                    identifier
-                    title
-                    description
-                    priority
-                    url
-                    createdAt
-                    updatedAt
-                    dueDate
-                    estimate
-                    state {
-                        id
-                        name
-                        type
-                        position
-                    }
-                    assignee {
-                        id
-                        name
-                    }
-                    creator {
-                        id
-                        name
-                    }
+            mutation CreateProject($input: ProjectCreateInput!) {
+                projectCreate(input: $input) {
+                    success
                     project {
                         id
                         name
-                    }
-                    labels {
-                        nodes {
+                        description
+                        slugId
+                        state
+                        targetDate
+                        progress
+                        createdAt
+                        updatedAt
+                        lead {
                             id
-                            name
                         }
                     }
                 }
             }
         "#;
 
-        let variables = serde_json::json!({
-            "id": issue_id
-        });
+        let data = self.execute_query(query, Some(serde_json::json!({ "input": input }))).await?;
 
-        let data = self.execute_query(query, Some(variables)).await?;
-        
-        if data["issue"].is_null() {
-            return Ok(None);
+        if !data["projectCreate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to create project"));
         }
 
-        let issue = self.parse_issue(&data["issue"])?;
-        Ok(Some(issue))
+        self.parse_project(&data["projectCreate"]["project"])
     }
 
-    async fn create_issue(&self, request: &CreateIssueRequest) -> Result<Issue> {
-        let priority = match request.priority.as_ref().unwrap_or(&IssuePriority::Medium) {
-            IssuePriority::NoPriority => 0,
-            IssuePriority::Urgent => 1,
-            IssuePriority::High => 2,
-            IssuePriority::Medium => 3,
-            IssuePriority::Low => 4,
-        };
+    async fn update_project(&self, request: &UpdateProjectRequest) -> Result<Project> {
+        let mut input = serde_json::json!({});
 
-        let mut variables = serde_json::json!({
-            "title": request.title,
-            "priority": priority
-        });
+        if let Some(name) = &request.name {
+            input["name"] = Value::String(name.clone());
+        }
 
         if let Some(description) = &request.description {
-            variables["description"] = serde_json::Value::String(description.clone());
+            input["description"] = Value::String(description.clone());
         }
 
-        if let Some(assignee_id) = &request.assignee_id {
-            variables["assigneeId"] = serde_json::Value::String(assignee_id.clone());
+        if let Some(state) = &request.state {
+            input["state"] = Value::String(Self::project_state_value(state).to_string());
         }
 
-        let team_id = request.team_id.as_ref()
-            .ok_or_else(|| anyhow!("team_id is required for issue creation"))?;
-        variables["teamId"] = serde_json::Value::String(team_id.clone());
-
-        if let Some(project_id) = &request.project_id {
-            variables["projectId"] = serde_json::Value::String(project_id.clone());
+        if let Some(lead_id) = &request.lead_id {
+            input["leadId"] = Value::String(lead_id.clone());
         }
 
-        if let Some(label_ids) = &request.label_ids {
-            variables["labelIds"] = serde_json::Value::Array(
-                label_ids.iter().map(|id| serde_json::Value::String(id.clone())).collect()
-            );
+        if let Some(target_date) = &request.target_date {
+            input["targetDate"] = Value::String(target_date.format("%Y-%m-%d").to_string());
         }
 
         let query = r#"
-            mutation CreateIssue($title: String!, $description: String, $priority: Int, $assigneeId: String, $teamId: String!, $projectId: String, $labelIds: [String!]) {
-                issueCreate(input: {
-                    title: $title
-                    description: $description
-                    priority: $priority
-                    assigneeId: $assigneeId
-                    teamId: $teamId
-                    projectId: $projectId
-                    labelIds: $labelIds
-                }) {
+            mutation UpdateProject($id: String!, $input: ProjectUpdateInput!) {
+                projectUpdate(id: $id, input: $input) {
                     success
-                    issue {
+                    project {
                         id
-                        identifier
-                        title
+                        name
                         description
-                        priority
-                        url
+                        slugId
+                        state
+                        targetDate
+                        progress
                         createdAt
                         updatedAt
-                        dueDate
-                        estimate
-                        state {
+                        lead {
                             id
-                            name
-                            type
-                            position
-                        }
-                        assignee {
-                            id
-                            name
-                        }
-                        creator {
-                            id
-                            name
-                        }
-                        project {
-                            id
-                            name
-                        }
-                        labels {
-                            nodes {
-                                id
-                                name
-                            }
                         }
                     }
                 }
             }
         "#;
 
+        let variables = serde_json::json!({
+            "id": request.id,
+            "input": input
+        });
+
         let data = self.execute_query(query, Some(variables)).await?;
-        
-        if !data["issueCreate"]["success"].as_bool().unwrap_or(false) {
-            return Err(anyhow!("Failed to create issue"));
+
+        if !data["projectUpdate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to update project {}", request.id));
         }
 
-        let issue_data = &data["issueCreate"]["issue"];
-        self.parse_issue(issue_data)
+        self.parse_project(&data["projectUpdate"]["project"])
     }
 
-    async fn update_issue(&self, _request: &UpdateIssueRequest) -> Result<Issue> {
-        todo!("Implement update_issue")
-    }
+    async fn create_milestone(&self, request: &CreateMilestoneRequest) -> Result<ProjectMilestone> {
+        let mut input = serde_json::json!({
+            "projectId": request.project_id,
+            "name": request.name
+        });
+
+        if let Some(description) = &request.description {
+            input["description"] = Value::String(description.clone());
+        }
+
+        if let Some(target_date) = &request.target_date {
+            input["targetDate"] = Value::String(target_date.format("%Y-%m-%d").to_string());
+        }
 
-    async fn get_current_user(&self) -> Result<User> {
         let query = r#"
-            query GetCurrentUser {
-                viewer {
-                    id
-                    name
-                    email
-                    avatarUrl
-                    displayName
-                    active
+            mutation CreateMilestone($input: ProjectMilestoneCreateInput!) {
+                projectMilestoneCreate(input: $input) {
+                    success
+                    projectMilestone {
+                        id
+                        name
+                        description
+                        targetDate
+                    }
                 }
             }
         "#;
 
-        let data = self.execute_query(query, None).await?;
-        let user_data = &data["viewer"];
+        let data = self.execute_query(query, Some(serde_json::json!({ "input": input }))).await?;
 
-        Ok(User {
-            id: user_data["id"].as_str().unwrap_or_default().to_string(),
-            name: user_data["name"].as_str().unwrap_or_default().to_string(),
-            email: user_data["email"].as_str().unwrap_or_default().to_string(),
-            avatar_url: user_data["avatarUrl"].as_str().map(|s| s.to_string()),
-            display_name: user_data["displayName"].as_str().unwrap_or_default().to_string(),
-            active: user_data["active"].as_bool().unwrap_or(true),
-            custom_fields: HashMap::new(),
-        })
+        if !data["projectMilestoneCreate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to create milestone in project {}", request.project_id));
+        }
+
+        self.parse_milestone(&data["projectMilestoneCreate"]["projectMilestone"], &request.project_id)
     }
 
-    async fn get_teams(&self) -> Result<Vec<Team>> {
+    async fn get_initiatives(&self) -> Result<Vec<Initiative>> {
         let query = r#"
-            query GetTeams {
-                teams {
+            query GetInitiatives {
+                initiatives {
                     nodes {
                         id
                         name
-                        key
                         description
+                        status
+                        targetDate
+                        owner {
+                            id
+                        }
+                        projects {
+                            nodes {
+                                id
+                            }
+                        }
                     }
                 }
             }
         "#;
 
         let data = self.execute_query(query, None).await?;
-        let teams_data = data["teams"]["nodes"].as_array()
-            .ok_or_else(|| anyhow!("Invalid teams response format"))?;
+        let initiatives_data = data["initiatives"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid initiatives response format"))?;
 
-        let mut teams = Vec::new();
-        for team_data in teams_data {
-            teams.push(Team {
-                id: team_data["id"].as_str().unwrap_or_default().to_string(),
-                name: team_data["name"].as_str().unwrap_or_default().to_string(),
-                key: team_data["key"].as_str().unwrap_or_default().to_string(),
-                description: team_data["description"].as_str().map(|s| s.to_string()),
-                members: Vec::new(), // We'll populate this separately if needed
-                custom_fields: HashMap::new(),
-            });
+        initiatives_data.iter().map(Self::parse_initiative).collect()
+    }
+
+    async fn get_initiative_projects(&self, initiative_id: &str) -> Result<Vec<Project>> {
+        let query = r#"
+            query GetInitiativeProjects($id: String!) {
+                initiative(id: $id) {
+                    projects {
+                        nodes {
+                            id
+                            name
+                            description
+                            slugId
+                            state
+                            targetDate
+                            progress
+                            createdAt
+                            updatedAt
+                            lead {
+                                id
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": initiative_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        let projects_data = data["initiative"]["projects"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Initiative not found: {}", initiative_id))?;
+
+        let mut projects = Vec::new();
+        for project_data in projects_data {
+            projects.push(self.parse_project(project_data)?);
         }
 
-        Ok(teams)
+        Ok(projects)
     }
 
-    async fn get_team_members(&self, _team_id: &str) -> Result<Vec<User>> {
-        todo!("Implement get_team_members")
+    async fn get_cycles(&self, team_id: &str) -> Result<Vec<Cycle>> {
+        let query = format!(r#"
+            query GetCycles($id: String!, $first: Int, $after: String) {{
+                team(id: $id) {{
+                    cycles(first: $first, after: $after) {{
+                        pageInfo {{
+                            hasNextPage
+                            endCursor
+                        }}
+                        nodes {{ {fields} }}
+                    }}
+                }}
+            }}
+        "#, fields = CYCLE_FIELDS);
+
+        let mut cycles = Vec::new();
+        let mut request = PageRequest::default();
+
+        for _ in 0..self.max_pages {
+            let (first, after) = self.page_variables(&request);
+            let variables = serde_json::json!({
+                "id": team_id,
+                "first": first,
+                "after": after
+            });
+
+            let data = self.execute_query(&query, Some(variables)).await?;
+            if data["team"].is_null() {
+                return Err(anyhow!("Team not found: {}", team_id));
+            }
+            let connection = &data["team"]["cycles"];
+            let cycles_data = connection["nodes"].as_array()
+                .ok_or_else(|| anyhow!("Invalid cycles response format"))?;
+
+            for cycle_data in cycles_data {
+                cycles.push(self.parse_cycle(cycle_data, team_id)?);
+            }
+
+            match Self::next_cursor(connection) {
+                Some(cursor) => request = request.after(cursor),
+                None => break,
+            }
+        }
+        cycles.sort_by_key(|cycle| cycle.starts_at);
+
+        Ok(cycles)
     }
 
-    async fn get_labels(&self) -> Result<Vec<Label>> {
-        todo!("Implement get_labels")
+    async fn get_active_cycle(&self, team_id: &str) -> Result<Option<Cycle>> {
+        let query = format!(r#"
+            query GetActiveCycle($id: String!) {{
+                team(id: $id) {{
+                    activeCycle {{ {fields} }}
+                }}
+            }}
+        "#, fields = CYCLE_FIELDS);
+
+        let variables = serde_json::json!({
+            "id": team_id
+        });
+
+        let data = self.execute_query(&query, Some(variables)).await?;
+        if data["team"].is_null() {
+            return Err(anyhow!("Team not found: {}", team_id));
+        }
+        let cycle_data = &data["team"]["activeCycle"];
+
+        if cycle_data.is_null() {
+            return Ok(None);
+        }
+
+        Ok(Some(self.parse_cycle(cycle_data, team_id)?))
     }
 
-    async fn create_label(&self, _request: &CreateLabelRequest) -> Result<Label> {
-        todo!("Implement create_label")
+    async fn set_issue_cycle(&self, issue_id: &str, cycle_id: &str) -> Result<Issue> {
+        let query = format!(r#"
+            mutation SetIssueCycle($id: String!, $cycleId: String!) {{
+                issueUpdate(id: $id, input: {{ cycleId: $cycleId }}) {{
+                    success
+                    issue {{ {fields} }}
+                }}
+            }}
+        "#, fields = ISSUE_FIELDS);
+
+        let variables = serde_json::json!({
+            "id": issue_id,
+            "cycleId": cycle_id
+        });
+
+        let data = self.execute_query(&query, Some(variables)).await?;
+        if !data["issueUpdate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to move issue {} into cycle {}", issue_id, cycle_id));
+        }
+
+        self.parse_issue(&data["issueUpdate"]["issue"])
     }
 
-    async fn get_projects(&self) -> Result<Vec<Project>> {
-        todo!("Implement get_projects")
+    async fn get_webhooks(&self) -> Result<Vec<Webhook>> {
+        let query = format!(r#"
+            query GetWebhooks {{
+                webhooks(first: 250) {{
+                    nodes {{ {fields} }}
+                }}
+            }}
+        "#, fields = WEBHOOK_FIELDS);
+
+        let data = self.execute_query(&query, None).await?;
+        let webhooks_data = data["webhooks"]["nodes"].as_array()
+            .ok_or_else(|| anyhow!("Invalid webhooks response format"))?;
+
+        webhooks_data.iter().map(Self::parse_webhook).collect()
     }
 
-    async fn get_project(&self, _project_id: &str) -> Result<Option<Project>> {
-        todo!("Implement get_project")
+    async fn create_webhook(&self, request: &CreateWebhookRequest) -> Result<Webhook> {
+        let query = format!(r#"
+            mutation CreateWebhook($input: WebhookCreateInput!) {{
+                webhookCreate(input: $input) {{
+                    success
+                    webhook {{ {fields} }}
+                }}
+            }}
+        "#, fields = WEBHOOK_FIELDS);
+
+        let mut input = serde_json::json!({
+            "url": request.url,
+            "resourceTypes": request.resource_types
+        });
+        match &request.team_id {
+            Some(team_id) => input["teamId"] = serde_json::json!(team_id),
+            None => input["allPublicTeams"] = serde_json::json!(true),
+        }
+        if let Some(label) = &request.label {
+            input["label"] = serde_json::json!(label);
+        }
+        if let Some(secret) = &request.secret {
+            input["secret"] = serde_json::json!(secret);
+        }
+
+        let data = self.execute_query(&query, Some(serde_json::json!({ "input": input }))).await?;
+        if !data["webhookCreate"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to create webhook for {}", request.url));
+        }
+
+        Self::parse_webhook(&data["webhookCreate"]["webhook"])
     }
 
-    async fn get_project_milestones(&self, _project_id: &str) -> Result<Vec<ProjectMilestone>> {
-        todo!("Implement get_project_milestones")
+    async fn delete_webhook(&self, webhook_id: &str) -> Result<()> {
+        let query = r#"
+            mutation DeleteWebhook($id: String!) {
+                webhookDelete(id: $id) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({
+            "id": webhook_id
+        });
+
+        let data = self.execute_query(query, Some(variables)).await?;
+        if !data["webhookDelete"]["success"].as_bool().unwrap_or(false) {
+            return Err(anyhow!("Failed to delete webhook {}", webhook_id));
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,315 @@
+//! Typed shapes of the Linear GraphQL objects `LinearClient` selects. Fields
+//! every query selects are required, so a renamed or removed field fails
+//! decoding with its name instead of turning into an empty string; fields
+//! only some queries select default to empty.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Connection<T> {
+    pub nodes: Vec<T>,
+}
+
+impl<T> Default for Connection<T> {
+    fn default() -> Self {
+        Self { nodes: Vec::new() }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct IdRef {
+    pub id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LabelRef {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct NameRef {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct CycleRef {
+    pub number: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct IssueRef {
+    pub id: String,
+    pub identifier: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct StateNode {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub position: f64,
+    /// Only selected when listing a team's states.
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RelationNode {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub related_issue: IssueRef,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct InverseRelationNode {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub issue: IssueRef,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AttachmentNode {
+    pub id: String,
+    pub title: String,
+    pub url: String,
+    /// Free-form; uploads carry `contentType` and `size`, links do not.
+    #[serde(default)]
+    pub metadata: Value,
+    pub creator: Option<IdRef>,
+    pub source_type: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ReactionNode {
+    pub id: String,
+    pub emoji: String,
+    pub user: Option<IdRef>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CommentNode {
+    pub id: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Null for comments posted by integrations.
+    pub user: Option<IdRef>,
+    pub parent: Option<IdRef>,
+    #[serde(default)]
+    pub reactions: Vec<ReactionNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct IssueNode {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: f64,
+    pub url: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub due_date: Option<NaiveDate>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub snoozed_until_at: Option<DateTime<Utc>>,
+    pub estimate: Option<f64>,
+    pub sla_started_at: Option<DateTime<Utc>>,
+    pub sla_breaches_at: Option<DateTime<Utc>>,
+    pub state: StateNode,
+    pub assignee: Option<IdRef>,
+    pub creator: Option<IdRef>,
+    pub project: Option<IdRef>,
+    pub cycle: Option<IdRef>,
+    #[serde(default)]
+    pub labels: Connection<LabelRef>,
+    #[serde(default)]
+    pub subscribers: Connection<IdRef>,
+    #[serde(default)]
+    pub attachments: Connection<AttachmentNode>,
+    #[serde(default)]
+    pub reactions: Vec<ReactionNode>,
+    pub parent: Option<IssueRef>,
+    #[serde(default)]
+    pub children: Connection<IssueRef>,
+    #[serde(default)]
+    pub relations: Connection<RelationNode>,
+    #[serde(default)]
+    pub inverse_relations: Connection<InverseRelationNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UserNode {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    pub avatar_url: Option<String>,
+    pub display_name: String,
+    pub active: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MilestoneNode {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub target_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectNode {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub slug_id: String,
+    pub state: String,
+    pub target_date: Option<NaiveDate>,
+    pub progress: f64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub lead: Option<IdRef>,
+    #[serde(default)]
+    pub project_milestones: Connection<MilestoneNode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CycleNode {
+    pub id: String,
+    pub number: f64,
+    pub name: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub progress: Option<f64>,
+    #[serde(default)]
+    pub issues: Connection<IdRef>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WebhookNode {
+    pub id: String,
+    pub url: String,
+    pub label: Option<String>,
+    pub enabled: bool,
+    pub resource_types: Vec<String>,
+    pub team: Option<IdRef>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ProjectUpdateNode {
+    pub id: String,
+    pub body: String,
+    /// `onTrack`, `atRisk`, or `offTrack`; null when not set.
+    pub health: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub url: Option<String>,
+    pub user: Option<IdRef>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DocumentNode {
+    pub id: String,
+    pub title: String,
+    pub content: Option<String>,
+    pub url: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub creator: Option<IdRef>,
+    pub project: Option<IdRef>,
+}
+
+/// One entry of an issue's history, which may record several changes.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HistoryNode {
+    pub id: String,
+    pub created_at: DateTime<Utc>,
+    pub actor: Option<IdRef>,
+    pub from_state: Option<NameRef>,
+    pub to_state: Option<NameRef>,
+    pub from_assignee: Option<NameRef>,
+    pub to_assignee: Option<NameRef>,
+    pub from_title: Option<String>,
+    pub to_title: Option<String>,
+    pub from_priority: Option<f64>,
+    pub to_priority: Option<f64>,
+    pub from_estimate: Option<f64>,
+    pub to_estimate: Option<f64>,
+    pub from_due_date: Option<String>,
+    pub to_due_date: Option<String>,
+    pub from_project: Option<NameRef>,
+    pub to_project: Option<NameRef>,
+    pub from_cycle: Option<CycleRef>,
+    pub to_cycle: Option<CycleRef>,
+    pub from_parent: Option<IssueRef>,
+    pub to_parent: Option<IssueRef>,
+    pub added_labels: Option<Vec<LabelRef>>,
+    pub removed_labels: Option<Vec<LabelRef>>,
+    pub updated_description: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct NotificationIssueNode {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NotificationNode {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub created_at: DateTime<Utc>,
+    pub read_at: Option<DateTime<Utc>>,
+    pub actor: Option<IdRef>,
+    /// Only issue notifications have one.
+    pub issue: Option<NotificationIssueNode>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct TeamNode {
+    pub id: String,
+    pub name: String,
+    pub key: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LabelNode {
+    pub id: String,
+    pub name: String,
+    pub color: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct InitiativeNode {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub target_date: Option<NaiveDate>,
+    pub owner: Option<IdRef>,
+    #[serde(default)]
+    pub projects: Connection<IdRef>,
+}
@@ -1,148 +1,2132 @@
 use async_trait::async_trait;
 use anyhow::{Result, anyhow};
+use chrono::{DateTime, Duration, Utc};
 use serde_json::{Value, json};
-use std::sync::Arc;
-use tracing::{info, error, debug};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Instant;
+use tracing::{Instrument, info, info_span, error, debug};
 
-use crate::ports::{McpServer, McpTool, McpResource, LinearService};
-use crate::core::Application;
+use crate::domain::{
+    Priority, StateType, Ticket, TicketFilter, ProjectState, CreateTicketRequest,
+    CreateProjectRequest, UpdateProjectRequest, CreateMilestoneRequest, LogTimeRequest,
+    CreateCommentRequest, ReactionTarget, SavedView, TicketTemplate, RichText,
+    CreateRelationRequest, RelationKind, CreateAttachmentRequest,
+    WebhookEvent, WebhookEntity,
+};
+use crate::ports::{Capabilities, McpServer, McpTool, McpResource, TicketServiceError, UnsupportedOperation, unsupported_operation};
+use crate::core::{
+    Application, AuditLog, Continuations, OutputLimits, EventListener, ProviderRegistry, Redactor, Scheduler, SemanticIndex, SyncEngine, SyncSide, MigrationConfig, run_migration, ToolPolicy, DestructiveAction, Impersonation, track_stale_reads, ExportFormat, CsvField, DEFAULT_IMPORT_CONCURRENCY, HashPseudonymizer, Pseudonymizer, TicketSpec, WorkingSet,
+    error_class, find_saved_view, find_template, template_variables, parse_human_date, branch_name,
+    DEFAULT_CAPACITY_PER_MEMBER, DEFAULT_PLANNING_HORIZON_DAYS, DEFAULT_DUPLICATE_LIMIT, DEFAULT_DUE_REMINDER_HOURS,
+};
+#[cfg(feature = "local-search")]
+use crate::core::TicketIndex;
+#[cfg(feature = "local-search")]
+use crate::ports::LOCAL_SEARCH_FIELDS;
+use crate::adapters::tool_args::*;
 
+/// Tools that write to a provider; hidden when the policy is read-only.
+const MUTATING_TOOLS: &[&str] = &[
+    "watch_ticket", "unwatch_ticket",
+    "create_project", "update_project", "create_milestone",
+    "assign_ticket_to_cycle", "log_time",
+    "add_comment", "link_tickets", "set_parent_ticket",
+    "start_ticket_branch",
+    "add_attachment", "add_reaction", "remove_reaction",
+    "create_ticket", "create_from_template", "batch_create_tickets", "create_tickets_from_markdown",
+    "import_tickets_csv",
+    "archive_tickets", "delete_tickets",
+    "sync_run",
+    "migrate_workspace",
+];
+
+/// The optional provider feature a tool is built on, if any, and whether
+/// `capabilities` include it.
+fn required_feature(tool: &str, capabilities: &Capabilities) -> Option<(&'static str, bool)> {
+    Some(match tool {
+        "start_ticket_branch" => ("Ticket updates", capabilities.ticket_updates),
+        "list_comments" | "add_comment" => ("Comments", capabilities.comments),
+        "get_ticket_history" => ("Ticket history", capabilities.ticket_history),
+        "link_tickets" => ("Ticket relations", capabilities.relations),
+        "set_parent_ticket" => ("Sub-tickets", capabilities.sub_tickets),
+        "list_attachments" | "add_attachment" => ("Attachments", capabilities.attachments),
+        "add_reaction" | "remove_reaction" => ("Reactions", capabilities.reactions),
+        "get_notifications" => ("Notifications", capabilities.notifications),
+        "list_project_updates" => ("Project updates", capabilities.project_updates),
+        "list_documents" | "get_document" => ("Documents", capabilities.documents),
+        "list_initiatives" | "get_initiative_projects" => ("Initiatives", capabilities.initiatives),
+        "list_cycles" | "get_current_cycle" | "assign_ticket_to_cycle" => ("Cycles", capabilities.cycles),
+        "log_time" | "list_time_entries" => ("Time tracking", capabilities.time_tracking),
+        _ => return None,
+    })
+}
+
+/// Most resource updates and progress notifications held for a transport to
+/// take; the oldest are dropped past it.
+pub const MAX_PENDING_NOTIFICATIONS: usize = 256;
+
+/// HTTP header a transport reads a session's profile from.
+pub const PROFILE_HEADER: &str = "x-mcp-profile";
+
+/// What a transport knows about a client when its session starts; see
+/// `McpServerImpl::open_session`.
+#[derive(Debug, Clone, Default)]
+pub struct SessionRequest {
+    /// Headers of the client's HTTP request, with lowercase names; empty for
+    /// stdio.
+    pub headers: HashMap<String, String>,
+    /// Params of the client's `initialize` request.
+    pub initialize_params: Option<Value>,
+    /// The client the transport authenticated, if it does.
+    pub identity: Option<String>,
+}
+
+#[derive(Clone)]
 pub struct McpServerImpl {
+    /// The provider a tool call runs against; see `for_application`.
     application: Arc<Application>,
+    registry: ProviderRegistry,
+    working_set: Option<Arc<Mutex<WorkingSet>>>,
+    pseudonymizer: Arc<dyn Pseudonymizer + Send + Sync>,
+    pseudonymize_reports: bool,
+    default_team: Option<String>,
+    /// What ticket branch names start with when a tool call gives no prefix.
+    branch_prefix: Option<String>,
+    /// `provider`, or `profile` when the registry holds named profiles.
+    provider_argument: &'static str,
+    saved_views: Arc<Vec<SavedView>>,
+    templates: Arc<Vec<TicketTemplate>>,
+    sync: Option<Arc<SyncEngine>>,
+    migration: Option<Arc<MigrationConfig>>,
+    scheduler: Option<Arc<Scheduler>>,
+    policy: ToolPolicy,
+    audit: Option<Arc<AuditLog>>,
+    redactor: Option<Arc<Redactor>>,
+    limits: OutputLimits,
+    continuations: Arc<Continuations>,
+    #[cfg(feature = "local-search")]
+    search_index: Option<Arc<TicketIndex>>,
+    semantic: Option<Arc<SemanticIndex>>,
+    /// Resource URIs changed by webhook events and not yet announced, each
+    /// once.
+    resource_updates: Arc<Mutex<Vec<String>>>,
+    /// `notifications/progress` params not yet sent, the latest per token.
+    progress_notifications: Arc<Mutex<Vec<Value>>>,
+    /// The `progressToken` of the request a `for_progress_token` view serves.
+    progress_token: Option<Value>,
+    /// Between `start_server` and `stop_server`.
+    running: Arc<AtomicBool>,
+    /// Tags this server's logs; the audit log's session when there is one.
+    session_id: Arc<str>,
+    impersonation: Option<Arc<Impersonation>>,
+    /// The authenticated client of a `for_user` session.
+    user: Option<Arc<str>>,
 }
 
-impl McpServerImpl {
-    pub fn new(application: Arc<Application>) -> Self {
-        Self { application }
+impl McpServerImpl {
+    pub fn new(application: Arc<Application>) -> Self {
+        Self::with_registry(ProviderRegistry::new("default", application))
+    }
+
+    /// Serves several providers; tools take a `provider` argument naming
+    /// which one to use and fall back to the registry's default.
+    pub fn with_registry(registry: ProviderRegistry) -> Self {
+        Self {
+            application: registry.default_application(),
+            registry,
+            working_set: None,
+            pseudonymizer: Arc::new(HashPseudonymizer::random()),
+            pseudonymize_reports: false,
+            default_team: None,
+            branch_prefix: None,
+            provider_argument: "provider",
+            saved_views: Arc::new(Vec::new()),
+            templates: Arc::new(Vec::new()),
+            sync: None,
+            migration: None,
+            scheduler: None,
+            policy: ToolPolicy::default(),
+            audit: None,
+            redactor: None,
+            limits: OutputLimits::default(),
+            continuations: Arc::new(Continuations::default()),
+            #[cfg(feature = "local-search")]
+            search_index: None,
+            semantic: None,
+            resource_updates: Arc::new(Mutex::new(Vec::new())),
+            progress_notifications: Arc::new(Mutex::new(Vec::new())),
+            progress_token: None,
+            running: Arc::new(AtomicBool::new(false)),
+            session_id: uuid::Uuid::new_v4().to_string().into(),
+            impersonation: None,
+            user: None,
+        }
+    }
+
+    /// A view of this server whose tools run against `application`, sharing
+    /// the working set and configuration.
+    fn for_application(&self, application: Arc<Application>) -> Self {
+        Self {
+            application,
+            ..self.clone()
+        }
+    }
+
+    /// The server a new client session talks to, which transports call once
+    /// per session before dispatching its requests. Each session gets its
+    /// own working set and truncated results. A profile the client asked for
+    /// pins the session to it, for deployments serving several teams with
+    /// their own tokens: tools run against that profile only, and a
+    /// `profile` argument naming another one fails. An authenticated
+    /// identity then runs the session's tools with that user's own tokens.
+    pub async fn open_session(&self, request: &SessionRequest) -> Result<Self> {
+        let header = request.headers.get(PROFILE_HEADER).map(String::as_str);
+        let session = self.for_session(Self::session_profile(header, request.initialize_params.as_ref()))?;
+        match &request.identity {
+            Some(identity) => session.for_user(identity).await,
+            None => Ok(session),
+        }
+    }
+
+    /// A view pinned to `profile`; `None` leaves every profile reachable.
+    fn for_session(&self, profile: Option<&str>) -> Result<Self> {
+        let registry = match profile {
+            Some(profile) => self.registry.only(profile)?,
+            None => self.registry.clone(),
+        };
+        Ok(self.with_session_registry(registry))
+    }
+
+    /// A view of this server for an authenticated client, whose tools run
+    /// with `identity`'s own provider tokens when impersonation is enabled
+    /// and are attributed to it in logs and the audit log either way.
+    /// Providers the user has no token for are not reachable in the view.
+    async fn for_user(&self, identity: &str) -> Result<Self> {
+        let view = match &self.impersonation {
+            Some(impersonation) => self.with_session_registry(impersonation.registry_for(identity, &self.registry).await?),
+            None => self.clone(),
+        };
+        Ok(Self { user: Some(identity.into()), ..view })
+    }
+
+    /// Runs the tool calls of sessions opened with an identity with that
+    /// user's own provider tokens.
+    pub fn with_impersonation(mut self, impersonation: Arc<Impersonation>) -> Self {
+        self.impersonation = Some(impersonation);
+        self
+    }
+
+    /// A view running against `registry`, with session state of its own.
+    fn with_session_registry(&self, registry: ProviderRegistry) -> Self {
+        let working_set = self.working_set.as_ref().map(|working_set| {
+            let capacity = working_set.lock().unwrap().capacity();
+            Arc::new(Mutex::new(WorkingSet::new(capacity)))
+        });
+        Self {
+            application: registry.default_application(),
+            registry,
+            working_set,
+            continuations: Arc::new(Continuations::default()),
+            session_id: uuid::Uuid::new_v4().to_string().into(),
+            ..self.clone()
+        }
+    }
+
+    /// The profile a client asked for: the `X-MCP-Profile` header of its
+    /// HTTP requests, else `_meta.profile` in its `initialize` params.
+    fn session_profile<'a>(header: Option<&'a str>, initialize_params: Option<&'a Value>) -> Option<&'a str> {
+        header
+            .or_else(|| initialize_params?.pointer("/_meta/profile")?.as_str())
+            .map(str::trim)
+            .filter(|profile| !profile.is_empty())
+    }
+
+    /// Replaces the pseudonymizer used for exports and resources.
+    pub fn with_pseudonymizer(mut self, pseudonymizer: Arc<dyn Pseudonymizer + Send + Sync>) -> Self {
+        self.pseudonymizer = pseudonymizer;
+        self
+    }
+
+    /// When enabled, exports and resources replace real user identities with
+    /// stable aliases unless a call explicitly opts out.
+    pub fn with_pseudonymized_reports(mut self, enabled: bool) -> Self {
+        self.pseudonymize_reports = enabled;
+        self
+    }
+
+    /// Team (id or key) that ticket-creating tools fall back to when no team
+    /// is given.
+    pub fn with_default_team(mut self, team: impl Into<String>) -> Self {
+        self.default_team = Some(team.into());
+        self
+    }
+
+    /// Prefix of the branch names `ticket_branch_name` suggests, e.g. a
+    /// username; without one the local part of the current user's email is
+    /// used.
+    pub fn with_branch_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.branch_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Advertises the registry's entries as profiles: tools take a `profile`
+    /// argument instead of `provider`. Both are accepted either way.
+    pub fn with_profiles(mut self) -> Self {
+        self.provider_argument = "profile";
+        self
+    }
+
+    /// Named ticket queries exposed through `run_saved_view`.
+    pub fn with_saved_views(mut self, views: Vec<SavedView>) -> Self {
+        self.saved_views = Arc::new(views);
+        self
+    }
+
+    /// Ticket templates exposed through `create_from_template`.
+    pub fn with_templates(mut self, templates: Vec<TicketTemplate>) -> Self {
+        self.templates = Arc::new(templates);
+        self
+    }
+
+    /// Enables the `sync_status` and `sync_run` tools.
+    pub fn with_sync(mut self, engine: Arc<SyncEngine>) -> Self {
+        self.sync = Some(engine);
+        self
+    }
+
+    /// Enables the `migrate_workspace` tool.
+    pub fn with_migration(mut self, config: MigrationConfig) -> Self {
+        self.migration = Some(Arc::new(config));
+        self
+    }
+
+    /// A view for one request carrying `_meta.progressToken`, whose
+    /// long-running tools report progress against that token.
+    pub fn for_progress_token(&self, token: Value) -> Self {
+        Self {
+            progress_token: Some(token),
+            ..self.clone()
+        }
+    }
+
+    /// Enables the `list_schedules` and `pause_schedule` tools.
+    pub fn with_scheduler(mut self, scheduler: Arc<Scheduler>) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
+    /// Restricts which tools are listed and callable.
+    pub fn with_policy(mut self, policy: ToolPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Records every tool call and enables the `get_audit_log` tool.
+    pub fn with_audit_log(mut self, audit: Arc<AuditLog>) -> Self {
+        self.session_id = audit.session_id().into();
+        self.audit = Some(audit);
+        self
+    }
+
+    /// Filters personal data out of tool results and resources.
+    pub fn with_redactor(mut self, redactor: Arc<Redactor>) -> Self {
+        self.redactor = Some(redactor);
+        self
+    }
+
+    /// Caps ticket lists and descriptions in tool results; truncated lists
+    /// continue through the `get_more_results` tool.
+    pub fn with_output_limits(mut self, limits: OutputLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Whether the server has started and not been stopped.
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    /// Enables the `local_search` tool over `index`.
+    #[cfg(feature = "local-search")]
+    pub fn with_search_index(mut self, index: Arc<TicketIndex>) -> Self {
+        self.search_index = Some(index);
+        self
+    }
+
+    /// Enables the `semantic_search` tool over `semantic`.
+    pub fn with_semantic_index(mut self, semantic: Arc<SemanticIndex>) -> Self {
+        self.semantic = Some(semantic);
+        self
+    }
+
+    /// URIs of resources changed since the last call, for the transport to
+    /// announce with `notifications/resources/updated`. At most
+    /// `MAX_PENDING_NOTIFICATIONS` are held, so a transport that never takes
+    /// them loses only the oldest.
+    pub fn take_resource_updates(&self) -> Vec<String> {
+        self.resource_updates.lock().map(|mut updates| std::mem::take(&mut *updates)).unwrap_or_default()
+    }
+
+    /// Progress of long-running tool calls since the last call, for the
+    /// transport to send as `notifications/progress`. Only the latest
+    /// progress of each token is kept.
+    pub fn take_progress_notifications(&self) -> Vec<Value> {
+        self.progress_notifications.lock().map(|mut progress| std::mem::take(&mut *progress)).unwrap_or_default()
+    }
+
+    /// Queues `notifications/progress` for the request's `progressToken`,
+    /// if it sent one.
+    fn progress_reporter(&self) -> impl Fn(usize, usize, &str) + Send + Sync + use<> {
+        let notifications = self.progress_notifications.clone();
+        let token = self.progress_token.clone();
+        move |done: usize, total: usize, message: &str| {
+            let Some(token) = &token else {
+                return;
+            };
+            if let Ok(mut notifications) = notifications.lock() {
+                let mut params = json!({ "progressToken": token, "progress": done, "message": message });
+                if total > 0 {
+                    params["total"] = json!(total);
+                }
+                // Progress only moves forward, so the latest supersedes the rest
+                notifications.retain(|pending| pending["progressToken"] != *token);
+                push_bounded(&mut notifications, params);
+            }
+        }
+    }
+
+    /// Applies the output limits, marking results that lost anything with
+    /// `truncated: true` and, when tickets were held back, a `continue_cursor`.
+    fn limit_output(&self, result: &mut Value) {
+        if self.limits.is_unlimited() || !result.is_object() {
+            return;
+        }
+        let mut truncated = false;
+        if let Some((field, rest)) = self.limits.split_tickets(result) {
+            result["remaining"] = json!(rest.len());
+            result["continue_cursor"] = json!(self.continuations.stash(field, rest));
+            truncated = true;
+        }
+        truncated |= self.limits.shrink(result);
+        if truncated {
+            result["truncated"] = json!(true);
+        }
+    }
+
+    /// The current profile's default team, else the server-wide one.
+    fn default_team(&self) -> Option<&str> {
+        self.application.defaults().team.as_deref().or(self.default_team.as_deref())
+    }
+
+    fn audit(&self) -> Result<&AuditLog> {
+        self.audit
+            .as_deref()
+            .ok_or_else(|| anyhow!("Audit logging is disabled for this server"))
+    }
+
+    fn permits_tool(&self, name: &str) -> bool {
+        self.policy.permits(name, MUTATING_TOOLS.contains(&name))
+    }
+
+    fn pseudonymize_tickets(&self, tickets: Vec<Ticket>, enabled: bool) -> Vec<Ticket> {
+        if !enabled {
+            return tickets;
+        }
+        tickets.iter().map(|t| self.pseudonymizer.pseudonymize_ticket(t)).collect()
+    }
+
+    /// Enables the session working set, remembering up to `capacity` touched tickets.
+    pub fn with_working_set(mut self, capacity: usize) -> Self {
+        self.working_set = Some(Arc::new(Mutex::new(WorkingSet::new(capacity))));
+        self
+    }
+
+    fn remember_tickets<'a>(&self, tickets: impl IntoIterator<Item = &'a Ticket>) {
+        if let Some(working_set) = &self.working_set {
+            working_set.lock().unwrap().touch_all(tickets);
+        }
+    }
+
+    /// Reads an optional date argument: RFC3339, a plain `YYYY-MM-DD` date,
+    /// or a phrase like "next friday"; see `parse_human_date`.
+    fn parse_date_arg(value: Option<&str>, key: &str, end_of_day: bool) -> Result<Option<DateTime<Utc>>> {
+        let Some(value) = value else {
+            return Ok(None);
+        };
+
+        parse_human_date(value, Utc::now(), end_of_day)
+            .map(Some)
+            .map_err(|e| anyhow!("{}: {}", key, e))
+    }
+
+    fn parse_project_state(value: &str) -> Result<ProjectState> {
+        match value.to_lowercase().as_str() {
+            "planned" => Ok(ProjectState::Planned),
+            "started" => Ok(ProjectState::Started),
+            "paused" => Ok(ProjectState::Paused),
+            "completed" => Ok(ProjectState::Completed),
+            "canceled" | "cancelled" => Ok(ProjectState::Canceled),
+            other => Err(anyhow!("Unknown project state: {}. Expected planned, started, paused, completed, or canceled", other)),
+        }
+    }
+
+    fn parse_relation_kind(value: &str) -> Result<RelationKind> {
+        match value.to_lowercase().replace([' ', '-'], "_").as_str() {
+            "blocks" => Ok(RelationKind::Blocks),
+            "blocked_by" => Ok(RelationKind::BlockedBy),
+            "duplicates" | "duplicate" => Ok(RelationKind::Duplicates),
+            "relates_to" | "related" => Ok(RelationKind::RelatesTo),
+            other => Err(anyhow!("Unknown relation: {}. Expected blocks, blocked_by, duplicates, or relates_to", other)),
+        }
+    }
+
+    fn parse_ticket_spec(spec: TicketSpecArgs) -> Result<TicketSpec> {
+        let request = CreateTicketRequest {
+            title: spec.title,
+            description: spec.description.map(RichText::Markdown),
+            priority: spec.priority.as_deref().map(Priority::parse),
+            assignee_id: spec.assignee_id,
+            team_id: spec.team_id,
+            project_id: spec.project_id,
+            label_ids: spec.label_ids,
+            due_date: Self::parse_date_arg(spec.due_date.as_deref(), "due_date", false)?,
+            estimate: spec.estimate,
+            ..Default::default()
+        };
+        let children = spec.children
+            .into_iter()
+            .map(Self::parse_ticket_spec)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(TicketSpec { request, children })
+    }
+
+    /// Builds a `TicketFilter` from the common filter arguments shared by
+    /// filter-driven tools.
+    fn parse_filter_args(args: FilterArgs) -> Result<TicketFilter> {
+        Ok(TicketFilter {
+            created_after: Self::parse_date_arg(args.created_after.as_deref(), "created_after", false)?,
+            created_before: Self::parse_date_arg(args.created_before.as_deref(), "created_before", false)?,
+            updated_after: Self::parse_date_arg(args.updated_after.as_deref(), "updated_after", false)?,
+            updated_before: match args.stale_days {
+                Some(days) => Some(Utc::now() - Duration::days(days)),
+                None => Self::parse_date_arg(args.updated_before.as_deref(), "updated_before", false)?,
+            },
+            due_before: Self::parse_date_arg(args.due_before.as_deref(), "due_before", false)?,
+            assignee_id: args.assignee_id,
+            project_id: args.project_id,
+            team_id: args.team_id,
+            cycle_id: args.cycle_id,
+            blocked: args.blocked,
+            state_type: args.state.as_deref().map(StateType::parse),
+            priority: args.priority.as_deref().map(Priority::parse),
+            labels: args.labels,
+            search_query: args.query,
+            include_archived: args.include_archived,
+            triage: args.triage,
+            snoozed: args.snoozed,
+            custom_filters: std::collections::HashMap::new(),
+        })
+    }
+
+    /// Parses durations like `90`, `45m`, `2h`, or `1h 30m` into minutes.
+    fn parse_duration_minutes(value: &str) -> Result<u32> {
+        let invalid = || anyhow!("Invalid duration: {}. Use minutes or forms like 2h, 45m, 1h 30m", value);
+        let compact: String = value.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_lowercase();
+
+        if let Ok(minutes) = compact.parse::<u32>() {
+            return Ok(minutes);
+        }
+
+        let mut minutes = 0u32;
+        let mut number = String::new();
+        for c in compact.chars() {
+            if c.is_ascii_digit() {
+                number.push(c);
+                continue;
+            }
+            let amount: u32 = number.parse().map_err(|_| invalid())?;
+            number.clear();
+            let added = match c {
+                'h' => amount.checked_mul(60),
+                'm' => Some(amount),
+                _ => return Err(invalid()),
+            };
+            minutes = added.and_then(|added| minutes.checked_add(added)).ok_or_else(invalid)?;
+        }
+
+        if !number.is_empty() || minutes == 0 {
+            return Err(invalid());
+        }
+        Ok(minutes)
+    }
+
+    /// The JSON-RPC error object for a failed tool call or resource read,
+    /// for transports to send: `-32602` (invalid params) for input the
+    /// provider rejected, `-32601` for unknown tools, `-32002` for unknown
+    /// resources, and `-32603` for everything else, with `data.kind` naming
+    /// the failure and `data.retry_after_secs` when the provider asked to
+    /// back off.
+    pub fn json_rpc_error(error: &anyhow::Error) -> Value {
+        let typed = error.downcast_ref::<TicketServiceError>();
+        let kind = match typed {
+            Some(typed) => typed.kind(),
+            None => error_class(error),
+        };
+        let code = match kind {
+            "validation" | "invalid_arguments" => -32602,
+            _ if error.to_string().starts_with("Unknown tool") => -32601,
+            _ if error.to_string().starts_with("Unknown resource") => -32002,
+            _ => -32603,
+        };
+        let mut data = json!({
+            "kind": kind,
+            "retryable": typed.is_some_and(TicketServiceError::is_retryable),
+        });
+        if let Some(TicketServiceError::RateLimited { retry_after: Some(delay), .. }) = typed {
+            data["retry_after_secs"] = json!(delay.as_secs());
+        }
+        json!({
+            "code": code,
+            "message": format!("{:#}", error),
+            "data": data
+        })
+    }
+
+    /// Turns an `UnsupportedOperation` into a normal result so clients can tell
+    /// "this provider can't do that" apart from a failed call.
+    fn unsupported_or(result: Result<Value>) -> Result<Value> {
+        match result {
+            Err(e) => match unsupported_operation(&e) {
+                Some(unsupported) => Ok(json!({
+                    "supported": false,
+                    "message": unsupported.to_string()
+                })),
+                None => Err(e),
+            },
+            ok => ok,
+        }
+    }
+
+    fn working_set(&self) -> Result<&Mutex<WorkingSet>> {
+        self.working_set
+            .as_deref()
+            .ok_or_else(|| anyhow!("Working set is disabled for this server"))
+    }
+
+    fn sync(&self) -> Result<&SyncEngine> {
+        self.sync
+            .as_deref()
+            .ok_or_else(|| anyhow!("Sync is not configured for this server"))
+    }
+
+    fn migration(&self) -> Result<&MigrationConfig> {
+        self.migration
+            .as_deref()
+            .ok_or_else(|| anyhow!("Migration is not configured for this server"))
+    }
+
+    fn scheduler(&self) -> Result<&Scheduler> {
+        self.scheduler
+            .as_deref()
+            .ok_or_else(|| anyhow!("Recurring tickets are not configured for this server"))
+    }
+
+    fn semantic(&self) -> Result<&SemanticIndex> {
+        self.semantic
+            .as_deref()
+            .ok_or_else(|| anyhow!("Semantic search is not enabled for this server"))
+    }
+
+    /// Fetches tickets an index was told changed: `Some` to re-index, `None`
+    /// for tickets that no longer exist. Tickets that fail to load are
+    /// skipped and keep their indexed version.
+    async fn fetch_stale(&self, stale: Vec<(String, String)>) -> Vec<(String, String, Option<Ticket>)> {
+        let mut fetched = Vec::new();
+        for (provider, ticket_id) in stale {
+            let Ok(application) = self.registry.get(Some(&provider)) else {
+                continue;
+            };
+            match application.get_ticket(&ticket_id).await {
+                Ok(ticket) => fetched.push((provider, ticket_id, ticket)),
+                Err(e) => tracing::warn!("Could not refresh {} for search: {}", ticket_id, e),
+            }
+        }
+        fetched
+    }
+
+    #[cfg(feature = "local-search")]
+    fn search_index(&self) -> Result<&TicketIndex> {
+        self.search_index
+            .as_deref()
+            .ok_or_else(|| anyhow!("Local search is not enabled for this server"))
+    }
+
+    async fn handle_get_assigned_issues(&self, args: GetAssignedIssuesArgs) -> Result<Value> {
+        let page = self.application.get_assigned_tickets(&args.user_id, &args.page.into()).await?;
+        self.remember_tickets(&page.items);
+        Ok(json!({
+            "issues": page.items,
+            "count": page.items.len(),
+            "next_cursor": page.next_cursor,
+            "total": page.total
+        }))
+    }
+
+    async fn handle_get_current_user(&self) -> Result<Value> {
+        let user = self.application.get_current_user().await?;
+        Ok(json!({ "user": user }))
+    }
+
+    async fn handle_search_issues(&self, args: SearchIssuesArgs) -> Result<Value> {
+        let query = args.query;
+
+        let page = self.application.search_tickets(&query, &args.page.into()).await?;
+        self.remember_tickets(&page.items);
+        Ok(json!({
+            "issues": page.items,
+            "count": page.items.len(),
+            "next_cursor": page.next_cursor,
+            "total": page.total,
+            "query": query
+        }))
+    }
+
+    async fn handle_all_providers_search(&self, args: AllProvidersSearchArgs) -> Result<Value> {
+        let query = args.query;
+        let limit = args.limit.unwrap_or(50);
+
+        let results = Application::search_all(&self.registry, &query, limit).await?;
+        Ok(json!({
+            "tickets": results.tickets,
+            "count": results.tickets.len(),
+            "failed_providers": results.failed_providers,
+            "query": query
+        }))
+    }
+
+    #[cfg(feature = "local-search")]
+    async fn handle_local_search(&self, args: LocalSearchArgs, provider: Option<&str>) -> Result<Value> {
+        let index = self.search_index()?;
+        let query = args.query;
+        let limit = args.limit.unwrap_or(50);
+
+        // Catch up on tickets webhooks reported as changed
+        for (ticket_provider, ticket_id, ticket) in self.fetch_stale(index.take_stale()).await {
+            match ticket {
+                Some(ticket) => index.index_tickets(&ticket_provider, &[ticket])?,
+                None => index.remove_ticket(&ticket_provider, &ticket_id)?,
+            }
+        }
+
+        let tickets = index.search(&query, provider, args.fuzzy, limit)?;
+        self.remember_tickets(tickets.iter().map(|t| &t.ticket));
+        Ok(json!({
+            "tickets": tickets,
+            "count": tickets.len(),
+            "query": query
+        }))
+    }
+
+    async fn handle_semantic_search(&self, args: SemanticSearchArgs, provider: Option<&str>) -> Result<Value> {
+        let semantic = self.semantic()?;
+        let query = args.query;
+        let limit = args.limit.unwrap_or(10);
+
+        // Catch up on tickets webhooks reported as changed
+        for (ticket_provider, ticket_id, ticket) in self.fetch_stale(semantic.take_stale()).await {
+            match ticket {
+                Some(ticket) => semantic.index_tickets(&ticket_provider, &[ticket]).await?,
+                None => semantic.remove_ticket(&ticket_provider, &ticket_id),
+            }
+        }
+
+        let matches = semantic.search(&query, provider, limit).await?;
+        self.remember_tickets(matches.iter().map(|m| &m.ticket));
+        Ok(json!({
+            "tickets": matches,
+            "count": matches.len(),
+            "indexed": semantic.len(),
+            "model": semantic.model(),
+            "query": query
+        }))
+    }
+
+    async fn handle_get_issue(&self, args: GetIssueArgs) -> Result<Value> {
+        let issue = self.application.get_ticket(&args.issue_id).await?;
+        self.remember_tickets(&issue);
+        Ok(json!({ "issue": issue }))
+    }
+
+    async fn handle_get_tickets(&self, args: GetTicketsArgs) -> Result<Value> {
+        let ticket_ids = args.ticket_ids;
+        if ticket_ids.is_empty() {
+            return Err(anyhow!("ticket_ids must not be empty"));
+        }
+
+        let results = self.application.get_tickets(&ticket_ids).await?;
+        let mut tickets = Vec::new();
+        let mut not_found = Vec::new();
+        for (id, ticket) in ticket_ids.into_iter().zip(results) {
+            match ticket {
+                Some(ticket) => tickets.push(ticket),
+                None => not_found.push(id),
+            }
+        }
+
+        self.remember_tickets(&tickets);
+        Ok(json!({
+            "tickets": tickets,
+            "count": tickets.len(),
+            "not_found": not_found
+        }))
+    }
+
+    async fn handle_list_saved_views(&self) -> Result<Value> {
+        let views: Vec<Value> = self.saved_views
+            .iter()
+            .map(|view| json!({
+                "name": view.name,
+                "description": view.description
+            }))
+            .collect();
+        Ok(json!({
+            "views": views,
+            "count": views.len()
+        }))
+    }
+
+    async fn handle_run_saved_view(&self, args: RunSavedViewArgs) -> Result<Value> {
+        let name = args.name;
+        let view = find_saved_view(&self.saved_views, &name)
+            .ok_or_else(|| anyhow!("No saved view named {}", name))?;
+
+        let tickets = self.application.run_saved_view(view).await?;
+        self.remember_tickets(&tickets);
+        Ok(json!({
+            "view": view.name,
+            "tickets": tickets,
+            "count": tickets.len()
+        }))
+    }
+
+    async fn handle_working_set_list(&self) -> Result<Value> {
+        let entries = self.working_set()?.lock().unwrap().entries();
+        Ok(json!({
+            "tickets": entries,
+            "count": entries.len()
+        }))
+    }
+
+    async fn handle_get_audit_log(&self, args: AuditLogArgs) -> Result<Value> {
+        let limit = args.limit.unwrap_or(50);
+        let tool = args.tool.as_deref();
+        let session = match args.session.as_deref() {
+            Some("current") => Some(self.audit()?.session_id()),
+            other => other,
+        };
+
+        let entries = self.audit()?.read(limit, tool, session)?;
+        Ok(json!({
+            "entries": entries,
+            "count": entries.len(),
+            "session_id": self.audit()?.session_id()
+        }))
+    }
+
+    async fn handle_sync_status(&self) -> Result<Value> {
+        Ok(json!({ "sync": self.sync()?.status() }))
+    }
+
+    async fn handle_sync_run(&self, args: SyncRunArgs) -> Result<Value> {
+        let prefer = match args.prefer.as_deref() {
+            Some("left") => Some(SyncSide::Left),
+            Some("right") => Some(SyncSide::Right),
+            Some(other) => return Err(anyhow!("prefer must be left or right, got: {}", other)),
+            None => None,
+        };
+
+        let report = self.sync()?.run(prefer).await?;
+        Ok(json!({ "report": report }))
+    }
+
+    async fn handle_migrate_workspace(&self, args: MigrateWorkspaceArgs) -> Result<Value> {
+        let config = self.migration()?;
+        let dry_run = args.dry_run.unwrap_or(true);
+        let progress = self.progress_reporter();
+        let outcome = run_migration(&self.registry, config, dry_run, &progress).await?;
+        Ok(json!({
+            "source": config.source,
+            "target": config.target,
+            "dry_run": dry_run,
+            "outcome": outcome
+        }))
+    }
+
+    async fn handle_get_more_results(&self, args: MoreResultsArgs) -> Result<Value> {
+        let cursor = args.cursor;
+        let (field, items) = self.continuations.take(&cursor)
+            .ok_or_else(|| anyhow!("Unknown or already used cursor: {}", cursor))?;
+
+        // call_tool splits these again if they still exceed the limit
+        Ok(json!({
+            field: items,
+            "count": items.len()
+        }))
+    }
+
+    async fn handle_list_schedules(&self) -> Result<Value> {
+        let schedules = self.scheduler()?.list()?;
+        Ok(json!({
+            "schedules": schedules,
+            "count": schedules.len()
+        }))
+    }
+
+    async fn handle_pause_schedule(&self, args: PauseScheduleArgs) -> Result<Value> {
+        let paused = args.paused.unwrap_or(true);
+
+        let schedule = self.scheduler()?.set_paused(&args.name, paused)?;
+        Ok(json!({ "schedule": schedule }))
+    }
+
+    async fn handle_working_set_pin(&self, args: WorkingSetPinArgs) -> Result<Value> {
+        let ticket_id = args.ticket_id.as_str();
+        let pinned = args.pinned.unwrap_or(true);
+
+        let found = self.working_set()?.lock().unwrap().set_pinned(ticket_id, pinned);
+        if !found {
+            if !pinned {
+                return Err(anyhow!("Ticket {} is not in the working set", ticket_id));
+            }
+
+            // Pinning a ticket the session hasn't seen yet pulls it in first
+            let ticket = self.application.get_ticket(ticket_id).await?
+                .ok_or_else(|| anyhow!("Ticket not found: {}", ticket_id))?;
+            let mut working_set = self.working_set()?.lock().unwrap();
+            working_set.touch(&ticket);
+            working_set.set_pinned(&ticket.id, true);
+        }
+
+        Ok(json!({
+            "ticket_id": ticket_id,
+            "pinned": pinned
+        }))
+    }
+
+    async fn handle_destructive(&self, action: DestructiveAction, args: DestructiveArgs) -> Result<Value> {
+        let ticket_ids = args.ticket_ids;
+
+        match args.confirmation_token.as_deref() {
+            None => {
+                let preview = self.application.preview_destructive(action, &ticket_ids).await?;
+                Ok(json!({
+                    "status": "confirmation_required",
+                    "message": "Nothing has been changed. Call this tool again with the same ticket_ids and this confirmation_token to proceed.",
+                    "preview": preview
+                }))
+            },
+            Some(token) => {
+                let result = self.application.confirm_destructive(action, &ticket_ids, token).await?;
+                Ok(json!({
+                    "status": if result.failed.is_empty() { "completed" } else { "partially_completed" },
+                    "result": result
+                }))
+            },
+        }
+    }
+
+    async fn handle_export_tickets(&self, args: ExportTicketsArgs) -> Result<Value> {
+        let format = ExportFormat::parse(args.format.as_deref().unwrap_or("json"))?;
+        let pseudonymize = args.pseudonymize.unwrap_or(self.pseudonymize_reports);
+        let filter = Self::parse_filter_args(args.filter)?;
+
+        let tickets = self.application.filter_tickets(&filter).await?;
+        let tickets = self.pseudonymize_tickets(tickets, pseudonymize);
+        let text = format.render(&tickets)?;
+        let uri = format!(
+            "tickets://export/{}.{}",
+            Utc::now().format("%Y%m%dT%H%M%SZ"),
+            format.extension()
+        );
+
+        Ok(json!({
+            "content": [{
+                "type": "resource",
+                "resource": {
+                    "uri": uri,
+                    "mimeType": format.mime_type(),
+                    "text": text
+                }
+            }],
+            "count": tickets.len()
+        }))
+    }
+
+    async fn handle_watch_ticket(&self, args: WatchTicketArgs, watch: bool) -> Result<Value> {
+        let ticket_id = args.ticket_id.as_str();
+        let user_id = args.user_id.as_deref();
+
+        let user_id = if watch {
+            self.application.watch_ticket(ticket_id, user_id).await?
+        } else {
+            self.application.unwatch_ticket(ticket_id, user_id).await?
+        };
+
+        Ok(json!({
+            "ticket_id": ticket_id,
+            "user_id": user_id,
+            "watching": watch
+        }))
+    }
+
+    async fn handle_planning_bundle(&self, args: PlanningBundleArgs) -> Result<Value> {
+        let horizon = args.horizon.unwrap_or(DEFAULT_PLANNING_HORIZON_DAYS);
+        let capacity_per_member = args.capacity_per_member.unwrap_or(DEFAULT_CAPACITY_PER_MEMBER);
+
+        let bundle = self.application.get_planning_bundle(&args.team, horizon, capacity_per_member).await?;
+        self.remember_tickets(bundle.carry_over.iter());
+        Ok(json!({ "bundle": bundle }))
+    }
+
+    async fn handle_list_sla_breaches(&self, args: SlaBreachesArgs) -> Result<Value> {
+        let team = args.team.as_deref().or(self.default_team());
+
+        let tickets = self.application.get_sla_breaches(team).await?;
+        self.remember_tickets(&tickets);
+        Ok(json!({
+            "tickets": tickets,
+            "count": tickets.len()
+        }))
+    }
+
+    async fn handle_list_due_soon(&self, args: DueSoonArgs) -> Result<Value> {
+        let hours = args.hours.unwrap_or(DEFAULT_DUE_REMINDER_HOURS);
+
+        let tickets = self.application.get_due_soon(Duration::hours(hours)).await?;
+        self.remember_tickets(&tickets);
+        let now = Utc::now();
+        let overdue = tickets.iter().filter(|ticket| ticket.due_date.is_some_and(|due| due <= now)).count();
+        Ok(json!({
+            "tickets": tickets,
+            "count": tickets.len(),
+            "overdue": overdue,
+            "hours": hours
+        }))
+    }
+
+    async fn handle_list_projects(&self, args: PageArgs) -> Result<Value> {
+        let page = self.application.get_projects(&args.into()).await?;
+        Ok(json!({
+            "projects": page.items,
+            "count": page.items.len(),
+            "next_cursor": page.next_cursor
+        }))
+    }
+
+    async fn handle_get_project(&self, args: ProjectArgs) -> Result<Value> {
+        let project = self.application.get_project(&args.project_id).await?;
+        Ok(json!({ "project": project }))
+    }
+
+    async fn handle_list_project_updates(&self, args: ProjectUpdatesArgs) -> Result<Value> {
+        Self::unsupported_or(
+            self.application.get_project_updates(&args.project_id, &args.page.into()).await
+                .map(|page| json!({
+                    "supported": true,
+                    "updates": page.items,
+                    "count": page.items.len(),
+                    "next_cursor": page.next_cursor
+                }))
+        )
+    }
+
+    async fn handle_list_documents(&self, args: ListDocumentsArgs) -> Result<Value> {
+        Self::unsupported_or(
+            self.application.get_documents(args.project_id.as_deref(), &args.page.into()).await
+                .map(|page| json!({
+                    "supported": true,
+                    "documents": page.items,
+                    "count": page.items.len(),
+                    "next_cursor": page.next_cursor
+                }))
+        )
+    }
+
+    async fn handle_get_document(&self, args: GetDocumentArgs) -> Result<Value> {
+        Self::unsupported_or(
+            self.application.get_document(&args.document_id).await
+                .map(|document| json!({ "supported": true, "document": document }))
+        )
+    }
+
+    async fn handle_create_project(&self, args: CreateProjectArgs) -> Result<Value> {
+        // Teams may be given by key, which is what people actually remember
+        let mut team_ids = Vec::new();
+        for team in &args.teams {
+            team_ids.push(self.application.find_team(team).await?.id);
+        }
+
+        let request = CreateProjectRequest {
+            target_date: Self::parse_date_arg(args.target_date.as_deref(), "target_date", false)?,
+            name: args.name,
+            description: args.description,
+            team_ids,
+            lead_id: args.lead_id,
+        };
+
+        let project = self.application.create_project(&request).await?;
+        Ok(json!({ "project": project }))
+    }
+
+    async fn handle_update_project(&self, args: UpdateProjectArgs) -> Result<Value> {
+        let request = UpdateProjectRequest {
+            state: args.state.as_deref().map(Self::parse_project_state).transpose()?,
+            target_date: Self::parse_date_arg(args.target_date.as_deref(), "target_date", false)?,
+            id: args.project_id,
+            name: args.name,
+            description: args.description,
+            lead_id: args.lead_id,
+        };
+
+        let project = self.application.update_project(&request).await?;
+        Ok(json!({ "project": project }))
+    }
+
+    async fn handle_create_milestone(&self, args: CreateMilestoneArgs) -> Result<Value> {
+        let request = CreateMilestoneRequest {
+            target_date: Self::parse_date_arg(args.target_date.as_deref(), "target_date", false)?,
+            project_id: args.project_id,
+            name: args.name,
+            description: args.description,
+        };
+
+        let milestone = self.application.create_milestone(&request).await?;
+        Ok(json!({ "milestone": milestone }))
+    }
+
+    async fn handle_get_notifications(&self, args: NotificationsArgs) -> Result<Value> {
+        let unread_only = args.unread_only.unwrap_or(true);
+
+        Self::unsupported_or(
+            self.application.get_notifications(unread_only, &args.page.into()).await
+                .map(|page| json!({
+                    "supported": true,
+                    "notifications": page.items,
+                    "count": page.items.len(),
+                    "next_cursor": page.next_cursor
+                }))
+        )
+    }
+
+    async fn handle_list_initiatives(&self) -> Result<Value> {
+        Self::unsupported_or(
+            self.application.get_initiatives().await
+                .map(|initiatives| json!({
+                    "supported": true,
+                    "initiatives": initiatives,
+                    "count": initiatives.len()
+                }))
+        )
+    }
+
+    async fn handle_get_initiative_projects(&self, args: InitiativeProjectsArgs) -> Result<Value> {
+        Self::unsupported_or(
+            self.application.get_initiative_projects(&args.initiative).await
+                .map(|(initiative, projects)| json!({
+                    "supported": true,
+                    "initiative": initiative,
+                    "projects": projects,
+                    "count": projects.len()
+                }))
+        )
+    }
+
+    async fn handle_list_workflow_states(&self, args: TeamArgs) -> Result<Value> {
+        let workflow = self.application.get_workflow(&args.team).await?;
+        Ok(json!({
+            "team_id": workflow.team_id,
+            "states": workflow.states,
+            "count": workflow.states.len()
+        }))
+    }
+
+    async fn handle_list_cycles(&self, args: TeamArgs) -> Result<Value> {
+        let cycles = self.application.get_cycles(&args.team).await?;
+        Ok(json!({
+            "cycles": cycles,
+            "count": cycles.len()
+        }))
+    }
+
+    async fn handle_get_current_cycle(&self, args: TeamArgs) -> Result<Value> {
+        let cycle = self.application.get_active_cycle(&args.team).await?;
+        Ok(json!({ "cycle": cycle }))
+    }
+
+    async fn handle_assign_ticket_to_cycle(&self, args: AssignToCycleArgs) -> Result<Value> {
+        let ticket = self.application
+            .move_ticket_to_cycle(&args.ticket_id, args.cycle_id.as_deref(), args.team.as_deref())
+            .await?;
+        self.remember_tickets([&ticket]);
+        Ok(json!({ "ticket": ticket }))
+    }
+
+    async fn handle_log_time(&self, args: LogTimeArgs) -> Result<Value> {
+        let duration = match &args.duration {
+            DurationArg::Minutes(minutes) => *minutes,
+            DurationArg::Text(text) => Self::parse_duration_minutes(text)?,
+        };
+
+        let request = LogTimeRequest {
+            started_at: Self::parse_date_arg(args.started_at.as_deref(), "started_at", false)?,
+            ticket_id: args.ticket_id,
+            duration_minutes: duration,
+            comment: args.comment,
+        };
+
+        Self::unsupported_or(
+            self.application.log_time(&request).await
+                .map(|entry| json!({ "supported": true, "entry": entry }))
+        )
+    }
+
+    async fn handle_list_time_entries(&self, args: TicketArgs) -> Result<Value> {
+        Self::unsupported_or(
+            self.application.get_time_entries(&args.ticket_id).await
+                .map(|entries| {
+                    let total_minutes: u32 = entries.iter().map(|e| e.duration_minutes).sum();
+                    json!({
+                        "supported": true,
+                        "entries": entries,
+                        "count": entries.len(),
+                        "total_minutes": total_minutes
+                    })
+                })
+        )
+    }
+
+    async fn handle_get_ticket_history(&self, args: TicketArgs) -> Result<Value> {
+        Self::unsupported_or(
+            self.application.get_ticket_history(&args.ticket_id).await
+                .map(|events| json!({
+                    "supported": true,
+                    "events": events,
+                    "count": events.len()
+                }))
+        )
+    }
+
+    async fn handle_list_comments(&self, args: ListCommentsArgs) -> Result<Value> {
+        Self::unsupported_or(
+            self.application.get_comments(&args.ticket_id, &args.page.into()).await
+                .map(|page| json!({
+                    "supported": true,
+                    "comments": page.items,
+                    "count": page.items.len(),
+                    "next_cursor": page.next_cursor
+                }))
+        )
+    }
+
+    async fn handle_add_comment(&self, args: AddCommentArgs) -> Result<Value> {
+        let request = CreateCommentRequest {
+            ticket_id: args.ticket_id,
+            body: args.body,
+            parent_comment_id: args.parent_comment_id,
+        };
+
+        Self::unsupported_or(
+            self.application.add_comment(&request).await
+                .map(|comment| json!({ "supported": true, "comment": comment }))
+        )
+    }
+
+    async fn handle_link_tickets(&self, args: LinkTicketsArgs) -> Result<Value> {
+        let kind = args.relation.as_deref()
+            .map(Self::parse_relation_kind)
+            .transpose()?
+            .unwrap_or(RelationKind::RelatesTo);
+
+        let request = CreateRelationRequest {
+            ticket_id: args.ticket_id,
+            related_ticket_id: args.related_ticket_id,
+            kind,
+        };
+
+        Self::unsupported_or(
+            self.application.link_tickets(&request).await
+                .map(|_| json!({ "supported": true, "linked": request }))
+        )
+    }
+
+    async fn handle_set_parent_ticket(&self, args: SetParentArgs) -> Result<Value> {
+        Self::unsupported_or(
+            self.application.set_ticket_parent(&args.ticket_id, args.parent_id.as_deref()).await
+                .map(|ticket| json!({ "supported": true, "ticket": ticket }))
+        )
+    }
+
+    /// A branch name for `ticket` starting with `prefix`, the configured
+    /// prefix, or the local part of the current user's email.
+    async fn ticket_branch(&self, ticket: &Ticket, prefix: Option<String>) -> Result<String> {
+        let prefix = match prefix.or_else(|| self.branch_prefix.clone()) {
+            Some(prefix) => Some(prefix),
+            None => self.application.get_current_user().await?
+                .email
+                .split_once('@')
+                .map(|(local, _)| local.to_string()),
+        };
+        Ok(branch_name(prefix.as_deref(), &ticket.identifier, &ticket.title))
+    }
+
+    async fn handle_ticket_branch_name(&self, args: TicketBranchNameArgs) -> Result<Value> {
+        let ticket = self.application.get_ticket(&args.ticket_id).await?
+            .ok_or_else(|| anyhow!("Ticket not found: {}", args.ticket_id))?;
+        let branch = self.ticket_branch(&ticket, args.prefix).await?;
+        self.remember_tickets([&ticket]);
+        Ok(json!({
+            "branch": branch,
+            "checkout": format!("git checkout -b {}", branch),
+            "identifier": ticket.identifier,
+            "url": ticket.url
+        }))
+    }
+
+    async fn handle_parse_ticket_references(&self, args: ParseTicketReferencesArgs) -> Result<Value> {
+        let references = self.application.ticket_references(&args.text).await?;
+        if references.is_empty() {
+            return Ok(json!({ "references": [], "tickets": [], "not_found": [] }));
+        }
+
+        let results = self.application.get_tickets(&references).await?;
+        let mut tickets = Vec::new();
+        let mut not_found = Vec::new();
+        for (reference, ticket) in references.iter().zip(results) {
+            match ticket {
+                Some(ticket) => tickets.push(ticket),
+                None => not_found.push(reference.clone()),
+            }
+        }
+
+        self.remember_tickets(&tickets);
+        Ok(json!({
+            "references": references,
+            "tickets": tickets,
+            "not_found": not_found
+        }))
+    }
+
+    async fn handle_start_ticket_branch(&self, args: StartTicketBranchArgs) -> Result<Value> {
+        let (ticket_id, branch) = match (args.ticket_id, args.branch) {
+            (Some(ticket_id), None) => (ticket_id, None),
+            (None, Some(branch)) => {
+                let references = self.application.ticket_references(&branch).await?;
+                let ticket_id = references.into_iter().next().ok_or_else(|| {
+                    TicketServiceError::Validation(format!("Branch {} names no ticket", branch))
+                })?;
+                (ticket_id, Some(branch))
+            }
+            _ => return Err(TicketServiceError::Validation("Give either ticket_id or branch".to_string()).into()),
+        };
+
+        let (ticket, moved) = self.application.start_ticket(&ticket_id, args.state.as_deref()).await?;
+        // An existing branch needs no checkout command
+        let (branch, checkout) = match branch {
+            Some(branch) => (branch, None),
+            None => {
+                let branch = self.ticket_branch(&ticket, args.prefix).await?;
+                let checkout = format!("git checkout -b {}", branch);
+                (branch, Some(checkout))
+            }
+        };
+        self.remember_tickets([&ticket]);
+        Ok(json!({
+            "branch": branch,
+            "checkout": checkout,
+            "moved": moved,
+            "ticket": ticket
+        }))
+    }
+
+    async fn handle_list_attachments(&self, args: TicketArgs) -> Result<Value> {
+        Self::unsupported_or(
+            self.application.get_attachments(&args.ticket_id).await
+                .map(|attachments| json!({
+                    "supported": true,
+                    "attachments": attachments,
+                    "count": attachments.len()
+                }))
+        )
     }
 
-    fn create_tool_schema(name: &str, description: &str, properties: Value) -> Value {
-        json!({
-            "type": "object",
-            "properties": properties,
-            "required": []
-        })
+    async fn handle_add_attachment(&self, args: AddAttachmentArgs) -> Result<Value> {
+        let request = CreateAttachmentRequest {
+            ticket_id: args.ticket_id,
+            url: args.url,
+            title: args.title,
+        };
+
+        Self::unsupported_or(
+            self.application.add_attachment(&request).await
+                .map(|attachment| json!({ "supported": true, "attachment": attachment }))
+        )
     }
 
-    async fn handle_get_assigned_issues(&self, args: Value) -> Result<Value> {
-        let user_id = args.get("user_id")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| anyhow!("user_id is required"))?;
+    async fn handle_add_reaction(&self, args: AddReactionArgs) -> Result<Value> {
+        let target = match (args.ticket_id, args.comment_id) {
+            (None, Some(comment_id)) => ReactionTarget::Comment(comment_id),
+            (Some(ticket_id), None) => ReactionTarget::Ticket(ticket_id),
+            _ => return Err(TicketServiceError::Validation("Exactly one of ticket_id or comment_id is required".to_string()).into()),
+        };
+
+        Self::unsupported_or(
+            self.application.add_reaction(&target, &args.emoji).await
+                .map(|reaction| json!({ "supported": true, "reaction": reaction }))
+        )
+    }
+
+    async fn handle_remove_reaction(&self, args: RemoveReactionArgs) -> Result<Value> {
+        Self::unsupported_or(
+            self.application.remove_reaction(&args.reaction_id).await
+                .map(|_| json!({ "supported": true, "removed": args.reaction_id }))
+        )
+    }
+
+    async fn handle_find_duplicate_tickets(&self, args: FindDuplicatesArgs) -> Result<Value> {
+        let limit = args.limit.unwrap_or(DEFAULT_DUPLICATE_LIMIT);
 
-        let issues = self.application.get_assigned_tickets(user_id).await?;
+        let candidates = self.application
+            .find_duplicate_tickets(&args.title, args.description.as_deref(), &args.labels, args.team_id.as_deref(), limit)
+            .await?;
         Ok(json!({
-            "issues": issues,
-            "count": issues.len()
+            "candidates": candidates,
+            "count": candidates.len()
         }))
     }
 
-    async fn handle_get_current_user(&self) -> Result<Value> {
-        let user = self.application.get_current_user().await?;
-        Ok(json!({ "user": user }))
+    async fn handle_batch_create_tickets(&self, args: BatchCreateArgs) -> Result<Value> {
+        let mut specs = args.tickets
+            .into_iter()
+            .map(Self::parse_ticket_spec)
+            .collect::<Result<Vec<_>>>()?;
+
+        if let Some(team) = args.team.as_deref().or(self.default_team()) {
+            let team_id = self.application.find_team(team).await?.id;
+            for spec in specs.iter_mut().filter(|s| s.request.team_id.is_none()) {
+                spec.request.team_id = Some(team_id.clone());
+            }
+        }
+
+        let result = self.application.batch_create_tickets(&specs, args.rollback_on_failure).await?;
+        Ok(json!({ "result": result }))
     }
 
-    async fn handle_search_issues(&self, args: Value) -> Result<Value> {
-        let query = args.get("query")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+    async fn handle_create_tickets_from_markdown(&self, args: MarkdownTicketsArgs) -> Result<Value> {
+        let team = args.team.as_deref()
+            .or(self.default_team())
+            .ok_or_else(|| anyhow!("team is required when no default team is configured"))?;
+
+        let team_id = self.application.find_team(team).await?.id;
+        let result = self.application
+            .create_tickets_from_markdown(&args.markdown, &team_id, args.rollback_on_failure)
+            .await?;
+        Ok(json!({ "result": result }))
+    }
+
+    async fn handle_import_tickets_csv(&self, args: ImportTicketsCsvArgs) -> Result<Value> {
+        let columns = args.columns
+            .iter()
+            .map(|(column, field)| Ok((column.clone(), CsvField::parse(field)?)))
+            .collect::<Result<BTreeMap<_, _>>>()
+            .map_err(|e| TicketServiceError::Validation(e.to_string()))?;
+        let team = args.team.as_deref().or(self.default_team());
+        let plan = self.application.plan_csv_import(&args.csv, &columns, team).await
+            .map_err(|e| match e.downcast::<TicketServiceError>() {
+                Ok(typed) => typed.into(),
+                // Everything else is a problem with the CSV itself
+                Err(e) => anyhow::Error::from(TicketServiceError::Validation(e.to_string())),
+            })?;
+
+        let dry_run = args.dry_run.unwrap_or(true);
+        if dry_run {
+            return Ok(json!({ "dry_run": true, "valid": plan.is_valid(), "plan": plan }));
+        }
+        if !plan.is_valid() {
+            return Err(TicketServiceError::Validation(format!(
+                "{} rows are invalid; nothing was created. Fix them or run with dry_run for the report:\n{}",
+                plan.invalid.len(), plan
+            )).into());
+        }
+
+        let progress = self.progress_reporter();
+        let concurrency = args.concurrency.unwrap_or(DEFAULT_IMPORT_CONCURRENCY);
+        let report = self.application.import_csv(&plan, concurrency, &progress).await;
+        Ok(json!({ "dry_run": false, "report": report }))
+    }
 
-        let issues = self.application.search_tickets(query).await?;
+    async fn handle_list_templates(&self) -> Result<Value> {
+        let templates: Vec<Value> = self.templates
+            .iter()
+            .map(|template| json!({
+                "name": template.name,
+                "title": template.title,
+                "variables": template_variables(template)
+            }))
+            .collect();
         Ok(json!({
-            "issues": issues,
-            "count": issues.len(),
-            "query": query
+            "templates": templates,
+            "count": templates.len()
         }))
     }
 
-    async fn handle_get_issue(&self, args: Value) -> Result<Value> {
-        let issue_id = args.get("issue_id")
+    async fn handle_create_ticket(&self, args: CreateTicketArgs) -> Result<Value> {
+        let team = args.team.as_deref()
+            .or(self.default_team())
+            .ok_or_else(|| anyhow!("team is required: no default team is configured"))?;
+
+        let request = CreateTicketRequest {
+            team_id: Some(self.application.find_team(team).await?.id),
+            due_date: Self::parse_date_arg(args.due_date.as_deref(), "due_date", true)?,
+            title: args.title,
+            description: args.description.as_deref().map(RichText::markdown),
+            priority: args.priority.as_deref().map(Priority::parse),
+            assignee_id: args.assignee_id,
+            project_id: args.project_id,
+            parent_id: args.parent_id,
+            label_ids: args.label_ids,
+            estimate: args.estimate,
+            custom_fields: None,
+        };
+
+        let ticket = self.application.create_ticket(&request, args.idempotency_key.as_deref()).await?;
+        self.remember_tickets([&ticket]);
+        Ok(json!({ "ticket": ticket }))
+    }
+
+    async fn handle_create_from_template(&self, args: CreateFromTemplateArgs) -> Result<Value> {
+        let template = find_template(&self.templates, &args.template)
+            .ok_or_else(|| anyhow!("No ticket template named {}", args.template))?;
+
+        let variables = args.variables
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    Value::String(s) => s,
+                    other => other.to_string(),
+                };
+                (key, value)
+            })
+            .collect();
+
+        let team = args.team.as_deref()
+            .or(template.team.as_deref())
+            .or(self.default_team())
+            .ok_or_else(|| anyhow!("team is required: the template has none and no default team is configured"))?;
+
+        let ticket = self.application
+            .create_from_template(template, &variables, team, args.assignee_id.as_deref())
+            .await?;
+        self.remember_tickets([&ticket]);
+        Ok(json!({ "ticket": ticket }))
+    }
+
+    async fn handle_release_notes(&self, args: ReleaseNotesArgs) -> Result<Value> {
+        let title = args.title.as_deref().unwrap_or("Release notes");
+        let until = Self::parse_date_arg(args.until.as_deref(), "until", true)?.unwrap_or_else(Utc::now);
+        let since = Self::parse_date_arg(args.since.as_deref(), "since", false)?
+            .unwrap_or_else(|| until - Duration::days(14));
+
+        let notes = self.application.get_release_notes(title, args.project_id.as_deref(), since, until).await?;
+        Ok(json!({
+            "markdown": notes.to_markdown(),
+            "sections": notes.sections,
+            "count": notes.item_count()
+        }))
+    }
+
+    /// Reads a resource for `read_resource`, before redaction.
+    async fn load_resource(&self, uri: &str) -> Result<Value> {
+        debug!("Reading resource: {}", uri);
+
+        match uri {
+            "linear://issues/assigned" => {
+                let user = self.application.get_current_user().await?;
+                let issues = self.application.get_all_assigned_tickets(&user.id).await?;
+                let issues = self.pseudonymize_tickets(issues, self.pseudonymize_reports);
+                Ok(json!({
+                    "uri": uri,
+                    "mimeType": "application/json",
+                    "text": serde_json::to_string_pretty(&issues)?
+                }))
+            },
+            "audit://log" => {
+                let entries = self.audit()?.read(100, None, None)?;
+                Ok(json!({
+                    "uri": uri,
+                    "mimeType": "application/json",
+                    "text": serde_json::to_string_pretty(&entries)?
+                }))
+            },
+            "linear://user/current" => {
+                let mut user = self.application.get_current_user().await?;
+                if self.pseudonymize_reports {
+                    user = self.pseudonymizer.pseudonymize_user(&user);
+                }
+                Ok(json!({
+                    "uri": uri,
+                    "mimeType": "application/json", 
+                    "text": serde_json::to_string_pretty(&user)?
+                }))
+            },
+            _ => {
+                if let Some(project_id) = uri.strip_prefix("linear://projects/").and_then(|rest| rest.strip_suffix("/status")) {
+                    let (project, latest_update) = self.application.get_project_status(project_id).await?;
+                    return Ok(json!({
+                        "uri": uri,
+                        "mimeType": "application/json",
+                        "text": serde_json::to_string_pretty(&json!({
+                            "project": project,
+                            "latest_update": latest_update
+                        }))?
+                    }));
+                }
+
+                if let Some(document_id) = uri.strip_prefix("linear://documents/") {
+                    let document = self.application.get_document(document_id).await?;
+                    let content = document.content.as_ref().map(RichText::to_markdown).unwrap_or_default();
+                    return Ok(json!({
+                        "uri": uri,
+                        "mimeType": "text/markdown",
+                        "text": format!("# {}\n\n{}", document.title, content)
+                    }));
+                }
+
+                Err(anyhow!("Unknown resource: {}", uri))
+            },
+        }
+    }
+
+    /// Runs a tool against the provider named in its arguments.
+    async fn dispatch_tool(&self, name: &str, arguments: Value) -> Result<Value> {
+        self.policy.check(name, MUTATING_TOOLS.contains(&name))?;
+
+        // Each call runs against the profile or provider it names, or the default
+        let provider = arguments.get("profile")
+            .or_else(|| arguments.get("provider"))
             .and_then(|v| v.as_str())
-            .ok_or_else(|| anyhow!("issue_id is required"))?;
+            .map(str::to_string);
+        let server = self.for_application(self.registry.get(provider.as_deref())?);
+        if let Some((feature, false)) = required_feature(name, &server.application.capabilities()) {
+            return Self::unsupported_or(Err(UnsupportedOperation::new(feature).into()));
+        }
 
-        let issue = self.application.get_ticket(issue_id).await?;
-        Ok(json!({ "issue": issue }))
+        match name {
+            "linear_get_assigned_issues" => server.handle_get_assigned_issues(parse_args(arguments)?).await,
+            "linear_get_current_user" => server.handle_get_current_user().await,
+            "linear_search_issues" => server.handle_search_issues(parse_args(arguments)?).await,
+            "all_providers_search" => server.handle_all_providers_search(parse_args(arguments)?).await,
+            "linear_get_issue" => server.handle_get_issue(parse_args(arguments)?).await,
+            "get_tickets" => server.handle_get_tickets(parse_args(arguments)?).await,
+            #[cfg(feature = "local-search")]
+            "local_search" => server.handle_local_search(parse_args(arguments)?, provider.as_deref()).await,
+            "semantic_search" => server.handle_semantic_search(parse_args(arguments)?, provider.as_deref()).await,
+            "list_saved_views" => server.handle_list_saved_views().await,
+            "run_saved_view" => server.handle_run_saved_view(parse_args(arguments)?).await,
+            "release_notes" => server.handle_release_notes(parse_args(arguments)?).await,
+            "export_tickets" => server.handle_export_tickets(parse_args(arguments)?).await,
+            "watch_ticket" => server.handle_watch_ticket(parse_args(arguments)?, true).await,
+            "unwatch_ticket" => server.handle_watch_ticket(parse_args(arguments)?, false).await,
+            "planning_bundle" => server.handle_planning_bundle(parse_args(arguments)?).await,
+            "list_sla_breaches" => server.handle_list_sla_breaches(parse_args(arguments)?).await,
+            "list_due_soon" => server.handle_list_due_soon(parse_args(arguments)?).await,
+            "list_projects" => server.handle_list_projects(parse_args(arguments)?).await,
+            "get_project" => server.handle_get_project(parse_args(arguments)?).await,
+            "list_project_updates" => server.handle_list_project_updates(parse_args(arguments)?).await,
+            "list_documents" => server.handle_list_documents(parse_args(arguments)?).await,
+            "get_document" => server.handle_get_document(parse_args(arguments)?).await,
+            "create_project" => server.handle_create_project(parse_args(arguments)?).await,
+            "update_project" => server.handle_update_project(parse_args(arguments)?).await,
+            "create_milestone" => server.handle_create_milestone(parse_args(arguments)?).await,
+            "get_notifications" => server.handle_get_notifications(parse_args(arguments)?).await,
+            "list_initiatives" => server.handle_list_initiatives().await,
+            "get_initiative_projects" => server.handle_get_initiative_projects(parse_args(arguments)?).await,
+            "list_workflow_states" => server.handle_list_workflow_states(parse_args(arguments)?).await,
+            "list_cycles" => server.handle_list_cycles(parse_args(arguments)?).await,
+            "get_current_cycle" => server.handle_get_current_cycle(parse_args(arguments)?).await,
+            "assign_ticket_to_cycle" => server.handle_assign_ticket_to_cycle(parse_args(arguments)?).await,
+            "log_time" => server.handle_log_time(parse_args(arguments)?).await,
+            "list_time_entries" => server.handle_list_time_entries(parse_args(arguments)?).await,
+            "get_ticket_history" => server.handle_get_ticket_history(parse_args(arguments)?).await,
+            "list_comments" => server.handle_list_comments(parse_args(arguments)?).await,
+            "add_comment" => server.handle_add_comment(parse_args(arguments)?).await,
+            "link_tickets" => server.handle_link_tickets(parse_args(arguments)?).await,
+            "set_parent_ticket" => server.handle_set_parent_ticket(parse_args(arguments)?).await,
+            "ticket_branch_name" => server.handle_ticket_branch_name(parse_args(arguments)?).await,
+            "parse_ticket_references" => server.handle_parse_ticket_references(parse_args(arguments)?).await,
+            "start_ticket_branch" => server.handle_start_ticket_branch(parse_args(arguments)?).await,
+            "list_attachments" => server.handle_list_attachments(parse_args(arguments)?).await,
+            "add_attachment" => server.handle_add_attachment(parse_args(arguments)?).await,
+            "add_reaction" => server.handle_add_reaction(parse_args(arguments)?).await,
+            "remove_reaction" => server.handle_remove_reaction(parse_args(arguments)?).await,
+            "find_duplicate_tickets" => server.handle_find_duplicate_tickets(parse_args(arguments)?).await,
+            "list_templates" => server.handle_list_templates().await,
+            "create_ticket" => server.handle_create_ticket(parse_args(arguments)?).await,
+            "create_from_template" => server.handle_create_from_template(parse_args(arguments)?).await,
+            "batch_create_tickets" => server.handle_batch_create_tickets(parse_args(arguments)?).await,
+            "create_tickets_from_markdown" => server.handle_create_tickets_from_markdown(parse_args(arguments)?).await,
+            "import_tickets_csv" => server.handle_import_tickets_csv(parse_args(arguments)?).await,
+            "archive_tickets" => server.handle_destructive(DestructiveAction::ArchiveTickets, parse_args(arguments)?).await,
+            "delete_tickets" => server.handle_destructive(DestructiveAction::DeleteTickets, parse_args(arguments)?).await,
+            "working_set_list" => server.handle_working_set_list().await,
+            "get_audit_log" => server.handle_get_audit_log(parse_args(arguments)?).await,
+            "sync_status" => server.handle_sync_status().await,
+            "sync_run" => server.handle_sync_run(parse_args(arguments)?).await,
+            "migrate_workspace" => server.handle_migrate_workspace(parse_args(arguments)?).await,
+            "get_more_results" => server.handle_get_more_results(parse_args(arguments)?).await,
+            "list_schedules" => server.handle_list_schedules().await,
+            "pause_schedule" => server.handle_pause_schedule(parse_args(arguments)?).await,
+            "working_set_pin" => server.handle_working_set_pin(parse_args(arguments)?).await,
+            _ => Err(anyhow!("Unknown tool: {}", name)),
+        }
     }
 }
 
 #[async_trait]
 impl McpServer for McpServerImpl {
     async fn list_tools(&self) -> Result<Vec<McpTool>> {
-        Ok(vec![
+        let mut tools = vec![
             McpTool {
                 name: "linear_get_assigned_issues".to_string(),
                 description: "Get issues assigned to a specific user".to_string(),
-                input_schema: Self::create_tool_schema(
-                    "linear_get_assigned_issues",
-                    "Get assigned issues for a user",
-                    json!({
-                        "user_id": {
-                            "type": "string",
-                            "description": "The ID of the user to get assigned issues for"
-                        }
-                    })
-                ),
+                input_schema: tool_schema::<GetAssignedIssuesArgs>(),
             },
             McpTool {
                 name: "linear_get_current_user".to_string(),
                 description: "Get information about the current authenticated user".to_string(),
-                input_schema: Self::create_tool_schema(
-                    "linear_get_current_user",
-                    "Get current user info",
-                    json!({})
-                ),
+                input_schema: tool_schema::<NoArgs>(),
             },
             McpTool {
                 name: "linear_search_issues".to_string(),
                 description: "Search for issues using a text query".to_string(),
-                input_schema: Self::create_tool_schema(
-                    "linear_search_issues",
-                    "Search issues",
-                    json!({
-                        "query": {
-                            "type": "string",
-                            "description": "Search query to find issues"
-                        }
-                    })
-                ),
+                input_schema: tool_schema::<SearchIssuesArgs>(),
             },
             McpTool {
                 name: "linear_get_issue".to_string(),
-                description: "Get a specific issue by ID".to_string(),
-                input_schema: Self::create_tool_schema(
-                    "linear_get_issue",
-                    "Get issue by ID",
-                    json!({
-                        "issue_id": {
-                            "type": "string",
-                            "description": "The ID of the issue to retrieve"
-                        }
-                    })
-                ),
+                description: "Get a specific issue by ID or identifier (e.g. METAL-37, #123, or the issue URL)".to_string(),
+                input_schema: tool_schema::<GetIssueArgs>(),
+            },
+            McpTool {
+                name: "get_tickets".to_string(),
+                description: "Fetch several tickets in one call by ID or identifier (e.g. METAL-37, #123). Use this instead of repeated single lookups when several tickets are referenced".to_string(),
+                input_schema: tool_schema::<GetTicketsArgs>(),
+            },
+            McpTool {
+                name: "list_saved_views".to_string(),
+                description: "List the saved ticket views configured on this server".to_string(),
+                input_schema: tool_schema::<NoArgs>(),
+            },
+            McpTool {
+                name: "run_saved_view".to_string(),
+                description: "Run a saved view by name (e.g. \"on-call queue\") and return its tickets, sorted and limited as the view defines".to_string(),
+                input_schema: tool_schema::<RunSavedViewArgs>(),
+            },
+            McpTool {
+                name: "release_notes".to_string(),
+                description: "Render markdown release notes from tickets completed in a project and date window, grouped into features, fixes, and chores by label".to_string(),
+                input_schema: tool_schema::<ReleaseNotesArgs>(),
+            },
+            McpTool {
+                name: "export_tickets".to_string(),
+                description: "Export tickets matching a filter as JSON, CSV, or a Markdown table, returned as an embedded resource that can be saved".to_string(),
+                input_schema: tool_schema::<ExportTicketsArgs>(),
+            },
+            McpTool {
+                name: "watch_ticket".to_string(),
+                description: "Subscribe a user (default: the current user) to updates on a ticket".to_string(),
+                input_schema: tool_schema::<WatchTicketArgs>(),
+            },
+            McpTool {
+                name: "unwatch_ticket".to_string(),
+                description: "Unsubscribe a user (default: the current user) from updates on a ticket".to_string(),
+                input_schema: tool_schema::<WatchTicketArgs>(),
+            },
+            McpTool {
+                name: "planning_bundle".to_string(),
+                description: "Assemble sprint-planning data for a team: backlog candidates ranked by score, capacity per member, carry-over work, and milestones due within the horizon".to_string(),
+                input_schema: tool_schema::<PlanningBundleArgs>(),
+            },
+            McpTool {
+                name: "list_due_soon".to_string(),
+                description: "List the current user's open tickets due within the next hours, or already overdue, soonest first".to_string(),
+                input_schema: tool_schema::<DueSoonArgs>(),
+            },
+            McpTool {
+                name: "list_sla_breaches".to_string(),
+                description: "List open tickets whose SLA deadline (first response or resolution) has passed, most overdue first".to_string(),
+                input_schema: tool_schema::<SlaBreachesArgs>(),
+            },
+            McpTool {
+                name: "list_projects".to_string(),
+                description: "List projects with their state, lead, target date, and progress".to_string(),
+                input_schema: tool_schema::<PageArgs>(),
+            },
+            McpTool {
+                name: "get_project".to_string(),
+                description: "Get a project by ID, including its milestones".to_string(),
+                input_schema: tool_schema::<ProjectArgs>(),
+            },
+            McpTool {
+                name: "list_project_updates".to_string(),
+                description: "List a project's status updates (health and narrative), newest first".to_string(),
+                input_schema: tool_schema::<ProjectUpdatesArgs>(),
+            },
+            McpTool {
+                name: "list_documents".to_string(),
+                description: "List documents (specs, notes), optionally only one project's; use get_document to read one".to_string(),
+                input_schema: tool_schema::<ListDocumentsArgs>(),
+            },
+            McpTool {
+                name: "get_document".to_string(),
+                description: "Get a document with its full content".to_string(),
+                input_schema: tool_schema::<GetDocumentArgs>(),
+            },
+            McpTool {
+                name: "create_project".to_string(),
+                description: "Create a project owned by one or more teams".to_string(),
+                input_schema: tool_schema::<CreateProjectArgs>(),
+            },
+            McpTool {
+                name: "update_project".to_string(),
+                description: "Update a project's name, description, state, lead, or target date".to_string(),
+                input_schema: tool_schema::<UpdateProjectArgs>(),
+            },
+            McpTool {
+                name: "create_milestone".to_string(),
+                description: "Create a milestone within a project".to_string(),
+                input_schema: tool_schema::<CreateMilestoneArgs>(),
+            },
+            McpTool {
+                name: "get_notifications".to_string(),
+                description: "Show the current user's tracker inbox: mentions, assignments, state changes, and comments, newest first".to_string(),
+                input_schema: tool_schema::<NotificationsArgs>(),
+            },
+            McpTool {
+                name: "list_initiatives".to_string(),
+                description: "List roadmap initiatives and the projects they group".to_string(),
+                input_schema: tool_schema::<NoArgs>(),
+            },
+            McpTool {
+                name: "get_initiative_projects".to_string(),
+                description: "Get the projects under one initiative, with their state and progress".to_string(),
+                input_schema: tool_schema::<InitiativeProjectsArgs>(),
+            },
+            McpTool {
+                name: "list_workflow_states".to_string(),
+                description: "List the workflow states a team's tickets can move into, in board order, with their category (open, in progress, closed, cancelled)".to_string(),
+                input_schema: tool_schema::<TeamArgs>(),
+            },
+            McpTool {
+                name: "list_cycles".to_string(),
+                description: "List a team's cycles (sprints) ordered by start date".to_string(),
+                input_schema: tool_schema::<TeamArgs>(),
+            },
+            McpTool {
+                name: "get_current_cycle".to_string(),
+                description: "Get a team's currently active cycle (sprint)".to_string(),
+                input_schema: tool_schema::<TeamArgs>(),
+            },
+            McpTool {
+                name: "assign_ticket_to_cycle".to_string(),
+                description: "Move a ticket into a cycle, or into a team's active cycle when cycle_id is omitted".to_string(),
+                input_schema: tool_schema::<AssignToCycleArgs>(),
+            },
+            McpTool {
+                name: "log_time".to_string(),
+                description: "Log time worked on a ticket, for providers with time tracking (reports supported: false otherwise)".to_string(),
+                input_schema: tool_schema::<LogTimeArgs>(),
+            },
+            McpTool {
+                name: "list_time_entries".to_string(),
+                description: "List time logged against a ticket, for providers with time tracking (reports supported: false otherwise)".to_string(),
+                input_schema: tool_schema::<TicketArgs>(),
+            },
+            McpTool {
+                name: "get_ticket_history".to_string(),
+                description: "Show what happened on a ticket and when: state transitions, assignments, field changes, and comments, oldest first".to_string(),
+                input_schema: tool_schema::<TicketArgs>(),
+            },
+            McpTool {
+                name: "list_comments".to_string(),
+                description: "List the comments on a ticket, oldest first. Replies carry the id of the comment they answer".to_string(),
+                input_schema: tool_schema::<ListCommentsArgs>(),
+            },
+            McpTool {
+                name: "add_comment".to_string(),
+                description: "Add a comment to a ticket, optionally as a reply to another comment".to_string(),
+                input_schema: tool_schema::<AddCommentArgs>(),
+            },
+            McpTool {
+                name: "link_tickets".to_string(),
+                description: "Link two tickets: blocks, blocked_by, duplicates, or relates_to (the default), read from ticket_id's side".to_string(),
+                input_schema: tool_schema::<LinkTicketsArgs>(),
+            },
+            McpTool {
+                name: "set_parent_ticket".to_string(),
+                description: "Make a ticket a sub-ticket of another, or top-level again when parent_id is omitted".to_string(),
+                input_schema: tool_schema::<SetParentArgs>(),
+            },
+            McpTool {
+                name: "ticket_branch_name".to_string(),
+                description: "Suggest a git branch name for working on a ticket, e.g. kenny/metal-42-fix-ipmi-auth".to_string(),
+                input_schema: tool_schema::<TicketBranchNameArgs>(),
+            },
+            McpTool {
+                name: "parse_ticket_references".to_string(),
+                description: "Find the tickets a branch name, commit message, or pull request mentions by identifier, and look them up".to_string(),
+                input_schema: tool_schema::<ParseTicketReferencesArgs>(),
+            },
+            McpTool {
+                name: "start_ticket_branch".to_string(),
+                description: "Start work on a ticket from git: name its branch (or read the ticket from an existing branch) and move the ticket to In Progress if it has not been started".to_string(),
+                input_schema: tool_schema::<StartTicketBranchArgs>(),
+            },
+            McpTool {
+                name: "list_attachments".to_string(),
+                description: "List files and links attached to a ticket, oldest first".to_string(),
+                input_schema: tool_schema::<TicketArgs>(),
+            },
+            McpTool {
+                name: "add_attachment".to_string(),
+                description: "Attach a link (pull request, doc, thread, any URL) to a ticket. Omit title to let the provider recognize the link and title it".to_string(),
+                input_schema: tool_schema::<AddAttachmentArgs>(),
+            },
+            McpTool {
+                name: "add_reaction".to_string(),
+                description: "React to a ticket or a comment with an emoji".to_string(),
+                input_schema: tool_schema::<AddReactionArgs>(),
+            },
+            McpTool {
+                name: "remove_reaction".to_string(),
+                description: "Remove a reaction by its id (as listed on the ticket or comment)".to_string(),
+                input_schema: tool_schema::<RemoveReactionArgs>(),
+            },
+            McpTool {
+                name: "find_duplicate_tickets".to_string(),
+                description: "Find existing tickets likely to duplicate a proposed one, ranked by fuzzy title match and shared labels. Call before creating a ticket".to_string(),
+                input_schema: tool_schema::<FindDuplicatesArgs>(),
+            },
+            McpTool {
+                name: "list_templates".to_string(),
+                description: "List the ticket templates configured on this server and the variables each one needs".to_string(),
+                input_schema: tool_schema::<NoArgs>(),
+            },
+            McpTool {
+                name: "create_ticket".to_string(),
+                description: "Create a ticket. Pass an idempotency_key to make retries safe: repeating a call with the same key returns the ticket the first call created".to_string(),
+                input_schema: tool_schema::<CreateTicketArgs>(),
+            },
+            McpTool {
+                name: "create_from_template".to_string(),
+                description: "Create a ticket from a configured template, filling {{variable}} placeholders. {{date}} defaults to today".to_string(),
+                input_schema: tool_schema::<CreateFromTemplateArgs>(),
+            },
+            McpTool {
+                name: "batch_create_tickets".to_string(),
+                description: "Create several tickets in one call. Specs may nest sub-tickets under children; a child is skipped if its parent fails. With rollback_on_failure, the first failure stops the batch and deletes everything it created".to_string(),
+                input_schema: tool_schema::<BatchCreateArgs>(),
+            },
+            McpTool {
+                name: "create_tickets_from_markdown".to_string(),
+                description: "Create a ticket hierarchy from a markdown plan: headings become epics (nested by level), checklist items (- [ ]) become subtasks of the heading above, and other text becomes descriptions. Returns the created identifiers as a tree".to_string(),
+                input_schema: tool_schema::<MarkdownTicketsArgs>(),
+            },
+            McpTool {
+                name: "import_tickets_csv".to_string(),
+                description: "Create tickets from CSV rows. The header names each column's field (title, description, priority, team, assignee, project, labels, due_date, estimate, or common aliases like Summary); columns maps any others. dry_run (the default) validates every row against the provider and reports what would be created and what is wrong; a real import creates nothing if any row is invalid".to_string(),
+                input_schema: tool_schema::<ImportTicketsCsvArgs>(),
+            },
+            McpTool {
+                name: "archive_tickets".to_string(),
+                description: "Archive one or more tickets. The first call only returns a preview and a confirmation_token; call again with the token within two minutes to archive".to_string(),
+                input_schema: tool_schema::<DestructiveArgs>(),
+            },
+            McpTool {
+                name: "delete_tickets".to_string(),
+                description: "Permanently delete one or more tickets. The first call only returns a preview and a confirmation_token; call again with the token within two minutes to delete".to_string(),
+                input_schema: tool_schema::<DestructiveArgs>(),
             },
-        ])
+        ];
+
+        if self.working_set.is_some() {
+            tools.push(McpTool {
+                name: "working_set_list".to_string(),
+                description: "List tickets touched in this session, pinned tickets first".to_string(),
+                input_schema: tool_schema::<NoArgs>(),
+            });
+            tools.push(McpTool {
+                name: "working_set_pin".to_string(),
+                description: "Pin or unpin a ticket in the session working set so it is kept for later reference".to_string(),
+                input_schema: tool_schema::<WorkingSetPinArgs>(),
+            });
+        }
+
+        if self.audit.is_some() {
+            tools.push(McpTool {
+                name: "get_audit_log".to_string(),
+                description: "Read the audit trail of tool calls, newest first: tool, arguments (secrets redacted), outcome, and latency".to_string(),
+                input_schema: tool_schema::<AuditLogArgs>(),
+            });
+        }
+
+        if self.sync.is_some() {
+            tools.push(McpTool {
+                name: "sync_status".to_string(),
+                description: "Show the two-way sync between providers: endpoints, synced fields, linked ticket pairs, and the last run's changes and conflicts".to_string(),
+                input_schema: tool_schema::<NoArgs>(),
+            });
+            tools.push(McpTool {
+                name: "sync_run".to_string(),
+                description: "Mirror tickets between the synced providers now. Pairs changed on both sides are reported as conflicts unless prefer names the side that wins".to_string(),
+                input_schema: tool_schema::<SyncRunArgs>(),
+            });
+        }
+
+        if self.migration.is_some() {
+            tools.push(McpTool {
+                name: "migrate_workspace".to_string(),
+                description: "Copy the configured source provider's workspace into the target provider, translating teams, states, labels, and people per the field mapping. dry_run (the default) only lists what would be created, reused, or left unmatched".to_string(),
+                input_schema: tool_schema::<MigrateWorkspaceArgs>(),
+            });
+        }
+
+        if self.limits.max_tickets.is_some() {
+            tools.push(McpTool {
+                name: "get_more_results".to_string(),
+                description: "Continue a result marked truncated: returns the next tickets it held back, with another continue_cursor if more remain".to_string(),
+                input_schema: tool_schema::<MoreResultsArgs>(),
+            });
+        }
+
+        if self.scheduler.is_some() {
+            tools.push(McpTool {
+                name: "list_schedules".to_string(),
+                description: "List recurring tickets: the template each creates, its cron schedule (UTC), whether it is paused, and its last and next run".to_string(),
+                input_schema: tool_schema::<NoArgs>(),
+            });
+            tools.push(McpTool {
+                name: "pause_schedule".to_string(),
+                description: "Pause or resume a recurring ticket. A resumed schedule skips the runs it missed while paused".to_string(),
+                input_schema: tool_schema::<PauseScheduleArgs>(),
+            });
+        }
+
+        #[cfg(feature = "local-search")]
+        if self.search_index.is_some() {
+            tools.push(McpTool {
+                name: "local_search".to_string(),
+                description: format!(
+                    "Search tickets already seen through prefetching and webhooks, instantly and without calling the provider. All terms must match; \"quoted phrases\" match exactly, and field:value targets one of: {}",
+                    LOCAL_SEARCH_FIELDS.join(", ")
+                ),
+                input_schema: tool_schema::<LocalSearchArgs>(),
+            });
+        }
+
+        if self.semantic.is_some() {
+            tools.push(McpTool {
+                name: "semantic_search".to_string(),
+                description: "Find tickets by meaning rather than exact words, e.g. \"flaky CI on the GPU nodes\", among tickets seen through prefetching and webhooks; closest matches first, with their similarity score".to_string(),
+                input_schema: tool_schema::<SemanticSearchArgs>(),
+            });
+        }
+
+        // Tools no provider can serve are hidden; ones only some can serve
+        // say which cannot
+        let capabilities: Vec<(&str, Capabilities)> = self.registry.iter()
+            .map(|(name, application)| (name, application.capabilities()))
+            .collect();
+        tools.retain_mut(|tool| {
+            let unsupported: Vec<&str> = capabilities.iter()
+                .filter(|(_, capabilities)| matches!(required_feature(&tool.name, capabilities), Some((_, false))))
+                .map(|(name, _)| *name)
+                .collect();
+            if !unsupported.is_empty() && unsupported.len() == capabilities.len() {
+                return false;
+            }
+            if !unsupported.is_empty() {
+                tool.description.push_str(&format!(" (not supported by {})", unsupported.join(", ")));
+            }
+            true
+        });
+
+        if self.registry.len() > 1 {
+            let provider = json!({
+                "type": "string",
+                "enum": self.registry.names(),
+                "description": format!(
+                    "{} to run against (default: {})",
+                    if self.provider_argument == "profile" { "Profile" } else { "Provider" },
+                    self.registry.default_name()
+                )
+            });
+            for tool in &mut tools {
+                tool.input_schema["properties"][self.provider_argument] = provider.clone();
+            }
+
+            tools.push(McpTool {
+                name: "all_providers_search".to_string(),
+                description: format!(
+                    "Search tickets in every configured provider ({}) at once; results are tagged with their provider, most recently updated first",
+                    self.registry.names().join(", ")
+                ),
+                input_schema: tool_schema::<AllProvidersSearchArgs>(),
+            });
+        }
+
+        tools.retain(|tool| self.permits_tool(&tool.name));
+        Ok(tools)
     }
 
     async fn call_tool(&self, name: &str, arguments: Value) -> Result<Value> {
         debug!("Calling tool: {} with arguments: {}", name, arguments);
+        let started = Instant::now();
+        let audited_arguments = self.audit.as_ref().map(|_| arguments.clone());
+        let provider = arguments.get("profile")
+            .or_else(|| arguments.get("provider"))
+            .and_then(|v| v.as_str())
+            .unwrap_or(self.registry.default_name())
+            .to_string();
+        let span = info_span!("tool_call", tool = name, session = &*self.session_id, user = self.user.as_deref(), provider = provider.as_str());
+        let (mut result, stale_age) = track_stale_reads(self.dispatch_tool(name, arguments).instrument(span.clone())).await;
+        if let Ok(value) = &mut result {
+            self.limit_output(value);
+        }
+        // Cached data served during a provider outage is marked as such
+        if let (Ok(value @ Value::Object(_)), Some(age)) = (&mut result, stale_age) {
+            value["stale"] = json!(true);
+            value["stale_age_secs"] = json!(age.as_secs());
+        }
+        if let (Some(redactor), Ok(value)) = (&self.redactor, &mut result) {
+            redactor.redact_value(value);
+        }
 
-        let result = match name {
-            "linear_get_assigned_issues" => self.handle_get_assigned_issues(arguments).await,
-            "linear_get_current_user" => self.handle_get_current_user().await,
-            "linear_search_issues" => self.handle_search_issues(arguments).await,
-            "linear_get_issue" => self.handle_get_issue(arguments).await,
-            _ => Err(anyhow!("Unknown tool: {}", name)),
-        };
-
-        match &result {
-            Ok(value) => info!("Tool {} completed successfully", name),
-            Err(e) => error!("Tool {} failed: {}", name, e),
+        let duration_ms = started.elapsed().as_millis() as u64;
+        span.in_scope(|| match &result {
+            Ok(_) => info!(duration_ms, "Tool {} completed successfully", name),
+            Err(e) => error!(duration_ms, error_class = error_class(e), "Tool {} failed: {}", name, e),
+        });
+        if let (Some(audit), Some(arguments)) = (&self.audit, audited_arguments) {
+            audit.record(self.user.as_deref(), name, &arguments, &result, started.elapsed());
         }
 
         result
     }
 
     async fn list_resources(&self) -> Result<Vec<McpResource>> {
-        Ok(vec![
+        let mut resources = vec![
             McpResource {
                 uri: "linear://issues/assigned".to_string(),
                 name: "Assigned Issues".to_string(),
@@ -155,41 +2139,82 @@ impl McpServer for McpServerImpl {
                 description: Some("Information about the current authenticated user".to_string()),
                 mime_type: Some("application/json".to_string()),
             },
-        ])
+            McpResource {
+                uri: "linear://projects/{project_id}/status".to_string(),
+                name: "Project Status".to_string(),
+                description: Some("A project with its latest status update; substitute a project id".to_string()),
+                mime_type: Some("application/json".to_string()),
+            },
+            McpResource {
+                uri: "linear://documents/{document_id}".to_string(),
+                name: "Document".to_string(),
+                description: Some("A document's content as markdown; substitute a document id".to_string()),
+                mime_type: Some("text/markdown".to_string()),
+            },
+        ];
+
+        if self.audit.is_some() {
+            resources.push(McpResource {
+                uri: "audit://log".to_string(),
+                name: "Audit Log".to_string(),
+                description: Some("The most recent tool calls, newest first".to_string()),
+                mime_type: Some("application/json".to_string()),
+            });
+        }
+
+        Ok(resources)
     }
 
     async fn read_resource(&self, uri: &str) -> Result<Value> {
-        debug!("Reading resource: {}", uri);
-
-        match uri {
-            "linear://issues/assigned" => {
-                let user = self.application.get_current_user().await?;
-                let issues = self.application.get_assigned_tickets(&user.id).await?;
-                Ok(json!({
-                    "uri": uri,
-                    "mimeType": "application/json",
-                    "text": serde_json::to_string_pretty(&issues)?
-                }))
-            },
-            "linear://user/current" => {
-                let user = self.application.get_current_user().await?;
-                Ok(json!({
-                    "uri": uri,
-                    "mimeType": "application/json", 
-                    "text": serde_json::to_string_pretty(&user)?
-                }))
-            },
-            _ => Err(anyhow!("Unknown resource: {}", uri)),
+        let mut resource = self.load_resource(uri).await?;
+        if let Some(redactor) = &self.redactor {
+            redactor.redact_value(&mut resource);
         }
+        Ok(resource)
     }
 
     async fn start_server(&self) -> Result<()> {
         info!("MCP server starting...");
+        self.running.store(true, Ordering::SeqCst);
         Ok(())
     }
 
     async fn stop_server(&self) -> Result<()> {
         info!("MCP server stopping...");
+        self.running.store(false, Ordering::SeqCst);
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+impl EventListener for McpServerImpl {
+    fn on_event(&self, event: &WebhookEvent) {
+        let uris = match event.entity {
+            WebhookEntity::Ticket | WebhookEntity::Comment => vec!["linear://issues/assigned".to_string()],
+            WebhookEntity::Project => vec![format!("linear://projects/{}/status", event.entity_id)],
+            WebhookEntity::ProjectUpdate => event.project_id
+                .iter()
+                .map(|project_id| format!("linear://projects/{}/status", project_id))
+                .collect(),
+            WebhookEntity::Document => vec![format!("linear://documents/{}", event.entity_id)],
+            WebhookEntity::Label | WebhookEntity::Cycle | WebhookEntity::Other(_) => Vec::new(),
+        };
+
+        if let Ok(mut updates) = self.resource_updates.lock() {
+            for uri in uris {
+                if !updates.contains(&uri) {
+                    debug!("Resource updated: {}", uri);
+                    push_bounded(&mut updates, uri);
+                }
+            }
+        }
+    }
+}
+
+/// Appends `item`, dropping the oldest past `MAX_PENDING_NOTIFICATIONS`.
+fn push_bounded<T>(pending: &mut Vec<T>, item: T) {
+    if pending.len() >= MAX_PENDING_NOTIFICATIONS {
+        pending.remove(0);
+    }
+    pending.push(item);
+}
+
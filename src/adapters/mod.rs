@@ -1,5 +1,49 @@
+pub mod conditional_transport;
+pub mod embedders;
+pub mod file_secret_store;
+pub mod fixture_transport;
+#[cfg(feature = "tokio")]
+pub mod health_server;
+pub mod http_client;
+#[cfg(feature = "keyring")]
+pub mod keyring_secret_store;
 pub mod linear_client;
+mod linear_schema;
 pub mod mcp_server_impl;
+pub mod oauth;
+#[cfg(feature = "reqwest")]
+pub mod reqwest_transport;
+pub mod secrets;
+pub mod slack_webhook;
+#[cfg(feature = "local-search")]
+pub mod tantivy_index;
+#[cfg(feature = "persistent-cache")]
+pub mod sqlite_cache_store;
+mod tool_args;
+pub mod webhook_decoders;
+#[cfg(feature = "tokio")]
+pub mod webhook_server;
 
+pub use conditional_transport::*;
+pub use embedders::*;
+pub use file_secret_store::*;
+pub use fixture_transport::*;
+#[cfg(feature = "tokio")]
+pub use health_server::*;
+pub use http_client::*;
+#[cfg(feature = "keyring")]
+pub use keyring_secret_store::*;
 pub use linear_client::*;
-pub use mcp_server_impl::*;
\ No newline at end of file
+pub use mcp_server_impl::*;
+pub use oauth::*;
+#[cfg(feature = "reqwest")]
+pub use reqwest_transport::*;
+pub use secrets::*;
+pub use slack_webhook::*;
+#[cfg(feature = "local-search")]
+pub use tantivy_index::*;
+#[cfg(feature = "persistent-cache")]
+pub use sqlite_cache_store::*;
+pub use webhook_decoders::*;
+#[cfg(feature = "tokio")]
+pub use webhook_server::*;
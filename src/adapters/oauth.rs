@@ -0,0 +1,489 @@
+use anyhow::{Result, anyhow};
+use bytes::Bytes;
+use chrono::{DateTime, Duration, Utc};
+use http_body_util::Full;
+use hyper::{Request, Method, header::{ACCEPT, CONTENT_TYPE}};
+use hyper::rt::Timer;
+use hyper_util::rt::TokioTimer;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info};
+
+use crate::adapters::{HttpsClient, default_https_client};
+use crate::ports::SecretStore;
+
+pub const LINEAR_AUTHORIZE_URL: &str = "https://linear.app/oauth/authorize";
+pub const LINEAR_TOKEN_URL: &str = "https://api.linear.app/oauth/token";
+pub const GITHUB_AUTHORIZE_URL: &str = "https://github.com/login/oauth/authorize";
+pub const GITHUB_TOKEN_URL: &str = "https://github.com/login/oauth/access_token";
+pub const GITHUB_DEVICE_CODE_URL: &str = "https://github.com/login/device/code";
+pub const ATLASSIAN_AUTHORIZE_URL: &str = "https://auth.atlassian.com/authorize";
+pub const ATLASSIAN_TOKEN_URL: &str = "https://auth.atlassian.com/oauth/token";
+
+/// Access tokens this close to expiry are refreshed before use.
+const REFRESH_MARGIN_SECS: i64 = 60;
+
+const DEVICE_CODE_GRANT: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// An OAuth2 application registered with a provider.
+#[derive(Debug, Clone)]
+pub struct OAuthConfig {
+    /// Names the secret store key, `<provider>.oauth`.
+    pub provider: String,
+    pub client_id: String,
+    /// `None` for public clients, e.g. GitHub Apps using the device flow.
+    pub client_secret: Option<String>,
+    pub authorize_url: String,
+    pub token_url: String,
+    /// Enables `authorize_with_device_code`.
+    pub device_authorization_url: Option<String>,
+    /// Where the browser flow redirects to; a `localhost` URI is served by
+    /// `authorize_with_browser` itself.
+    pub redirect_uri: Option<String>,
+    /// Sent as is, in the provider's own separator.
+    pub scope: String,
+    /// Provider-specific query parameters for the authorization URL.
+    pub authorize_params: Vec<(String, String)>,
+}
+
+impl OAuthConfig {
+    pub fn linear(client_id: String, client_secret: String, redirect_uri: String) -> Self {
+        Self {
+            provider: "linear".to_string(),
+            client_id,
+            client_secret: Some(client_secret),
+            authorize_url: LINEAR_AUTHORIZE_URL.to_string(),
+            token_url: LINEAR_TOKEN_URL.to_string(),
+            device_authorization_url: None,
+            redirect_uri: Some(redirect_uri),
+            scope: "read,write".to_string(),
+            authorize_params: Vec::new(),
+        }
+    }
+
+    /// A GitHub App (or OAuth App) with device flow enabled; GitHub Apps
+    /// take their permissions from the app, so no scope is requested.
+    pub fn github(client_id: String, client_secret: Option<String>) -> Self {
+        Self {
+            provider: "github".to_string(),
+            client_id,
+            client_secret,
+            authorize_url: GITHUB_AUTHORIZE_URL.to_string(),
+            token_url: GITHUB_TOKEN_URL.to_string(),
+            device_authorization_url: Some(GITHUB_DEVICE_CODE_URL.to_string()),
+            redirect_uri: None,
+            scope: String::new(),
+            authorize_params: Vec::new(),
+        }
+    }
+
+    /// An Atlassian OAuth 2.0 (3LO) app for Jira Cloud. `offline_access`
+    /// is what makes Atlassian issue refresh tokens.
+    pub fn jira(client_id: String, client_secret: String, redirect_uri: String) -> Self {
+        Self {
+            provider: "jira".to_string(),
+            client_id,
+            client_secret: Some(client_secret),
+            authorize_url: ATLASSIAN_AUTHORIZE_URL.to_string(),
+            token_url: ATLASSIAN_TOKEN_URL.to_string(),
+            device_authorization_url: None,
+            redirect_uri: Some(redirect_uri),
+            scope: "read:jira-work write:jira-work read:jira-user offline_access".to_string(),
+            authorize_params: vec![
+                ("audience".to_string(), "api.atlassian.com".to_string()),
+                ("prompt".to_string(), "consent".to_string()),
+            ],
+        }
+    }
+
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = scope.into();
+        self
+    }
+
+    fn secret_key(&self) -> String {
+        format!("{}.oauth", self.provider)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct OAuthTokens {
+    access_token: String,
+    refresh_token: Option<String>,
+    /// `None` for tokens issued without an expiry.
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl OAuthTokens {
+    fn expires_soon(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at - Duration::seconds(REFRESH_MARGIN_SECS) <= Utc::now())
+    }
+}
+
+/// Token endpoint replies. Errors come back as `error` fields, with a 400
+/// status from most providers but a 200 from GitHub.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: Option<String>,
+    refresh_token: Option<String>,
+    expires_in: Option<i64>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+/// What the user has to do to finish a device authorization.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeviceAuthorization {
+    device_code: String,
+    pub user_code: String,
+    /// Google calls it `verification_url`.
+    #[serde(alias = "verification_url")]
+    pub verification_uri: String,
+    /// The verification URI with the code filled in, where supported.
+    pub verification_uri_complete: Option<String>,
+    pub expires_in: u64,
+    /// Seconds between polls.
+    #[serde(default = "DeviceAuthorization::default_interval")]
+    pub interval: u64,
+}
+
+impl DeviceAuthorization {
+    fn default_interval() -> u64 {
+        5
+    }
+}
+
+/// OAuth2 credentials for one provider. Tokens come from an authorization
+/// code exchange or the device flow, are refreshed shortly before they
+/// expire, and are persisted in the secret store so restarts reuse them.
+pub struct OAuthClient {
+    client: HttpsClient,
+    config: OAuthConfig,
+    store: Arc<dyn SecretStore>,
+    tokens: Mutex<Option<OAuthTokens>>,
+}
+
+impl OAuthClient {
+    /// Loads any tokens saved by a previous run.
+    pub fn new(config: OAuthConfig, store: Arc<dyn SecretStore>) -> Result<Self> {
+        let tokens = match store.get(&config.secret_key())? {
+            Some(saved) => Some(serde_json::from_str(&saved)
+                .map_err(|e| anyhow!("Invalid saved {} OAuth tokens: {}", config.provider, e))?),
+            None => None,
+        };
+
+        Ok(Self {
+            client: default_https_client(),
+            config,
+            store,
+            tokens: Mutex::new(tokens),
+        })
+    }
+
+    /// Sends requests through another client, e.g. one with proxy settings.
+    pub fn with_http_client(mut self, client: HttpsClient) -> Self {
+        self.client = client;
+        self
+    }
+
+    pub fn provider(&self) -> &str {
+        &self.config.provider
+    }
+
+    pub fn is_authorized(&self) -> bool {
+        self.tokens.lock().map(|tokens| tokens.is_some()).unwrap_or(false)
+    }
+
+    /// Where to send the user to grant access; the provider redirects back
+    /// to the redirect URI with `code` and the given `state`.
+    pub fn authorize_url(&self, state: &str) -> Result<String> {
+        let redirect_uri = self.redirect_uri()?;
+        let mut url = url::Url::parse(&self.config.authorize_url)
+            .map_err(|e| anyhow!("Invalid authorize URL {}: {}", self.config.authorize_url, e))?;
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("client_id", &self.config.client_id)
+                .append_pair("redirect_uri", redirect_uri)
+                .append_pair("response_type", "code")
+                .append_pair("state", state);
+            if !self.config.scope.is_empty() {
+                query.append_pair("scope", &self.config.scope);
+            }
+            query.extend_pairs(&self.config.authorize_params);
+        }
+        Ok(url.into())
+    }
+
+    /// Exchanges the code from the authorization redirect for tokens.
+    pub async fn exchange_code(&self, code: &str) -> Result<()> {
+        let redirect_uri = self.redirect_uri()?.to_string();
+        let tokens = self.request_token(&[
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", &redirect_uri),
+        ]).await?;
+        self.save(tokens)?;
+        info!("Authorized {} via OAuth", self.config.provider);
+        Ok(())
+    }
+
+    /// Runs the browser flow end to end: serves the `localhost` redirect
+    /// URI, passes the authorization URL to `prompt` to show the user, and
+    /// exchanges the code the browser comes back with.
+    #[cfg(feature = "tokio")]
+    pub async fn authorize_with_browser(&self, prompt: impl FnOnce(&str)) -> Result<()> {
+        let redirect_uri = url::Url::parse(self.redirect_uri()?)
+            .map_err(|e| anyhow!("Invalid redirect URI: {}", e))?;
+        if !matches!(redirect_uri.host_str(), Some("localhost" | "127.0.0.1")) {
+            return Err(anyhow!(
+                "The browser flow serves the redirect itself, so the redirect URI must be on localhost, got: {}",
+                redirect_uri
+            ));
+        }
+        let port = redirect_uri.port_or_known_default().unwrap_or(80);
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", port)).await
+            .map_err(|e| anyhow!("Could not listen for the OAuth redirect on port {}: {}", port, e))?;
+
+        let state = uuid::Uuid::new_v4().to_string();
+        prompt(&self.authorize_url(&state)?);
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let (params, reply) = callback::receive(stream, redirect_uri.path()).await?;
+            let Some(params) = params else {
+                continue;
+            };
+            let result = match (params.get("code"), params.get("state"), params.get("error")) {
+                (_, _, Some(error)) => Err(anyhow!("Authorization was denied: {}", error)),
+                (Some(code), Some(returned), _) if *returned == state => self.exchange_code(code).await,
+                (Some(_), _, _) => Err(anyhow!("OAuth redirect carried the wrong state; try again")),
+                _ => Err(anyhow!("OAuth redirect carried no code")),
+            };
+            let _ = reply.send(result.as_ref().map(|_| ()).map_err(|e| e.to_string()));
+            return result;
+        }
+    }
+
+    /// Runs the device flow (RFC 8628): passes the code to enter and where
+    /// to `prompt`, then polls until the user approves, denies, or the code
+    /// expires.
+    pub async fn authorize_with_device_code(&self, prompt: impl FnOnce(&DeviceAuthorization)) -> Result<()> {
+        let url = self.config.device_authorization_url.as_deref()
+            .ok_or_else(|| anyhow!("{} does not support the device flow", self.config.provider))?;
+        let mut params = vec![("client_id", self.config.client_id.as_str())];
+        if !self.config.scope.is_empty() {
+            params.push(("scope", &self.config.scope));
+        }
+        let (_, body) = self.post_form(url, &params).await?;
+        let device: DeviceAuthorization = serde_json::from_slice(&body)
+            .map_err(|e| anyhow!("Unexpected device authorization response: {} ({})", e, String::from_utf8_lossy(&body)))?;
+        prompt(&device);
+
+        let deadline = Utc::now() + Duration::seconds(device.expires_in as i64);
+        let mut interval = device.interval;
+        while Utc::now() < deadline {
+            TokioTimer::new().sleep(std::time::Duration::from_secs(interval)).await;
+            let response = self.token_response(&[
+                ("grant_type", DEVICE_CODE_GRANT),
+                ("device_code", &device.device_code),
+            ]).await?;
+            match response.error.as_deref() {
+                None => {
+                    self.save(Self::tokens(response, &self.config.provider)?)?;
+                    info!("Authorized {} via OAuth device flow", self.config.provider);
+                    return Ok(());
+                }
+                Some("authorization_pending") => {}
+                Some("slow_down") => interval += 5,
+                Some(_) => return Err(Self::token_error(&self.config.provider, response)),
+            }
+        }
+        Err(anyhow!("The device code expired before it was approved; try again"))
+    }
+
+    /// A usable access token, refreshed first if it is about to expire.
+    pub async fn access_token(&self) -> Result<String> {
+        let tokens = self.current()?;
+        if tokens.expires_soon() && tokens.refresh_token.is_some() {
+            return self.refresh(&tokens.access_token).await;
+        }
+        Ok(tokens.access_token)
+    }
+
+    /// Replaces `stale` with a fresh access token. If another request already
+    /// refreshed it, that token is returned instead of refreshing again.
+    pub async fn refresh(&self, stale: &str) -> Result<String> {
+        let tokens = self.current()?;
+        if tokens.access_token != stale {
+            return Ok(tokens.access_token);
+        }
+        let refresh_token = tokens.refresh_token
+            .ok_or_else(|| anyhow!("{} OAuth token expired and has no refresh token; authorize again", self.config.provider))?;
+
+        debug!("Refreshing {} OAuth access token", self.config.provider);
+        match self.request_token(&[("grant_type", "refresh_token"), ("refresh_token", &refresh_token)]).await {
+            Ok(mut fresh) => {
+                // Providers may keep the refresh token instead of rotating it
+                fresh.refresh_token.get_or_insert(refresh_token);
+                let access_token = fresh.access_token.clone();
+                self.save(fresh)?;
+                Ok(access_token)
+            }
+            // A concurrent refresh may have rotated the refresh token first
+            Err(e) => match self.current() {
+                Ok(tokens) if tokens.access_token != stale => Ok(tokens.access_token),
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Forgets the saved tokens.
+    pub fn sign_out(&self) -> Result<()> {
+        self.store.delete(&self.config.secret_key())?;
+        *self.tokens.lock().map_err(|_| anyhow!("OAuth token lock poisoned"))? = None;
+        Ok(())
+    }
+
+    fn redirect_uri(&self) -> Result<&str> {
+        self.config.redirect_uri.as_deref()
+            .ok_or_else(|| anyhow!("{} OAuth needs a redirect URI for the browser flow", self.config.provider))
+    }
+
+    fn current(&self) -> Result<OAuthTokens> {
+        self.tokens
+            .lock()
+            .map_err(|_| anyhow!("OAuth token lock poisoned"))?
+            .clone()
+            .ok_or_else(|| anyhow!("{} OAuth is not authorized yet; complete the authorization flow first", self.config.provider))
+    }
+
+    fn save(&self, tokens: OAuthTokens) -> Result<()> {
+        self.store.set(&self.config.secret_key(), &serde_json::to_string(&tokens)?)?;
+        *self.tokens.lock().map_err(|_| anyhow!("OAuth token lock poisoned"))? = Some(tokens);
+        Ok(())
+    }
+
+    async fn request_token(&self, params: &[(&str, &str)]) -> Result<OAuthTokens> {
+        let response = self.token_response(params).await?;
+        if response.error.is_some() {
+            return Err(Self::token_error(&self.config.provider, response));
+        }
+        Self::tokens(response, &self.config.provider)
+    }
+
+    async fn token_response(&self, params: &[(&str, &str)]) -> Result<TokenResponse> {
+        let mut params = params.to_vec();
+        params.push(("client_id", &self.config.client_id));
+        if let Some(secret) = &self.config.client_secret {
+            params.push(("client_secret", secret));
+        }
+        let (status, body) = self.post_form(&self.config.token_url, &params).await?;
+        match serde_json::from_slice::<TokenResponse>(&body) {
+            Ok(response) => Ok(response),
+            Err(_) => Err(anyhow!(
+                "{} OAuth token request failed with {}: {}",
+                self.config.provider, status, String::from_utf8_lossy(&body)
+            )),
+        }
+    }
+
+    fn tokens(response: TokenResponse, provider: &str) -> Result<OAuthTokens> {
+        let access_token = response.access_token
+            .ok_or_else(|| anyhow!("{} OAuth token response has no access token", provider))?;
+        Ok(OAuthTokens {
+            access_token,
+            refresh_token: response.refresh_token,
+            expires_at: response.expires_in.map(|secs| Utc::now() + Duration::seconds(secs)),
+        })
+    }
+
+    fn token_error(provider: &str, response: TokenResponse) -> anyhow::Error {
+        anyhow!(
+            "{} OAuth token request failed: {}{}",
+            provider,
+            response.error.unwrap_or_default(),
+            response.error_description.map(|d| format!(" ({})", d)).unwrap_or_default()
+        )
+    }
+
+    async fn post_form(&self, url: &str, params: &[(&str, &str)]) -> Result<(hyper::StatusCode, Bytes)> {
+        let body = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(params)
+            .finish();
+
+        // GitHub answers in form encoding unless asked for JSON
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(url)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header(ACCEPT, "application/json")
+            .body(Full::new(Bytes::from(body)))?;
+
+        let response = self.client.request(request).await?;
+        Ok((response.status(), response.into_body()))
+    }
+}
+
+/// The one-request HTTP server behind the browser flow's redirect.
+#[cfg(feature = "tokio")]
+mod callback {
+    use anyhow::Result;
+    use bytes::Bytes;
+    use http_body_util::Full;
+    use hyper::body::Incoming;
+    use hyper::server::conn::http1;
+    use hyper::service::service_fn;
+    use hyper::{Request, Response, StatusCode};
+    use hyper_util::rt::TokioIo;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tokio::net::TcpStream;
+    use tokio::sync::oneshot;
+
+    /// Reads one request from `stream`. Requests to `path` yield their query
+    /// parameters and a channel whose outcome is shown in the browser;
+    /// anything else (e.g. `/favicon.ico`) yields `None` and a 404.
+    pub(super) async fn receive(
+        stream: TcpStream,
+        path: &str,
+    ) -> Result<(Option<HashMap<String, String>>, oneshot::Sender<std::result::Result<(), String>>)> {
+        let (params_tx, params_rx) = oneshot::channel();
+        let (outcome_tx, outcome_rx) = oneshot::channel::<std::result::Result<(), String>>();
+        let params_tx = Mutex::new(Some(params_tx));
+        let outcome_rx = Mutex::new(Some(outcome_rx));
+        let path = path.to_string();
+
+        let service = service_fn(move |request: Request<Incoming>| {
+            let matched = request.uri().path() == path;
+            let params: HashMap<String, String> = url::form_urlencoded::parse(request.uri().query().unwrap_or("").as_bytes())
+                .into_owned()
+                .collect();
+            let params_tx = params_tx.lock().ok().and_then(|mut tx| tx.take());
+            let outcome_rx = outcome_rx.lock().ok().and_then(|mut rx| rx.take());
+            async move {
+                if let Some(tx) = params_tx {
+                    let _ = tx.send(matched.then_some(params));
+                }
+                let (status, message) = match (matched, outcome_rx) {
+                    (true, Some(rx)) => match rx.await {
+                        Ok(Ok(())) => (StatusCode::OK, "Authorized. You can close this window.".to_string()),
+                        Ok(Err(e)) => (StatusCode::BAD_REQUEST, format!("Authorization failed: {}", e)),
+                        Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Authorization was interrupted".to_string()),
+                    },
+                    _ => (StatusCode::NOT_FOUND, "Not found".to_string()),
+                };
+                Ok::<_, hyper::Error>(Response::builder()
+                    .status(status)
+                    .body(Full::new(Bytes::from(message)))
+                    .unwrap_or_default())
+            }
+        });
+        tokio::spawn(async move {
+            let _ = http1::Builder::new().serve_connection(TokioIo::new(stream), service).await;
+        });
+
+        let params = params_rx.await.unwrap_or(None);
+        Ok((params, outcome_tx))
+    }
+}
@@ -0,0 +1,88 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use bytes::Bytes;
+use hyper::{Request, Response};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+use crate::adapters::http_client::{DEFAULT_CONNECT_TIMEOUT, DEFAULT_POOL_IDLE_TIMEOUT, DEFAULT_REQUEST_TIMEOUT, enabled, env_var};
+use crate::ports::{HttpSettings, HttpTransport};
+
+/// Sends provider requests with reqwest instead of the built-in hyper
+/// client, honoring the same proxy, certificate, and timeout settings.
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+    in_flight: Option<Arc<Semaphore>>,
+}
+
+impl ReqwestTransport {
+    pub fn new(settings: &HttpSettings) -> Result<Self> {
+        let mut builder = reqwest::Client::builder()
+            .tcp_keepalive(settings.tcp_keepalive.filter(|keepalive| !keepalive.is_zero()));
+        if let Some(timeout) = enabled(settings.connect_timeout, DEFAULT_CONNECT_TIMEOUT) {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = enabled(settings.request_timeout, DEFAULT_REQUEST_TIMEOUT) {
+            builder = builder.timeout(timeout);
+        }
+        builder = match settings.pool_idle_timeout.unwrap_or(DEFAULT_POOL_IDLE_TIMEOUT) {
+            Duration::ZERO => builder.pool_max_idle_per_host(0),
+            idle => builder.pool_idle_timeout(idle),
+        };
+
+        // reqwest reads the proxy variables itself unless a setting
+        // overrides them
+        if settings.proxy.is_some() || settings.no_proxy.is_some() {
+            let proxy = settings.proxy.clone()
+                .unwrap_or_else(|| env_var(&["HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy"]));
+            if proxy.is_empty() {
+                builder = builder.no_proxy();
+            } else {
+                let no_proxy = settings.no_proxy.clone().unwrap_or_else(|| env_var(&["NO_PROXY", "no_proxy"]));
+                let proxy = reqwest::Proxy::all(&proxy)
+                    .map_err(|e| anyhow!("Invalid proxy URL {}: {}", proxy, e))?
+                    .no_proxy(reqwest::NoProxy::from_string(&no_proxy));
+                builder = builder.proxy(proxy);
+            }
+        }
+
+        for path in &settings.ca_certificates {
+            let pem = std::fs::read(path)
+                .map_err(|e| anyhow!("Failed to read CA certificate {}: {}", path, e))?;
+            let certificates = reqwest::Certificate::from_pem_bundle(&pem)
+                .map_err(|e| anyhow!("Invalid CA certificate {}: {}", path, e))?;
+            if certificates.is_empty() {
+                return Err(anyhow!("No PEM certificates in {}", path));
+            }
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+        if settings.accept_invalid_certs {
+            builder = builder.danger_accept_invalid_certs(true).danger_accept_invalid_hostnames(true);
+        }
+
+        Ok(Self {
+            client: builder.build().map_err(|e| anyhow!("Failed to set up the HTTP client: {}", e))?,
+            in_flight: settings.max_in_flight.map(|max| Arc::new(Semaphore::new(max.max(1)))),
+        })
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ReqwestTransport {
+    async fn execute(&self, request: Request<Bytes>) -> Result<Response<Bytes>> {
+        let _permit = match &self.in_flight {
+            Some(in_flight) => in_flight.acquire().await.ok(),
+            None => None,
+        };
+        let response = self.client.execute(reqwest::Request::try_from(request)?).await?;
+        let mut builder = Response::builder().status(response.status()).version(response.version());
+        if let Some(headers) = builder.headers_mut() {
+            *headers = response.headers().clone();
+        }
+        Ok(builder.body(response.bytes().await?)?)
+    }
+}
@@ -0,0 +1,582 @@
+use anyhow::{Context, Result, anyhow};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use bytes::Bytes;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use http_body_util::Full;
+use hyper::{Method, Request, header::{AUTHORIZATION, CONTENT_TYPE}};
+use percent_encoding::percent_decode_str;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+use std::env;
+use url::Url;
+
+use crate::adapters::{HttpsClient, default_https_client};
+
+/// Prefix of token values fetched from a secret manager rather than given.
+pub const SECRET_URI_SCHEME: &str = "secret://";
+
+pub const GCP_SECRET_MANAGER_URL: &str = "https://secretmanager.googleapis.com";
+
+/// A secret in a cloud secret manager, as named by a `secret://` URI:
+///
+/// - `secret://aws/<secret id or ARN>[?region=us-east-1]`: AWS Secrets
+///   Manager. The region comes from the ARN, then `AWS_REGION`.
+/// - `secret://gcp/<project>/<secret>[/<version>]`: GCP Secret Manager,
+///   `latest` unless a version is given.
+/// - `secret://vault/<mount>/<path>`: a HashiCorp Vault KV v2 secret.
+///
+/// A `#field` fragment picks one field of a JSON secret; Vault secrets
+/// with several fields need one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretRef {
+    Aws { secret_id: String, region: Option<String>, field: Option<String> },
+    Gcp { project: String, secret: String, version: String, field: Option<String> },
+    Vault { mount: String, path: String, field: Option<String> },
+}
+
+impl SecretRef {
+    /// The secret `value` names, or `None` when it is not a `secret://` URI.
+    pub fn parse(value: &str) -> Result<Option<Self>> {
+        if !value.starts_with(SECRET_URI_SCHEME) {
+            return Ok(None);
+        }
+        let url = Url::parse(value).map_err(|e| anyhow!("Invalid secret URI {}: {}", value, e))?;
+        let field = url.fragment().filter(|field| !field.is_empty()).map(decode);
+        let segments: Vec<String> = url.path().split('/').filter(|segment| !segment.is_empty()).map(decode).collect();
+        let secret = match url.host_str() {
+            Some("aws") if !segments.is_empty() => SecretRef::Aws {
+                secret_id: segments.join("/"),
+                region: url.query_pairs().find(|(name, _)| name == "region").map(|(_, region)| region.into_owned()),
+                field,
+            },
+            Some("gcp") if matches!(segments.len(), 2 | 3) => SecretRef::Gcp {
+                project: segments[0].clone(),
+                secret: segments[1].clone(),
+                version: segments.get(2).cloned().unwrap_or_else(|| "latest".to_string()),
+                field,
+            },
+            Some("vault") if segments.len() >= 2 => SecretRef::Vault {
+                mount: segments[0].clone(),
+                path: segments[1..].join("/"),
+                field,
+            },
+            _ => return Err(anyhow!(
+                "Unsupported secret URI {}: expected secret://aws/<secret id>, secret://gcp/<project>/<secret>[/<version>], or secret://vault/<mount>/<path>",
+                value
+            )),
+        };
+        Ok(Some(secret))
+    }
+}
+
+fn decode(text: &str) -> String {
+    percent_decode_str(text).decode_utf8_lossy().into_owned()
+}
+
+/// Fetches `secret://` values from AWS Secrets Manager, GCP Secret Manager,
+/// or Vault, so tokens never sit in the environment or on disk. Each
+/// manager is reached with the credentials its own tools use:
+///
+/// - AWS: `AWS_ACCESS_KEY_ID`/`AWS_SECRET_ACCESS_KEY`/`AWS_SESSION_TOKEN`,
+///   then the ECS task role, then the EC2 instance role (IMDSv2).
+///   `AWS_ENDPOINT_URL_SECRETS_MANAGER` or `AWS_ENDPOINT_URL` overrides the
+///   endpoint.
+/// - GCP: the service account of the GCE/GKE/Cloud Run metadata server
+///   (`GCE_METADATA_HOST`), or `GOOGLE_OAUTH_ACCESS_TOKEN`.
+/// - Vault: `VAULT_ADDR` with `VAULT_TOKEN`, `~/.vault-token`, or, with
+///   `VAULT_K8S_ROLE`, Kubernetes auth (`VAULT_K8S_MOUNT`, default
+///   `kubernetes`) with the pod's service account. `VAULT_NAMESPACE` is sent
+///   when set.
+pub struct SecretResolver {
+    client: HttpsClient,
+    gcp_url: String,
+}
+
+impl Default for SecretResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SecretResolver {
+    pub fn new() -> Self {
+        Self {
+            client: default_https_client(),
+            gcp_url: GCP_SECRET_MANAGER_URL.to_string(),
+        }
+    }
+
+    /// Sends requests through another client, e.g. one with proxy settings.
+    pub fn with_http_client(mut self, client: HttpsClient) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Reaches GCP Secret Manager at another URL, e.g. a private endpoint.
+    pub fn with_gcp_url(mut self, url: impl Into<String>) -> Self {
+        self.gcp_url = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// `value` itself, or the secret it names when it is a `secret://` URI.
+    pub async fn resolve(&self, value: &str) -> Result<String> {
+        let Some(secret) = SecretRef::parse(value)? else {
+            return Ok(value.to_string());
+        };
+        let resolved = match &secret {
+            SecretRef::Aws { secret_id, region, field } => {
+                let text = self.aws_secret(secret_id, region.as_deref()).await?;
+                json_field(text, field.as_deref())
+            }
+            SecretRef::Gcp { project, secret, version, field } => {
+                let text = self.gcp_secret(project, secret, version).await?;
+                json_field(text, field.as_deref())
+            }
+            SecretRef::Vault { mount, path, field } => self.vault_secret(mount, path, field.as_deref()).await,
+        };
+        resolved.with_context(|| format!("Failed to fetch {}", value))
+    }
+
+    async fn aws_secret(&self, secret_id: &str, region: Option<&str>) -> Result<String> {
+        // ARNs carry their region: arn:aws:secretsmanager:<region>:<account>:secret:<name>
+        let region = region.map(str::to_string)
+            .or_else(|| secret_id.strip_prefix("arn:").and_then(|arn| arn.split(':').nth(2)).map(str::to_string))
+            .or_else(|| env::var("AWS_REGION").ok())
+            .or_else(|| env::var("AWS_DEFAULT_REGION").ok())
+            .ok_or_else(|| anyhow!("No AWS region: add ?region= to the URI or set AWS_REGION"))?;
+        let endpoint = env::var("AWS_ENDPOINT_URL_SECRETS_MANAGER")
+            .or_else(|_| env::var("AWS_ENDPOINT_URL"))
+            .unwrap_or_else(|_| format!("https://secretsmanager.{}.amazonaws.com", region));
+        let credentials = self.aws_credentials().await?;
+
+        let body = serde_json::to_vec(&json!({ "SecretId": secret_id }))?;
+        let url = Url::parse(&endpoint)?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(anyhow!("Invalid AWS endpoint {}", endpoint)),
+        };
+        let mut headers = vec![
+            ("content-type", "application/x-amz-json-1.1".to_string()),
+            ("host", host),
+            ("x-amz-date", Utc::now().format("%Y%m%dT%H%M%SZ").to_string()),
+            ("x-amz-target", "secretsmanager.GetSecretValue".to_string()),
+        ];
+        if let Some(token) = &credentials.session_token {
+            headers.push(("x-amz-security-token", token.clone()));
+        }
+        headers.sort();
+        let authorization = credentials.sign("POST", &headers, &body, &region, "secretsmanager");
+
+        let mut request = Request::builder().method(Method::POST).uri(url.as_str());
+        for (name, value) in &headers {
+            if *name != "host" {
+                request = request.header(*name, value);
+            }
+        }
+        let request = request
+            .header(AUTHORIZATION, authorization)
+            .body(Full::new(Bytes::from(body)))?;
+        let body = self.send(request, "AWS Secrets Manager").await?;
+
+        #[derive(Deserialize)]
+        struct SecretValue {
+            #[serde(rename = "SecretString")]
+            secret_string: Option<String>,
+            #[serde(rename = "SecretBinary")]
+            secret_binary: Option<String>,
+        }
+        let value: SecretValue = serde_json::from_slice(&body)
+            .map_err(|e| anyhow!("Unexpected AWS Secrets Manager response: {}", e))?;
+        match (value.secret_string, value.secret_binary) {
+            (Some(text), _) => Ok(text),
+            (None, Some(binary)) => utf8(BASE64.decode(binary)?),
+            (None, None) => Err(anyhow!("The secret has no value")),
+        }
+    }
+
+    async fn aws_credentials(&self) -> Result<AwsCredentials> {
+        if let (Ok(access_key_id), Ok(secret_access_key)) = (env::var("AWS_ACCESS_KEY_ID"), env::var("AWS_SECRET_ACCESS_KEY")) {
+            return Ok(AwsCredentials {
+                access_key_id,
+                secret_access_key,
+                session_token: env::var("AWS_SESSION_TOKEN").ok(),
+            });
+        }
+
+        // The ECS task role, then the EC2 instance role
+        let container_uri = env::var("AWS_CONTAINER_CREDENTIALS_FULL_URI").ok().or_else(|| {
+            env::var("AWS_CONTAINER_CREDENTIALS_RELATIVE_URI").ok().map(|path| format!("http://169.254.170.2{}", path))
+        });
+        let body = if let Some(uri) = container_uri {
+            let mut request = Request::builder().uri(uri);
+            if let Ok(token) = env::var("AWS_CONTAINER_AUTHORIZATION_TOKEN") {
+                request = request.header(AUTHORIZATION, token);
+            }
+            self.send(request.body(Full::default())?, "the ECS credentials endpoint").await?
+        } else {
+            let imds = env::var("AWS_EC2_METADATA_SERVICE_ENDPOINT").unwrap_or_else(|_| "http://169.254.169.254".to_string());
+            let imds = imds.trim_end_matches('/');
+            let token_request = Request::builder()
+                .method(Method::PUT)
+                .uri(format!("{}/latest/api/token", imds))
+                .header("x-aws-ec2-metadata-token-ttl-seconds", "60")
+                .body(Full::default())?;
+            let token = utf8(self.send(token_request, "the EC2 metadata service").await
+                .context("No AWS credentials: set AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY, or run with an ECS task or EC2 instance role")?.to_vec())?;
+            let role_url = format!("{}/latest/meta-data/iam/security-credentials/", imds);
+            let get = |url: String| Request::builder().uri(url).header("x-aws-ec2-metadata-token", token.as_str()).body(Full::default());
+            let role = utf8(self.send(get(role_url.clone())?, "the EC2 metadata service").await?.to_vec())?;
+            let role = role.lines().next().ok_or_else(|| anyhow!("The EC2 instance has no IAM role"))?;
+            self.send(get(format!("{}{}", role_url, role))?, "the EC2 metadata service").await?
+        };
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct RoleCredentials {
+            access_key_id: String,
+            secret_access_key: String,
+            token: Option<String>,
+        }
+        let credentials: RoleCredentials = serde_json::from_slice(&body)
+            .map_err(|e| anyhow!("Unexpected AWS role credentials: {}", e))?;
+        Ok(AwsCredentials {
+            access_key_id: credentials.access_key_id,
+            secret_access_key: credentials.secret_access_key,
+            session_token: credentials.token,
+        })
+    }
+
+    async fn gcp_secret(&self, project: &str, secret: &str, version: &str) -> Result<String> {
+        let token = match env::var("GOOGLE_OAUTH_ACCESS_TOKEN") {
+            Ok(token) => token,
+            Err(_) => {
+                let host = env::var("GCE_METADATA_HOST").unwrap_or_else(|_| "metadata.google.internal".to_string());
+                let request = Request::builder()
+                    .uri(format!("http://{}/computeMetadata/v1/instance/service-accounts/default/token", host))
+                    .header("Metadata-Flavor", "Google")
+                    .body(Full::default())?;
+                let body = self.send(request, "the GCP metadata server").await
+                    .context("No GCP credentials: run on GCE, GKE, or Cloud Run with a service account, or set GOOGLE_OAUTH_ACCESS_TOKEN")?;
+                #[derive(Deserialize)]
+                struct AccessToken {
+                    access_token: String,
+                }
+                serde_json::from_slice::<AccessToken>(&body)
+                    .map_err(|e| anyhow!("Unexpected GCP metadata token: {}", e))?
+                    .access_token
+            }
+        };
+
+        let request = Request::builder()
+            .uri(format!("{}/v1/projects/{}/secrets/{}/versions/{}:access", self.gcp_url, project, secret, version))
+            .header(AUTHORIZATION, format!("Bearer {}", token))
+            .body(Full::default())?;
+        let body = self.send(request, "GCP Secret Manager").await?;
+        let response: Value = serde_json::from_slice(&body)
+            .map_err(|e| anyhow!("Unexpected GCP Secret Manager response: {}", e))?;
+        let data = response["payload"]["data"].as_str()
+            .ok_or_else(|| anyhow!("GCP Secret Manager response has no payload"))?;
+        utf8(BASE64.decode(data)?)
+    }
+
+    async fn vault_secret(&self, mount: &str, path: &str, field: Option<&str>) -> Result<String> {
+        let address = env::var("VAULT_ADDR").map_err(|_| anyhow!("VAULT_ADDR is required for secret://vault URIs"))?;
+        let address = address.trim_end_matches('/');
+        let token = self.vault_token(address).await?;
+
+        let mut request = Request::builder()
+            .uri(format!("{}/v1/{}/data/{}", address, mount, path))
+            .header("X-Vault-Token", token);
+        if let Ok(namespace) = env::var("VAULT_NAMESPACE") {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+        let body = self.send(request.body(Full::default())?, "Vault").await?;
+        let response: Value = serde_json::from_slice(&body).map_err(|e| anyhow!("Unexpected Vault response: {}", e))?;
+        let data = response["data"]["data"].as_object()
+            .ok_or_else(|| anyhow!("Vault response has no data; is {} a KV version 2 mount?", mount))?;
+
+        let value = match field {
+            Some(field) => data.get(field).ok_or_else(|| anyhow!(
+                "The secret has no field {}; it has {}", field, data.keys().cloned().collect::<Vec<_>>().join(", ")
+            ))?,
+            None if data.len() == 1 => data.values().next().unwrap(),
+            None => return Err(anyhow!(
+                "The secret has several fields; pick one with #field: {}", data.keys().cloned().collect::<Vec<_>>().join(", ")
+            )),
+        };
+        Ok(value.as_str().map_or_else(|| value.to_string(), str::to_string))
+    }
+
+    async fn vault_token(&self, address: &str) -> Result<String> {
+        if let Ok(token) = env::var("VAULT_TOKEN") {
+            return Ok(token);
+        }
+        if let Ok(role) = env::var("VAULT_K8S_ROLE") {
+            let jwt = std::fs::read_to_string("/var/run/secrets/kubernetes.io/serviceaccount/token")
+                .context("Failed to read the Kubernetes service account token")?;
+            let mount = env::var("VAULT_K8S_MOUNT").unwrap_or_else(|_| "kubernetes".to_string());
+            let mut request = Request::builder()
+                .method(Method::POST)
+                .uri(format!("{}/v1/auth/{}/login", address, mount))
+                .header(CONTENT_TYPE, "application/json");
+            if let Ok(namespace) = env::var("VAULT_NAMESPACE") {
+                request = request.header("X-Vault-Namespace", namespace);
+            }
+            let body = serde_json::to_vec(&json!({ "role": role, "jwt": jwt.trim() }))?;
+            let body = self.send(request.body(Full::new(Bytes::from(body)))?, "Vault").await?;
+            let response: Value = serde_json::from_slice(&body).map_err(|e| anyhow!("Unexpected Vault login response: {}", e))?;
+            return response["auth"]["client_token"].as_str()
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("Vault login response has no client token"));
+        }
+        let home = env::var("HOME").map_err(|_| anyhow!("No Vault token: set VAULT_TOKEN or VAULT_K8S_ROLE"))?;
+        std::fs::read_to_string(std::path::Path::new(&home).join(".vault-token"))
+            .map(|token| token.trim().to_string())
+            .map_err(|_| anyhow!("No Vault token: set VAULT_TOKEN or VAULT_K8S_ROLE, or log in with `vault login`"))
+    }
+
+    /// The body of a successful response; anything else is an error naming
+    /// `service`.
+    async fn send(&self, request: Request<Full<Bytes>>, service: &str) -> Result<Bytes> {
+        let response = self.client.request(request).await
+            .map_err(|e| anyhow!("Could not reach {}: {}", service, e))?;
+        let status = response.status();
+        let body = response.into_body();
+        if !status.is_success() {
+            return Err(anyhow!("{} answered {}: {}", service, status, String::from_utf8_lossy(&body)));
+        }
+        Ok(body)
+    }
+}
+
+/// `text`, or its `field` when it is a JSON object and a field is given.
+fn json_field(text: String, field: Option<&str>) -> Result<String> {
+    let Some(field) = field else {
+        return Ok(text);
+    };
+    let object: serde_json::Map<String, Value> = serde_json::from_str(&text)
+        .map_err(|_| anyhow!("The secret is not a JSON object, so it has no field {}", field))?;
+    match object.get(field) {
+        Some(Value::String(value)) => Ok(value.clone()),
+        Some(value) => Ok(value.to_string()),
+        None => Err(anyhow!("The secret has no field {}", field)),
+    }
+}
+
+fn utf8(bytes: Vec<u8>) -> Result<String> {
+    String::from_utf8(bytes).map_err(|_| anyhow!("The secret is not UTF-8 text"))
+}
+
+struct AwsCredentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+}
+
+impl AwsCredentials {
+    /// The Signature Version 4 `Authorization` header of a request to the
+    /// service's root path with `headers`, which are lowercase and sorted.
+    /// Header values are signed trimmed, with runs of spaces collapsed.
+    fn sign(&self, method: &str, headers: &[(&str, String)], body: &[u8], region: &str, service: &str) -> String {
+        let amz_date = headers.iter().find(|(name, _)| *name == "x-amz-date").map_or("", |(_, value)| value.as_str());
+        let date = &amz_date[..amz_date.len().min(8)];
+        let signed_headers = headers.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(";");
+        let canonical_headers: String = headers.iter().map(|(name, value)| format!("{}:{}\n", name, value.split_whitespace().collect::<Vec<_>>().join(" "))).collect();
+        let canonical_request = format!(
+            "{}\n/\n\n{}\n{}\n{}",
+            method, canonical_headers, signed_headers, hex::encode(Sha256::digest(body))
+        );
+        let scope = format!("{}/{}/{}/aws4_request", date, region, service);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date, scope, hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let key = [date, region, service, "aws4_request"]
+            .iter()
+            .fold(format!("AWS4{}", self.secret_access_key).into_bytes(), |key, part| hmac_sha256(&key, part.as_bytes()));
+        let signature = hex::encode(hmac_sha256(&key, string_to_sign.as_bytes()));
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.access_key_id, scope, signed_headers, signature
+        )
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC takes keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The credentials and scope of the AWS SigV4 test suite.
+    fn example_credentials() -> AwsCredentials {
+        AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            session_token: None,
+        }
+    }
+
+    /// The signature of a test suite request with the suite's host and date
+    /// plus `extra` headers, which are lowercase.
+    fn signature(method: &str, extra: &[(&'static str, &str)], body: &str) -> (String, String) {
+        let mut headers = vec![
+            ("host", "example.amazonaws.com".to_string()),
+            ("x-amz-date", "20150830T123600Z".to_string()),
+        ];
+        headers.extend(extra.iter().map(|(name, value)| (*name, value.to_string())));
+        headers.sort();
+        let authorization = example_credentials().sign(method, &headers, body.as_bytes(), "us-east-1", "service");
+        let (prefix, signature) = authorization.split_once(", Signature=").unwrap();
+        let signed_headers = prefix.split_once("SignedHeaders=").unwrap().1;
+        (signed_headers.to_string(), signature.to_string())
+    }
+
+    #[test]
+    fn signs_the_get_and_post_vanilla_vectors() {
+        let authorization = example_credentials().sign(
+            "GET",
+            &[("host", "example.amazonaws.com".to_string()), ("x-amz-date", "20150830T123600Z".to_string())],
+            b"",
+            "us-east-1",
+            "service",
+        );
+        assert_eq!(
+            authorization,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+        assert_eq!(
+            signature("POST", &[], "").1,
+            "5da7c1a2acd57cee7505fc6676e4e544621c30862966e37dddb68e92efbe5d6b"
+        );
+    }
+
+    #[test]
+    fn signs_the_header_vectors() {
+        // post-header-key-sort
+        assert_eq!(signature("POST", &[("my-header1", "value1")], ""), (
+            "host;my-header1;x-amz-date".to_string(),
+            "c5410059b04c1ee005303aed430f6e6645f61f4dc9e1461ec8f8916fdf18852c".to_string(),
+        ));
+        // post-header-value-case
+        assert_eq!(
+            signature("POST", &[("my-header1", "VALUE1")], "").1,
+            "cdbc9802e29d2942e5e10b5bccfdd67c5f22c7c4e8ae67b53629efa58b974b7d"
+        );
+        // get-header-value-trim
+        assert_eq!(
+            signature("GET", &[("my-header1", " value1"), ("my-header2", " \"a   b   c\"")], "").1,
+            "acc3ed3afb60bb290fc8d2dd0098b9911fcaa05412b367055dee359757a9c736"
+        );
+    }
+
+    #[test]
+    fn signs_the_body_vector() {
+        // post-x-www-form-urlencoded
+        assert_eq!(
+            signature("POST", &[("content-type", "application/x-www-form-urlencoded")], "Param1=value1").1,
+            "ff11897932ad3f4e8b18135d722051e5ac45fc38421b1da7b9d196a0fe09473a"
+        );
+    }
+
+    #[test]
+    fn parses_secret_uris() {
+        assert_eq!(SecretRef::parse("lin_api_token").unwrap(), None);
+        assert_eq!(SecretRef::parse("").unwrap(), None);
+        assert_eq!(SecretRef::parse("secret://aws/prod/linear?region=eu-west-1#token").unwrap(), Some(SecretRef::Aws {
+            secret_id: "prod/linear".to_string(),
+            region: Some("eu-west-1".to_string()),
+            field: Some("token".to_string()),
+        }));
+        assert_eq!(
+            SecretRef::parse("secret://aws/arn:aws:secretsmanager:us-east-2:123456789012:secret:linear").unwrap(),
+            Some(SecretRef::Aws {
+                secret_id: "arn:aws:secretsmanager:us-east-2:123456789012:secret:linear".to_string(),
+                region: None,
+                field: None,
+            })
+        );
+        assert_eq!(SecretRef::parse("secret://gcp/my-project/linear-token").unwrap(), Some(SecretRef::Gcp {
+            project: "my-project".to_string(),
+            secret: "linear-token".to_string(),
+            version: "latest".to_string(),
+            field: None,
+        }));
+        assert_eq!(SecretRef::parse("secret://gcp/my-project/linear-token/3#key").unwrap(), Some(SecretRef::Gcp {
+            project: "my-project".to_string(),
+            secret: "linear-token".to_string(),
+            version: "3".to_string(),
+            field: Some("key".to_string()),
+        }));
+        assert_eq!(SecretRef::parse("secret://vault/secret/teams/infra/linear#api%20key").unwrap(), Some(SecretRef::Vault {
+            mount: "secret".to_string(),
+            path: "teams/infra/linear".to_string(),
+            field: Some("api key".to_string()),
+        }));
+    }
+
+    #[test]
+    fn decodes_percent_escaped_segments_and_ignores_an_empty_fragment() {
+        assert_eq!(SecretRef::parse("secret://aws/team%2Flinear%20token#").unwrap(), Some(SecretRef::Aws {
+            secret_id: "team/linear token".to_string(),
+            region: None,
+            field: None,
+        }));
+    }
+
+    #[test]
+    fn refuses_malformed_secret_uris() {
+        for uri in [
+            "secret://",
+            "secret://aws",
+            "secret://aws/",
+            "secret://gcp/my-project",
+            "secret://gcp/my-project/linear-token/3/extra",
+            "secret://vault/secret",
+            "secret://azure/vault/linear",
+            "secret://[::1",
+        ] {
+            let error = SecretRef::parse(uri).unwrap_err();
+            assert!(error.to_string().contains(uri), "{}: {}", uri, error);
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn passes_plain_values_through_and_reports_bad_uris() {
+        let resolver = SecretResolver::new();
+        assert_eq!(resolver.resolve("lin_api_token").await.unwrap(), "lin_api_token");
+        assert!(resolver.resolve("secret://azure/linear").await.is_err());
+    }
+
+    #[test]
+    fn picks_fields_of_json_secrets() {
+        assert_eq!(json_field("plain".to_string(), None).unwrap(), "plain");
+        assert_eq!(json_field(r#"{"token":"abc","port":8080}"#.to_string(), Some("token")).unwrap(), "abc");
+        assert_eq!(json_field(r#"{"token":"abc","port":8080}"#.to_string(), Some("port")).unwrap(), "8080");
+
+        let error = json_field(r#"{"token":"abc"}"#.to_string(), Some("password")).unwrap_err();
+        assert_eq!(error.to_string(), "The secret has no field password");
+        let error = json_field("plain".to_string(), Some("token")).unwrap_err();
+        assert!(error.to_string().contains("not a JSON object"), "{}", error);
+        let error = json_field("[1, 2]".to_string(), Some("token")).unwrap_err();
+        assert!(error.to_string().contains("not a JSON object"), "{}", error);
+    }
+
+    #[test]
+    fn refuses_binary_secrets_that_are_not_text() {
+        assert_eq!(utf8(b"token".to_vec()).unwrap(), "token");
+        assert!(utf8(vec![0xff, 0xfe]).is_err());
+    }
+}
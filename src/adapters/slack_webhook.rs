@@ -0,0 +1,80 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use bytes::Bytes;
+use http_body_util::Full;
+use hyper::{Method, Request, header::CONTENT_TYPE};
+use serde_json::json;
+
+use crate::adapters::{HttpsClient, default_https_client};
+use crate::domain::{AlertKind, TicketAlert};
+use crate::ports::AlertSink;
+
+/// Posts alerts to a Slack incoming webhook, one message each, linking the
+/// ticket.
+pub struct SlackWebhook {
+    client: HttpsClient,
+    url: String,
+}
+
+impl SlackWebhook {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: default_https_client(),
+            url: url.into(),
+        }
+    }
+
+    /// Sends requests through another client, e.g. one with proxy settings.
+    pub fn with_http_client(mut self, client: HttpsClient) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// The alert in Slack's mrkdwn, with the identifier linking the ticket.
+    pub fn text(alert: &TicketAlert) -> String {
+        let ticket = format!("<{}|{}> *{}*", escape(&alert.url), escape(&alert.identifier), escape(&alert.title));
+        match alert.kind {
+            AlertKind::AssignedToMe => format!(":bust_in_silhouette: {} was assigned to you", ticket),
+            AlertKind::PriorityEscalated => format!(
+                ":arrow_up: {} was escalated from {} to *{}*",
+                ticket,
+                escape(alert.previous_priority.as_ref().map_or("none", |priority| priority.name())),
+                escape(alert.priority.name())
+            ),
+            AlertKind::SlaBreached => match alert.sla_due {
+                Some(due) => format!(
+                    ":rotating_light: {} breached its SLA (due <!date^{}^{{date_short_pretty}} {{time}}|{}>)",
+                    ticket,
+                    due.timestamp(),
+                    due.format("%Y-%m-%d %H:%M UTC")
+                ),
+                None => format!(":rotating_light: {} breached its SLA", ticket),
+            },
+        }
+    }
+}
+
+/// Slack's escaping of the three characters its markup uses.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[async_trait]
+impl AlertSink for SlackWebhook {
+    async fn send(&self, alert: &TicketAlert) -> Result<()> {
+        let body = json!({ "text": Self::text(alert) });
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(&self.url)
+            .header(CONTENT_TYPE, "application/json")
+            .body(Full::new(Bytes::from(serde_json::to_vec(&body)?)))?;
+
+        let response = self.client.request(request).await?;
+        let status = response.status();
+        if !status.is_success() {
+            // The URL is the credential, so it stays out of the error
+            return Err(anyhow!("Slack webhook answered {}: {}", status, String::from_utf8_lossy(response.body())));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use anyhow::{Context, Result, anyhow};
+use rusqlite::{Connection, params};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::ports::{CacheStore, StoredEntry};
+
+/// The response cache in a SQLite database, so stdio servers spawned fresh
+/// for each client session start with warm data.
+pub struct SqliteCacheStore {
+    connection: Mutex<Connection>,
+}
+
+impl SqliteCacheStore {
+    /// Opens the database at `path`, creating it readable only by its owner.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        let connection = Connection::open(path)
+            .with_context(|| format!("Failed to open cache database {}", path.display()))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS cache_entries (
+                namespace TEXT NOT NULL,
+                key TEXT NOT NULL,
+                value TEXT NOT NULL,
+                fetched_at INTEGER NOT NULL,
+                expires_at INTEGER NOT NULL,
+                PRIMARY KEY (namespace, key)
+            )",
+        )?;
+        Ok(Self { connection: Mutex::new(connection) })
+    }
+
+    fn connection(&self) -> Result<std::sync::MutexGuard<'_, Connection>> {
+        self.connection.lock().map_err(|_| anyhow!("Cache database lock poisoned"))
+    }
+}
+
+/// Milliseconds since the epoch, as SQLite stores them.
+fn millis(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |since| since.as_millis() as i64)
+}
+
+fn time(millis: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(millis.max(0) as u64)
+}
+
+impl CacheStore for SqliteCacheStore {
+    fn load(&self, namespace: &str) -> Result<Vec<StoredEntry>> {
+        let connection = self.connection()?;
+        let mut statement = connection.prepare(
+            "SELECT key, value, fetched_at, expires_at FROM cache_entries WHERE namespace = ?1",
+        )?;
+        let entries = statement
+            .query_map(params![namespace], |row| {
+                Ok(StoredEntry {
+                    key: row.get(0)?,
+                    value: row.get(1)?,
+                    fetched_at: time(row.get(2)?),
+                    expires_at: time(row.get(3)?),
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(entries)
+    }
+
+    fn put(&self, namespace: &str, entry: &StoredEntry) -> Result<()> {
+        self.connection()?.execute(
+            "INSERT OR REPLACE INTO cache_entries (namespace, key, value, fetched_at, expires_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![namespace, entry.key, entry.value, millis(entry.fetched_at), millis(entry.expires_at)],
+        )?;
+        Ok(())
+    }
+
+    fn remove(&self, namespace: &str, key: &str) -> Result<()> {
+        self.connection()?.execute(
+            "DELETE FROM cache_entries WHERE namespace = ?1 AND key = ?2",
+            params![namespace, key],
+        )?;
+        Ok(())
+    }
+
+    fn clear(&self, namespace: &str) -> Result<()> {
+        self.connection()?.execute("DELETE FROM cache_entries WHERE namespace = ?1", params![namespace])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use anyhow::{Context, Result, anyhow};
+use std::path::Path;
+use std::sync::Mutex;
+use tantivy::collector::TopDocs;
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{BooleanQuery, Occur, Query, QueryParser, TermQuery};
+use tantivy::schema::{Field, IndexRecordOption, Schema, Value, STORED, STRING, TEXT};
+use tantivy::{Index, IndexReader, IndexWriter, ReloadPolicy, TantivyDocument, Term, doc};
+
+use crate::domain::Ticket;
+use crate::ports::FullTextIndex;
+
+/// The smallest indexing buffer tantivy accepts for one thread.
+const WRITER_MEMORY_BYTES: usize = 15_000_000;
+
+struct Fields {
+    /// `provider/id`, unique per document.
+    key: Field,
+    provider: Field,
+    identifier: Field,
+    title: Field,
+    description: Field,
+    state: Field,
+    label: Field,
+    priority: Field,
+    assignee: Field,
+    /// The ticket as JSON, returned by searches.
+    ticket: Field,
+}
+
+/// A `FullTextIndex` kept by tantivy, in memory or in a directory, with
+/// tantivy's query syntax.
+pub struct TantivyIndex {
+    index: Index,
+    reader: IndexReader,
+    writer: Mutex<IndexWriter>,
+    fields: Fields,
+}
+
+impl TantivyIndex {
+    pub fn in_memory() -> Result<Self> {
+        let (schema, fields) = Self::schema();
+        Self::with_index(Index::create_in_ram(schema), fields)
+    }
+
+    /// Opens the index in `dir`, creating it if needed, so it survives restarts.
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create search index directory {}", dir.display()))?;
+        let (schema, fields) = Self::schema();
+        let directory = MmapDirectory::open(dir)
+            .with_context(|| format!("Failed to open search index directory {}", dir.display()))?;
+        Self::with_index(Index::open_or_create(directory, schema)?, fields)
+    }
+
+    fn schema() -> (Schema, Fields) {
+        let mut builder = Schema::builder();
+        let fields = Fields {
+            key: builder.add_text_field("key", STRING),
+            provider: builder.add_text_field("provider", STRING | STORED),
+            identifier: builder.add_text_field("identifier", TEXT),
+            title: builder.add_text_field("title", TEXT),
+            description: builder.add_text_field("description", TEXT),
+            state: builder.add_text_field("state", TEXT),
+            label: builder.add_text_field("label", TEXT),
+            priority: builder.add_text_field("priority", TEXT),
+            assignee: builder.add_text_field("assignee", STRING),
+            ticket: builder.add_text_field("ticket", STORED),
+        };
+        (builder.build(), fields)
+    }
+
+    fn with_index(index: Index, fields: Fields) -> Result<Self> {
+        let writer = index.writer_with_num_threads(1, WRITER_MEMORY_BYTES)?;
+        let reader = index.reader_builder().reload_policy(ReloadPolicy::Manual).try_into()?;
+        Ok(Self {
+            index,
+            reader,
+            writer: Mutex::new(writer),
+            fields,
+        })
+    }
+
+    fn key(provider: &str, ticket_id: &str) -> String {
+        format!("{}/{}", provider, ticket_id)
+    }
+}
+
+impl FullTextIndex for TantivyIndex {
+    fn index_tickets(&self, provider: &str, tickets: &[Ticket]) -> Result<()> {
+        if tickets.is_empty() {
+            return Ok(());
+        }
+        let f = &self.fields;
+        let mut writer = self.writer.lock().map_err(|_| anyhow!("Search index lock poisoned"))?;
+        for ticket in tickets {
+            let key = Self::key(provider, &ticket.id);
+            writer.delete_term(Term::from_field_text(f.key, &key));
+
+            let mut document = doc!(
+                f.key => key,
+                f.provider => provider,
+                f.identifier => ticket.identifier.as_str(),
+                f.title => ticket.title.as_str(),
+                f.state => ticket.state.name.as_str(),
+                f.priority => format!("{:?}", ticket.priority),
+                f.ticket => serde_json::to_string(ticket)?,
+            );
+            if let Some(description) = &ticket.description {
+                document.add_text(f.description, description.to_markdown());
+            }
+            for label in &ticket.labels {
+                document.add_text(f.label, label);
+            }
+            if let Some(assignee) = &ticket.assignee_id {
+                document.add_text(f.assignee, assignee);
+            }
+            writer.add_document(document)?;
+        }
+        writer.commit()?;
+        drop(writer);
+        self.reader.reload()?;
+        Ok(())
+    }
+
+    fn remove_ticket(&self, provider: &str, ticket_id: &str) -> Result<()> {
+        let mut writer = self.writer.lock().map_err(|_| anyhow!("Search index lock poisoned"))?;
+        writer.delete_term(Term::from_field_text(self.fields.key, &Self::key(provider, ticket_id)));
+        writer.commit()?;
+        drop(writer);
+        self.reader.reload()?;
+        Ok(())
+    }
+
+    fn search(&self, query: &str, provider: Option<&str>, fuzzy: bool, limit: usize) -> Result<Vec<(String, Ticket)>> {
+        let f = &self.fields;
+        let mut parser = QueryParser::for_index(&self.index, vec![f.identifier, f.title, f.description, f.label]);
+        parser.set_conjunction_by_default();
+        if fuzzy {
+            parser.set_field_fuzzy(f.title, false, 1, true);
+            parser.set_field_fuzzy(f.description, false, 1, true);
+        }
+        let mut parsed = parser.parse_query(query)
+            .map_err(|e| anyhow!("Invalid search query {:?}: {}", query, e))?;
+        if let Some(provider) = provider {
+            let only_provider: Box<dyn Query> = Box::new(TermQuery::new(
+                Term::from_field_text(f.provider, provider),
+                IndexRecordOption::Basic,
+            ));
+            parsed = Box::new(BooleanQuery::new(vec![(Occur::Must, parsed), (Occur::Must, only_provider)]));
+        }
+
+        let searcher = self.reader.searcher();
+        let mut results = Vec::new();
+        for (_score, address) in searcher.search(&parsed, &TopDocs::with_limit(limit))? {
+            let document: TantivyDocument = searcher.doc(address)?;
+            let provider = document.get_first(f.provider).and_then(|v| v.as_str()).unwrap_or_default();
+            let Some(ticket) = document.get_first(f.ticket).and_then(|v| v.as_str()) else {
+                continue;
+            };
+            results.push((provider.to_string(), serde_json::from_str(ticket)?));
+        }
+        Ok(results)
+    }
+}
@@ -0,0 +1,631 @@
+//! Argument types of the MCP tools. The input schema `list_tools`
+//! advertises is derived from each type, and `dispatch_tool` deserializes
+//! the call's arguments into it, so the two cannot drift apart. Field doc
+//! comments become the property descriptions.
+
+use schemars::JsonSchema;
+use schemars::r#gen::{SchemaGenerator, SchemaSettings};
+use schemars::schema::{InstanceType, Schema, SchemaObject};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::ports::{PageRequest, TicketServiceError};
+
+/// The input schema of a tool taking `T`: an object schema with the
+/// properties inlined, and `required` listing the fields without defaults.
+pub fn tool_schema<T: JsonSchema>() -> Value {
+    let settings = SchemaSettings::draft07().with(|settings| {
+        settings.option_add_null_type = false;
+        settings.inline_subschemas = true;
+        settings.meta_schema = None;
+    });
+    let schema = SchemaGenerator::new(settings).into_root_schema_for::<T>();
+    let mut schema = serde_json::to_value(schema).unwrap_or_default();
+    if let Some(schema) = schema.as_object_mut() {
+        // Flattened structs would otherwise lend the tool their doc comment
+        schema.remove("title");
+        schema.remove("description");
+        schema.entry("properties").or_insert_with(|| Value::Object(Default::default()));
+    }
+    schema
+}
+
+/// Deserializes a tool's arguments, reporting a missing or mistyped one as
+/// a validation error.
+pub fn parse_args<T: DeserializeOwned>(arguments: Value) -> anyhow::Result<T> {
+    // Clients send `null` or nothing for tools without arguments
+    let arguments = match arguments {
+        Value::Null => Value::Object(Default::default()),
+        arguments => arguments,
+    };
+    serde_json::from_value(arguments)
+        .map_err(|e| TicketServiceError::Validation(format!("Invalid arguments: {}", e)).into())
+}
+
+/// Declares a schema-only type for a string argument limited to `values`,
+/// for use with `#[schemars(with = ...)]`. The handlers still parse these
+/// leniently, accepting other cases and aliases.
+macro_rules! string_enum {
+    ($name:ident: $($value:literal),+) => {
+        struct $name;
+
+        impl JsonSchema for $name {
+            fn schema_name() -> String {
+                stringify!($name).to_string()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                SchemaObject {
+                    instance_type: Some(InstanceType::String.into()),
+                    enum_values: Some(vec![$(Value::from($value)),+]),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
+    };
+}
+
+string_enum!(ExportFormatName: "json", "csv", "markdown");
+string_enum!(ProjectStateName: "planned", "started", "paused", "completed", "canceled");
+string_enum!(RelationName: "blocks", "blocked_by", "duplicates", "relates_to");
+string_enum!(SyncSideName: "left", "right");
+
+/// For tools that take no arguments.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct NoArgs {}
+
+/// The `cursor` and `limit` arguments of paged tools.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct PageArgs {
+    /// next_cursor from a previous call, to fetch the following page
+    pub cursor: Option<String>,
+    /// Maximum number of items to return in this page
+    pub limit: Option<usize>,
+}
+
+impl From<PageArgs> for PageRequest {
+    fn from(page: PageArgs) -> Self {
+        PageRequest { cursor: page.cursor, limit: page.limit }
+    }
+}
+
+/// The filter arguments shared by filter-driven tools.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct FilterArgs {
+    /// Text to match in ticket titles and descriptions
+    pub query: Option<String>,
+    /// Only tickets assigned to this user
+    pub assignee_id: Option<String>,
+    /// Only tickets in this project
+    pub project_id: Option<String>,
+    /// Only tickets owned by this team
+    pub team_id: Option<String>,
+    /// Only tickets in this cycle (sprint)
+    pub cycle_id: Option<String>,
+    /// true for tickets blocked by another ticket, false for unblocked ones
+    pub blocked: Option<bool>,
+    /// State category: open, in_progress, closed, or cancelled
+    pub state: Option<String>,
+    /// Priority: none, lowest, low, medium, high, or highest
+    pub priority: Option<String>,
+    /// Only tickets with any of these label names
+    pub labels: Option<Vec<String>>,
+    /// Only tickets created on or after this date (YYYY-MM-DD, RFC3339, or a phrase like "last monday")
+    pub created_after: Option<String>,
+    /// Only tickets created before this date (YYYY-MM-DD, RFC3339, or a phrase like "last monday")
+    pub created_before: Option<String>,
+    /// Only tickets updated on or after this date (YYYY-MM-DD, RFC3339, or a phrase like "last monday")
+    pub updated_after: Option<String>,
+    /// Only tickets last updated before this date (YYYY-MM-DD, RFC3339, or a phrase like "last monday")
+    pub updated_before: Option<String>,
+    /// Only tickets untouched for at least this many days; overrides updated_before
+    pub stale_days: Option<i64>,
+    /// Only tickets due before this date (YYYY-MM-DD, RFC3339, or a phrase like "next friday")
+    pub due_before: Option<String>,
+    /// Also match archived tickets, which are left out by default
+    #[serde(default)]
+    pub include_archived: bool,
+    /// true for tickets awaiting triage, false to leave them out
+    pub triage: Option<bool>,
+    /// true for snoozed tickets, false to leave them out
+    pub snoozed: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetAssignedIssuesArgs {
+    /// The ID of the user to get assigned issues for
+    pub user_id: String,
+    #[serde(flatten)]
+    pub page: PageArgs,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SearchIssuesArgs {
+    /// Search query to find issues
+    #[serde(default)]
+    pub query: String,
+    #[serde(flatten)]
+    pub page: PageArgs,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AllProvidersSearchArgs {
+    /// Search query to find tickets
+    pub query: String,
+    /// Maximum number of merged results (default 50)
+    pub limit: Option<usize>,
+}
+
+#[cfg(feature = "local-search")]
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct LocalSearchArgs {
+    /// Search query, e.g. login "rate limit" state:"in progress" label:bug
+    pub query: String,
+    /// Let terms in titles and descriptions match within one typo (default false)
+    #[serde(default)]
+    pub fuzzy: bool,
+    /// Maximum number of results, best match first (default 50)
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SemanticSearchArgs {
+    /// What the tickets are about, in plain language
+    pub query: String,
+    /// Maximum number of matches (default 10)
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetIssueArgs {
+    /// The ID, identifier (METAL-37), or URL of the issue to retrieve
+    pub issue_id: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetTicketsArgs {
+    /// IDs, identifiers, or URLs of the tickets to fetch
+    pub ticket_ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RunSavedViewArgs {
+    /// Name of the saved view (case-insensitive)
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReleaseNotesArgs {
+    /// Only include tickets from this project (default: all projects)
+    pub project_id: Option<String>,
+    /// Start of the window, RFC3339, YYYY-MM-DD, or a phrase like "2 weeks ago" (default: 14 days before until)
+    pub since: Option<String>,
+    /// End of the window, RFC3339, YYYY-MM-DD, or a phrase like "yesterday" (default: now)
+    pub until: Option<String>,
+    /// Heading for the release notes (default: "Release notes")
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ExportTicketsArgs {
+    #[serde(flatten)]
+    pub filter: FilterArgs,
+    /// Export format (default json)
+    #[schemars(with = "Option<ExportFormatName>")]
+    pub format: Option<String>,
+    /// Replace user ids with stable aliases so the export can be shared externally (default: server setting)
+    pub pseudonymize: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct WatchTicketArgs {
+    /// The ID of the ticket
+    pub ticket_id: String,
+    /// The user to subscribe or unsubscribe (default: current user)
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PlanningBundleArgs {
+    /// Team ID or key (e.g. METAL)
+    pub team: String,
+    /// Planning horizon in days (default 14)
+    pub horizon: Option<i64>,
+    /// Estimate points each member can take on over the horizon (default 10)
+    pub capacity_per_member: Option<f32>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DueSoonArgs {
+    /// How far ahead to look in hours (default 24)
+    pub hours: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SlaBreachesArgs {
+    /// Team ID or key (default: the server's default team, or all teams)
+    pub team: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjectArgs {
+    /// The ID of the project
+    pub project_id: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ProjectUpdatesArgs {
+    /// The ID of the project
+    pub project_id: String,
+    #[serde(flatten)]
+    pub page: PageArgs,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListDocumentsArgs {
+    /// Only documents in this project
+    pub project_id: Option<String>,
+    #[serde(flatten)]
+    pub page: PageArgs,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetDocumentArgs {
+    /// The ID of the document
+    pub document_id: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateProjectArgs {
+    /// Project name
+    pub name: String,
+    /// IDs or keys of the teams that own the project
+    pub teams: Vec<String>,
+    /// Project description
+    pub description: Option<String>,
+    /// User ID of the project lead
+    pub lead_id: Option<String>,
+    /// Target completion date, YYYY-MM-DD or a phrase like "in 6 weeks"
+    pub target_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct UpdateProjectArgs {
+    /// The ID of the project to update
+    pub project_id: String,
+    /// New project name
+    pub name: Option<String>,
+    /// New project description
+    pub description: Option<String>,
+    /// New project state
+    #[schemars(with = "Option<ProjectStateName>")]
+    pub state: Option<String>,
+    /// User ID of the new project lead
+    pub lead_id: Option<String>,
+    /// New target date, YYYY-MM-DD or a phrase like "in 6 weeks"
+    pub target_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateMilestoneArgs {
+    /// The ID of the project
+    pub project_id: String,
+    /// Milestone name
+    pub name: String,
+    /// Milestone description
+    pub description: Option<String>,
+    /// Milestone target date, YYYY-MM-DD or a phrase like "in 6 weeks"
+    pub target_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct NotificationsArgs {
+    /// Only unread notifications (default true)
+    pub unread_only: Option<bool>,
+    #[serde(flatten)]
+    pub page: PageArgs,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct InitiativeProjectsArgs {
+    /// Initiative id or name
+    pub initiative: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TeamArgs {
+    /// Team ID or key
+    pub team: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AssignToCycleArgs {
+    /// The ID of the ticket to move
+    pub ticket_id: String,
+    /// The target cycle ID
+    pub cycle_id: Option<String>,
+    /// Team ID or key whose active cycle to use when cycle_id is omitted
+    pub team: Option<String>,
+}
+
+/// Minutes, or a duration like `1h 30m`.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum DurationArg {
+    Minutes(u32),
+    Text(String),
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct LogTimeArgs {
+    /// The ID of the ticket to log time against
+    pub ticket_id: String,
+    /// Minutes as a number, or a duration like 2h, 45m, 1h 30m
+    pub duration: DurationArg,
+    /// When the work started, RFC3339, YYYY-MM-DD, or a phrase like "2 weeks ago" (default: now)
+    pub started_at: Option<String>,
+    /// What the time was spent on
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TicketArgs {
+    /// The ID or identifier of the ticket
+    pub ticket_id: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListCommentsArgs {
+    /// The ID of the ticket
+    pub ticket_id: String,
+    #[serde(flatten)]
+    pub page: PageArgs,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AddCommentArgs {
+    /// The ID of the ticket
+    pub ticket_id: String,
+    /// Comment text (markdown)
+    pub body: String,
+    /// Reply to this comment instead of starting a new thread
+    pub parent_comment_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct LinkTicketsArgs {
+    /// The ticket the relation is read from
+    pub ticket_id: String,
+    /// The other ticket
+    pub related_ticket_id: String,
+    /// How ticket_id relates to related_ticket_id (default relates_to)
+    #[schemars(with = "Option<RelationName>")]
+    pub relation: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SetParentArgs {
+    /// The ticket to move
+    pub ticket_id: String,
+    /// The new parent ticket; omit to detach from the current parent
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TicketBranchNameArgs {
+    /// The ID or identifier of the ticket
+    pub ticket_id: String,
+    /// Branch prefix, e.g. your username; defaults to the configured prefix or the local part of your email
+    pub prefix: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ParseTicketReferencesArgs {
+    /// A branch name, commit message, or pull request title and body
+    pub text: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct StartTicketBranchArgs {
+    /// The ID or identifier of the ticket; omit when giving branch
+    pub ticket_id: Option<String>,
+    /// An existing branch whose name references the ticket, instead of ticket_id
+    pub branch: Option<String>,
+    /// Branch prefix for a new branch name; as for ticket_branch_name
+    pub prefix: Option<String>,
+    /// State to move the ticket to (name, id, or category); defaults to in progress, and only moves tickets not yet started
+    pub state: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AddAttachmentArgs {
+    /// The ID of the ticket
+    pub ticket_id: String,
+    /// The http(s) URL to attach
+    pub url: String,
+    /// Title to show for the link
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AddReactionArgs {
+    /// Emoji name, e.g. thumbsup or eyes
+    pub emoji: String,
+    /// React to this ticket
+    pub ticket_id: Option<String>,
+    /// React to this comment instead
+    pub comment_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct RemoveReactionArgs {
+    /// The ID of the reaction to remove
+    pub reaction_id: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct FindDuplicatesArgs {
+    /// Title of the proposed ticket
+    pub title: String,
+    /// Description of the proposed ticket, used to widen the search
+    pub description: Option<String>,
+    /// Label names the proposed ticket would carry
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Only consider tickets owned by this team
+    pub team_id: Option<String>,
+    /// Maximum number of candidates to return (default 10)
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateTicketArgs {
+    /// Ticket title
+    pub title: String,
+    /// Ticket description in markdown
+    pub description: Option<String>,
+    /// Team id or key; defaults to the configured default team
+    pub team: Option<String>,
+    /// none, lowest, low, medium, high, or highest
+    pub priority: Option<String>,
+    /// Assign the new ticket to this user; defaults to you when self-assign is configured
+    pub assignee_id: Option<String>,
+    /// Add the ticket to this project; defaults to the configured default project
+    pub project_id: Option<String>,
+    /// Create the ticket as a sub-ticket of this one
+    pub parent_id: Option<String>,
+    /// Label ids to apply
+    pub label_ids: Option<Vec<String>>,
+    /// Due date (YYYY-MM-DD, RFC3339, or a phrase like "next friday")
+    pub due_date: Option<String>,
+    /// Estimate in the team's points
+    pub estimate: Option<f32>,
+    /// Unique key for this creation, e.g. a UUID; reuse it when retrying
+    pub idempotency_key: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct CreateFromTemplateArgs {
+    /// Name of the template (case-insensitive)
+    pub template: String,
+    /// Values for the template's placeholders, e.g. {"service": "billing"}
+    #[serde(default)]
+    pub variables: HashMap<String, Value>,
+    /// Team id or key; overrides the template's team
+    pub team: Option<String>,
+    /// Assign the new ticket to this user
+    pub assignee_id: Option<String>,
+}
+
+/// One ticket of a batch, with its sub-tickets.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct TicketSpecArgs {
+    pub title: String,
+    pub description: Option<String>,
+    /// none, low, medium, high, or urgent
+    pub priority: Option<String>,
+    pub assignee_id: Option<String>,
+    pub team_id: Option<String>,
+    pub project_id: Option<String>,
+    pub label_ids: Option<Vec<String>>,
+    /// YYYY-MM-DD, RFC 3339, or a phrase like "in 2 weeks"
+    pub due_date: Option<String>,
+    pub estimate: Option<f32>,
+    /// Sub-ticket specs of the same shape, created under this ticket
+    #[serde(default)]
+    pub children: Vec<TicketSpecArgs>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BatchCreateArgs {
+    /// Ticket specs to create in order
+    pub tickets: Vec<TicketSpecArgs>,
+    /// Team id or key used for specs without a team_id (defaults to MCP_DEFAULT_TEAM)
+    pub team: Option<String>,
+    /// Delete every created ticket if any creation fails (default false)
+    #[serde(default)]
+    pub rollback_on_failure: bool,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MarkdownTicketsArgs {
+    /// The markdown document to turn into tickets
+    pub markdown: String,
+    /// Team id or key to create the tickets in (defaults to MCP_DEFAULT_TEAM)
+    pub team: Option<String>,
+    /// Delete every created ticket if any creation fails (default false)
+    #[serde(default)]
+    pub rollback_on_failure: bool,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ImportTicketsCsvArgs {
+    /// CSV text whose header names each column's field: title (required),
+    /// description, priority, team, assignee, project, labels, due_date, estimate
+    pub csv: String,
+    /// Header name to field, for columns not named after their field
+    #[serde(default)]
+    pub columns: HashMap<String, String>,
+    /// Team id or key for rows without a team column (defaults to MCP_DEFAULT_TEAM)
+    pub team: Option<String>,
+    /// Only validate the rows without creating anything (default true)
+    pub dry_run: Option<bool>,
+    /// Tickets created at once (default 4)
+    pub concurrency: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DestructiveArgs {
+    /// IDs of the tickets
+    pub ticket_ids: Vec<String>,
+    /// Token from the preview call; omit to request a preview
+    pub confirmation_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct WorkingSetPinArgs {
+    /// The ID or identifier of the ticket to pin
+    pub ticket_id: String,
+    /// Whether the ticket should be pinned (default true)
+    pub pinned: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct AuditLogArgs {
+    /// Maximum number of entries (default 50)
+    pub limit: Option<usize>,
+    /// Only calls to this tool
+    pub tool: Option<String>,
+    /// Only calls from this session id, or "current" for this session
+    pub session: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SyncRunArgs {
+    /// Resolve conflicts by copying from this side
+    #[schemars(with = "Option<SyncSideName>")]
+    pub prefer: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MigrateWorkspaceArgs {
+    /// Only plan the migration without creating anything (default true)
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct MoreResultsArgs {
+    /// The continue_cursor of the truncated result
+    pub cursor: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct PauseScheduleArgs {
+    /// Name of the schedule
+    pub name: String,
+    /// false to resume (default true)
+    pub paused: Option<bool>,
+}
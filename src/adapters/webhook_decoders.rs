@@ -0,0 +1,198 @@
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use serde_json::Value;
+use sha2::Sha256;
+use std::collections::HashMap;
+
+use crate::domain::{WebhookAction, WebhookEntity, WebhookEvent};
+use crate::ports::WebhookDecoder;
+
+/// Linear deliveries older than this are treated as replays.
+const LINEAR_MAX_DELIVERY_AGE_SECS: i64 = 60;
+
+/// Checks a hex-encoded HMAC-SHA256 of `body` in constant time.
+fn verify_hmac(secret: &str, body: &[u8], signature_hex: &str) -> Result<()> {
+    let signature = hex::decode(signature_hex.trim())
+        .map_err(|_| anyhow!("Webhook signature is not valid hex"))?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .map_err(|e| anyhow!("Invalid webhook secret: {}", e))?;
+    mac.update(body);
+    mac.verify_slice(&signature).map_err(|_| anyhow!("Webhook signature does not match"))
+}
+
+/// Decodes Linear webhooks, signed with the secret given when the webhook was
+/// registered (`Linear-Signature`).
+pub struct LinearWebhookDecoder {
+    secret: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LinearDelivery {
+    action: String,
+    #[serde(rename = "type")]
+    type_: String,
+    data: Value,
+    created_at: Option<DateTime<Utc>>,
+    /// Milliseconds since the epoch at which Linear sent the delivery.
+    webhook_timestamp: Option<i64>,
+}
+
+impl LinearWebhookDecoder {
+    /// Without a secret deliveries are accepted unsigned, which the webhook
+    /// server refuses unless told otherwise.
+    pub fn new(secret: Option<String>) -> Self {
+        Self { secret }
+    }
+}
+
+impl WebhookDecoder for LinearWebhookDecoder {
+    fn provider(&self) -> &str {
+        "linear"
+    }
+
+    fn signed(&self) -> bool {
+        self.secret.is_some()
+    }
+
+    fn verify(&self, headers: &HashMap<String, String>, body: &[u8]) -> Result<()> {
+        let Some(secret) = &self.secret else {
+            return Ok(());
+        };
+        let signature = headers.get("linear-signature")
+            .ok_or_else(|| anyhow!("Missing Linear-Signature header"))?;
+        verify_hmac(secret, body, signature)?;
+
+        let delivery: LinearDelivery = serde_json::from_slice(body)?;
+        if let Some(sent_ms) = delivery.webhook_timestamp {
+            let age = Utc::now().timestamp_millis() - sent_ms;
+            if age.abs() > LINEAR_MAX_DELIVERY_AGE_SECS * 1000 {
+                return Err(anyhow!("Linear webhook delivery is {}s old; rejecting as a replay", age / 1000));
+            }
+        }
+        Ok(())
+    }
+
+    fn decode(&self, _headers: &HashMap<String, String>, body: &[u8]) -> Result<Vec<WebhookEvent>> {
+        let delivery: LinearDelivery = serde_json::from_slice(body)
+            .map_err(|e| anyhow!("Unexpected Linear webhook payload: {}", e))?;
+
+        let action = match delivery.action.as_str() {
+            "create" => WebhookAction::Created,
+            "update" => WebhookAction::Updated,
+            "remove" => WebhookAction::Removed,
+            _ => return Ok(Vec::new()),
+        };
+        let entity = match delivery.type_.as_str() {
+            "Issue" => WebhookEntity::Ticket,
+            "Comment" => WebhookEntity::Comment,
+            "Project" => WebhookEntity::Project,
+            "ProjectUpdate" => WebhookEntity::ProjectUpdate,
+            "Document" => WebhookEntity::Document,
+            "IssueLabel" => WebhookEntity::Label,
+            "Cycle" => WebhookEntity::Cycle,
+            other => WebhookEntity::Other(other.to_string()),
+        };
+        let field = |name: &str| delivery.data[name].as_str().map(|s| s.to_string());
+        let entity_id = field("id").ok_or_else(|| anyhow!("Linear webhook payload has no data.id"))?;
+        let ticket_id = match entity {
+            WebhookEntity::Ticket => Some(entity_id.clone()),
+            _ => field("issueId"),
+        };
+
+        Ok(vec![WebhookEvent {
+            provider: self.provider().to_string(),
+            entity,
+            action,
+            entity_id,
+            ticket_id,
+            project_id: field("projectId"),
+            occurred_at: delivery.created_at.unwrap_or_else(Utc::now),
+        }])
+    }
+}
+
+/// Decodes GitHub webhooks, signed with the webhook's secret
+/// (`X-Hub-Signature-256`). Issues map to tickets; ticket ids take the
+/// `owner/repo#number` form.
+pub struct GithubWebhookDecoder {
+    secret: Option<String>,
+}
+
+impl GithubWebhookDecoder {
+    pub fn new(secret: Option<String>) -> Self {
+        Self { secret }
+    }
+}
+
+impl WebhookDecoder for GithubWebhookDecoder {
+    fn provider(&self) -> &str {
+        "github"
+    }
+
+    fn signed(&self) -> bool {
+        self.secret.is_some()
+    }
+
+    fn verify(&self, headers: &HashMap<String, String>, body: &[u8]) -> Result<()> {
+        let Some(secret) = &self.secret else {
+            return Ok(());
+        };
+        let signature = headers.get("x-hub-signature-256")
+            .and_then(|value| value.strip_prefix("sha256="))
+            .ok_or_else(|| anyhow!("Missing X-Hub-Signature-256 header"))?;
+        verify_hmac(secret, body, signature)
+    }
+
+    fn decode(&self, headers: &HashMap<String, String>, body: &[u8]) -> Result<Vec<WebhookEvent>> {
+        let event = headers.get("x-github-event").map(|s| s.as_str()).unwrap_or_default();
+        let payload: Value = serde_json::from_slice(body)
+            .map_err(|e| anyhow!("Unexpected GitHub webhook payload: {}", e))?;
+
+        let entity = match event {
+            "issues" => WebhookEntity::Ticket,
+            "issue_comment" => WebhookEntity::Comment,
+            "label" => WebhookEntity::Label,
+            "milestone" => WebhookEntity::Cycle,
+            // ping and events that do not touch tickets
+            _ => return Ok(Vec::new()),
+        };
+        let action = match payload["action"].as_str().unwrap_or_default() {
+            "opened" | "created" => WebhookAction::Created,
+            "deleted" | "transferred" => WebhookAction::Removed,
+            _ => WebhookAction::Updated,
+        };
+
+        let repository = payload["repository"]["full_name"].as_str().unwrap_or_default();
+        let ticket_id = payload["issue"]["number"]
+            .as_u64()
+            .map(|number| format!("{}#{}", repository, number));
+        let object = match entity {
+            WebhookEntity::Ticket => &payload["issue"],
+            WebhookEntity::Comment => &payload["comment"],
+            WebhookEntity::Label => &payload["label"],
+            _ => &payload["milestone"],
+        };
+        let entity_id = match &object["id"] {
+            Value::Number(id) => id.to_string(),
+            Value::String(id) => id.clone(),
+            _ => return Err(anyhow!("GitHub {} webhook payload has no id", event)),
+        };
+
+        Ok(vec![WebhookEvent {
+            provider: self.provider().to_string(),
+            entity,
+            action,
+            entity_id,
+            ticket_id,
+            project_id: None,
+            occurred_at: object["updated_at"]
+                .as_str()
+                .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                .map(|t| t.with_timezone(&Utc))
+                .unwrap_or_else(Utc::now),
+        }])
+    }
+}
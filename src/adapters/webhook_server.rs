@@ -0,0 +1,131 @@
+use anyhow::{Result, bail};
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full, Limited};
+use hyper::body::Incoming;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tracing::{debug, info, warn};
+
+use crate::core::EventBus;
+use crate::ports::WebhookDecoder;
+
+/// Deliveries are small JSON documents; anything bigger is refused.
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+/// Receives provider webhooks over HTTP, one path per provider, and
+/// publishes the verified events on the bus.
+pub struct WebhookServer {
+    addr: SocketAddr,
+    bus: EventBus,
+    routes: HashMap<String, Arc<dyn WebhookDecoder>>,
+    allow_unsigned: bool,
+}
+
+impl WebhookServer {
+    pub fn new(addr: SocketAddr, bus: EventBus) -> Self {
+        Self {
+            addr,
+            bus,
+            routes: HashMap::new(),
+            allow_unsigned: false,
+        }
+    }
+
+    /// Serves decoders without a secret too. Anyone who can reach the
+    /// address can then publish events, so only use this behind something
+    /// else that authenticates the providers.
+    pub fn allow_unsigned(mut self, allow: bool) -> Self {
+        self.allow_unsigned = allow;
+        self
+    }
+
+    /// Serves `decoder`'s provider at `/webhooks/<provider>`.
+    pub fn with_decoder(mut self, decoder: Arc<dyn WebhookDecoder>) -> Self {
+        self.routes.insert(format!("/webhooks/{}", decoder.provider()), decoder);
+        self
+    }
+
+    /// Refuses to serve a decoder that accepts unsigned deliveries, unless
+    /// allowed with [`Self::allow_unsigned`].
+    pub fn check(&self) -> Result<()> {
+        let mut unsigned: Vec<&str> = self.routes.values()
+            .filter(|decoder| !decoder.signed())
+            .map(|decoder| decoder.provider())
+            .collect();
+        if !unsigned.is_empty() && !self.allow_unsigned {
+            unsigned.sort();
+            bail!("Webhooks from {} would be accepted unsigned; configure their secrets", unsigned.join(", "));
+        }
+        Ok(())
+    }
+
+    /// Accepts connections until the task is dropped.
+    pub async fn run(self) -> Result<()> {
+        self.check()?;
+        let listener = TcpListener::bind(self.addr).await?;
+        info!("Listening for webhooks on {} ({})", self.addr, self.routes.keys().cloned().collect::<Vec<_>>().join(", "));
+
+        let server = Arc::new(self);
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let server = server.clone();
+            tokio::spawn(async move {
+                let service = service_fn(|request| {
+                    let server = server.clone();
+                    async move { Ok::<_, hyper::Error>(server.handle(request).await) }
+                });
+                if let Err(e) = http1::Builder::new().serve_connection(TokioIo::new(stream), service).await {
+                    debug!("Webhook connection from {} ended: {}", peer, e);
+                }
+            });
+        }
+    }
+
+    async fn handle(&self, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        let Some(decoder) = self.routes.get(request.uri().path()) else {
+            return Self::respond(StatusCode::NOT_FOUND, "Unknown webhook path");
+        };
+        if request.method() != Method::POST {
+            return Self::respond(StatusCode::METHOD_NOT_ALLOWED, "Webhooks must be POSTed");
+        }
+
+        let headers: HashMap<String, String> = request.headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str().to_lowercase(), value.to_str().ok()?.to_string())))
+            .collect();
+        let body = match Limited::new(request.into_body(), MAX_BODY_BYTES).collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(_) => return Self::respond(StatusCode::PAYLOAD_TOO_LARGE, "Webhook body too large"),
+        };
+
+        if let Err(e) = decoder.verify(&headers, &body) {
+            warn!("Rejected {} webhook: {}", decoder.provider(), e);
+            return Self::respond(StatusCode::UNAUTHORIZED, "Invalid webhook signature");
+        }
+        let events = match decoder.decode(&headers, &body) {
+            Ok(events) => events,
+            Err(e) => {
+                warn!("Could not decode {} webhook: {}", decoder.provider(), e);
+                return Self::respond(StatusCode::BAD_REQUEST, "Unrecognized webhook payload");
+            }
+        };
+
+        for event in &events {
+            debug!("{} webhook: {:?} {:?} {}", event.provider, event.action, event.entity, event.entity_id);
+            self.bus.publish(event);
+        }
+        Self::respond(StatusCode::OK, "ok")
+    }
+
+    fn respond(status: StatusCode, message: &'static str) -> Response<Full<Bytes>> {
+        let mut response = Response::new(Full::new(Bytes::from_static(message.as_bytes())));
+        *response.status_mut() = status;
+        response
+    }
+}
@@ -0,0 +1,216 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::core::{EventListener, ProviderRegistry, priority_rank};
+use crate::domain::{AlertKind, StateType, Ticket, TicketAlert, WebhookAction, WebhookEntity, WebhookEvent};
+use crate::ports::AlertSink;
+
+/// How often changed tickets are looked up and SLA deadlines checked.
+pub const DEFAULT_ALERT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Where alerts go, as read from `MCP_ALERTS_FILE`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AlertsConfig {
+    #[serde(default)]
+    pub slack: Vec<SlackAlerts>,
+    /// Seconds between checks (default 15).
+    #[serde(default)]
+    pub interval_secs: Option<u64>,
+}
+
+/// A Slack incoming webhook and the alerts posted to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlackAlerts {
+    /// The webhook URL, or a `secret://` URI naming it.
+    pub webhook_url: String,
+    pub events: Vec<AlertKind>,
+    /// Providers whose tickets are posted; empty for all.
+    #[serde(default)]
+    pub providers: Vec<String>,
+}
+
+pub fn load_alerts_config(path: impl AsRef<Path>) -> Result<AlertsConfig> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read alerts configuration from {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid alerts configuration in {}", path.display()))
+}
+
+struct AlertRoute {
+    kinds: Vec<AlertKind>,
+    /// Empty for every provider.
+    providers: Vec<String>,
+    sink: Arc<dyn AlertSink>,
+}
+
+impl AlertRoute {
+    fn accepts(&self, alert: &TicketAlert) -> bool {
+        self.kinds.contains(&alert.kind) && (self.providers.is_empty() || self.providers.contains(&alert.provider))
+    }
+}
+
+/// A ticket as last seen, to tell what changed.
+struct Seen {
+    ticket: Ticket,
+    sla_alerted: bool,
+}
+
+/// Turns webhook events into alerts. Events only queue the ticket they
+/// are about; `run` looks each one up and compares it with how it last saw
+/// it, so a reassignment or escalation alerts once however many events it
+/// causes. The current user's open tickets are loaded first so that only
+/// tickets newly assigned to them count as assigned. SLA deadlines pass
+/// without an event, so they are checked on every round.
+pub struct AlertWatcher {
+    registry: ProviderRegistry,
+    interval: Duration,
+    routes: Vec<AlertRoute>,
+    changed: Mutex<Vec<(String, String)>>,
+}
+
+impl AlertWatcher {
+    pub fn new(registry: ProviderRegistry, interval: Duration) -> Self {
+        Self {
+            registry,
+            interval,
+            routes: Vec::new(),
+            changed: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Sends `kinds` of alerts about `providers`' tickets (all when empty)
+    /// to `sink`.
+    pub fn with_route(mut self, kinds: Vec<AlertKind>, providers: Vec<String>, sink: Arc<dyn AlertSink>) -> Self {
+        self.routes.push(AlertRoute { kinds, providers, sink });
+        self
+    }
+
+    fn watches(&self, provider: &str) -> bool {
+        self.routes.iter().any(|route| route.providers.is_empty() || route.providers.iter().any(|p| p == provider))
+    }
+
+    /// Checks until the task is dropped.
+    pub async fn run(self: Arc<Self>) {
+        info!("Checking for ticket alerts every {:?}", self.interval);
+        // The current user's id by provider, once their tickets are loaded
+        let mut me: HashMap<String, String> = HashMap::new();
+        let mut seen: HashMap<(String, String), Seen> = HashMap::new();
+        loop {
+            for (provider, application) in self.registry.iter().filter(|(provider, _)| self.watches(provider)) {
+                if me.contains_key(provider) {
+                    continue;
+                }
+                let loaded = async {
+                    let user = application.get_current_user().await?;
+                    let tickets = application.get_all_assigned_tickets(&user.id).await?;
+                    anyhow::Ok((user, tickets))
+                };
+                match loaded.await {
+                    Ok((user, tickets)) => {
+                        let now = Utc::now();
+                        for ticket in tickets {
+                            // Breaches from before the start are not news
+                            let sla_alerted = ticket.sla.as_ref().is_some_and(|sla| sla.is_breached(now));
+                            seen.insert((provider.to_string(), ticket.id.clone()), Seen { ticket, sla_alerted });
+                        }
+                        me.insert(provider.to_string(), user.id);
+                    }
+                    Err(e) => warn!("Loading {}'s tickets for alerts failed: {}", provider, e),
+                }
+            }
+
+            let changed = self.changed.lock().map(|mut changed| std::mem::take(&mut *changed)).unwrap_or_default();
+            for (provider, ticket_id) in changed {
+                // Tickets of providers still loading are picked up later
+                let (Some(my_id), Ok(application)) = (me.get(&provider), self.registry.get(Some(&provider))) else {
+                    continue;
+                };
+                let ticket = match application.get_ticket_by_id(&ticket_id).await {
+                    Ok(Some(ticket)) => ticket,
+                    Ok(None) => {
+                        seen.remove(&(provider, ticket_id));
+                        continue;
+                    }
+                    Err(e) => {
+                        warn!("Looking up {} ticket {} for alerts failed: {}", provider, ticket_id, e);
+                        continue;
+                    }
+                };
+                let key = (provider.clone(), ticket.id.clone());
+                let previous = seen.get(&key).map(|seen| &seen.ticket);
+                let mut alerts = Vec::new();
+                if ticket.assignee_id.as_deref() == Some(my_id.as_str())
+                    && previous.is_none_or(|previous| previous.assignee_id != ticket.assignee_id)
+                {
+                    alerts.push(TicketAlert::new(&provider, AlertKind::AssignedToMe, &ticket));
+                }
+                if let Some(previous) = previous.filter(|previous| priority_rank(&ticket.priority) > priority_rank(&previous.priority)) {
+                    alerts.push(TicketAlert {
+                        previous_priority: Some(previous.priority.clone()),
+                        ..TicketAlert::new(&provider, AlertKind::PriorityEscalated, &ticket)
+                    });
+                }
+                for alert in alerts {
+                    self.send(&alert).await;
+                }
+
+                if matches!(ticket.state.type_, StateType::Closed | StateType::Cancelled) {
+                    seen.remove(&key);
+                } else {
+                    // A new deadline may be breached again
+                    let sla_alerted = seen.get(&key).is_some_and(|seen| seen.sla_alerted)
+                        && ticket.sla.as_ref().is_some_and(|sla| sla.is_breached(Utc::now()));
+                    seen.insert(key, Seen { ticket, sla_alerted });
+                }
+            }
+
+            let now = Utc::now();
+            for ((provider, _), seen) in seen.iter_mut().filter(|(_, seen)| !seen.sla_alerted) {
+                let Some(sla) = seen.ticket.sla.as_ref().filter(|sla| sla.is_breached(now)) else {
+                    continue;
+                };
+                let alert = TicketAlert {
+                    sla_due: sla.next_due(),
+                    ..TicketAlert::new(provider, AlertKind::SlaBreached, &seen.ticket)
+                };
+                self.send(&alert).await;
+                seen.sla_alerted = true;
+            }
+
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+
+    async fn send(&self, alert: &TicketAlert) {
+        debug!("Alert: {}", alert.message());
+        for route in self.routes.iter().filter(|route| route.accepts(alert)) {
+            if let Err(e) = route.sink.send(alert).await {
+                warn!("Sending alert for {} failed: {}", alert.identifier, e);
+            }
+        }
+    }
+}
+
+impl EventListener for AlertWatcher {
+    fn on_event(&self, event: &WebhookEvent) {
+        if event.entity != WebhookEntity::Ticket || event.action == WebhookAction::Removed {
+            return;
+        }
+        if !self.watches(&event.provider) {
+            return;
+        }
+        if let (Some(ticket_id), Ok(mut changed)) = (&event.ticket_id, self.changed.lock()) {
+            let entry = (event.provider.clone(), ticket_id.clone());
+            if !changed.contains(&entry) {
+                changed.push(entry);
+            }
+        }
+    }
+}
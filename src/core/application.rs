@@ -1,27 +1,134 @@
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Duration, Utc};
+use futures::{StreamExt, TryFutureExt};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
 use std::sync::Arc;
-use tracing::{info, debug};
+use tracing::{info, debug, warn};
 
-use crate::domain::{Ticket, TicketFilter, StateType, Workspace};
-use crate::domain::workspace::User;
-use crate::ports::TicketService;
+use crate::core::{
+    ReleaseNotes, ConfirmationGate, ConfirmationPreview, DestructiveAction,
+    BulkActionResult, BulkFailure, PlanningBundle, PlanningParameters,
+    DuplicateCandidate, keywords, rank_duplicates,
+    TicketSpec, BatchCreateResult, BatchFailure, flatten_specs, build_created_tree, parse_markdown_plan,
+    sort_tickets, render_template, IdempotencyStore,
+    ProviderRegistry, ProviderTicket, ProviderFailure, CrossProviderResults, TicketDefaults,
+    WorkspaceSnapshot, TicketSnapshot, ImportReport, SNAPSHOT_VERSION, attributed_comment,
+    FieldMapping, ImportProgress, ImportTargets, MigrationPlan, Resolver,
+    CsvField, CsvImportPlan, CsvImportReport, CsvCreated, CsvFailure, parse_ticket_csv,
+    ticket_references,
+};
+use crate::domain::{
+    Ticket, TicketFilter, StateType, Workspace, Project, ProjectMilestone,
+    CreateProjectRequest, UpdateProjectRequest, CreateMilestoneRequest, Cycle,
+    TimeEntry, LogTimeRequest, TicketRef, Comment, CreateCommentRequest, TicketEvent,
+    Workflow, Reaction, ReactionTarget, Initiative, SavedView,
+    Notification, TicketTemplate, CreateTicketRequest, UpdateTicketRequest, RichText, CreateRelationRequest,
+    Attachment, CreateAttachmentRequest, ProjectUpdate, Document,
+    CreateLabelRequest, Label, ProjectState, RelationKind, WorkflowState,
+};
+use crate::domain::{Team, User};
+use crate::ports::{Capabilities, TicketService, Page, PageRequest, UnsupportedOperation, collect_pages};
+
+/// Provider calls one operation keeps in flight when it fetches several
+/// independent things; the provider's rate limits still apply on top.
+const MAX_CONCURRENT_FETCHES: usize = 8;
+
+/// Runs `fetch` for each item with up to `MAX_CONCURRENT_FETCHES` in
+/// flight, returning the results in the order of `items`.
+async fn fan_out<I, T, F, Fut>(items: I, fetch: F) -> Vec<T>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = T>,
+{
+    futures::stream::iter(items.into_iter().map(fetch))
+        .buffered(MAX_CONCURRENT_FETCHES)
+        .collect()
+        .await
+}
 
 pub struct Application {
     ticket_service: Arc<dyn TicketService + Send + Sync>,
+    confirmations: ConfirmationGate,
+    idempotency: Arc<IdempotencyStore>,
+    /// Prefixes idempotency keys so providers sharing a store stay apart.
+    idempotency_scope: String,
+    defaults: TicketDefaults,
 }
 
 impl Application {
     pub fn new(ticket_service: Arc<dyn TicketService + Send + Sync>) -> Self {
-        Self { ticket_service }
+        Self {
+            ticket_service,
+            confirmations: ConfirmationGate::default(),
+            idempotency: Arc::new(IdempotencyStore::in_memory()),
+            idempotency_scope: String::new(),
+            defaults: TicketDefaults::default(),
+        }
+    }
+
+    /// Shares (or persists) idempotency keys; `scope` keeps this provider's
+    /// keys apart from others in the same store.
+    pub fn with_idempotency_store(mut self, store: Arc<IdempotencyStore>, scope: impl Into<String>) -> Self {
+        self.idempotency = store;
+        self.idempotency_scope = scope.into();
+        self
+    }
+
+    /// Defaults of the profile this application serves.
+    pub fn with_defaults(mut self, defaults: TicketDefaults) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    pub fn defaults(&self) -> &TicketDefaults {
+        &self.defaults
     }
 
-    pub async fn get_assigned_tickets(&self, user_id: &str) -> Result<Vec<Ticket>> {
+    pub fn capabilities(&self) -> Capabilities {
+        self.ticket_service.capabilities()
+    }
+
+    /// Resolves the configured defaults to ids, looking up only those set.
+    async fn default_ids(&self) -> Result<DefaultIds> {
+        let team_id = match &self.defaults.team {
+            Some(team) => Some(self.find_team(team).await?.id),
+            None => None,
+        };
+        let project_id = match &self.defaults.project {
+            Some(project) => Some(self.find_project(project).await?.id),
+            None => None,
+        };
+        let assignee_id = match self.defaults.self_assign {
+            Some(true) => Some(self.ticket_service.get_current_user().await?.id),
+            _ => None,
+        };
+        Ok(DefaultIds { team_id, project_id, assignee_id })
+    }
+
+    pub async fn get_assigned_tickets(&self, user_id: &str, page: &PageRequest) -> Result<Page<Ticket>> {
         debug!("Getting assigned tickets for user: {}", user_id);
-        let tickets = self.ticket_service.get_assigned_tickets(user_id).await?;
+        let tickets = self.ticket_service.get_assigned_tickets(user_id, page).await?;
+        info!("Retrieved {} assigned tickets for user {}", tickets.items.len(), user_id);
+        Ok(tickets)
+    }
+
+    /// Every ticket assigned to a user, following pages to the end.
+    pub async fn get_all_assigned_tickets(&self, user_id: &str) -> Result<Vec<Ticket>> {
+        debug!("Getting all assigned tickets for user: {}", user_id);
+        let tickets = self.ticket_service.get_all_assigned_tickets(user_id).await?;
         info!("Retrieved {} assigned tickets for user {}", tickets.len(), user_id);
         Ok(tickets)
     }
 
+    /// Every ticket matching a filter, following pages to the end.
+    async fn search_all_tickets(&self, filter: &TicketFilter) -> Result<Vec<Ticket>> {
+        Ok(collect_pages(|page| async move {
+            self.ticket_service.search_tickets(filter, &page).await
+        }).await?)
+    }
+
     pub async fn get_current_user(&self) -> Result<User> {
         debug!("Getting current user information");
         let user = self.ticket_service.get_current_user().await?;
@@ -29,27 +136,94 @@ impl Application {
         Ok(user)
     }
 
-    pub async fn search_tickets(&self, query: &str) -> Result<Vec<Ticket>> {
+    pub async fn search_tickets(&self, query: &str, page: &PageRequest) -> Result<Page<Ticket>> {
         debug!("Searching tickets with query: {}", query);
         
         let filter = TicketFilter {
-            assignee_id: None,
-            project_id: None,
-            state_type: None,
-            priority: None,
-            labels: None,
             search_query: Some(query.to_string()),
-            custom_filters: std::collections::HashMap::new(),
+            ..Default::default()
         };
 
-        let tickets = self.ticket_service.search_tickets(&filter).await?;
-        info!("Found {} tickets for query: {}", tickets.len(), query);
+        let tickets = self.ticket_service.search_tickets(&filter, page).await?;
+        info!("Found {} tickets for query: {}", tickets.items.len(), query);
         Ok(tickets)
     }
 
+    /// Runs `query` against every provider concurrently and merges the
+    /// first page of each, most recently updated first.
+    pub async fn search_all(registry: &ProviderRegistry, query: &str, limit: usize) -> Result<CrossProviderResults> {
+        debug!("Searching {} providers with query: {}", registry.len(), query);
+        let page = PageRequest::first(limit);
+
+        let searches = registry.iter().map(|(provider, application)| {
+            let page = &page;
+            async move { (provider, application.search_tickets(query, page).await) }
+        });
+
+        let mut tickets = Vec::new();
+        let mut failed_providers = Vec::new();
+        for (provider, result) in futures::future::join_all(searches).await {
+            match result {
+                Ok(page) => tickets.extend(page.items.into_iter().map(|ticket| ProviderTicket {
+                    provider: provider.to_string(),
+                    ticket,
+                })),
+                Err(e) => {
+                    warn!("Search failed for provider {}: {}", provider, e);
+                    failed_providers.push(ProviderFailure { provider: provider.to_string(), error: e.to_string() });
+                }
+            }
+        }
+
+        if tickets.is_empty() && failed_providers.len() == registry.len() {
+            return Err(anyhow!("Search failed for every provider: {}", failed_providers.iter()
+                .map(|f| format!("{} ({})", f.provider, f.error))
+                .collect::<Vec<_>>()
+                .join(", ")));
+        }
+
+        tickets.sort_by_key(|t| std::cmp::Reverse(t.ticket.updated_at));
+        tickets.truncate(limit);
+        info!("Found {} tickets across {} providers for query: {}", tickets.len(), registry.len(), query);
+        Ok(CrossProviderResults { tickets, failed_providers })
+    }
+
+    /// One page of the tickets matching a filter.
+    pub async fn filter_tickets_page(&self, filter: &TicketFilter, page: &PageRequest) -> Result<Page<Ticket>> {
+        debug!("Filtering tickets: {:?}", filter);
+        let tickets = self.ticket_service.search_tickets(filter, page).await?;
+        info!("Found {} tickets matching filter", tickets.items.len());
+        Ok(tickets)
+    }
+
+    pub async fn filter_tickets(&self, filter: &TicketFilter) -> Result<Vec<Ticket>> {
+        debug!("Filtering tickets: {:?}", filter);
+        let tickets = self.search_all_tickets(filter).await?;
+        info!("Found {} tickets matching filter", tickets.len());
+        Ok(tickets)
+    }
+
+    /// Runs a saved view: its filter, then its sort and limit.
+    pub async fn run_saved_view(&self, view: &SavedView) -> Result<Vec<Ticket>> {
+        debug!("Running saved view: {}", view.name);
+        let mut tickets = self.search_all_tickets(&view.filter).await?;
+
+        if let Some(sort) = &view.sort {
+            sort_tickets(&mut tickets, sort);
+        }
+        if let Some(limit) = view.limit {
+            tickets.truncate(limit);
+        }
+
+        info!("Saved view {} returned {} tickets", view.name, tickets.len());
+        Ok(tickets)
+    }
+
+    /// Accepts provider ids as well as human identifiers like `METAL-37`,
+    /// `#123`, or a ticket URL.
     pub async fn get_ticket(&self, ticket_id: &str) -> Result<Option<Ticket>> {
         debug!("Getting ticket: {}", ticket_id);
-        let ticket = self.ticket_service.get_ticket(ticket_id).await?;
+        let ticket = self.ticket_service.find_ticket(&TicketRef::parse(ticket_id)).await?;
         
         match &ticket {
             Some(t) => info!("Retrieved ticket: {} - {}", t.identifier, t.title),
@@ -59,10 +233,94 @@ impl Application {
         Ok(ticket)
     }
 
+    /// A ticket by its provider id only, e.g. one a webhook named, whose id
+    /// might otherwise read as an identifier.
+    pub async fn get_ticket_by_id(&self, ticket_id: &str) -> Result<Option<Ticket>> {
+        debug!("Getting ticket by id: {}", ticket_id);
+        Ok(self.ticket_service.find_ticket(&TicketRef::Id(ticket_id.to_string())).await?)
+    }
+
+    /// Fetches several tickets by id or human identifier in as few provider
+    /// round trips as possible. Results follow the order of `ticket_ids`.
+    pub async fn get_tickets(&self, ticket_ids: &[String]) -> Result<Vec<Option<Ticket>>> {
+        debug!("Getting {} tickets", ticket_ids.len());
+        let references: Vec<TicketRef> = ticket_ids.iter().map(|id| TicketRef::parse(id)).collect();
+        let tickets = self.ticket_service.get_tickets(&references).await?;
+
+        info!(
+            "Retrieved {} of {} tickets",
+            tickets.iter().filter(|t| t.is_some()).count(), ticket_ids.len()
+        );
+        Ok(tickets)
+    }
+
+    /// Creates a ticket. A retry with the same `idempotency_key` returns the
+    /// ticket the first call created instead of a duplicate.
+    pub async fn create_ticket(&self, request: &CreateTicketRequest, idempotency_key: Option<&str>) -> Result<Ticket> {
+        debug!("Creating ticket: {}", request.title);
+        let mut request = request.clone();
+        self.default_ids().await?.apply(&mut request);
+        let request = &request;
+        let Some(key) = idempotency_key else {
+            let ticket = self.ticket_service.create_ticket(request).await?;
+            info!("Created ticket: {}", ticket.identifier);
+            return Ok(ticket);
+        };
+
+        let key = format!("{}/{}", self.idempotency_scope, key);
+        let _creating = self.idempotency.lock().await;
+        if let Some(ticket_id) = self.idempotency.lookup(&key, request)? {
+            info!("Idempotency key {} already created {}", key, ticket_id);
+            return self.ticket_service.find_ticket(&TicketRef::Id(ticket_id.clone())).await?
+                .ok_or_else(|| anyhow!("Ticket {} created for idempotency key {} no longer exists", ticket_id, key));
+        }
+
+        let ticket = self.ticket_service.create_ticket(request).await?;
+        self.idempotency.remember(&key, request, &ticket.id)?;
+        info!("Created ticket: {}", ticket.identifier);
+        Ok(ticket)
+    }
+
+    /// Fails with `UnsupportedOperation` on providers that cannot update
+    /// tickets, without calling them.
+    pub async fn update_ticket(&self, request: &UpdateTicketRequest) -> Result<Ticket> {
+        debug!("Updating ticket: {}", request.id);
+        if !self.capabilities().ticket_updates {
+            return Err(UnsupportedOperation::new("Ticket updates").into());
+        }
+        let ticket = self.ticket_service.update_ticket(request).await?;
+        info!("Updated ticket: {}", ticket.identifier);
+        Ok(ticket)
+    }
+
+    /// Subscribes `user_id` (or the current user) to updates on a ticket.
+    /// Returns the id of the user that now watches it.
+    pub async fn watch_ticket(&self, ticket_id: &str, user_id: Option<&str>) -> Result<String> {
+        let user_id = match user_id {
+            Some(id) => id.to_string(),
+            None => self.get_current_user().await?.id,
+        };
+        debug!("Adding watcher {} to ticket {}", user_id, ticket_id);
+        self.ticket_service.watch_ticket(ticket_id, &user_id).await?;
+        info!("User {} is now watching ticket {}", user_id, ticket_id);
+        Ok(user_id)
+    }
+
+    pub async fn unwatch_ticket(&self, ticket_id: &str, user_id: Option<&str>) -> Result<String> {
+        let user_id = match user_id {
+            Some(id) => id.to_string(),
+            None => self.get_current_user().await?.id,
+        };
+        debug!("Removing watcher {} from ticket {}", user_id, ticket_id);
+        self.ticket_service.unwatch_ticket(ticket_id, &user_id).await?;
+        info!("User {} stopped watching ticket {}", user_id, ticket_id);
+        Ok(user_id)
+    }
+
     pub async fn get_my_active_tickets(&self) -> Result<Vec<Ticket>> {
         debug!("Getting active tickets for current user");
         let user = self.get_current_user().await?;
-        let all_tickets = self.get_assigned_tickets(&user.id).await?;
+        let all_tickets = self.get_all_assigned_tickets(&user.id).await?;
         
         let active_tickets: Vec<Ticket> = all_tickets
             .into_iter()
@@ -83,4 +341,1149 @@ impl Application {
         info!("Retrieved workspace: {}", workspace.name);
         Ok(workspace)
     }
-}
\ No newline at end of file
+
+    pub async fn get_release_notes(
+        &self,
+        title: &str,
+        project_id: Option<&str>,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<ReleaseNotes> {
+        if since > until {
+            return Err(anyhow!("Release notes window start {} is after its end {}", since, until));
+        }
+
+        debug!("Collecting completed tickets for release notes (project: {:?}, {} - {})", project_id, since, until);
+
+        let filter = TicketFilter {
+            project_id: project_id.map(|id| id.to_string()),
+            state_type: Some(StateType::Closed),
+            // Completed tickets are often auto-archived before notes are cut
+            include_archived: true,
+            ..Default::default()
+        };
+
+        let tickets = self.search_all_tickets(&filter).await?;
+        let notes = ReleaseNotes::build(title, since, until, &tickets);
+        info!("Built release notes with {} of {} completed tickets", notes.item_count(), tickets.len());
+        Ok(notes)
+    }
+
+    /// First phase of a destructive operation: resolves every ticket and
+    /// returns them with a short-lived token that `confirm_destructive` requires.
+    pub async fn preview_destructive(&self, action: DestructiveAction, ticket_ids: &[String]) -> Result<ConfirmationPreview> {
+        if ticket_ids.is_empty() {
+            return Err(anyhow!("At least one ticket id is required"));
+        }
+
+        let tickets = fan_out(ticket_ids.iter().cloned(), |ticket_id| async move {
+            self.ticket_service.get_ticket(&ticket_id).await?
+                .ok_or_else(|| anyhow!("Ticket not found: {}", ticket_id))
+        }).await.into_iter().collect::<Result<Vec<_>>>()?;
+
+        let (confirmation_token, expires_at) = self.confirmations.issue(action, ticket_ids);
+        info!("Issued confirmation token for {:?} on {} tickets", action, tickets.len());
+
+        Ok(ConfirmationPreview {
+            action,
+            tickets,
+            confirmation_token,
+            expires_at,
+        })
+    }
+
+    /// Second phase: redeems the token and performs the mutation on each ticket,
+    /// reporting per-ticket failures instead of stopping at the first one.
+    pub async fn confirm_destructive(
+        &self,
+        action: DestructiveAction,
+        ticket_ids: &[String],
+        confirmation_token: &str,
+    ) -> Result<BulkActionResult> {
+        self.confirmations.redeem(confirmation_token, action, ticket_ids)?;
+
+        let outcomes = fan_out(ticket_ids.iter().cloned(), |ticket_id| async move {
+            match action {
+                DestructiveAction::ArchiveTickets => self.ticket_service.archive_ticket(&ticket_id).await,
+                DestructiveAction::DeleteTickets => self.ticket_service.delete_ticket(&ticket_id).await,
+            }
+        }).await;
+
+        let mut result = BulkActionResult::default();
+        for (ticket_id, outcome) in ticket_ids.iter().zip(outcomes) {
+            match outcome {
+                Ok(()) => result.completed.push(ticket_id.clone()),
+                Err(e) => result.failed.push(BulkFailure {
+                    ticket_id: ticket_id.clone(),
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        info!("{:?} completed for {} tickets, {} failed", action, result.completed.len(), result.failed.len());
+        Ok(result)
+    }
+
+    pub async fn get_teams(&self) -> Result<Vec<Team>> {
+        debug!("Getting teams");
+        let teams = self.ticket_service.get_teams().await?;
+        info!("Retrieved {} teams", teams.len());
+        Ok(teams)
+    }
+
+    pub async fn get_labels(&self) -> Result<Vec<Label>> {
+        debug!("Getting labels");
+        let labels = self.ticket_service.get_labels().await?;
+        info!("Retrieved {} labels", labels.len());
+        Ok(labels)
+    }
+
+    pub async fn get_projects(&self, page: &PageRequest) -> Result<Page<Project>> {
+        debug!("Getting projects");
+        let projects = self.ticket_service.get_projects(page).await?;
+        info!("Retrieved {} projects", projects.items.len());
+        Ok(projects)
+    }
+
+    pub async fn get_project(&self, project_id: &str) -> Result<Project> {
+        debug!("Getting project: {}", project_id);
+        let project = self.ticket_service.get_project(project_id).await?
+            .ok_or_else(|| anyhow!("Project not found: {}", project_id))?;
+        info!("Retrieved project: {} with {} milestones", project.name, project.milestones.len());
+        Ok(project)
+    }
+
+    pub async fn get_project_updates(&self, project_id: &str, page: &PageRequest) -> Result<Page<ProjectUpdate>> {
+        debug!("Getting status updates for project {}", project_id);
+        let updates = self.ticket_service.get_project_updates(project_id, page).await?;
+        info!("Retrieved {} status updates for project {}", updates.items.len(), project_id);
+        Ok(updates)
+    }
+
+    /// The project with its most recent status update, if it has one.
+    pub async fn get_project_status(&self, project_id: &str) -> Result<(Project, Option<ProjectUpdate>)> {
+        let project = self.get_project(project_id).await?;
+        let latest = self.get_project_updates(project_id, &PageRequest::first(1)).await?
+            .items
+            .into_iter()
+            .next();
+        Ok((project, latest))
+    }
+
+    pub async fn get_documents(&self, project_id: Option<&str>, page: &PageRequest) -> Result<Page<Document>> {
+        debug!("Getting documents (project: {:?})", project_id);
+        let documents = self.ticket_service.get_documents(project_id, page).await?;
+        info!("Retrieved {} documents", documents.items.len());
+        Ok(documents)
+    }
+
+    pub async fn get_document(&self, document_id: &str) -> Result<Document> {
+        debug!("Getting document: {}", document_id);
+        let document = self.ticket_service.get_document(document_id).await?
+            .ok_or_else(|| anyhow!("Document not found: {}", document_id))?;
+        info!("Retrieved document: {}", document.title);
+        Ok(document)
+    }
+
+    pub async fn create_project(&self, request: &CreateProjectRequest) -> Result<Project> {
+        debug!("Creating project: {}", request.name);
+        let project = self.ticket_service.create_project(request).await?;
+        info!("Created project: {} ({})", project.name, project.id);
+        Ok(project)
+    }
+
+    pub async fn update_project(&self, request: &UpdateProjectRequest) -> Result<Project> {
+        debug!("Updating project: {}", request.id);
+        let project = self.ticket_service.update_project(request).await?;
+        info!("Updated project: {} ({})", project.name, project.id);
+        Ok(project)
+    }
+
+    pub async fn create_milestone(&self, request: &CreateMilestoneRequest) -> Result<ProjectMilestone> {
+        debug!("Creating milestone {} in project {}", request.name, request.project_id);
+        let milestone = self.ticket_service.create_milestone(request).await?;
+        info!("Created milestone: {} ({})", milestone.name, milestone.id);
+        Ok(milestone)
+    }
+
+    /// Looks for existing tickets that a proposed ticket would duplicate. The
+    /// provider is searched once per significant keyword and the union is
+    /// ranked locally, since provider search only does substring matching.
+    pub async fn find_duplicate_tickets(
+        &self,
+        title: &str,
+        description: Option<&str>,
+        labels: &[String],
+        team_id: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<DuplicateCandidate>> {
+        let mut search_terms = keywords(title);
+        search_terms.sort_by_key(|word| std::cmp::Reverse(word.len()));
+        search_terms.truncate(4);
+
+        if let Some(description) = description {
+            let mut extra: Vec<String> = keywords(description)
+                .into_iter()
+                .filter(|word| !search_terms.contains(word))
+                .collect();
+            extra.sort_by_key(|word| std::cmp::Reverse(word.len()));
+            search_terms.extend(extra.into_iter().take(2));
+        }
+
+        if search_terms.is_empty() {
+            return Err(anyhow!("Title has no significant words to search for"));
+        }
+
+        debug!("Searching for duplicates of '{}' using terms {:?}", title, search_terms);
+
+        let searches = fan_out(search_terms.iter().cloned(), |term| {
+            let filter = TicketFilter {
+                team_id: team_id.map(|id| id.to_string()),
+                search_query: Some(term),
+                ..Default::default()
+            };
+            async move { self.ticket_service.search_tickets(&filter, &PageRequest::default()).await }
+        }).await;
+
+        let mut seen = std::collections::HashSet::new();
+        let mut tickets = Vec::new();
+        for found in searches {
+            tickets.extend(found?.items.into_iter().filter(|t| seen.insert(t.id.clone())));
+        }
+
+        let candidates = rank_duplicates(title, labels, &tickets, limit);
+        info!("Found {} likely duplicates among {} candidates for '{}'", candidates.len(), tickets.len(), title);
+        Ok(candidates)
+    }
+
+    /// Creates a batch of tickets, parents before their children. A child whose
+    /// parent failed is skipped. With `rollback_on_failure`, the first failure
+    /// stops the batch and every ticket created so far is deleted again.
+    pub async fn batch_create_tickets(&self, specs: &[TicketSpec], rollback_on_failure: bool) -> Result<BatchCreateResult> {
+        let planned = flatten_specs(specs);
+        if planned.is_empty() {
+            return Err(anyhow!("At least one ticket spec is required"));
+        }
+
+        debug!("Creating batch of {} tickets (rollback on failure: {})", planned.len(), rollback_on_failure);
+
+        let defaults = self.default_ids().await?;
+        let mut result = BatchCreateResult::default();
+        let mut created: Vec<Option<Ticket>> = vec![None; planned.len()];
+        let mut stopped = false;
+
+        for (index, item) in planned.iter().enumerate() {
+            let mut request = item.request.clone();
+
+            if let Some(parent) = item.parent {
+                match &created[parent] {
+                    Some(parent_ticket) => request.parent_id = Some(parent_ticket.id.clone()),
+                    None => {
+                        result.skipped.push(request.title);
+                        continue;
+                    }
+                }
+                if request.team_id.is_none() {
+                    request.team_id = planned[parent].request.team_id.clone();
+                }
+            }
+
+            defaults.apply(&mut request);
+
+            if stopped {
+                result.skipped.push(request.title);
+                continue;
+            }
+
+            match self.ticket_service.create_ticket(&request).await {
+                Ok(ticket) => {
+                    debug!("Created {} - {}", ticket.identifier, ticket.title);
+                    created[index] = Some(ticket);
+                }
+                Err(e) => {
+                    result.failed.push(BatchFailure {
+                        title: request.title.clone(),
+                        error: e.to_string(),
+                    });
+                    stopped = rollback_on_failure;
+                }
+            }
+        }
+
+        if rollback_on_failure && !result.failed.is_empty() {
+            // Children were created after their parents, so undo in reverse
+            for ticket in created.iter_mut().rev().filter_map(|t| t.take()) {
+                if let Err(e) = self.ticket_service.delete_ticket(&ticket.id).await {
+                    result.rollback_failures.push(BatchFailure {
+                        title: format!("{} - {}", ticket.identifier, ticket.title),
+                        error: e.to_string(),
+                    });
+                }
+            }
+            result.rolled_back = true;
+            info!("Batch creation failed; rolled back ({} rollback failures)", result.rollback_failures.len());
+            return Ok(result);
+        }
+
+        result.created = build_created_tree(&planned, &created);
+        info!(
+            "Batch created {} tickets, {} failed, {} skipped",
+            created.iter().filter(|t| t.is_some()).count(), result.failed.len(), result.skipped.len()
+        );
+        Ok(result)
+    }
+
+    /// Parses a markdown plan (headings as epics, checklist items as subtasks)
+    /// and creates the resulting hierarchy in `team_id`.
+    pub async fn create_tickets_from_markdown(&self, markdown: &str, team_id: &str, rollback_on_failure: bool) -> Result<BatchCreateResult> {
+        let mut specs = parse_markdown_plan(markdown);
+        if specs.is_empty() {
+            return Err(anyhow!("No headings or checklist items found in the markdown"));
+        }
+
+        debug!("Parsed {} top-level tickets from markdown for team {}", specs.len(), team_id);
+        for spec in &mut specs {
+            spec.request.team_id = Some(team_id.to_string());
+        }
+
+        self.batch_create_tickets(&specs, rollback_on_failure).await
+    }
+
+    pub async fn get_notifications(&self, unread_only: bool, page: &PageRequest) -> Result<Page<Notification>> {
+        debug!("Getting notifications (unread only: {})", unread_only);
+        let notifications = self.ticket_service.get_notifications(unread_only, page).await?;
+        info!("Retrieved {} notifications", notifications.items.len());
+        Ok(notifications)
+    }
+
+    pub async fn get_initiatives(&self) -> Result<Vec<Initiative>> {
+        debug!("Getting initiatives");
+        let initiatives = self.ticket_service.get_initiatives().await?;
+        info!("Retrieved {} initiatives", initiatives.len());
+        Ok(initiatives)
+    }
+
+    /// Lists the projects under an initiative given by id or name
+    /// (case-insensitive).
+    pub async fn get_initiative_projects(&self, initiative: &str) -> Result<(Initiative, Vec<Project>)> {
+        let initiative = self.get_initiatives().await?
+            .into_iter()
+            .find(|i| i.id == initiative || i.name.eq_ignore_ascii_case(initiative))
+            .ok_or_else(|| anyhow!("Initiative not found: {}", initiative))?;
+
+        debug!("Getting projects for initiative {}", initiative.name);
+        let projects = self.ticket_service.get_initiative_projects(&initiative.id).await?;
+        info!("Retrieved {} projects for initiative {}", projects.len(), initiative.name);
+        Ok((initiative, projects))
+    }
+
+    /// Creates a ticket from a template, filling `{{variable}}` placeholders
+    /// and resolving the template's label names to ids.
+    pub async fn create_from_template(
+        &self,
+        template: &TicketTemplate,
+        variables: &HashMap<String, String>,
+        team: &str,
+        assignee_id: Option<&str>,
+    ) -> Result<Ticket> {
+        let now = Utc::now();
+        let title = render_template(&template.title, variables, now)?;
+        let description = template.body
+            .as_ref()
+            .map(|body| render_template(body, variables, now))
+            .transpose()?
+            .map(RichText::Markdown);
+
+        let label_ids = if template.labels.is_empty() {
+            None
+        } else {
+            let labels = self.ticket_service.get_labels().await?;
+            let ids = template.labels
+                .iter()
+                .map(|wanted| labels
+                    .iter()
+                    .find(|l| l.id == *wanted || l.name.eq_ignore_ascii_case(wanted))
+                    .map(|l| l.id.clone())
+                    .ok_or_else(|| anyhow!("Label not found for template {}: {}", template.name, wanted)))
+                .collect::<Result<Vec<_>>>()?;
+            Some(ids)
+        };
+
+        let team = self.find_team(team).await?;
+        let mut request = CreateTicketRequest {
+            title,
+            description,
+            priority: template.priority.clone(),
+            assignee_id: assignee_id.map(|id| id.to_string()),
+            team_id: Some(team.id),
+            label_ids,
+            estimate: template.estimate,
+            ..Default::default()
+        };
+        self.default_ids().await?.apply(&mut request);
+
+        debug!("Creating ticket from template {}: {}", template.name, request.title);
+        let ticket = self.ticket_service.create_ticket(&request).await?;
+        info!("Created {} from template {}", ticket.identifier, template.name);
+        Ok(ticket)
+    }
+
+    /// Resolves a project by id or name (case-insensitive).
+    pub async fn find_project(&self, project: &str) -> Result<Project> {
+        let projects = collect_pages(|page| async move {
+            self.ticket_service.get_projects(&page).await
+        }).await?;
+        projects.into_iter()
+            .find(|p| p.id == project || p.name.eq_ignore_ascii_case(project))
+            .ok_or_else(|| anyhow!("Project not found: {}", project))
+    }
+
+    /// Resolves a team by id or key (case-insensitive).
+    pub async fn find_team(&self, team: &str) -> Result<Team> {
+        let teams = self.ticket_service.get_teams().await?;
+        teams.into_iter()
+            .find(|t| t.id == team || t.key.eq_ignore_ascii_case(team))
+            .ok_or_else(|| anyhow!("Team not found: {}", team))
+    }
+
+    /// Open and in-progress tickets whose SLA is breached, most overdue first.
+    pub async fn get_sla_breaches(&self, team: Option<&str>) -> Result<Vec<Ticket>> {
+        let team_id = match team {
+            Some(team) => Some(self.find_team(team).await?.id),
+            None => None,
+        };
+        debug!("Checking SLA breaches (team: {:?})", team_id);
+
+        let mut tickets = Vec::new();
+        let searches = fan_out([StateType::Open, StateType::InProgress], |state_type| {
+            let filter = TicketFilter {
+                team_id: team_id.clone(),
+                state_type: Some(state_type),
+                ..Default::default()
+            };
+            async move { self.search_all_tickets(&filter).await }
+        }).await;
+        for found in searches {
+            tickets.extend(found?);
+        }
+
+        let now = Utc::now();
+        let mut breached: Vec<Ticket> = tickets
+            .into_iter()
+            .filter(|ticket| ticket.sla.as_ref().is_some_and(|sla| sla.is_breached(now)))
+            .collect();
+        breached.sort_by_key(|ticket| ticket.sla.as_ref().and_then(|sla| sla.next_due()));
+
+        info!("Found {} tickets with breached SLAs", breached.len());
+        Ok(breached)
+    }
+
+    /// The current user's open tickets due within `within` or already
+    /// overdue, soonest first.
+    pub async fn get_due_soon(&self, within: Duration) -> Result<Vec<Ticket>> {
+        let deadline = Utc::now() + within;
+        let mut due: Vec<Ticket> = self.get_my_active_tickets().await?
+            .into_iter()
+            .filter(|ticket| ticket.due_date.is_some_and(|due_date| due_date <= deadline))
+            .collect();
+        due.sort_by_key(|ticket| ticket.due_date);
+
+        info!("Found {} tickets due within {}h", due.len(), within.num_hours());
+        Ok(due)
+    }
+
+    /// Returns a team's states in board order, so callers can pick a valid
+    /// target state instead of guessing names.
+    pub async fn get_workflow(&self, team: &str) -> Result<Workflow> {
+        let team = self.find_team(team).await?;
+        debug!("Getting workflow states for team {}", team.key);
+        let states = self.ticket_service.get_workflow_states(&team.id).await?;
+        info!("Retrieved {} workflow states for team {}", states.len(), team.key);
+        Ok(Workflow::new(team.id, states))
+    }
+
+    /// Identifiers of this provider's tickets mentioned in a branch name or
+    /// commit message; see `ticket_references`. Mentions whose prefix is
+    /// not one of its team keys, like `utf-8`, are dropped.
+    pub async fn ticket_references(&self, text: &str) -> Result<Vec<String>> {
+        let teams = self.ticket_service.get_teams().await?;
+        Ok(ticket_references(text)
+            .into_iter()
+            .filter(|reference| {
+                let key = reference.split_once('-').map_or(reference.as_str(), |(key, _)| key);
+                teams.iter().any(|team| team.key.eq_ignore_ascii_case(key))
+            })
+            .collect())
+    }
+
+    /// The workflow a ticket moves through: its team's, which the prefix of
+    /// its identifier names.
+    pub async fn ticket_workflow(&self, ticket: &Ticket) -> Result<Workflow> {
+        let team = ticket.identifier.split_once('-').map_or(ticket.identifier.as_str(), |(team, _)| team);
+        self.get_workflow(team).await
+    }
+
+    /// Marks a ticket as being worked on, e.g. when a branch is created for
+    /// it: moves it to `state` (a state name, id, or category; "in progress"
+    /// when unset). Without an explicit state only tickets not yet started
+    /// move, so a ticket under review or done stays where it is. Returns the
+    /// ticket and whether it moved.
+    pub async fn start_ticket(&self, ticket_id: &str, state: Option<&str>) -> Result<(Ticket, bool)> {
+        let ticket = self.get_ticket(ticket_id).await?
+            .ok_or_else(|| anyhow!("Ticket not found: {}", ticket_id))?;
+        if state.is_none() && !matches!(ticket.state.type_, StateType::Open) {
+            debug!("{} is already {}, leaving it there", ticket.identifier, ticket.state.name);
+            return Ok((ticket, false));
+        }
+
+        let workflow = self.ticket_workflow(&ticket).await?;
+        let target = state.unwrap_or("in_progress");
+        let target = workflow.resolve(target)
+            .ok_or_else(|| anyhow!("{}'s workflow has no state {}", ticket.identifier, target))?;
+        if target.id == ticket.state.id {
+            return Ok((ticket, false));
+        }
+
+        let updated = self.update_ticket(&UpdateTicketRequest {
+            id: ticket.id,
+            title: None,
+            description: None,
+            priority: None,
+            assignee_id: None,
+            state_id: Some(target.id.clone()),
+            label_ids: None,
+            due_date: None,
+            estimate: None,
+            custom_fields: None,
+        }).await?;
+        info!("Started {}: {}", updated.identifier, updated.state.name);
+        Ok((updated, true))
+    }
+
+    pub async fn get_cycles(&self, team: &str) -> Result<Vec<Cycle>> {
+        let team = self.find_team(team).await?;
+        debug!("Getting cycles for team {}", team.key);
+        let cycles = self.ticket_service.get_cycles(&team.id).await?;
+        info!("Retrieved {} cycles for team {}", cycles.len(), team.key);
+        Ok(cycles)
+    }
+
+    pub async fn get_active_cycle(&self, team: &str) -> Result<Option<Cycle>> {
+        let team = self.find_team(team).await?;
+        debug!("Getting active cycle for team {}", team.key);
+        let cycle = self.ticket_service.get_active_cycle(&team.id).await?;
+
+        match &cycle {
+            Some(c) => info!("Active cycle for team {}: #{}", team.key, c.number),
+            None => info!("Team {} has no active cycle", team.key),
+        }
+
+        Ok(cycle)
+    }
+
+    /// Moves a ticket into `cycle_id`, or into the team's active cycle when no
+    /// cycle is given.
+    pub async fn move_ticket_to_cycle(&self, ticket_id: &str, cycle_id: Option<&str>, team: Option<&str>) -> Result<Ticket> {
+        let cycle_id = match (cycle_id, team) {
+            (Some(cycle_id), _) => cycle_id.to_string(),
+            (None, Some(team)) => self.get_active_cycle(team).await?
+                .ok_or_else(|| anyhow!("Team {} has no active cycle", team))?
+                .id,
+            (None, None) => return Err(anyhow!("Either a cycle id or a team (for its active cycle) is required")),
+        };
+
+        debug!("Moving ticket {} into cycle {}", ticket_id, cycle_id);
+        let ticket = self.ticket_service.assign_ticket_to_cycle(ticket_id, &cycle_id).await?;
+        info!("Moved ticket {} into cycle {}", ticket.identifier, cycle_id);
+        Ok(ticket)
+    }
+
+    pub async fn log_time(&self, request: &LogTimeRequest) -> Result<TimeEntry> {
+        if request.duration_minutes == 0 {
+            return Err(anyhow!("Logged time must be at least one minute"));
+        }
+
+        debug!("Logging {} minutes on ticket {}", request.duration_minutes, request.ticket_id);
+        let entry = self.ticket_service.log_time(request).await?;
+        info!("Logged {} minutes on ticket {}", entry.duration_minutes, entry.ticket_id);
+        Ok(entry)
+    }
+
+    pub async fn get_time_entries(&self, ticket_id: &str) -> Result<Vec<TimeEntry>> {
+        debug!("Getting time entries for ticket {}", ticket_id);
+        let entries = self.ticket_service.get_time_entries(ticket_id).await?;
+        info!("Retrieved {} time entries for ticket {}", entries.len(), ticket_id);
+        Ok(entries)
+    }
+
+    /// Returns what happened on a ticket, oldest first.
+    pub async fn get_ticket_history(&self, ticket_id: &str) -> Result<Vec<TicketEvent>> {
+        debug!("Getting history for ticket {}", ticket_id);
+        let mut events = self.ticket_service.get_ticket_history(ticket_id).await?;
+        events.sort_by_key(|event| event.occurred_at);
+        info!("Retrieved {} history events for ticket {}", events.len(), ticket_id);
+        Ok(events)
+    }
+
+    pub async fn get_comments(&self, ticket_id: &str, page: &PageRequest) -> Result<Page<Comment>> {
+        debug!("Getting comments for ticket {}", ticket_id);
+        let comments = self.ticket_service.get_comments(ticket_id, page).await?;
+        info!("Retrieved {} comments for ticket {}", comments.items.len(), ticket_id);
+        Ok(comments)
+    }
+
+    pub async fn add_comment(&self, request: &CreateCommentRequest) -> Result<Comment> {
+        if request.body.trim().is_empty() {
+            return Err(anyhow!("Comment body must not be empty"));
+        }
+
+        debug!("Adding comment to ticket {}", request.ticket_id);
+        let comment = self.ticket_service.add_comment(request).await?;
+        info!("Added comment {} to ticket {}", comment.id, request.ticket_id);
+        Ok(comment)
+    }
+
+    pub async fn link_tickets(&self, request: &CreateRelationRequest) -> Result<()> {
+        if request.ticket_id == request.related_ticket_id {
+            return Err(anyhow!("A ticket cannot be linked to itself"));
+        }
+
+        debug!("Linking ticket {} to {} ({:?})", request.ticket_id, request.related_ticket_id, request.kind);
+        self.ticket_service.add_relation(request).await?;
+        info!("Linked ticket {} to {}", request.ticket_id, request.related_ticket_id);
+        Ok(())
+    }
+
+    /// Moves a ticket under `parent_id`, or back to the top level with `None`.
+    pub async fn set_ticket_parent(&self, ticket_id: &str, parent_id: Option<&str>) -> Result<Ticket> {
+        if parent_id == Some(ticket_id) {
+            return Err(anyhow!("A ticket cannot be its own parent"));
+        }
+
+        debug!("Setting parent of ticket {} to {:?}", ticket_id, parent_id);
+        let ticket = self.ticket_service.set_parent(ticket_id, parent_id).await?;
+        match &ticket.relations.parent {
+            Some(parent) => info!("Ticket {} is now a sub-ticket of {}", ticket.identifier, parent.identifier),
+            None => info!("Ticket {} is now top-level", ticket.identifier),
+        }
+        Ok(ticket)
+    }
+
+    pub async fn get_attachments(&self, ticket_id: &str) -> Result<Vec<Attachment>> {
+        debug!("Getting attachments for ticket {}", ticket_id);
+        let attachments = self.ticket_service.get_attachments(ticket_id).await?;
+        info!("Retrieved {} attachments for ticket {}", attachments.len(), ticket_id);
+        Ok(attachments)
+    }
+
+    pub async fn add_attachment(&self, request: &CreateAttachmentRequest) -> Result<Attachment> {
+        if !(request.url.starts_with("https://") || request.url.starts_with("http://")) {
+            return Err(anyhow!("Attachment URL must be http(s): {}", request.url));
+        }
+
+        debug!("Attaching {} to ticket {}", request.url, request.ticket_id);
+        let attachment = self.ticket_service.add_attachment(request).await?;
+        info!("Attached {} ({}) to ticket {}", attachment.title, attachment.id, request.ticket_id);
+        Ok(attachment)
+    }
+
+    pub async fn add_reaction(&self, target: &ReactionTarget, emoji: &str) -> Result<Reaction> {
+        let emoji = emoji.trim().trim_matches(':');
+        if emoji.is_empty() {
+            return Err(anyhow!("An emoji is required"));
+        }
+
+        debug!("Adding reaction {} to {:?}", emoji, target);
+        let reaction = self.ticket_service.add_reaction(target, emoji).await?;
+        info!("Added reaction {} ({})", reaction.emoji, reaction.id);
+        Ok(reaction)
+    }
+
+    pub async fn remove_reaction(&self, reaction_id: &str) -> Result<()> {
+        debug!("Removing reaction {}", reaction_id);
+        self.ticket_service.remove_reaction(reaction_id).await?;
+        info!("Removed reaction {}", reaction_id);
+        Ok(())
+    }
+
+    /// Gathers next-cycle candidates, member capacity, carry-over work, and
+    /// milestones due within `horizon_days` for a sprint-planning session.
+    pub async fn get_planning_bundle(&self, team: &str, horizon_days: i64, capacity_per_member: f32) -> Result<PlanningBundle> {
+        if horizon_days <= 0 {
+            return Err(anyhow!("Planning horizon must be at least one day"));
+        }
+
+        let team = self.find_team(team).await?;
+        debug!("Building planning bundle for team {} over {} days", team.key, horizon_days);
+
+        let backlog_filter = TicketFilter {
+            team_id: Some(team.id.clone()),
+            state_type: Some(StateType::Open),
+            ..Default::default()
+        };
+        let carry_over_filter = TicketFilter {
+            state_type: Some(StateType::InProgress),
+            ..backlog_filter.clone()
+        };
+        let (backlog, carry_over, members) = futures::try_join!(
+            self.search_all_tickets(&backlog_filter),
+            self.search_all_tickets(&carry_over_filter),
+            self.ticket_service.get_team_members(&team.id).map_err(anyhow::Error::from),
+        )?;
+
+        // Milestones are context rather than essentials; report failures instead of aborting
+        let mut project_ids: Vec<String> = backlog.iter()
+            .chain(carry_over.iter())
+            .filter_map(|t| t.project_id.clone())
+            .collect();
+        project_ids.sort();
+        project_ids.dedup();
+
+        let fetched = fan_out(project_ids.iter().cloned(), |project_id| async move {
+            self.ticket_service.get_project_milestones(&project_id).await
+        }).await;
+        let mut milestones = Vec::new();
+        let mut warnings = Vec::new();
+        for (project_id, fetched) in project_ids.iter().zip(fetched) {
+            match fetched {
+                Ok(project_milestones) => milestones.extend(project_milestones),
+                Err(e) => warnings.push(format!("Could not load milestones for project {}: {}", project_id, e)),
+            }
+        }
+
+        let now = Utc::now();
+        let mut bundle = PlanningBundle::build(&team, &members, backlog, carry_over, milestones, PlanningParameters {
+            now,
+            horizon_end: now + Duration::days(horizon_days),
+            capacity_per_member,
+        });
+        bundle.warnings = warnings;
+
+        info!(
+            "Planning bundle for team {}: {} candidates, {} carry-over, {} milestones",
+            team.key, bundle.candidates.len(), bundle.carry_over.len(), bundle.milestones.len()
+        );
+        Ok(bundle)
+    }
+
+    /// Exports every team with its members, the labels, the projects, and
+    /// every ticket (archived ones too) fetched in detail with its comments.
+    pub async fn export_workspace(&self, provider: &str) -> Result<WorkspaceSnapshot> {
+        debug!("Exporting the {} workspace", provider);
+        let mut workspace = self.ticket_service.get_workspace().await?;
+        let teams = self.ticket_service.get_teams().await?;
+        let team_ids: Vec<String> = teams.iter().map(|team| team.id.clone()).collect();
+        let members = fan_out(team_ids, |team_id| async move {
+            self.ticket_service.get_team_members(&team_id).await
+        }).await;
+        workspace.teams = teams.into_iter()
+            .zip(members)
+            .map(|(team, members)| Ok(Team { members: members?, ..team }))
+            .collect::<Result<_>>()?;
+
+        let labels = self.ticket_service.get_labels().await?;
+        let projects = collect_pages(|page| async move {
+            self.ticket_service.get_projects(&page).await
+        }).await?;
+
+        let with_comments = self.capabilities().comments;
+        let mut tickets = Vec::new();
+        for team in &workspace.teams {
+            let filter = TicketFilter {
+                team_id: Some(team.id.clone()),
+                include_archived: true,
+                ..Default::default()
+            };
+            let listed = self.search_all_tickets(&filter).await?;
+            // Lists may leave out relations, so each ticket is fetched again
+            let fetched = fan_out(listed, |listed| async move {
+                let ticket = self.ticket_service.find_ticket(&TicketRef::Id(listed.id.clone())).await?
+                    .unwrap_or(listed);
+                let comments = if with_comments {
+                    let ticket_id = &ticket.id;
+                    collect_pages(|page| async move {
+                        self.ticket_service.get_comments(ticket_id, &page).await
+                    }).await?
+                } else {
+                    Vec::new()
+                };
+                Ok::<_, anyhow::Error>(TicketSnapshot { team: team.key.clone(), ticket, comments })
+            }).await;
+            for ticket in fetched {
+                tickets.push(ticket?);
+            }
+        }
+
+        info!(
+            "Exported {} teams, {} labels, {} projects, and {} tickets from {}",
+            workspace.teams.len(), labels.len(), projects.len(), tickets.len(), provider
+        );
+        Ok(WorkspaceSnapshot {
+            version: SNAPSHOT_VERSION,
+            provider: provider.to_string(),
+            exported_at: Utc::now(),
+            workspace,
+            labels,
+            projects,
+            tickets,
+        })
+    }
+
+    /// What the target of an import already has: teams with their members
+    /// and workflows, labels, and projects.
+    async fn import_targets(&self) -> Result<ImportTargets> {
+        let teams = self.ticket_service.get_teams().await?;
+        let team_ids: Vec<String> = teams.iter().map(|team| team.id.clone()).collect();
+        let (members, states) = futures::join!(
+            fan_out(team_ids.clone(), |team_id| async move {
+                self.ticket_service.get_team_members(&team_id).await
+            }),
+            fan_out(team_ids, |team_id| async move {
+                self.ticket_service.get_workflow_states(&team_id).await
+            }),
+        );
+        let mut users = HashMap::new();
+        let mut workflows = HashMap::new();
+        for ((team, members), states) in teams.iter().zip(members).zip(states) {
+            users.extend(members?.into_iter().map(|user| (user.email.to_lowercase(), user.id)));
+            workflows.insert(team.id.clone(), Workflow::new(team.id.clone(), states?));
+        }
+        let labels = self.ticket_service.get_labels().await?;
+        let projects = collect_pages(|page| async move {
+            self.ticket_service.get_projects(&page).await
+        }).await?;
+        Ok(ImportTargets { teams, users, labels, projects, workflows })
+    }
+
+    /// What `import_workspace` would create and reuse here, and what it
+    /// could not match, without changing anything.
+    pub async fn plan_import(&self, snapshot: &WorkspaceSnapshot, mapping: &FieldMapping) -> Result<MigrationPlan> {
+        let targets = self.import_targets().await?;
+        let plan = MigrationPlan::build(snapshot, &Resolver::new(snapshot, mapping, &targets));
+        info!("Planned importing {} tickets from a snapshot of {}", plan.tickets.len(), snapshot.provider);
+        Ok(plan)
+    }
+
+    /// Re-creates a snapshot here, translating teams, states, labels, and
+    /// people per `mapping`. Labels and projects that exist by name are
+    /// reused; tickets are always created. Comments are posted headed with
+    /// their original author and date. What fails is reported and the rest
+    /// carries on; `progress` hears of each ticket created and linked.
+    pub async fn import_workspace(
+        &self,
+        snapshot: &WorkspaceSnapshot,
+        mapping: &FieldMapping,
+        progress: ImportProgress<'_>,
+    ) -> Result<ImportReport> {
+        debug!("Importing a snapshot of {} with {} tickets", snapshot.provider, snapshot.tickets.len());
+        let capabilities = self.capabilities();
+        let targets = self.import_targets().await?;
+        let resolver = Resolver::new(snapshot, mapping, &targets);
+        let mut report = ImportReport::default();
+        let total = snapshot.tickets.len() * 2;
+
+        let mut label_ids: HashMap<String, String> = targets.labels
+            .iter()
+            .map(|label| (label.name.to_lowercase(), label.id.clone()))
+            .collect();
+
+        // Refused before anything is created, rather than leaving a half
+        // imported workspace behind: tickets are moved to their states after
+        // they are created, and missing labels are created first
+        if !capabilities.ticket_updates {
+            return Err(anyhow::Error::new(UnsupportedOperation::new("Ticket updates"))
+                .context("Cannot import here: imported tickets could not be moved to their states"));
+        }
+        let missing_labels = snapshot.labels
+            .iter()
+            .filter(|label| !label_ids.contains_key(&resolver.label_name(&label.name).to_lowercase()))
+            .count();
+        if missing_labels > 0 && !capabilities.label_creation {
+            return Err(anyhow::Error::new(UnsupportedOperation::new("Label creation")).context(format!(
+                "Cannot import here: {} labels do not exist; create them or map them to existing ones",
+                missing_labels
+            )));
+        }
+        for label in &snapshot.labels {
+            let name = resolver.label_name(&label.name);
+            if label_ids.contains_key(&name.to_lowercase()) {
+                continue;
+            }
+            let request = CreateLabelRequest {
+                name: name.to_string(),
+                color: label.color.clone(),
+                description: label.description.clone(),
+            };
+            match self.ticket_service.create_label(&request).await {
+                Ok(created) => {
+                    report.labels_created += 1;
+                    label_ids.insert(name.to_lowercase(), created.id);
+                }
+                Err(e) => report.failures.push(format!("Label {}: {}", name, e)),
+            }
+        }
+
+        let mut project_ids = HashMap::new();
+        for project in &snapshot.projects {
+            if let Some(existing) = resolver.existing_project(project) {
+                project_ids.insert(project.id.clone(), existing.id.clone());
+                continue;
+            }
+            let mut team_ids: Vec<String> = snapshot.tickets
+                .iter()
+                .filter(|entry| entry.ticket.project_id.as_deref() == Some(project.id.as_str()))
+                .filter_map(|entry| resolver.team(&entry.team))
+                .map(|team| team.id.clone())
+                .collect();
+            team_ids.sort();
+            team_ids.dedup();
+            if team_ids.is_empty() {
+                team_ids.extend(targets.teams.first().map(|team| team.id.clone()));
+            }
+            let request = CreateProjectRequest {
+                name: project.name.clone(),
+                description: project.description.clone(),
+                team_ids,
+                lead_id: project.lead_id.as_deref().and_then(|id| resolver.user(id)),
+                target_date: project.target_date,
+            };
+            match self.import_project(project, &request).await {
+                Ok(created) => {
+                    report.projects_created += 1;
+                    project_ids.insert(project.id.clone(), created.id);
+                }
+                Err(e) => report.failures.push(format!("Project {}: {}", project.name, e)),
+            }
+        }
+
+        let mut ticket_ids = HashMap::new();
+        for (done, entry) in snapshot.tickets.iter().enumerate() {
+            let source = &entry.ticket;
+            progress(done, total, &format!("Creating {}", source.identifier));
+            let Some(team) = resolver.team(&entry.team) else {
+                report.failures.push(format!(
+                    "{}: no team matches {}; map it or set a fallback team",
+                    source.identifier, entry.team
+                ));
+                continue;
+            };
+            let labels: Vec<String> = source.labels
+                .iter()
+                .filter_map(|name| label_ids.get(&resolver.label_name(name).to_lowercase()).cloned())
+                .collect();
+            let request = CreateTicketRequest {
+                title: source.title.clone(),
+                description: source.description.clone(),
+                priority: Some(source.priority.clone()),
+                assignee_id: source.assignee_id.as_deref().and_then(|id| resolver.user(id)),
+                team_id: Some(team.id.clone()),
+                project_id: source.project_id.as_ref().and_then(|id| project_ids.get(id)).cloned(),
+                label_ids: Some(labels).filter(|labels| !labels.is_empty()),
+                due_date: source.due_date,
+                estimate: source.estimate,
+                ..Default::default()
+            };
+            let created = match self.ticket_service.create_ticket(&request).await {
+                Ok(created) => created,
+                Err(e) => {
+                    report.failures.push(format!("{}: {}", source.identifier, e));
+                    continue;
+                }
+            };
+            report.tickets_created += 1;
+            report.tickets.insert(source.identifier.clone(), created.identifier.clone());
+            ticket_ids.insert(source.id.clone(), created.id.clone());
+
+            let state = resolver.state(&team.id, &source.state);
+            if let Err(e) = self.import_state(source, &created, state).await {
+                report.failures.push(format!("{}: {}", source.identifier, e));
+            }
+        }
+
+        let mut linked = HashSet::new();
+        for (done, entry) in snapshot.tickets.iter().enumerate() {
+            let Some(ticket_id) = ticket_ids.get(&entry.ticket.id) else {
+                continue;
+            };
+            progress(snapshot.tickets.len() + done, total, &format!("Linking {}", entry.ticket.identifier));
+            let relations = &entry.ticket.relations;
+
+            if let Some(parent) = relations.parent.as_ref().filter(|_| capabilities.sub_tickets) {
+                if let Some(parent_id) = ticket_ids.get(&parent.id) {
+                    match self.ticket_service.set_parent(ticket_id, Some(parent_id)).await {
+                        Ok(_) => report.links_created += 1,
+                        Err(e) => report.failures.push(format!("{}: could not set parent {}: {}", entry.ticket.identifier, parent.identifier, e)),
+                    }
+                }
+            }
+
+            if capabilities.relations {
+                let outgoing = relations.blocks.iter().map(|related| (related, RelationKind::Blocks))
+                    .chain(relations.duplicates.iter().map(|related| (related, RelationKind::Duplicates)))
+                    .chain(relations.relates_to.iter().map(|related| (related, RelationKind::RelatesTo)));
+                for (related, kind) in outgoing {
+                    let Some(related_id) = ticket_ids.get(&related.id) else {
+                        continue;
+                    };
+                    // "Relates to" shows up on both tickets but is one link
+                    let pair = match kind {
+                        RelationKind::RelatesTo => (ticket_id.min(related_id), ticket_id.max(related_id), kind),
+                        _ => (ticket_id, related_id, kind),
+                    };
+                    if !linked.insert(pair) {
+                        continue;
+                    }
+                    let request = CreateRelationRequest {
+                        ticket_id: ticket_id.clone(),
+                        related_ticket_id: related_id.clone(),
+                        kind,
+                    };
+                    match self.ticket_service.add_relation(&request).await {
+                        Ok(()) => report.links_created += 1,
+                        Err(e) => report.failures.push(format!("{} -> {}: {}", entry.ticket.identifier, related.identifier, e)),
+                    }
+                }
+            }
+
+            if capabilities.comments {
+                let mut comments: Vec<&Comment> = entry.comments.iter().collect();
+                comments.sort_by_key(|comment| comment.created_at);
+                let mut comment_ids = HashMap::new();
+                for comment in comments {
+                    let author = comment.author_id.as_deref()
+                        .and_then(|id| resolver.source_user(id))
+                        .map(|user| user.display_name.as_str());
+                    let request = CreateCommentRequest {
+                        ticket_id: ticket_id.clone(),
+                        body: attributed_comment(comment, author),
+                        parent_comment_id: comment.parent_comment_id.as_ref().and_then(|id| comment_ids.get(id)).cloned(),
+                    };
+                    match self.ticket_service.add_comment(&request).await {
+                        Ok(created) => {
+                            report.comments_created += 1;
+                            comment_ids.insert(comment.id.clone(), created.id);
+                        }
+                        Err(e) => report.failures.push(format!("{}: comment {}: {}", entry.ticket.identifier, comment.id, e)),
+                    }
+                }
+            }
+        }
+        progress(total, total, "Done");
+
+        info!(
+            "Imported {} tickets from a snapshot of {} ({} failures)",
+            report.tickets_created, snapshot.provider, report.failures.len()
+        );
+        Ok(report)
+    }
+
+    /// Reads a CSV of tickets and resolves each row against this provider,
+    /// without creating anything. `team` applies to rows without a team.
+    pub async fn plan_csv_import(&self, csv: &str, columns: &BTreeMap<String, CsvField>, team: Option<&str>) -> Result<CsvImportPlan> {
+        let tickets = parse_ticket_csv(csv, columns)?;
+        let targets = self.import_targets().await?;
+        let plan = CsvImportPlan::build(tickets, &targets, team, Utc::now());
+        info!("Planned importing {} CSV rows ({} invalid)", plan.rows.len(), plan.invalid.len());
+        Ok(plan)
+    }
+
+    /// Creates a ticket for each row of a plan, `concurrency` at a time.
+    /// A row that fails is reported and the rest carry on; `progress`
+    /// hears of each ticket as it is done.
+    pub async fn import_csv(&self, plan: &CsvImportPlan, concurrency: usize, progress: ImportProgress<'_>) -> CsvImportReport {
+        let total = plan.rows.len();
+        let creations: Vec<_> = plan.rows
+            .iter()
+            .map(|row| async move { (row, self.create_ticket(&row.request, None).await) })
+            .collect();
+        let mut created = futures::stream::iter(creations).buffer_unordered(concurrency.max(1));
+        let mut results = Vec::with_capacity(total);
+        while let Some((row, result)) = created.next().await {
+            results.push((row, result));
+            progress(results.len(), total, &format!("Line {}: {}", row.line, row.request.title));
+        }
+        results.sort_by_key(|(row, _)| row.line);
+
+        let mut report = CsvImportReport::default();
+        for (row, result) in results {
+            match result {
+                Ok(ticket) => report.created.push(CsvCreated {
+                    line: row.line,
+                    identifier: ticket.identifier,
+                    title: ticket.title,
+                    url: ticket.url,
+                }),
+                Err(e) => report.failed.push(CsvFailure {
+                    line: row.line,
+                    title: row.request.title.clone(),
+                    error: e.to_string(),
+                }),
+            }
+        }
+        info!("Imported {} CSV rows ({} failures)", report.created.len(), report.failed.len());
+        report
+    }
+
+    /// Creates a project and moves it to the state it had in the snapshot.
+    async fn import_project(&self, project: &Project, request: &CreateProjectRequest) -> Result<Project> {
+        let created = self.ticket_service.create_project(request).await?;
+        if matches!(project.state, ProjectState::Planned) {
+            return Ok(created);
+        }
+        let request = UpdateProjectRequest {
+            id: created.id.clone(),
+            name: None,
+            description: None,
+            state: Some(project.state.clone()),
+            lead_id: None,
+            target_date: None,
+        };
+        Ok(self.ticket_service.update_project(&request).await?)
+    }
+
+    /// Moves an imported ticket to the state matching its source, then
+    /// archives it if the source was archived.
+    async fn import_state(&self, source: &Ticket, created: &Ticket, state: Option<&WorkflowState>) -> Result<()> {
+        let state = state.ok_or_else(|| anyhow!("No state matches {}", source.state.name))?;
+        if state.id != created.state.id {
+            let request = UpdateTicketRequest {
+                id: created.id.clone(),
+                title: None,
+                description: None,
+                priority: None,
+                assignee_id: None,
+                state_id: Some(state.id.clone()),
+                label_ids: None,
+                due_date: None,
+                estimate: None,
+                custom_fields: None,
+            };
+            self.ticket_service.update_ticket(&request).await?;
+        }
+        if source.archived_at.is_some() {
+            self.ticket_service.archive_ticket(&created.id).await?;
+        }
+        Ok(())
+    }
+}
+
+/// `TicketDefaults` resolved to ids, filling fields a request leaves unset.
+struct DefaultIds {
+    team_id: Option<String>,
+    project_id: Option<String>,
+    assignee_id: Option<String>,
+}
+
+impl DefaultIds {
+    fn apply(&self, request: &mut CreateTicketRequest) {
+        if request.team_id.is_none() {
+            request.team_id = self.team_id.clone();
+        }
+        if request.project_id.is_none() {
+            request.project_id = self.project_id.clone();
+        }
+        if request.assignee_id.is_none() {
+            request.assignee_id = self.assignee_id.clone();
+        }
+    }
+}
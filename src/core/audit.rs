@@ -0,0 +1,130 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::warn;
+
+/// Argument key endings whose values never reach the audit log
+/// (`api_token`, `client_secret`, ...).
+const SECRET_KEYS: &[&str] = &["token", "secret", "password", "api_key", "apikey", "authorization", "credential"];
+
+const REDACTED: &str = "[redacted]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditStatus {
+    Ok,
+    Error,
+}
+
+/// One tool invocation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: DateTime<Utc>,
+    pub session_id: String,
+    /// The authenticated client the call was made for, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    pub tool: String,
+    pub arguments: Value,
+    pub status: AuditStatus,
+    pub error: Option<String>,
+    pub latency_ms: u64,
+}
+
+/// Append-only record of tool calls, one JSON entry per line. Entries from
+/// earlier sessions stay in the file and are returned by `read`.
+pub struct AuditLog {
+    path: PathBuf,
+    session_id: String,
+    /// Serializes appends so concurrent calls cannot interleave lines.
+    file: Mutex<()>,
+}
+
+impl AuditLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            session_id: uuid::Uuid::new_v4().to_string(),
+            file: Mutex::new(()),
+        }
+    }
+
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// Appends an entry for a finished call. Failing to write is logged
+    /// rather than failing the call it describes.
+    pub fn record(&self, user: Option<&str>, tool: &str, arguments: &Value, result: &Result<Value>, latency: Duration) {
+        let entry = AuditEntry {
+            timestamp: Utc::now(),
+            session_id: self.session_id.clone(),
+            user: user.map(str::to_string),
+            tool: tool.to_string(),
+            arguments: redact_secrets(arguments),
+            status: if result.is_ok() { AuditStatus::Ok } else { AuditStatus::Error },
+            error: result.as_ref().err().map(|e| e.to_string()),
+            latency_ms: latency.as_millis() as u64,
+        };
+        if let Err(e) = self.append(&entry) {
+            warn!("Failed to write audit entry for {}: {}", tool, e);
+        }
+    }
+
+    /// The newest `limit` entries, newest first, optionally only one tool's
+    /// or one session's.
+    pub fn read(&self, limit: usize, tool: Option<&str>, session_id: Option<&str>) -> Result<Vec<AuditEntry>> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read audit log {}", self.path.display())),
+        };
+
+        Ok(contents
+            .lines()
+            .rev()
+            .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok())
+            .filter(|entry| tool.is_none_or(|tool| entry.tool == tool))
+            .filter(|entry| session_id.is_none_or(|session_id| entry.session_id == session_id))
+            .take(limit)
+            .collect())
+    }
+
+    fn append(&self, entry: &AuditEntry) -> Result<()> {
+        let line = serde_json::to_string(entry)?;
+        let _guard = self.file.lock().map_err(|_| anyhow::anyhow!("Audit log lock poisoned"))?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open audit log {}", self.path.display()))?;
+        writeln!(file, "{}", line)?;
+        Ok(())
+    }
+}
+
+/// A copy of `value` with the values of secret-looking keys replaced, at
+/// any depth.
+pub fn redact_secrets(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(map
+            .iter()
+            .map(|(key, value)| {
+                let lower = key.to_lowercase();
+                if SECRET_KEYS.iter().any(|secret| lower.ends_with(secret)) {
+                    (key.clone(), Value::String(REDACTED.to_string()))
+                } else {
+                    (key.clone(), redact_secrets(value))
+                }
+            })
+            .collect()),
+        Value::Array(items) => Value::Array(items.iter().map(redact_secrets).collect()),
+        other => other.clone(),
+    }
+}
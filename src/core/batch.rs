@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{CreateTicketRequest, Ticket};
+
+/// One ticket to create, with optional sub-tickets created underneath it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketSpec {
+    pub request: CreateTicketRequest,
+    pub children: Vec<TicketSpec>,
+}
+
+/// A created ticket and the sub-tickets created under it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedTicket {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    pub children: Vec<CreatedTicket>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchFailure {
+    pub title: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchCreateResult {
+    pub created: Vec<CreatedTicket>,
+    pub failed: Vec<BatchFailure>,
+    /// Titles not attempted because their parent failed or the batch stopped.
+    pub skipped: Vec<String>,
+    pub rolled_back: bool,
+    /// Tickets that could not be removed during rollback and need manual cleanup.
+    pub rollback_failures: Vec<BatchFailure>,
+}
+
+/// A spec flattened in creation order (parents before children).
+#[derive(Debug, Clone)]
+pub struct PlannedTicket {
+    pub parent: Option<usize>,
+    pub request: CreateTicketRequest,
+}
+
+/// Flattens nested specs pre-order so every parent is created before its
+/// children and can hand them its id.
+pub fn flatten_specs(specs: &[TicketSpec]) -> Vec<PlannedTicket> {
+    fn visit(spec: &TicketSpec, parent: Option<usize>, out: &mut Vec<PlannedTicket>) {
+        let index = out.len();
+        out.push(PlannedTicket {
+            parent,
+            request: spec.request.clone(),
+        });
+        for child in &spec.children {
+            visit(child, Some(index), out);
+        }
+    }
+
+    let mut planned = Vec::new();
+    for spec in specs {
+        visit(spec, None, &mut planned);
+    }
+    planned
+}
+
+/// Rebuilds the created tree from flattened results; entries that were not
+/// created (and therefore their descendants) are left out.
+pub fn build_created_tree(planned: &[PlannedTicket], created: &[Option<Ticket>]) -> Vec<CreatedTicket> {
+    fn node(index: usize, planned: &[PlannedTicket], created: &[Option<Ticket>]) -> Option<CreatedTicket> {
+        let ticket = created.get(index)?.as_ref()?;
+        let children = (index + 1..planned.len())
+            .filter(|&child| planned[child].parent == Some(index))
+            .filter_map(|child| node(child, planned, created))
+            .collect();
+
+        Some(CreatedTicket {
+            id: ticket.id.clone(),
+            identifier: ticket.identifier.clone(),
+            title: ticket.title.clone(),
+            url: ticket.url.clone(),
+            children,
+        })
+    }
+
+    (0..planned.len())
+        .filter(|&index| planned[index].parent.is_none())
+        .filter_map(|index| node(index, planned, created))
+        .collect()
+}
@@ -0,0 +1,597 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+use tracing::warn;
+
+use crate::domain::{
+    Ticket, TicketFilter, CreateTicketRequest, UpdateTicketRequest,
+    Label, CreateLabelRequest, Project, ProjectMilestone, Workspace,
+    CreateProjectRequest, UpdateProjectRequest, CreateMilestoneRequest, Cycle,
+    TimeEntry, LogTimeRequest, TicketRef, Comment, CreateCommentRequest, TicketEvent,
+    WorkflowState, Reaction, ReactionTarget, Initiative, Notification,
+    CreateRelationRequest, Attachment, CreateAttachmentRequest, ProjectUpdate, Document,
+    WebhookEvent, WebhookEntity,
+};
+use crate::domain::{Team, User};
+use crate::ports::{Capabilities, CacheStore, StoredEntry, TicketService, TicketServiceMiddleware, TicketResult, Page, PageRequest};
+use crate::core::{DEFAULT_MAX_STALENESS, EventListener, record_stale_read};
+
+/// How long users, teams, labels, and projects are reused.
+pub const DEFAULT_REFERENCE_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// How long a fetched ticket is reused; short, since tickets change often.
+pub const DEFAULT_TICKET_CACHE_TTL: Duration = Duration::from_secs(10);
+
+/// Values keyed by ID, team, or `""` for workspace-wide data, that expire
+/// `ttl` after they were fetched. A zero TTL disables caching.
+pub(crate) struct TtlCache<V> {
+    ttl: Duration,
+    /// Values with the instants they expire and were fetched. Expired ones
+    /// stay until replaced, for `get_stale`.
+    entries: Mutex<HashMap<String, (Instant, Instant, V)>>,
+    persistence: Option<Persistence<V>>,
+}
+
+/// Where a cache writes its changes through to, set by `persist`.
+struct Persistence<V> {
+    store: Arc<dyn CacheStore>,
+    namespace: String,
+    encode: fn(&V) -> Option<String>,
+}
+
+impl<V: Clone> TtlCache<V> {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+            persistence: None,
+        }
+    }
+
+    /// The cached value for `key`, or the result of `fetch`, which is cached
+    /// on success. Concurrent misses may both fetch; the last one wins.
+    pub(crate) async fn get_or_fetch<E, F, Fut>(&self, key: &str, fetch: F) -> Result<V, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, E>>,
+    {
+        if let Some(value) = self.get(key) {
+            return Ok(value);
+        }
+
+        let value = fetch().await?;
+        if !self.ttl.is_zero() {
+            self.put_for(key, value.clone(), self.ttl);
+        }
+        Ok(value)
+    }
+
+    /// Stores a value fetched ahead of time, valid for `ttl` regardless of
+    /// the cache's own TTL.
+    pub(crate) fn put_for(&self, key: &str, value: V, ttl: Duration) {
+        if let Some(persistence) = &self.persistence {
+            if let Some(encoded) = (persistence.encode)(&value) {
+                let fetched_at = SystemTime::now();
+                let entry = StoredEntry { key: key.to_string(), value: encoded, fetched_at, expires_at: fetched_at + ttl };
+                if let Err(e) = persistence.store.put(&persistence.namespace, &entry) {
+                    warn!("Could not persist cached {} entry: {}", persistence.namespace, e);
+                }
+            }
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            let now = Instant::now();
+            entries.insert(key.to_string(), (now + ttl, now, value));
+        }
+    }
+
+    /// Only the Linear provider drops entries on writes.
+    #[cfg(feature = "linear")]
+    pub(crate) fn invalidate(&self, key: &str) {
+        if let Some(persistence) = &self.persistence {
+            if let Err(e) = persistence.store.remove(&persistence.namespace, key) {
+                warn!("Could not remove persisted {} entry: {}", persistence.namespace, e);
+            }
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(key);
+        }
+    }
+
+    pub(crate) fn clear(&self) {
+        if let Some(persistence) = &self.persistence {
+            if let Err(e) = persistence.store.clear(&persistence.namespace) {
+                warn!("Could not clear persisted {} entries: {}", persistence.namespace, e);
+            }
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+
+    /// An expired value that expired less than `max_staleness` ago, with
+    /// how long ago it was fetched.
+    pub(crate) fn get_stale(&self, key: &str, max_staleness: Duration) -> Option<(V, Duration)> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .get(key)
+            .filter(|(expires_at, _, _)| expires_at.elapsed() < max_staleness)
+            .map(|(_, fetched_at, value)| (value.clone(), fetched_at.elapsed()))
+    }
+
+    fn get(&self, key: &str) -> Option<V> {
+        let entries = self.entries.lock().ok()?;
+        entries
+            .get(key)
+            .filter(|(expires_at, _, _)| *expires_at > Instant::now())
+            .map(|(_, _, value)| value.clone())
+    }
+}
+
+impl<V: Clone + Serialize + DeserializeOwned> TtlCache<V> {
+    /// Loads the entries `store` keeps under `namespace` and writes every
+    /// later change through to it. Entries that expired more than
+    /// `max_staleness` ago, or no longer parse, are dropped from the store.
+    pub(crate) fn persist(&mut self, store: Arc<dyn CacheStore>, namespace: String, max_staleness: Duration) {
+        match store.load(&namespace) {
+            Ok(stored) => {
+                let now = SystemTime::now();
+                if let Ok(mut entries) = self.entries.lock() {
+                    for entry in stored {
+                        let live = entry.expires_at + max_staleness > now;
+                        let restored = serde_json::from_str::<V>(&entry.value).ok()
+                            .filter(|_| live)
+                            .and_then(|value| Some((instant(entry.expires_at)?, instant(entry.fetched_at)?, value)));
+                        match restored {
+                            Some(restored) => {
+                                entries.insert(entry.key, restored);
+                            }
+                            None => {
+                                let _ = store.remove(&namespace, &entry.key);
+                            }
+                        }
+                    }
+                }
+            }
+            Err(e) => warn!("Could not load persisted {} entries: {}", namespace, e),
+        }
+        self.persistence = Some(Persistence {
+            store,
+            namespace,
+            encode: |value| serde_json::to_string(value).ok(),
+        });
+    }
+}
+
+/// The `Instant` corresponding to a wall-clock time, if representable.
+fn instant(at: SystemTime) -> Option<Instant> {
+    let (now, wall_now) = (Instant::now(), SystemTime::now());
+    match at.duration_since(wall_now) {
+        Ok(ahead) => now.checked_add(ahead),
+        Err(behind) => now.checked_sub(behind.duration()),
+    }
+}
+
+/// What `CachingTicketService::load_snapshot` fetches in one pass.
+pub struct PrefetchSnapshot {
+    pub user: User,
+    pub assigned: Vec<Ticket>,
+    pub teams: Vec<Team>,
+    pub labels: Vec<Label>,
+    pub workspace: Workspace,
+}
+
+/// Caches reads of a `TicketService` so chatty sessions do not refetch the
+/// same users, teams, and tickets. Writes through this service drop the
+/// entries they could have changed, even when they fail, since a failed
+/// request may still have been applied; changes made elsewhere show up once
+/// the TTL passes. While the provider is unavailable or rate limiting,
+/// reads fall back to expired entries up to `max_staleness` past their TTL,
+/// reported through `record_stale_read`.
+pub struct CachingTicketService {
+    inner: Arc<dyn TicketService + Send + Sync>,
+    max_staleness: Duration,
+    current_user: TtlCache<User>,
+    users: TtlCache<Option<User>>,
+    teams: TtlCache<Vec<Team>>,
+    team_members: TtlCache<Vec<User>>,
+    workflow_states: TtlCache<Vec<WorkflowState>>,
+    labels: TtlCache<Vec<Label>>,
+    projects: TtlCache<Page<Project>>,
+    project: TtlCache<Option<Project>>,
+    milestones: TtlCache<Vec<ProjectMilestone>>,
+    workspace: TtlCache<Workspace>,
+    tickets: TtlCache<Option<Ticket>>,
+    /// Every ticket assigned to a user, by user id.
+    assigned: TtlCache<Vec<Ticket>>,
+}
+
+impl CachingTicketService {
+    pub fn new(inner: Arc<dyn TicketService + Send + Sync>) -> Self {
+        Self::with_ttls(inner, DEFAULT_REFERENCE_CACHE_TTL, DEFAULT_TICKET_CACHE_TTL)
+    }
+
+    /// `reference_ttl` covers users, teams, labels, and projects; a zero TTL
+    /// disables that part of the cache.
+    pub fn with_ttls(
+        inner: Arc<dyn TicketService + Send + Sync>,
+        reference_ttl: Duration,
+        ticket_ttl: Duration,
+    ) -> Self {
+        Self {
+            inner,
+            max_staleness: DEFAULT_MAX_STALENESS,
+            current_user: TtlCache::new(reference_ttl),
+            users: TtlCache::new(reference_ttl),
+            teams: TtlCache::new(reference_ttl),
+            team_members: TtlCache::new(reference_ttl),
+            workflow_states: TtlCache::new(reference_ttl),
+            labels: TtlCache::new(reference_ttl),
+            projects: TtlCache::new(reference_ttl),
+            project: TtlCache::new(reference_ttl),
+            milestones: TtlCache::new(reference_ttl),
+            workspace: TtlCache::new(reference_ttl),
+            tickets: TtlCache::new(ticket_ttl),
+            assigned: TtlCache::new(ticket_ttl),
+        }
+    }
+
+    /// How long past their TTL entries may answer reads during an outage;
+    /// zero never serves expired entries.
+    pub fn with_max_staleness(mut self, max_staleness: Duration) -> Self {
+        self.max_staleness = max_staleness;
+        self
+    }
+
+    /// Keeps the cache in `store` across restarts, under namespaces starting
+    /// with `scope` (e.g. the provider name), and starts from what is there.
+    /// Set the maximum staleness first: older entries are not loaded.
+    pub fn with_store(mut self, store: Arc<dyn CacheStore>, scope: &str) -> Self {
+        let max_staleness = self.max_staleness;
+        let namespace = |cache: &str| format!("{}/{}", scope, cache);
+        self.current_user.persist(store.clone(), namespace("current_user"), max_staleness);
+        self.users.persist(store.clone(), namespace("users"), max_staleness);
+        self.teams.persist(store.clone(), namespace("teams"), max_staleness);
+        self.team_members.persist(store.clone(), namespace("team_members"), max_staleness);
+        self.workflow_states.persist(store.clone(), namespace("workflow_states"), max_staleness);
+        self.labels.persist(store.clone(), namespace("labels"), max_staleness);
+        self.projects.persist(store.clone(), namespace("projects"), max_staleness);
+        self.project.persist(store.clone(), namespace("project"), max_staleness);
+        self.milestones.persist(store.clone(), namespace("milestones"), max_staleness);
+        self.workspace.persist(store.clone(), namespace("workspace"), max_staleness);
+        self.tickets.persist(store.clone(), namespace("tickets"), max_staleness);
+        self.assigned.persist(store, namespace("assigned"), max_staleness);
+        self
+    }
+
+    /// `cache`'s value for `key`, else `fetch`'s, else an expired value if
+    /// the provider is unavailable and the value is recent enough.
+    async fn read<V, F, Fut>(&self, cache: &TtlCache<V>, key: &str, fetch: F) -> TicketResult<V>
+    where
+        V: Clone,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = TicketResult<V>>,
+    {
+        match cache.get_or_fetch(key, fetch).await {
+            Err(e) if e.is_retryable() => match cache.get_stale(key, self.max_staleness) {
+                Some((value, age)) => {
+                    warn!("Serving cached data {}s old while the provider is unavailable: {}", age.as_secs(), e);
+                    record_stale_read(age);
+                    Ok(value)
+                }
+                None => Err(e),
+            },
+            result => result,
+        }
+    }
+
+    /// Fetches the current user, their assigned tickets, and workspace
+    /// metadata straight from the provider, for `store_snapshot`.
+    pub async fn load_snapshot(&self) -> Result<PrefetchSnapshot> {
+        let user = self.inner.get_current_user().await?;
+        let assigned = self.inner.get_all_assigned_tickets(&user.id).await?;
+        Ok(PrefetchSnapshot {
+            assigned,
+            teams: self.inner.get_teams().await?,
+            labels: self.inner.get_labels().await?,
+            workspace: self.inner.get_workspace().await?,
+            user,
+        })
+    }
+
+    /// Caches a snapshot for `valid_for`, regardless of the cache's TTLs.
+    pub fn store_snapshot(&self, snapshot: PrefetchSnapshot, valid_for: Duration) {
+        // Under every key `get_ticket` and `find_ticket` look tickets up by
+        for ticket in &snapshot.assigned {
+            for key in [
+                format!("id:{}", ticket.id),
+                format!("ref:{:?}", TicketRef::Id(ticket.id.clone())),
+                format!("ref:{:?}", TicketRef::parse(&ticket.identifier)),
+            ] {
+                self.tickets.put_for(&key, Some(ticket.clone()), valid_for);
+            }
+        }
+        self.assigned.put_for(&snapshot.user.id, snapshot.assigned, valid_for);
+        self.current_user.put_for("", snapshot.user, valid_for);
+        self.teams.put_for("", snapshot.teams, valid_for);
+        self.labels.put_for("", snapshot.labels, valid_for);
+        self.workspace.put_for("", snapshot.workspace, valid_for);
+    }
+
+    /// Tickets are cached under every reference they were looked up by, so
+    /// any ticket write drops them all.
+    fn tickets_changed(&self) {
+        self.tickets.clear();
+        self.assigned.clear();
+    }
+
+    fn projects_changed(&self) {
+        self.projects.clear();
+        self.project.clear();
+        self.milestones.clear();
+    }
+}
+
+/// Wraps providers in a `CachingTicketService`. Callers that feed the
+/// cache webhook events or prefetched snapshots build the service
+/// themselves, since they need the concrete handle.
+#[derive(Debug, Clone, Copy)]
+pub struct CacheLayer {
+    pub reference_ttl: Duration,
+    pub ticket_ttl: Duration,
+    pub max_staleness: Duration,
+}
+
+impl TicketServiceMiddleware for CacheLayer {
+    fn wrap(&self, inner: Arc<dyn TicketService + Send + Sync>) -> Arc<dyn TicketService + Send + Sync> {
+        Arc::new(
+            CachingTicketService::with_ttls(inner, self.reference_ttl, self.ticket_ttl)
+                .with_max_staleness(self.max_staleness),
+        )
+    }
+}
+
+/// Changes made outside this server drop the same entries a write through
+/// it would, instead of waiting out the TTL.
+impl EventListener for CachingTicketService {
+    fn on_event(&self, event: &WebhookEvent) {
+        match event.entity {
+            WebhookEntity::Ticket | WebhookEntity::Cycle => self.tickets_changed(),
+            WebhookEntity::Project | WebhookEntity::ProjectUpdate => self.projects_changed(),
+            WebhookEntity::Label => self.labels.clear(),
+            WebhookEntity::Comment | WebhookEntity::Document | WebhookEntity::Other(_) => {}
+        }
+    }
+}
+
+#[async_trait]
+impl TicketService for CachingTicketService {
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+
+    async fn get_assigned_tickets(&self, user_id: &str, page: &PageRequest) -> TicketResult<Page<Ticket>> {
+        self.inner.get_assigned_tickets(user_id, page).await
+    }
+
+    async fn search_tickets(&self, filter: &TicketFilter, page: &PageRequest) -> TicketResult<Page<Ticket>> {
+        self.inner.search_tickets(filter, page).await
+    }
+
+    async fn get_all_assigned_tickets(&self, user_id: &str) -> TicketResult<Vec<Ticket>> {
+        self.read(&self.assigned, user_id, || self.inner.get_all_assigned_tickets(user_id))
+            .await
+    }
+
+    async fn get_ticket(&self, ticket_id: &str) -> TicketResult<Option<Ticket>> {
+        self.read(&self.tickets, &format!("id:{}", ticket_id), || self.inner.get_ticket(ticket_id))
+            .await
+    }
+
+    async fn create_ticket(&self, request: &CreateTicketRequest) -> TicketResult<Ticket> {
+        let result = self.inner.create_ticket(request).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn update_ticket(&self, request: &UpdateTicketRequest) -> TicketResult<Ticket> {
+        let result = self.inner.update_ticket(request).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn archive_ticket(&self, ticket_id: &str) -> TicketResult<()> {
+        let result = self.inner.archive_ticket(ticket_id).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn delete_ticket(&self, ticket_id: &str) -> TicketResult<()> {
+        let result = self.inner.delete_ticket(ticket_id).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn watch_ticket(&self, ticket_id: &str, user_id: &str) -> TicketResult<()> {
+        let result = self.inner.watch_ticket(ticket_id, user_id).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn unwatch_ticket(&self, ticket_id: &str, user_id: &str) -> TicketResult<()> {
+        let result = self.inner.unwatch_ticket(ticket_id, user_id).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn find_ticket(&self, reference: &TicketRef) -> TicketResult<Option<Ticket>> {
+        self.read(&self.tickets, &format!("ref:{:?}", reference), || self.inner.find_ticket(reference))
+            .await
+    }
+
+    async fn get_tickets(&self, references: &[TicketRef]) -> TicketResult<Vec<Option<Ticket>>> {
+        self.inner.get_tickets(references).await
+    }
+
+    async fn get_comments(&self, ticket_id: &str, page: &PageRequest) -> TicketResult<Page<Comment>> {
+        self.inner.get_comments(ticket_id, page).await
+    }
+
+    async fn add_comment(&self, request: &CreateCommentRequest) -> TicketResult<Comment> {
+        self.inner.add_comment(request).await
+    }
+
+    async fn get_ticket_history(&self, ticket_id: &str) -> TicketResult<Vec<TicketEvent>> {
+        self.inner.get_ticket_history(ticket_id).await
+    }
+
+    async fn add_relation(&self, request: &CreateRelationRequest) -> TicketResult<()> {
+        let result = self.inner.add_relation(request).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn set_parent(&self, ticket_id: &str, parent_id: Option<&str>) -> TicketResult<Ticket> {
+        let result = self.inner.set_parent(ticket_id, parent_id).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn get_attachments(&self, ticket_id: &str) -> TicketResult<Vec<Attachment>> {
+        self.inner.get_attachments(ticket_id).await
+    }
+
+    async fn add_attachment(&self, request: &CreateAttachmentRequest) -> TicketResult<Attachment> {
+        let result = self.inner.add_attachment(request).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn add_reaction(&self, target: &ReactionTarget, emoji: &str) -> TicketResult<Reaction> {
+        let result = self.inner.add_reaction(target, emoji).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn remove_reaction(&self, reaction_id: &str) -> TicketResult<()> {
+        let result = self.inner.remove_reaction(reaction_id).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn get_current_user(&self) -> TicketResult<User> {
+        self.read(&self.current_user, "", || self.inner.get_current_user()).await
+    }
+
+    async fn get_user(&self, user_id: &str) -> TicketResult<Option<User>> {
+        self.read(&self.users, user_id, || self.inner.get_user(user_id)).await
+    }
+
+    async fn get_notifications(&self, unread_only: bool, page: &PageRequest) -> TicketResult<Page<Notification>> {
+        self.inner.get_notifications(unread_only, page).await
+    }
+
+    async fn get_teams(&self) -> TicketResult<Vec<Team>> {
+        self.read(&self.teams, "", || self.inner.get_teams()).await
+    }
+
+    async fn get_team_members(&self, team_id: &str) -> TicketResult<Vec<User>> {
+        self.read(&self.team_members, team_id, || self.inner.get_team_members(team_id)).await
+    }
+
+    async fn get_workflow_states(&self, team_id: &str) -> TicketResult<Vec<WorkflowState>> {
+        self.read(&self.workflow_states, team_id, || self.inner.get_workflow_states(team_id)).await
+    }
+
+    async fn get_labels(&self) -> TicketResult<Vec<Label>> {
+        self.read(&self.labels, "", || self.inner.get_labels()).await
+    }
+
+    async fn create_label(&self, request: &CreateLabelRequest) -> TicketResult<Label> {
+        let result = self.inner.create_label(request).await;
+        self.labels.clear();
+        result
+    }
+
+    async fn get_projects(&self, page: &PageRequest) -> TicketResult<Page<Project>> {
+        self.read(&self.projects, &format!("{:?}", page), || self.inner.get_projects(page))
+            .await
+    }
+
+    async fn get_project(&self, project_id: &str) -> TicketResult<Option<Project>> {
+        self.read(&self.project, project_id, || self.inner.get_project(project_id)).await
+    }
+
+    async fn get_project_milestones(&self, project_id: &str) -> TicketResult<Vec<ProjectMilestone>> {
+        self.read(&self.milestones, project_id, || self.inner.get_project_milestones(project_id))
+            .await
+    }
+
+    async fn create_project(&self, request: &CreateProjectRequest) -> TicketResult<Project> {
+        let result = self.inner.create_project(request).await;
+        self.projects_changed();
+        result
+    }
+
+    async fn update_project(&self, request: &UpdateProjectRequest) -> TicketResult<Project> {
+        let result = self.inner.update_project(request).await;
+        self.projects_changed();
+        result
+    }
+
+    async fn create_milestone(&self, request: &CreateMilestoneRequest) -> TicketResult<ProjectMilestone> {
+        let result = self.inner.create_milestone(request).await;
+        self.projects_changed();
+        result
+    }
+
+    async fn get_project_updates(&self, project_id: &str, page: &PageRequest) -> TicketResult<Page<ProjectUpdate>> {
+        self.inner.get_project_updates(project_id, page).await
+    }
+
+    async fn get_documents(&self, project_id: Option<&str>, page: &PageRequest) -> TicketResult<Page<Document>> {
+        self.inner.get_documents(project_id, page).await
+    }
+
+    async fn get_document(&self, document_id: &str) -> TicketResult<Option<Document>> {
+        self.inner.get_document(document_id).await
+    }
+
+    async fn get_initiatives(&self) -> TicketResult<Vec<Initiative>> {
+        self.inner.get_initiatives().await
+    }
+
+    async fn get_initiative_projects(&self, initiative_id: &str) -> TicketResult<Vec<Project>> {
+        self.inner.get_initiative_projects(initiative_id).await
+    }
+
+    async fn get_cycles(&self, team_id: &str) -> TicketResult<Vec<Cycle>> {
+        self.inner.get_cycles(team_id).await
+    }
+
+    async fn get_active_cycle(&self, team_id: &str) -> TicketResult<Option<Cycle>> {
+        self.inner.get_active_cycle(team_id).await
+    }
+
+    async fn assign_ticket_to_cycle(&self, ticket_id: &str, cycle_id: &str) -> TicketResult<Ticket> {
+        let result = self.inner.assign_ticket_to_cycle(ticket_id, cycle_id).await;
+        self.tickets_changed();
+        result
+    }
+
+    async fn log_time(&self, request: &LogTimeRequest) -> TicketResult<TimeEntry> {
+        self.inner.log_time(request).await
+    }
+
+    async fn get_time_entries(&self, ticket_id: &str) -> TicketResult<Vec<TimeEntry>> {
+        self.inner.get_time_entries(ticket_id).await
+    }
+
+    async fn get_workspace(&self) -> TicketResult<Workspace> {
+        self.read(&self.workspace, "", || self.inner.get_workspace()).await
+    }
+}
@@ -0,0 +1,109 @@
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+use crate::domain::Ticket;
+
+pub const DEFAULT_CONFIRMATION_TTL_SECS: i64 = 120;
+
+/// Operations that must be previewed and confirmed before they run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DestructiveAction {
+    ArchiveTickets,
+    DeleteTickets,
+}
+
+/// First-phase result of a destructive operation: what would be affected and
+/// the token required to actually perform it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfirmationPreview {
+    pub action: DestructiveAction,
+    pub tickets: Vec<Ticket>,
+    pub confirmation_token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkFailure {
+    pub ticket_id: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkActionResult {
+    pub completed: Vec<String>,
+    pub failed: Vec<BulkFailure>,
+}
+
+#[derive(Debug, Clone)]
+struct PendingConfirmation {
+    action: DestructiveAction,
+    subject: Vec<String>,
+    expires_at: DateTime<Utc>,
+}
+
+/// Issues single-use tokens bound to an action and the exact set of tickets it
+/// was previewed for, so a confirmed call cannot be replayed or widened.
+pub struct ConfirmationGate {
+    ttl: Duration,
+    pending: Mutex<HashMap<String, PendingConfirmation>>,
+}
+
+impl ConfirmationGate {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn issue(&self, action: DestructiveAction, subject: &[String]) -> (String, DateTime<Utc>) {
+        let now = Utc::now();
+        let expires_at = now + self.ttl;
+        let token = Uuid::new_v4().simple().to_string();
+
+        let mut pending = self.pending.lock().unwrap();
+        pending.retain(|_, p| p.expires_at > now);
+        pending.insert(token.clone(), PendingConfirmation {
+            action,
+            subject: Self::normalize(subject),
+            expires_at,
+        });
+
+        (token, expires_at)
+    }
+
+    /// Consumes `token`. Fails if it is unknown, expired, or was issued for a
+    /// different action or ticket set.
+    pub fn redeem(&self, token: &str, action: DestructiveAction, subject: &[String]) -> Result<()> {
+        let pending = self.pending.lock().unwrap().remove(token)
+            .ok_or_else(|| anyhow!("Unknown or already used confirmation token"))?;
+
+        if pending.expires_at <= Utc::now() {
+            return Err(anyhow!("Confirmation token expired at {}; request a new preview", pending.expires_at));
+        }
+
+        if pending.action != action || pending.subject != Self::normalize(subject) {
+            return Err(anyhow!("Confirmation token was issued for a different operation; request a new preview"));
+        }
+
+        Ok(())
+    }
+
+    fn normalize(subject: &[String]) -> Vec<String> {
+        let mut subject = subject.to_vec();
+        subject.sort();
+        subject.dedup();
+        subject
+    }
+}
+
+impl Default for ConfirmationGate {
+    fn default() -> Self {
+        Self::new(Duration::seconds(DEFAULT_CONFIRMATION_TTL_SECS))
+    }
+}
@@ -0,0 +1,338 @@
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use crate::core::{ImportTargets, parse_human_date};
+use crate::domain::{CreateTicketRequest, Priority, RichText};
+
+/// Tickets created at once by a CSV import unless told otherwise.
+pub const DEFAULT_IMPORT_CONCURRENCY: usize = 4;
+
+/// A ticket field a CSV column can fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CsvField {
+    Title,
+    Description,
+    Priority,
+    /// Team key, name, or id.
+    Team,
+    /// Assignee email or id.
+    Assignee,
+    /// Project name, key, or id.
+    Project,
+    /// Label names, separated by `;` or `,`.
+    Labels,
+    DueDate,
+    Estimate,
+}
+
+impl CsvField {
+    /// A field by its name or one of its header aliases.
+    pub fn parse(value: &str) -> Result<Self> {
+        Self::from_header(value).ok_or_else(|| anyhow!(
+            "Unknown ticket field: {}. Expected title, description, priority, team, assignee, project, labels, due_date, or estimate",
+            value
+        ))
+    }
+
+    /// The name `parse` reads back as this field.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CsvField::Title => "title",
+            CsvField::Description => "description",
+            CsvField::Priority => "priority",
+            CsvField::Team => "team",
+            CsvField::Assignee => "assignee",
+            CsvField::Project => "project",
+            CsvField::Labels => "labels",
+            CsvField::DueDate => "due_date",
+            CsvField::Estimate => "estimate",
+        }
+    }
+
+    /// The field a header names, by its name or a common alias such as
+    /// `Summary` or `Story Points`.
+    fn from_header(header: &str) -> Option<Self> {
+        Some(match normalized(header).as_str() {
+            "title" | "summary" | "name" | "subject" => CsvField::Title,
+            "description" | "body" | "details" => CsvField::Description,
+            "priority" => CsvField::Priority,
+            "team" | "team_key" => CsvField::Team,
+            "assignee" | "assignee_email" | "assignee_id" | "owner" => CsvField::Assignee,
+            "project" => CsvField::Project,
+            "labels" | "label" | "tags" => CsvField::Labels,
+            "due_date" | "due" => CsvField::DueDate,
+            "estimate" | "points" | "story_points" => CsvField::Estimate,
+            _ => return None,
+        })
+    }
+}
+
+/// Lowercase, trimmed, with spaces and dashes as underscores.
+fn normalized(name: &str) -> String {
+    name.trim().to_lowercase().replace([' ', '-'], "_")
+}
+
+/// A CSV column and the field it fills.
+#[derive(Debug, Clone, Serialize)]
+pub struct CsvColumn {
+    pub column: String,
+    pub field: CsvField,
+}
+
+/// The fields of one CSV row, by the line it starts on.
+#[derive(Debug, Clone)]
+pub struct CsvRecord {
+    pub line: u64,
+    pub values: HashMap<CsvField, String>,
+}
+
+/// A CSV file read against its header.
+#[derive(Debug, Clone)]
+pub struct CsvTickets {
+    /// The mapped columns, in file order.
+    pub columns: Vec<CsvColumn>,
+    /// Columns that fill no field.
+    pub ignored_columns: Vec<String>,
+    pub records: Vec<CsvRecord>,
+}
+
+/// Reads a CSV file whose header names the field each column fills.
+/// `columns` maps header names to fields for headers that are not a
+/// field's name or alias, and takes precedence over them.
+pub fn parse_ticket_csv(text: &str, columns: &BTreeMap<String, CsvField>) -> Result<CsvTickets> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(text.as_bytes());
+    let headers = reader.headers().context("Failed to read the CSV header")?.clone();
+
+    for column in columns.keys() {
+        if !headers.iter().any(|header| header.eq_ignore_ascii_case(column.trim())) {
+            return Err(anyhow!("Column {} is mapped but not in the CSV header ({})", column, headers.iter().collect::<Vec<_>>().join(", ")));
+        }
+    }
+
+    let mut mapped: Vec<(usize, String, CsvField)> = Vec::new();
+    let mut ignored_columns = Vec::new();
+    for (index, header) in headers.iter().enumerate() {
+        let field = columns.iter()
+            .find(|(column, _)| header.eq_ignore_ascii_case(column.trim()))
+            .map(|(_, field)| *field)
+            .or_else(|| CsvField::from_header(header));
+        match field {
+            Some(field) => {
+                if let Some((_, other, _)) = mapped.iter().find(|(_, _, mapped)| *mapped == field) {
+                    return Err(anyhow!("Columns {} and {} both fill {}; map one of them to another field", other, header, field.name()));
+                }
+                mapped.push((index, header.to_string(), field));
+            }
+            None => ignored_columns.push(header.to_string()),
+        }
+    }
+    if !mapped.iter().any(|(_, _, field)| *field == CsvField::Title) {
+        return Err(anyhow!("No column holds the title; name one Title or map it to title"));
+    }
+
+    let mut records = Vec::new();
+    for record in reader.records() {
+        let record = record.context("Failed to read the CSV")?;
+        let line = record.position().map_or(0, |position| position.line());
+        let values = mapped.iter()
+            .filter_map(|(index, _, field)| Some((*field, record.get(*index)?.to_string())))
+            .filter(|(_, value)| !value.is_empty())
+            .collect::<HashMap<_, _>>();
+        // Blank lines between rows are not tickets
+        if !values.is_empty() {
+            records.push(CsvRecord { line, values });
+        }
+    }
+
+    Ok(CsvTickets {
+        columns: mapped.into_iter().map(|(_, column, field)| CsvColumn { column, field }).collect(),
+        ignored_columns,
+        records,
+    })
+}
+
+/// A row that will become a ticket.
+#[derive(Debug, Clone, Serialize)]
+pub struct CsvImportRow {
+    pub line: u64,
+    pub request: CreateTicketRequest,
+}
+
+/// A row that cannot become a ticket, with everything wrong with it.
+#[derive(Debug, Clone, Serialize)]
+pub struct CsvRowErrors {
+    pub line: u64,
+    pub errors: Vec<String>,
+}
+
+/// What a CSV import would create, and which rows it would have to refuse.
+#[derive(Debug, Clone, Serialize)]
+pub struct CsvImportPlan {
+    pub columns: Vec<CsvColumn>,
+    pub ignored_columns: Vec<String>,
+    pub rows: Vec<CsvImportRow>,
+    pub invalid: Vec<CsvRowErrors>,
+}
+
+impl CsvImportPlan {
+    /// Resolves each record's team, people, project, and labels in the
+    /// target provider. `team` is used for rows without one, and when it is
+    /// unset too the provider's default team.
+    pub fn build(tickets: CsvTickets, targets: &ImportTargets, team: Option<&str>, now: DateTime<Utc>) -> Self {
+        let mut rows = Vec::new();
+        let mut invalid = Vec::new();
+        for record in &tickets.records {
+            match resolve_row(record, targets, team, now) {
+                Ok(request) => rows.push(CsvImportRow { line: record.line, request }),
+                Err(errors) => invalid.push(CsvRowErrors { line: record.line, errors }),
+            }
+        }
+        Self {
+            columns: tickets.columns,
+            ignored_columns: tickets.ignored_columns,
+            rows,
+            invalid,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.invalid.is_empty()
+    }
+}
+
+fn resolve_row(
+    record: &CsvRecord,
+    targets: &ImportTargets,
+    default_team: Option<&str>,
+    now: DateTime<Utc>,
+) -> std::result::Result<CreateTicketRequest, Vec<String>> {
+    let mut errors = Vec::new();
+    let value = |field| record.values.get(&field).map(String::as_str);
+    let mut request = CreateTicketRequest {
+        title: value(CsvField::Title).unwrap_or_default().to_string(),
+        description: value(CsvField::Description).map(RichText::markdown),
+        ..Default::default()
+    };
+    if request.title.is_empty() {
+        errors.push("no title".to_string());
+    }
+
+    if let Some(priority) = value(CsvField::Priority) {
+        match Priority::parse(priority) {
+            Priority::Custom(_) => errors.push(format!("unknown priority {}", priority)),
+            priority => request.priority = Some(priority),
+        }
+    }
+    if let Some(team) = value(CsvField::Team).or(default_team) {
+        match targets.teams.iter().find(|t| t.id == team || t.key.eq_ignore_ascii_case(team) || t.name.eq_ignore_ascii_case(team)) {
+            Some(found) => request.team_id = Some(found.id.clone()),
+            None => errors.push(format!("no team {}", team)),
+        }
+    }
+    if let Some(assignee) = value(CsvField::Assignee) {
+        let by_email = targets.users.get(&assignee.to_lowercase());
+        match by_email.or_else(|| targets.users.values().find(|id| *id == assignee)) {
+            Some(id) => request.assignee_id = Some(id.clone()),
+            None => errors.push(format!("no team member {}", assignee)),
+        }
+    }
+    if let Some(project) = value(CsvField::Project) {
+        let found = targets.projects.iter()
+            .find(|p| p.id == project || p.key.eq_ignore_ascii_case(project) || p.name.eq_ignore_ascii_case(project));
+        match found {
+            Some(found) => request.project_id = Some(found.id.clone()),
+            None => errors.push(format!("no project {}", project)),
+        }
+    }
+    if let Some(labels) = value(CsvField::Labels) {
+        let mut label_ids = Vec::new();
+        for name in labels.split([';', ',']).map(str::trim).filter(|name| !name.is_empty()) {
+            match targets.labels.iter().find(|label| label.name.eq_ignore_ascii_case(name)) {
+                Some(label) => label_ids.push(label.id.clone()),
+                None => errors.push(format!("no label {}", name)),
+            }
+        }
+        request.label_ids = Some(label_ids);
+    }
+    if let Some(due_date) = value(CsvField::DueDate) {
+        match parse_human_date(due_date, now, true) {
+            Ok(date) => request.due_date = Some(date),
+            Err(e) => errors.push(format!("due date {}: {}", due_date, e)),
+        }
+    }
+    if let Some(estimate) = value(CsvField::Estimate) {
+        match estimate.parse::<f32>() {
+            Ok(points) if points >= 0.0 => request.estimate = Some(points),
+            _ => errors.push(format!("estimate {} is not a non-negative number", estimate)),
+        }
+    }
+
+    if errors.is_empty() { Ok(request) } else { Err(errors) }
+}
+
+impl fmt::Display for CsvImportPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let columns: Vec<String> = self.columns.iter()
+            .map(|column| format!("{} -> {}", column.column, column.field.name()))
+            .collect();
+        writeln!(f, "Columns: {}", columns.join(", "))?;
+        if !self.ignored_columns.is_empty() {
+            writeln!(f, "Ignored columns: {}", self.ignored_columns.join(", "))?;
+        }
+        writeln!(f, "{} tickets to create", self.rows.len())?;
+        for row in &self.rows {
+            writeln!(f, "+ line {}: {}", row.line, row.request.title)?;
+        }
+        if !self.invalid.is_empty() {
+            writeln!(f, "{} invalid rows", self.invalid.len())?;
+            for row in &self.invalid {
+                writeln!(f, "! line {}: {}", row.line, row.errors.join("; "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CsvCreated {
+    pub line: u64,
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CsvFailure {
+    pub line: u64,
+    pub title: String,
+    pub error: String,
+}
+
+/// What a CSV import created and what failed, in file order.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CsvImportReport {
+    pub created: Vec<CsvCreated>,
+    pub failed: Vec<CsvFailure>,
+}
+
+impl fmt::Display for CsvImportReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Created {} tickets", self.created.len())?;
+        for created in &self.created {
+            writeln!(f, "+ line {}: {} {}", created.line, created.identifier, created.title)?;
+        }
+        if !self.failed.is_empty() {
+            writeln!(f, "{} failed", self.failed.len())?;
+            for failed in &self.failed {
+                writeln!(f, "! line {}: {}: {}", failed.line, failed.title, failed.error)?;
+            }
+        }
+        Ok(())
+    }
+}
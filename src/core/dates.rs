@@ -0,0 +1,51 @@
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Months, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use human_date_parser::{ParseResult, from_human_time};
+
+/// Parses a date as an RFC3339 timestamp, a `YYYY-MM-DD` date, or an
+/// English phrase relative to `now` ("yesterday", "next friday", "in 2
+/// weeks", "3 days ago", "last monday at 17:00"), all in UTC. Anything that
+/// names a day without a time resolves to the start of that day, or with
+/// `end_of_day` to its last second.
+pub fn parse_human_date(text: &str, now: DateTime<Utc>, end_of_day: bool) -> Result<DateTime<Utc>> {
+    let text = text.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(text) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+    let day = |date: NaiveDate| {
+        let time = if end_of_day {
+            NaiveTime::from_hms_opt(23, 59, 59)
+        } else {
+            NaiveTime::from_hms_opt(0, 0, 0)
+        };
+        time.map(|time| date.and_time(time).and_utc())
+            .ok_or_else(|| anyhow!("Invalid date {}", date))
+    };
+    if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        return day(date);
+    }
+
+    // The parser reads "next month" as next Monday
+    let lowercase = text.to_lowercase();
+    let months = match lowercase.as_str() {
+        "next month" => Some(1),
+        "next year" => Some(12),
+        _ => None,
+    };
+    if let Some(months) = months {
+        return now.date_naive()
+            .checked_add_months(Months::new(months))
+            .ok_or_else(|| anyhow!("Date out of range: {}", text))
+            .and_then(day);
+    }
+
+    match from_human_time(text, now.naive_utc()) {
+        Ok(ParseResult::DateTime(datetime)) => Ok(datetime.and_utc()),
+        Ok(ParseResult::Date(date)) => day(date),
+        Ok(ParseResult::Time(time)) => Ok(NaiveDateTime::new(now.date_naive(), time).and_utc()),
+        Err(_) => Err(anyhow!(
+            "Could not understand the date {:?}; use YYYY-MM-DD, RFC3339, or a phrase like \"next friday\" or \"in 2 weeks\"",
+            text
+        )),
+    }
+}
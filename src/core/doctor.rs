@@ -0,0 +1,198 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::time::Instant;
+
+use crate::core::ProviderRegistry;
+use crate::ports::{PageRequest, TicketServiceError, unsupported_operation};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Passed,
+    Failed,
+    /// The provider does not offer what the check exercises.
+    Skipped,
+}
+
+/// The outcome of one diagnostic API call.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckResult {
+    pub provider: String,
+    pub check: &'static str,
+    pub status: CheckStatus,
+    /// What was found, or the error the call returned.
+    pub detail: String,
+    /// What to do about a failure.
+    pub hint: Option<String>,
+    pub elapsed_ms: u128,
+}
+
+/// Every check of every configured provider.
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorReport {
+    pub results: Vec<CheckResult>,
+}
+
+impl DoctorReport {
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(|result| result.status != CheckStatus::Failed)
+    }
+}
+
+impl fmt::Display for DoctorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut provider = None;
+        for result in &self.results {
+            if provider != Some(&result.provider) {
+                writeln!(f, "{}", result.provider)?;
+                provider = Some(&result.provider);
+            }
+            let mark = match result.status {
+                CheckStatus::Passed => "ok",
+                CheckStatus::Failed => "FAILED",
+                CheckStatus::Skipped => "skipped",
+            };
+            writeln!(f, "  {:<8} {:<15} {} ({} ms)", mark, result.check, result.detail, result.elapsed_ms)?;
+            if let Some(hint) = &result.hint {
+                writeln!(f, "           {:<15} -> {}", "", hint)?;
+            }
+        }
+        let failed = self.results.iter().filter(|r| r.status == CheckStatus::Failed).count();
+        if failed == 0 {
+            write!(f, "All checks passed")
+        } else {
+            write!(f, "{} of {} checks failed", failed, self.results.len())
+        }
+    }
+}
+
+/// Calls each provider the way tools will: authenticates, then reads
+/// workspace metadata, tickets, and projects. A failed authentication
+/// skips the provider's remaining checks, which could only fail the same
+/// way. Writes are not attempted, so write access is not verified.
+pub async fn run_checks(registry: &ProviderRegistry) -> DoctorReport {
+    let mut results = Vec::new();
+    for (provider, application) in registry.iter() {
+        let record = |check, started: Instant, outcome: anyhow::Result<String>| {
+            let elapsed_ms = started.elapsed().as_millis();
+            let (status, detail, hint) = match outcome {
+                Ok(detail) => (CheckStatus::Passed, detail, None),
+                Err(e) if unsupported_operation(&e).is_some() => (CheckStatus::Skipped, e.to_string(), None),
+                Err(e) => {
+                    let (detail, hint) = diagnose(&e);
+                    (CheckStatus::Failed, detail, Some(hint))
+                }
+            };
+            CheckResult { provider: provider.to_string(), check, status, detail, hint, elapsed_ms }
+        };
+
+        let started = Instant::now();
+        let user = application.get_current_user().await;
+        let user_id = user.as_ref().ok().map(|user| user.id.clone());
+        results.push(record("authentication", started, user.map(|user| format!("as {} <{}>", user.name, user.email))));
+        let Some(user_id) = user_id else {
+            continue;
+        };
+
+        let started = Instant::now();
+        let workspace = application.get_workspace().await
+            .map(|workspace| format!("{} with {} teams", workspace.name, workspace.teams.len()));
+        results.push(record("workspace", started, workspace));
+
+        let started = Instant::now();
+        let tickets = application.get_assigned_tickets(&user_id, &PageRequest::first(1)).await
+            .map(|page| format!("can read tickets ({} assigned on the first page)", page.items.len()));
+        results.push(record("tickets", started, tickets));
+
+        let started = Instant::now();
+        let projects = application.get_projects(&PageRequest::first(1)).await
+            .map(|_| "can read projects".to_string());
+        results.push(record("projects", started, projects));
+    }
+    DoctorReport { results }
+}
+
+/// Boils a provider error down to its message and a suggested fix, pulling
+/// the messages out of GraphQL error payloads.
+pub fn diagnose(error: &anyhow::Error) -> (String, String) {
+    let text = format!("{:#}", error);
+    let detail = graphql_messages(&text).unwrap_or(text);
+
+    let hint = match error_class(error) {
+        "unauthorized" => "The provider rejected the credentials: check the API token is current and for the right workspace, or run `generic-mcp auth set <provider>` / `auth login <provider>` again",
+        "forbidden" => "The credentials lack a permission this call needs: grant the missing scope to the token or OAuth application",
+        "rate_limited" => "The provider is rate limiting this token: wait a minute, or lower the request rate limits",
+        "certificate" => "The provider's TLS certificate was not trusted: add its CA with MCP_CA_CERTIFICATES or the profile's ca_certificates",
+        "timeout" => "The provider did not answer in time: check connectivity, or raise MCP_REQUEST_TIMEOUT_SECS",
+        "network" => "The provider could not be reached: check network access, proxies, and firewalls from this host",
+        "oauth" => "OAuth is not set up: run `generic-mcp auth login <provider>`",
+        "provider_unavailable" => "The provider failed on its side: check its status page and try again later",
+        _ => "Unexpected error from the provider; rerun with RUST_LOG=debug for the full exchange",
+    };
+    (detail, hint.to_string())
+}
+
+/// A coarse category of a tool or provider error, e.g. `unauthorized` or
+/// `rate_limited`, for logs and hints; `error` when nothing more specific
+/// matches.
+pub fn error_class(error: &anyhow::Error) -> &'static str {
+    if unsupported_operation(error).is_some() {
+        return "unsupported";
+    }
+    // Typed provider errors say what they are; unauthorized and unavailable
+    // ones are narrowed down by their message below
+    let typed = error.downcast_ref::<TicketServiceError>();
+    match typed {
+        Some(TicketServiceError::NotFound(_)) => return "not_found",
+        Some(TicketServiceError::RateLimited { .. }) => return "rate_limited",
+        Some(TicketServiceError::Validation(_)) => return "invalid_arguments",
+        _ => {}
+    }
+    let text = format!("{:#}", error).to_lowercase();
+    let mentions = |needles: &[&str]| needles.iter().any(|needle| text.contains(needle));
+
+    if mentions(&["authentication", "not authenticated", "401", "unauthorized"]) {
+        "unauthorized"
+    } else if mentions(&["scope", "forbidden", "403", "permission"]) {
+        "forbidden"
+    } else if mentions(&["rate limit", "ratelimited"]) {
+        "rate_limited"
+    } else if mentions(&["certificate"]) {
+        "certificate"
+    } else if mentions(&["timed out", "timeout"]) {
+        "timeout"
+    } else if mentions(&["dns error", "connect", "tls", "network"]) {
+        "network"
+    } else if mentions(&["oauth"]) {
+        "oauth"
+    } else if mentions(&["not found"]) {
+        "not_found"
+    } else if mentions(&["is required", "missing", "invalid", "must be"]) {
+        "invalid_arguments"
+    } else {
+        typed.map_or("error", TicketServiceError::kind)
+    }
+}
+
+/// The `message`s (and `extensions.code`s) of a GraphQL `errors` array
+/// embedded in an error message.
+fn graphql_messages(text: &str) -> Option<String> {
+    let start = text.find(['[', '{'])?;
+    let value: Value = serde_json::from_str(&text[start..]).ok()?;
+    let errors = match &value {
+        Value::Array(errors) => errors.clone(),
+        Value::Object(object) => object.get("errors")?.as_array()?.clone(),
+        _ => return None,
+    };
+    let messages: Vec<String> = errors.iter()
+        .filter_map(|error| {
+            let message = error.get("message")?.as_str()?;
+            Some(match error.pointer("/extensions/code").and_then(|code| code.as_str()) {
+                Some(code) => format!("{} ({})", message, code),
+                None => message.to_string(),
+            })
+        })
+        .collect();
+    (!messages.is_empty()).then(|| messages.join("; "))
+}
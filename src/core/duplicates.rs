@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::domain::Ticket;
+
+pub const DEFAULT_DUPLICATE_LIMIT: usize = 10;
+
+/// Candidates scoring below this are noise rather than likely duplicates.
+pub const DUPLICATE_SCORE_THRESHOLD: f32 = 0.35;
+
+const STOPWORDS: [&str; 24] = [
+    "the", "and", "for", "with", "from", "into", "onto", "that", "this", "when",
+    "not", "are", "was", "can", "should", "add", "fix", "make", "use", "via",
+    "all", "its", "out", "new",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateCandidate {
+    pub score: f32,
+    pub title_similarity: f32,
+    pub shared_labels: Vec<String>,
+    pub ticket: Ticket,
+}
+
+fn normalize(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect()
+}
+
+/// Significant lowercase words, without stopwords and very short tokens.
+pub fn keywords(text: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    normalize(text)
+        .split_whitespace()
+        .filter(|word| word.len() >= 3 && !STOPWORDS.contains(word))
+        .filter(|word| seen.insert(word.to_string()))
+        .map(|word| word.to_string())
+        .collect()
+}
+
+fn bigrams(text: &str) -> Vec<(char, char)> {
+    let compact: Vec<char> = normalize(text).split_whitespace().collect::<Vec<_>>().join(" ").chars().collect();
+    compact.windows(2).map(|w| (w[0], w[1])).collect()
+}
+
+/// Sørensen–Dice coefficient over character bigrams; tolerant of typos and
+/// word-order changes.
+fn dice(a: &str, b: &str) -> f32 {
+    let a = bigrams(a);
+    let mut b = bigrams(b);
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let total = a.len() + b.len();
+    let mut shared = 0;
+    for pair in &a {
+        if let Some(index) = b.iter().position(|p| p == pair) {
+            b.swap_remove(index);
+            shared += 1;
+        }
+    }
+
+    (2 * shared) as f32 / total as f32
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / a.union(b).count() as f32
+}
+
+/// Fuzzy similarity of two titles in `0.0..=1.0`.
+pub fn title_similarity(a: &str, b: &str) -> f32 {
+    let words_a: HashSet<String> = keywords(a).into_iter().collect();
+    let words_b: HashSet<String> = keywords(b).into_iter().collect();
+    dice(a, b).max(jaccard(&words_a, &words_b))
+}
+
+/// Scores `ticket` as a duplicate of a proposed ticket. Titles carry most of
+/// the weight; shared labels only count when the proposal has labels.
+pub fn score_duplicate(title: &str, labels: &[String], ticket: &Ticket) -> DuplicateCandidate {
+    let title_similarity = title_similarity(title, &ticket.title);
+
+    let proposed: HashSet<String> = labels.iter().map(|l| l.to_lowercase()).collect();
+    let existing: HashSet<String> = ticket.labels.iter().map(|l| l.to_lowercase()).collect();
+    let shared_labels: Vec<String> = ticket.labels
+        .iter()
+        .filter(|label| proposed.contains(&label.to_lowercase()))
+        .cloned()
+        .collect();
+
+    let score = if proposed.is_empty() {
+        title_similarity
+    } else {
+        0.8 * title_similarity + 0.2 * jaccard(&proposed, &existing)
+    };
+
+    DuplicateCandidate {
+        score,
+        title_similarity,
+        shared_labels,
+        ticket: ticket.clone(),
+    }
+}
+
+/// Ranks candidates, dropping weak matches and keeping at most `limit`.
+pub fn rank_duplicates(title: &str, labels: &[String], tickets: &[Ticket], limit: usize) -> Vec<DuplicateCandidate> {
+    let mut candidates: Vec<DuplicateCandidate> = tickets
+        .iter()
+        .map(|ticket| score_duplicate(title, labels, ticket))
+        .filter(|candidate| candidate.score >= DUPLICATE_SCORE_THRESHOLD)
+        .collect();
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    candidates.truncate(limit);
+    candidates
+}
@@ -0,0 +1,46 @@
+use std::sync::{Arc, Mutex};
+
+use crate::domain::WebhookEvent;
+
+/// Reacts to changes providers push through webhooks.
+pub trait EventListener: Send + Sync {
+    fn on_event(&self, event: &WebhookEvent);
+}
+
+struct Subscription {
+    /// `None` receives every provider's events.
+    provider: Option<String>,
+    listener: Arc<dyn EventListener>,
+}
+
+/// Fans webhook events out to caches and servers. Listeners run inline, so
+/// they should only record or invalidate, not call providers.
+#[derive(Clone, Default)]
+pub struct EventBus {
+    subscriptions: Arc<Mutex<Vec<Subscription>>>,
+}
+
+impl EventBus {
+    pub fn subscribe(&self, provider: Option<&str>, listener: Arc<dyn EventListener>) {
+        if let Ok(mut subscriptions) = self.subscriptions.lock() {
+            subscriptions.push(Subscription {
+                provider: provider.map(|p| p.to_string()),
+                listener,
+            });
+        }
+    }
+
+    pub fn publish(&self, event: &WebhookEvent) {
+        let listeners: Vec<Arc<dyn EventListener>> = match self.subscriptions.lock() {
+            Ok(subscriptions) => subscriptions
+                .iter()
+                .filter(|s| s.provider.as_deref().is_none_or(|provider| provider == event.provider))
+                .map(|s| s.listener.clone())
+                .collect(),
+            Err(_) => return,
+        };
+        for listener in listeners {
+            listener.on_event(event);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::core::{Table, TableFormat};
+use crate::domain::{Priority, Ticket};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Markdown,
+}
+
+const COLUMNS: [&str; 12] = [
+    "identifier", "title", "state", "priority", "assignee_id", "project_id",
+    "labels", "created_at", "updated_at", "due_date", "estimate", "url",
+];
+
+impl ExportFormat {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "json" => Ok(ExportFormat::Json),
+            "csv" => Ok(ExportFormat::Csv),
+            "markdown" | "md" => Ok(ExportFormat::Markdown),
+            other => Err(anyhow!("Unsupported export format: {}. Expected json, csv, or markdown", other)),
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "application/json",
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Markdown => "text/markdown",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Markdown => "md",
+        }
+    }
+
+    pub fn render(&self, tickets: &[Ticket]) -> Result<String> {
+        match self {
+            ExportFormat::Json => Ok(serde_json::to_string_pretty(tickets)?),
+            ExportFormat::Csv => table(tickets).render(TableFormat::Csv),
+            ExportFormat::Markdown => table(tickets).render(TableFormat::Markdown),
+        }
+    }
+}
+
+fn priority_label(priority: &Priority) -> String {
+    match priority {
+        Priority::Custom(name) => name.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+fn row(ticket: &Ticket) -> [String; 12] {
+    [
+        ticket.identifier.clone(),
+        ticket.title.clone(),
+        ticket.state.name.clone(),
+        priority_label(&ticket.priority),
+        ticket.assignee_id.clone().unwrap_or_default(),
+        ticket.project_id.clone().unwrap_or_default(),
+        ticket.labels.join("; "),
+        ticket.created_at.to_rfc3339(),
+        ticket.updated_at.to_rfc3339(),
+        ticket.due_date.map(|d| d.to_rfc3339()).unwrap_or_default(),
+        ticket.estimate.map(|e| e.to_string()).unwrap_or_default(),
+        ticket.url.clone(),
+    ]
+}
+
+fn table(tickets: &[Ticket]) -> Table {
+    let mut table = Table::new(&COLUMNS);
+    for ticket in tickets {
+        table.push(row(ticket).into_iter().map(Value::String).collect());
+    }
+    table
+}
@@ -0,0 +1,66 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Longest title slug a branch name carries, so names stay typeable.
+pub const MAX_BRANCH_SLUG_LEN: usize = 40;
+
+/// Identifiers like `METAL-42` or `metal-42`: a team key of letters and
+/// digits starting with a letter, a dash, and a number.
+static TICKET_REFERENCE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[^A-Za-z0-9])([A-Za-z][A-Za-z0-9]{0,9})-([0-9]{1,7})(?:$|[^A-Za-z0-9])").unwrap()
+});
+
+/// A git branch for working on a ticket, e.g. `kenny/metal-42-fix-ipmi-auth`:
+/// the prefix (usually who works on it), the lowercased identifier, and
+/// the start of the title.
+pub fn branch_name(prefix: Option<&str>, identifier: &str, title: &str) -> String {
+    let mut slug = String::new();
+    for word in title.split(|c: char| !c.is_ascii_alphanumeric()).filter(|word| !word.is_empty()) {
+        if !slug.is_empty() && slug.len() + 1 + word.len() > MAX_BRANCH_SLUG_LEN {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word.to_lowercase());
+    }
+    slug.truncate(MAX_BRANCH_SLUG_LEN);
+
+    let mut name = identifier.to_lowercase();
+    if !slug.is_empty() {
+        name = format!("{}-{}", name, slug);
+    }
+    match prefix.map(branch_prefix).filter(|prefix| !prefix.is_empty()) {
+        Some(prefix) => format!("{}/{}", prefix, name),
+        None => name,
+    }
+}
+
+/// `prefix` with the characters git refuses in branch names dropped and
+/// surrounding slashes trimmed.
+fn branch_prefix(prefix: &str) -> String {
+    let allowed: String = prefix
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | '-' | '_' | '.'))
+        .collect();
+    allowed.trim_matches('/').to_lowercase()
+}
+
+/// Ticket identifiers mentioned in a branch name or commit message, in
+/// upper case and in order of first mention. Text like `utf-8` matches
+/// too, so callers check the team keys against the provider's.
+pub fn ticket_references(text: &str) -> Vec<String> {
+    let mut references: Vec<String> = Vec::new();
+    // Matches consume the separator after them, so adjacent references
+    // like "ENG-1,ENG-2" are found by searching again from it
+    let mut start = 0;
+    while let Some(captures) = TICKET_REFERENCE.captures_at(text, start) {
+        let (key, number) = (&captures[1], &captures[2]);
+        let reference = format!("{}-{}", key.to_uppercase(), number);
+        if !references.contains(&reference) {
+            references.push(reference);
+        }
+        start = captures.get(2).map_or(text.len(), |number| number.end());
+    }
+    references
+}
@@ -0,0 +1,65 @@
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::ports::TicketService;
+
+/// How long a readiness result is reused before providers are probed again.
+pub const DEFAULT_READINESS_TTL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderReadiness {
+    pub provider: String,
+    pub reachable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Whether every provider answered its last probe.
+#[derive(Debug, Clone, Serialize)]
+pub struct Readiness {
+    pub ready: bool,
+    pub providers: Vec<ProviderReadiness>,
+    /// Seconds since the providers were probed.
+    pub checked_secs_ago: u64,
+}
+
+/// Probes each provider with a current-user lookup for readiness checks.
+/// Results are reused for the TTL, so frequent probes from an orchestrator
+/// cost one API call per provider per TTL, and concurrent probes share one
+/// round of calls.
+pub struct ReadinessCheck {
+    /// Services below the response cache, so a probe reaches the provider.
+    providers: Vec<(String, Arc<dyn TicketService + Send + Sync>)>,
+    ttl: Duration,
+    last: futures::lock::Mutex<Option<(Instant, Vec<ProviderReadiness>)>>,
+}
+
+impl ReadinessCheck {
+    pub fn new(providers: Vec<(String, Arc<dyn TicketService + Send + Sync>)>, ttl: Duration) -> Self {
+        Self {
+            providers,
+            ttl,
+            last: futures::lock::Mutex::new(None),
+        }
+    }
+
+    pub async fn readiness(&self) -> Readiness {
+        let mut last = self.last.lock().await;
+        let fresh = last.as_ref().is_some_and(|(checked_at, _)| checked_at.elapsed() < self.ttl);
+        if !fresh {
+            let probes = self.providers.iter().map(|(provider, service)| async move {
+                let error = service.get_current_user().await.err().map(|e| format!("{:#}", e));
+                ProviderReadiness { provider: provider.clone(), reachable: error.is_none(), error }
+            });
+            *last = Some((Instant::now(), futures::future::join_all(probes).await));
+        }
+
+        let (checked_at, providers) = last.as_ref().expect("probed above");
+        Readiness {
+            ready: providers.iter().all(|provider| provider.reachable),
+            providers: providers.clone(),
+            checked_secs_ago: checked_at.elapsed().as_secs(),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::domain::CreateTicketRequest;
+
+/// How long a key is remembered; retries come within minutes, so a day is
+/// plenty.
+pub const DEFAULT_IDEMPOTENCY_TTL_HOURS: i64 = 24;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IdempotencyRecord {
+    /// Hash of the request the key was first used with.
+    fingerprint: String,
+    ticket_id: String,
+    created_at: DateTime<Utc>,
+}
+
+/// Remembers which ticket each idempotency key created, so a create retried
+/// after a timeout returns the original ticket instead of a duplicate. With
+/// a file, keys survive restarts.
+pub struct IdempotencyStore {
+    path: Option<PathBuf>,
+    ttl: Duration,
+    records: Mutex<HashMap<String, IdempotencyRecord>>,
+    /// Held from lookup to remember, so a retry racing the original call
+    /// waits for it instead of creating a second ticket.
+    creating: futures::lock::Mutex<()>,
+}
+
+impl Default for IdempotencyStore {
+    fn default() -> Self {
+        Self::in_memory()
+    }
+}
+
+impl IdempotencyStore {
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            ttl: Duration::hours(DEFAULT_IDEMPOTENCY_TTL_HOURS),
+            records: Mutex::new(HashMap::new()),
+            creating: futures::lock::Mutex::new(()),
+        }
+    }
+
+    /// Loads keys saved by earlier runs from `path`, which need not exist yet.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let records = match std::fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .with_context(|| format!("Invalid idempotency keys in {}", path.display()))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read idempotency keys from {}", path.display())),
+        };
+
+        Ok(Self {
+            path: Some(path),
+            records: Mutex::new(records),
+            ..Self::in_memory()
+        })
+    }
+
+    pub(crate) async fn lock(&self) -> futures::lock::MutexGuard<'_, ()> {
+        self.creating.lock().await
+    }
+
+    /// The ticket already created under `key`, if any. Reusing a key for a
+    /// different request is an error rather than a silent no-op.
+    pub(crate) fn lookup(&self, key: &str, request: &CreateTicketRequest) -> Result<Option<String>> {
+        let records = self.records.lock().map_err(|_| anyhow!("Idempotency key lock poisoned"))?;
+        let Some(record) = records.get(key).filter(|r| r.created_at + self.ttl > Utc::now()) else {
+            return Ok(None);
+        };
+        if record.fingerprint != Self::fingerprint(request)? {
+            return Err(anyhow!("Idempotency key {} was already used for a different ticket", key));
+        }
+        Ok(Some(record.ticket_id.clone()))
+    }
+
+    pub(crate) fn remember(&self, key: &str, request: &CreateTicketRequest, ticket_id: &str) -> Result<()> {
+        let mut records = self.records.lock().map_err(|_| anyhow!("Idempotency key lock poisoned"))?;
+        let now = Utc::now();
+        records.retain(|_, record| record.created_at + self.ttl > now);
+        records.insert(key.to_string(), IdempotencyRecord {
+            fingerprint: Self::fingerprint(request)?,
+            ticket_id: ticket_id.to_string(),
+            created_at: now,
+        });
+
+        if let Some(path) = &self.path {
+            std::fs::write(path, serde_json::to_string(&*records)?)
+                .with_context(|| format!("Failed to save idempotency keys to {}", path.display()))?;
+        }
+        Ok(())
+    }
+
+    fn fingerprint(request: &CreateTicketRequest) -> Result<String> {
+        // Through `Value`, whose maps are sorted, so custom fields hash the
+        // same in every run
+        let digest = Sha256::digest(serde_json::to_vec(&serde_json::to_value(request)?)?);
+        Ok(digest.iter().map(|byte| format!("{:02x}", byte)).collect())
+    }
+}
@@ -0,0 +1,120 @@
+use crate::core::batch::TicketSpec;
+use crate::domain::{CreateTicketRequest, RichText};
+
+struct Node {
+    request: CreateTicketRequest,
+    description: Vec<String>,
+    children: Vec<usize>,
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    let title = rest.trim().trim_end_matches('#').trim();
+    (!title.is_empty()).then_some((level, title))
+}
+
+/// Returns the indent and text of a `- [ ]` / `- [x]` checklist item.
+fn checklist_item(line: &str) -> Option<(usize, &str)> {
+    let indent = line.len() - line.trim_start().len();
+    let rest = line.trim_start();
+    let rest = rest.strip_prefix("- ")
+        .or_else(|| rest.strip_prefix("* "))
+        .or_else(|| rest.strip_prefix("+ "))?;
+    let rest = rest.strip_prefix("[ ]")
+        .or_else(|| rest.strip_prefix("[x]"))
+        .or_else(|| rest.strip_prefix("[X]"))?;
+    let title = rest.trim();
+    (!title.is_empty()).then_some((indent, title))
+}
+
+/// Parses a markdown plan into ticket specs: headings become tickets nested by
+/// heading level, checklist items become sub-tickets of the heading above them
+/// (nested by indentation), and any other text becomes the description of the
+/// ticket it sits under. Headings inside fenced code blocks are ignored.
+pub fn parse_markdown_plan(markdown: &str) -> Vec<TicketSpec> {
+    let mut nodes: Vec<Node> = Vec::new();
+    let mut roots: Vec<usize> = Vec::new();
+    let mut headings: Vec<(usize, usize)> = Vec::new();
+    let mut items: Vec<(usize, usize)> = Vec::new();
+    let mut in_fence = false;
+
+    fn add(nodes: &mut Vec<Node>, roots: &mut Vec<usize>, parent: Option<usize>, title: &str) -> usize {
+        let index = nodes.len();
+        nodes.push(Node {
+            request: CreateTicketRequest {
+                title: title.to_string(),
+                ..Default::default()
+            },
+            description: Vec::new(),
+            children: Vec::new(),
+        });
+        match parent {
+            Some(parent) => nodes[parent].children.push(index),
+            None => roots.push(index),
+        }
+        index
+    }
+
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+
+        if !in_fence {
+            if let Some((level, title)) = heading(line) {
+                while headings.last().is_some_and(|&(l, _)| l >= level) {
+                    headings.pop();
+                }
+                let parent = headings.last().map(|&(_, index)| index);
+                let index = add(&mut nodes, &mut roots, parent, title);
+                headings.push((level, index));
+                items.clear();
+                continue;
+            }
+
+            if let Some((indent, title)) = checklist_item(line) {
+                while items.last().is_some_and(|&(i, _)| i >= indent) {
+                    items.pop();
+                }
+                let parent = items.last()
+                    .or(headings.last())
+                    .map(|&(_, index)| index);
+                let index = add(&mut nodes, &mut roots, parent, title);
+                items.push((indent, index));
+                continue;
+            }
+        }
+
+        // Indented text under a checklist item belongs to that item
+        let indent = line.len() - trimmed.len();
+        let owner = match items.last() {
+            Some(&(item_indent, index)) if indent > item_indent && !trimmed.is_empty() => Some(index),
+            _ => headings.last().map(|&(_, index)| index),
+        };
+        if let Some(owner) = owner {
+            nodes[owner].description.push(line.to_string());
+        }
+    }
+
+    fn build(index: usize, nodes: &[Node]) -> TicketSpec {
+        let node = &nodes[index];
+        let description = node.description.join("\n").trim().to_string();
+        let mut request = node.request.clone();
+        request.description = (!description.is_empty()).then_some(RichText::Markdown(description));
+
+        TicketSpec {
+            request,
+            children: node.children.iter().map(|&child| build(child, nodes)).collect(),
+        }
+    }
+
+    roots.iter().map(|&index| build(index, &nodes)).collect()
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::ports::{TicketService, TicketServiceMiddleware};
+
+/// An ordered list of middlewares applied around a provider. The first
+/// layer added sits closest to the provider, so
+/// `MiddlewareStack::new().layer(rate_limit).layer(cache)` answers cache
+/// hits without spending rate limit tokens.
+///
+/// Audit logging and redaction are not middlewares: they act on tool
+/// arguments and results, which never reach the provider, so they stay in
+/// the MCP server.
+#[derive(Clone, Default)]
+pub struct MiddlewareStack {
+    layers: Vec<Arc<dyn TicketServiceMiddleware>>,
+}
+
+impl MiddlewareStack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a layer outside the ones already added.
+    pub fn layer(mut self, middleware: impl TicketServiceMiddleware + 'static) -> Self {
+        self.layers.push(Arc::new(middleware));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Wraps `service` in every layer, innermost first.
+    pub fn wrap(&self, service: Arc<dyn TicketService + Send + Sync>) -> Arc<dyn TicketService + Send + Sync> {
+        self.layers.iter().fold(service, |service, layer| layer.wrap(service))
+    }
+}
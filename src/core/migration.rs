@@ -0,0 +1,325 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::path::Path;
+
+use crate::core::{ImportReport, ProviderRegistry, WorkspaceSnapshot};
+use crate::domain::{Label, Project, State, Team, User, Workflow, WorkflowState};
+
+/// How a snapshot's teams, states, labels, and people translate into the
+/// provider it is imported into. Anything not listed is matched as it is:
+/// teams by key, states by name and then by type, labels by name, and
+/// people by email.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FieldMapping {
+    /// Source team key to target team id or key.
+    #[serde(default)]
+    pub teams: BTreeMap<String, String>,
+    /// Team (id or key) for tickets whose team has no counterpart.
+    #[serde(default)]
+    pub fallback_team: Option<String>,
+    /// Source state name to target state name.
+    #[serde(default)]
+    pub states: BTreeMap<String, String>,
+    /// Source label name to target label name.
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    /// Source email to target email, for people whose address differs.
+    #[serde(default)]
+    pub users: BTreeMap<String, String>,
+}
+
+/// Loads a field mapping from a JSON file.
+pub fn load_field_mapping(path: impl AsRef<Path>) -> Result<FieldMapping> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read field mapping from {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid field mapping in {}", path.display()))
+}
+
+/// A migration between two providers of the registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationConfig {
+    /// Provider the workspace is exported from.
+    pub source: String,
+    /// Provider it is re-created in.
+    pub target: String,
+    #[serde(default)]
+    pub mapping: FieldMapping,
+}
+
+/// Loads a migration configuration from a JSON file.
+pub fn load_migration_config(path: impl AsRef<Path>) -> Result<MigrationConfig> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read migration configuration from {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid migration configuration in {}", path.display()))
+}
+
+/// Called as an import advances with the steps done, the steps in total,
+/// and what was just done.
+pub type ImportProgress<'a> = &'a (dyn Fn(usize, usize, &str) + Send + Sync);
+
+/// What the target provider already has, looked up once per import.
+pub struct ImportTargets {
+    pub teams: Vec<Team>,
+    /// User ids by lowercased email.
+    pub users: HashMap<String, String>,
+    pub labels: Vec<Label>,
+    pub projects: Vec<Project>,
+    /// Workflows by team id.
+    pub workflows: HashMap<String, Workflow>,
+}
+
+/// Resolves what a snapshot refers to into the target, per a mapping.
+pub struct Resolver<'a> {
+    mapping: &'a FieldMapping,
+    targets: &'a ImportTargets,
+    source_users: HashMap<&'a str, &'a User>,
+}
+
+impl<'a> Resolver<'a> {
+    pub fn new(snapshot: &'a WorkspaceSnapshot, mapping: &'a FieldMapping, targets: &'a ImportTargets) -> Self {
+        let source_users = snapshot.workspace.teams
+            .iter()
+            .flat_map(|team| &team.members)
+            .map(|user| (user.id.as_str(), user))
+            .collect();
+        Self { mapping, targets, source_users }
+    }
+
+    fn find_team(&self, team: &str) -> Option<&'a Team> {
+        self.targets.teams.iter().find(|t| t.id == team || t.key.eq_ignore_ascii_case(team))
+    }
+
+    /// The team tickets of the source team `key` go to.
+    pub fn team(&self, key: &str) -> Option<&'a Team> {
+        let mapped = self.mapping.teams.get(key).map(String::as_str).unwrap_or(key);
+        self.find_team(mapped)
+            .or_else(|| self.mapping.fallback_team.as_deref().and_then(|team| self.find_team(team)))
+    }
+
+    /// A person in the snapshot, by their source id.
+    pub fn source_user(&self, user_id: &str) -> Option<&'a User> {
+        self.source_users.get(user_id).copied()
+    }
+
+    /// The email a source user is matched by.
+    fn email(&self, user: &User) -> String {
+        self.mapping.users.get(&user.email).unwrap_or(&user.email).to_lowercase()
+    }
+
+    /// The target id of the person with the source id `user_id`.
+    pub fn user(&self, user_id: &str) -> Option<String> {
+        let user = self.source_user(user_id)?;
+        self.targets.users.get(&self.email(user)).cloned()
+    }
+
+    /// The name a source label has in the target.
+    pub fn label_name<'n>(&'n self, name: &'n str) -> &'n str {
+        self.mapping.labels.get(name).map(String::as_str).unwrap_or(name)
+    }
+
+    pub fn existing_label(&self, name: &str) -> Option<&'a Label> {
+        let name = self.label_name(name);
+        self.targets.labels.iter().find(|label| label.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn existing_project(&self, project: &Project) -> Option<&'a Project> {
+        self.targets.projects.iter().find(|p| p.name.eq_ignore_ascii_case(&project.name))
+    }
+
+    /// The state in `team_id`'s workflow matching a source state.
+    pub fn state(&self, team_id: &str, state: &State) -> Option<&'a WorkflowState> {
+        let workflow = self.targets.workflows.get(team_id)?;
+        let name = self.mapping.states.get(&state.name).unwrap_or(&state.name);
+        workflow.states
+            .iter()
+            .find(|s| s.name.eq_ignore_ascii_case(name))
+            .or_else(|| workflow.states.iter().find(|s| s.type_ == state.type_))
+    }
+}
+
+/// Whether an import would create something or reuse what exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlannedAction {
+    Create,
+    Reuse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedItem {
+    pub name: String,
+    pub action: PlannedAction,
+}
+
+/// A ticket an import would create, as it would land in the target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedImport {
+    pub identifier: String,
+    pub title: String,
+    /// Target team key; without one the ticket would fail to import.
+    pub team: Option<String>,
+    pub state: Option<String>,
+    /// Target email of the assignee, when they are matched.
+    pub assignee: Option<String>,
+    pub labels: Vec<String>,
+    pub comments: usize,
+}
+
+/// What importing a snapshot would do, without doing any of it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MigrationPlan {
+    pub labels: Vec<PlannedItem>,
+    pub projects: Vec<PlannedItem>,
+    pub tickets: Vec<PlannedImport>,
+    /// Emails of people with no counterpart; their tickets stay unassigned.
+    pub unmatched_users: Vec<String>,
+    /// Source teams with no counterpart and no fallback team.
+    pub unmatched_teams: Vec<String>,
+}
+
+impl MigrationPlan {
+    pub fn build(snapshot: &WorkspaceSnapshot, resolver: &Resolver<'_>) -> Self {
+        let action = |exists: bool| if exists { PlannedAction::Reuse } else { PlannedAction::Create };
+        let labels = snapshot.labels
+            .iter()
+            .map(|label| PlannedItem {
+                name: resolver.label_name(&label.name).to_string(),
+                action: action(resolver.existing_label(&label.name).is_some()),
+            })
+            .collect();
+        let projects = snapshot.projects
+            .iter()
+            .map(|project| PlannedItem {
+                name: project.name.clone(),
+                action: action(resolver.existing_project(project).is_some()),
+            })
+            .collect();
+
+        let mut unmatched_users = BTreeSet::new();
+        let mut unmatched_teams = BTreeSet::new();
+        let tickets = snapshot.tickets
+            .iter()
+            .map(|entry| {
+                let ticket = &entry.ticket;
+                let team = resolver.team(&entry.team);
+                if team.is_none() {
+                    unmatched_teams.insert(entry.team.clone());
+                }
+                let assignee = ticket.assignee_id.as_deref().and_then(|id| {
+                    let source = resolver.source_user(id)?;
+                    let target = resolver.user(id);
+                    if target.is_none() {
+                        unmatched_users.insert(source.email.clone());
+                    }
+                    target.map(|_| resolver.email(source))
+                });
+                PlannedImport {
+                    identifier: ticket.identifier.clone(),
+                    title: ticket.title.clone(),
+                    team: team.map(|team| team.key.clone()),
+                    state: team
+                        .and_then(|team| resolver.state(&team.id, &ticket.state))
+                        .map(|state| state.name.clone()),
+                    assignee,
+                    labels: ticket.labels.iter().map(|name| resolver.label_name(name).to_string()).collect(),
+                    comments: entry.comments.len(),
+                }
+            })
+            .collect();
+
+        Self {
+            labels,
+            projects,
+            tickets,
+            unmatched_users: unmatched_users.into_iter().collect(),
+            unmatched_teams: unmatched_teams.into_iter().collect(),
+        }
+    }
+}
+
+/// One line per change, `+` for what would be created, `=` for what is
+/// reused, and `!` for what would fail.
+impl fmt::Display for MigrationPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mark = |action| match action {
+            PlannedAction::Create => '+',
+            PlannedAction::Reuse => '=',
+        };
+        for label in &self.labels {
+            writeln!(f, "{} label {}", mark(label.action), label.name)?;
+        }
+        for project in &self.projects {
+            writeln!(f, "{} project {}", mark(project.action), project.name)?;
+        }
+        for ticket in &self.tickets {
+            let Some(team) = &ticket.team else {
+                writeln!(f, "! {} {} (no team to import it into)", ticket.identifier, ticket.title)?;
+                continue;
+            };
+            write!(f, "+ {} {} -> {}", ticket.identifier, ticket.title, team)?;
+            if let Some(state) = &ticket.state {
+                write!(f, ", {}", state)?;
+            }
+            if let Some(assignee) = &ticket.assignee {
+                write!(f, ", {}", assignee)?;
+            }
+            if ticket.comments > 0 {
+                write!(f, ", {} comments", ticket.comments)?;
+            }
+            writeln!(f)?;
+        }
+        for email in &self.unmatched_users {
+            writeln!(f, "! no user matches {}; their tickets stay unassigned", email)?;
+        }
+        for team in &self.unmatched_teams {
+            writeln!(f, "! no team matches {}; map it or set a fallback team", team)?;
+        }
+        let creates = |items: &[PlannedItem]| items.iter().filter(|item| item.action == PlannedAction::Create).count();
+        write!(
+            f,
+            "Would create {} labels, {} projects, and {} tickets",
+            creates(&self.labels), creates(&self.projects), self.tickets.iter().filter(|t| t.team.is_some()).count()
+        )
+    }
+}
+
+/// The plan of a dry run, or the report of a real one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MigrationOutcome {
+    Plan(MigrationPlan),
+    Report(ImportReport),
+}
+
+impl fmt::Display for MigrationOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plan(plan) => plan.fmt(f),
+            Self::Report(report) => report.fmt(f),
+        }
+    }
+}
+
+/// Exports the source provider's workspace and re-creates it in the
+/// target, or with `dry_run` only plans what that would do.
+pub async fn run_migration(
+    registry: &ProviderRegistry,
+    config: &MigrationConfig,
+    dry_run: bool,
+    progress: ImportProgress<'_>,
+) -> Result<MigrationOutcome> {
+    let source = registry.get(Some(&config.source))?;
+    let target = registry.get(Some(&config.target))?;
+    progress(0, 0, &format!("Exporting {}", config.source));
+    let snapshot = source.export_workspace(&config.source).await?;
+    if dry_run {
+        return Ok(MigrationOutcome::Plan(target.plan_import(&snapshot, &config.mapping).await?));
+    }
+    Ok(MigrationOutcome::Report(target.import_workspace(&snapshot, &config.mapping, progress).await?))
+}
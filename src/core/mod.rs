@@ -1,3 +1,85 @@
+pub mod alerts;
 pub mod application;
+pub mod audit;
+pub mod batch;
+pub mod cache;
+pub mod confirmation;
+pub mod csv_import;
+pub mod dates;
+pub mod doctor;
+pub mod duplicates;
+pub mod events;
+pub mod export;
+pub mod git_links;
+pub mod health;
+pub mod idempotency;
+pub mod markdown_plan;
+pub mod middleware_stack;
+pub mod migration;
+pub mod output_limits;
+pub mod planning;
+pub mod policy;
+pub mod prefetch;
+pub mod profiles;
+pub mod pseudonymize;
+pub mod rate_limit;
+pub mod redaction;
+pub mod registry;
+pub mod release_notes;
+pub mod reminders;
+pub mod retry;
+pub mod saved_views;
+pub mod schedule;
+#[cfg(feature = "local-search")]
+pub mod search_index;
+pub mod semantic;
+pub mod snapshot;
+pub mod stale;
+pub mod sync;
+pub mod table;
+pub mod templates;
+pub mod user_tokens;
+pub mod working_set;
 
-pub use application::*;
\ No newline at end of file
+pub use alerts::*;
+pub use application::*;
+pub use audit::*;
+pub use batch::*;
+pub use cache::*;
+pub use confirmation::*;
+pub use csv_import::*;
+pub use dates::*;
+pub use doctor::*;
+pub use duplicates::*;
+pub use events::*;
+pub use export::*;
+pub use git_links::*;
+pub use health::*;
+pub use idempotency::*;
+pub use markdown_plan::*;
+pub use middleware_stack::*;
+pub use migration::*;
+pub use output_limits::*;
+pub use planning::*;
+pub use policy::*;
+pub use prefetch::*;
+pub use profiles::*;
+pub use pseudonymize::*;
+pub use rate_limit::*;
+pub use redaction::*;
+pub use registry::*;
+pub use release_notes::*;
+pub use reminders::*;
+pub use retry::*;
+pub use saved_views::*;
+pub use schedule::*;
+#[cfg(feature = "local-search")]
+pub use search_index::*;
+pub use semantic::*;
+pub use snapshot::*;
+pub use stale::*;
+pub use sync::*;
+pub use table::*;
+pub use templates::*;
+pub use user_tokens::*;
+pub use working_set::*;
@@ -0,0 +1,145 @@
+use serde_json::{Map, Value};
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+/// Result fields holding ticket lists.
+pub const TICKET_LIST_FIELDS: &[&str] = &["issues", "tickets"];
+
+/// Ticket fields kept in summary-only mode; `provider` and `score` come from
+/// cross-provider and semantic searches.
+const SUMMARY_FIELDS: &[&str] = &[
+    "provider", "id", "identifier", "title", "state", "priority", "assignee_id", "due_date", "updated_at", "url", "score",
+];
+
+/// Truncated results kept for `get_more_results`; older ones are dropped.
+const MAX_CONTINUATIONS: usize = 16;
+
+/// Caps on how much of a result reaches the client, so a search matching
+/// thousands of tickets does not fill the model's context window.
+#[derive(Debug, Clone, Default)]
+pub struct OutputLimits {
+    /// Tickets per response; the rest wait behind a continuation cursor.
+    pub max_tickets: Option<usize>,
+    /// Longer descriptions are cut and end in `…`.
+    pub max_description_chars: Option<usize>,
+    /// Ticket lists carry only headline fields, without descriptions.
+    pub summary_only: bool,
+}
+
+impl OutputLimits {
+    pub fn is_unlimited(&self) -> bool {
+        self.max_tickets.is_none() && self.max_description_chars.is_none() && !self.summary_only
+    }
+
+    /// Removes ticket list items beyond `max_tickets` from `result`,
+    /// returning the list's field and the removed items.
+    pub fn split_tickets(&self, result: &mut Value) -> Option<(String, Vec<Value>)> {
+        let max_tickets = self.max_tickets?;
+        let field = TICKET_LIST_FIELDS.iter().find(|field| result.get(**field).is_some_and(Value::is_array))?;
+        let items = result.get_mut(*field)?.as_array_mut()?;
+        if items.len() <= max_tickets {
+            return None;
+        }
+        Some((field.to_string(), items.split_off(max_tickets)))
+    }
+
+    /// Summarizes ticket lists and shortens descriptions in place. Returns
+    /// whether anything was left out.
+    pub fn shrink(&self, result: &mut Value) -> bool {
+        let mut shrunk = false;
+        if self.summary_only {
+            for field in TICKET_LIST_FIELDS {
+                if let Some(items) = result.get_mut(*field).and_then(Value::as_array_mut) {
+                    for item in items.iter_mut() {
+                        shrunk |= summarize_ticket(item);
+                    }
+                }
+            }
+        }
+        if let Some(max_chars) = self.max_description_chars {
+            shrunk |= shorten_descriptions(result, max_chars);
+        }
+        shrunk
+    }
+}
+
+/// Keeps a ticket's `SUMMARY_FIELDS`, with its state reduced to the name.
+fn summarize_ticket(ticket: &mut Value) -> bool {
+    let Value::Object(fields) = ticket else {
+        return false;
+    };
+    let before = fields.len();
+    let mut summary: Map<String, Value> = SUMMARY_FIELDS
+        .iter()
+        .filter_map(|name| Some((name.to_string(), fields.remove(*name)?)))
+        .collect();
+    if let Some(state) = summary.get_mut("state") {
+        if let Some(name) = state.get("name").cloned() {
+            *state = name;
+        }
+    }
+    let shrunk = summary.len() < before;
+    *fields = summary;
+    shrunk
+}
+
+fn shorten_descriptions(value: &mut Value, max_chars: usize) -> bool {
+    match value {
+        Value::Array(items) => items.iter_mut().fold(false, |shrunk, item| shorten_descriptions(item, max_chars) | shrunk),
+        Value::Object(fields) => fields.iter_mut().fold(false, |shrunk, (key, field)| {
+            let cut = if key == "description" {
+                // Plain strings, or rich text as `{"format", "content"}`
+                match field {
+                    Value::String(text) => shorten(text, max_chars),
+                    Value::Object(rich) => match rich.get_mut("content") {
+                        Some(Value::String(text)) => shorten(text, max_chars),
+                        _ => false,
+                    },
+                    _ => false,
+                }
+            } else {
+                shorten_descriptions(field, max_chars)
+            };
+            cut | shrunk
+        }),
+        _ => false,
+    }
+}
+
+fn shorten(text: &mut String, max_chars: usize) -> bool {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => {
+            text.truncate(end);
+            text.push('…');
+            true
+        }
+        None => false,
+    }
+}
+
+/// Remainders of truncated results, handed out again by cursor.
+#[derive(Default)]
+pub struct Continuations {
+    pending: Mutex<VecDeque<(String, String, Vec<Value>)>>,
+}
+
+impl Continuations {
+    /// Keeps `items` of `field` and returns the cursor that retrieves them.
+    pub fn stash(&self, field: String, items: Vec<Value>) -> String {
+        let cursor = uuid::Uuid::new_v4().to_string();
+        if let Ok(mut pending) = self.pending.lock() {
+            if pending.len() >= MAX_CONTINUATIONS {
+                pending.pop_front();
+            }
+            pending.push_back((cursor.clone(), field, items));
+        }
+        cursor
+    }
+
+    /// The field and items behind `cursor`; each cursor works once.
+    pub fn take(&self, cursor: &str) -> Option<(String, Vec<Value>)> {
+        let mut pending = self.pending.lock().ok()?;
+        let position = pending.iter().position(|(c, _, _)| c == cursor)?;
+        pending.remove(position).map(|(_, field, items)| (field, items))
+    }
+}
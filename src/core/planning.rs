@@ -0,0 +1,146 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{Priority, ProjectMilestone, Ticket};
+use crate::domain::{Team, User};
+
+pub const DEFAULT_PLANNING_HORIZON_DAYS: i64 = 14;
+pub const DEFAULT_CAPACITY_PER_MEMBER: f32 = 10.0;
+
+/// Unestimated tickets still take someone's time; count them as one point.
+const UNESTIMATED_POINTS: f32 = 1.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PlanningParameters {
+    pub now: DateTime<Utc>,
+    pub horizon_end: DateTime<Utc>,
+    pub capacity_per_member: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanningTeam {
+    pub id: String,
+    pub name: String,
+    pub key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanningCandidate {
+    pub score: f32,
+    pub ticket: Ticket,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberCapacity {
+    pub user_id: String,
+    pub name: String,
+    pub capacity: f32,
+    pub committed: f32,
+    pub available: f32,
+    pub carry_over_count: usize,
+}
+
+/// Everything an LLM needs to run a sprint-planning conversation for one team.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanningBundle {
+    pub team: PlanningTeam,
+    pub horizon_start: DateTime<Utc>,
+    pub horizon_end: DateTime<Utc>,
+    pub candidates: Vec<PlanningCandidate>,
+    pub capacity: Vec<MemberCapacity>,
+    pub carry_over: Vec<Ticket>,
+    pub milestones: Vec<ProjectMilestone>,
+    pub warnings: Vec<String>,
+}
+
+fn points(ticket: &Ticket) -> f32 {
+    ticket.estimate.unwrap_or(UNESTIMATED_POINTS)
+}
+
+/// Ranks a backlog ticket for the next cycle: priority dominates, then due
+/// dates inside (or before) the horizon, then age so old tickets surface.
+pub fn candidate_score(ticket: &Ticket, now: DateTime<Utc>, horizon_end: DateTime<Utc>) -> f32 {
+    let priority = match &ticket.priority {
+        Priority::Highest => 100.0,
+        Priority::High => 75.0,
+        Priority::Medium => 50.0,
+        Priority::Custom(_) => 40.0,
+        Priority::Low => 25.0,
+        Priority::Lowest => 10.0,
+        Priority::None => 0.0,
+    };
+
+    let due = match ticket.due_date {
+        Some(due) if due < now => 80.0,
+        Some(due) if due <= horizon_end => 50.0,
+        _ => 0.0,
+    };
+
+    let age_weeks = (now - ticket.created_at).num_weeks().max(0) as f32;
+
+    priority + due + age_weeks.min(20.0)
+}
+
+impl PlanningBundle {
+    pub fn build(
+        team: &Team,
+        members: &[User],
+        backlog: Vec<Ticket>,
+        carry_over: Vec<Ticket>,
+        milestones: Vec<ProjectMilestone>,
+        parameters: PlanningParameters,
+    ) -> Self {
+        let PlanningParameters { now, horizon_end, capacity_per_member } = parameters;
+
+        let mut candidates: Vec<PlanningCandidate> = backlog
+            .into_iter()
+            .map(|ticket| PlanningCandidate {
+                score: candidate_score(&ticket, now, horizon_end),
+                ticket,
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        let capacity = members
+            .iter()
+            .filter(|member| member.active)
+            .map(|member| {
+                let assigned: Vec<&Ticket> = carry_over
+                    .iter()
+                    .filter(|t| t.assignee_id.as_deref() == Some(member.id.as_str()))
+                    .collect();
+                let committed: f32 = assigned.iter().map(|t| points(t)).sum();
+
+                MemberCapacity {
+                    user_id: member.id.clone(),
+                    name: member.name.clone(),
+                    capacity: capacity_per_member,
+                    committed,
+                    available: (capacity_per_member - committed).max(0.0),
+                    carry_over_count: assigned.len(),
+                }
+            })
+            .collect();
+
+        let mut milestones: Vec<ProjectMilestone> = milestones
+            .into_iter()
+            .filter(|m| m.target_date.is_some_and(|date| date >= now && date <= horizon_end))
+            .collect();
+        milestones.sort_by_key(|m| m.target_date);
+
+        Self {
+            team: PlanningTeam {
+                id: team.id.clone(),
+                name: team.name.clone(),
+                key: team.key.clone(),
+            },
+            horizon_start: now,
+            horizon_end,
+            candidates,
+            capacity,
+            carry_over,
+            milestones,
+            warnings: Vec::new(),
+        }
+    }
+}
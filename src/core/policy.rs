@@ -0,0 +1,64 @@
+use anyhow::{Result, anyhow};
+
+/// Which tools a deployment exposes. Deny patterns win over allow patterns;
+/// an empty allow list allows everything. Patterns match whole tool names,
+/// with `*` standing for any run of characters (`get_*`, `*_tickets`).
+#[derive(Debug, Clone, Default)]
+pub struct ToolPolicy {
+    /// Hides every tool that writes to a provider.
+    pub read_only: bool,
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+impl ToolPolicy {
+    /// Splits a comma-separated pattern list, as given in the environment.
+    pub fn parse_patterns(value: &str) -> Vec<String> {
+        value.split(',')
+            .map(|pattern| pattern.trim().to_string())
+            .filter(|pattern| !pattern.is_empty())
+            .collect()
+    }
+
+    pub fn permits(&self, tool: &str, mutating: bool) -> bool {
+        if self.read_only && mutating {
+            return false;
+        }
+        if self.deny.iter().any(|pattern| matches_pattern(pattern, tool)) {
+            return false;
+        }
+        self.allow.is_empty() || self.allow.iter().any(|pattern| matches_pattern(pattern, tool))
+    }
+
+    pub fn check(&self, tool: &str, mutating: bool) -> Result<()> {
+        if self.permits(tool, mutating) {
+            return Ok(());
+        }
+        if self.read_only && mutating {
+            Err(anyhow!("Tool {} is disabled: this server is read-only", tool))
+        } else {
+            Err(anyhow!("Tool {} is disabled by this server's tool policy", tool))
+        }
+    }
+}
+
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No `*`: the whole name has to match
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
@@ -0,0 +1,122 @@
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::core::{CachingTicketService, EventBus, SemanticIndex};
+#[cfg(feature = "local-search")]
+use crate::core::TicketIndex;
+use crate::domain::{Ticket, WebhookAction, WebhookEntity, WebhookEvent};
+
+/// Keeps one provider's cache warm: every `interval` it reloads the current
+/// user's assigned tickets and workspace metadata, and publishes the tickets
+/// that changed since the last pass so subscribers learn about them even
+/// without webhooks.
+pub struct Prefetcher {
+    provider: String,
+    cache: Arc<CachingTicketService>,
+    events: EventBus,
+    interval: Duration,
+    #[cfg(feature = "local-search")]
+    index: Option<Arc<TicketIndex>>,
+    semantic: Option<Arc<SemanticIndex>>,
+}
+
+impl Prefetcher {
+    pub fn new(provider: impl Into<String>, cache: Arc<CachingTicketService>, events: EventBus, interval: Duration) -> Self {
+        Self {
+            provider: provider.into(),
+            cache,
+            events,
+            interval,
+            #[cfg(feature = "local-search")]
+            index: None,
+            semantic: None,
+        }
+    }
+
+    /// Also adds every prefetched ticket to `index`.
+    #[cfg(feature = "local-search")]
+    pub fn with_index(mut self, index: Arc<TicketIndex>) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Also embeds every prefetched ticket into `semantic`.
+    pub fn with_semantic_index(mut self, semantic: Arc<SemanticIndex>) -> Self {
+        self.semantic = Some(semantic);
+        self
+    }
+
+    /// Refreshes until the task is dropped. A failed pass is logged and
+    /// retried at the next interval.
+    pub async fn run(self) {
+        info!("Prefetching {} every {:?}", self.provider, self.interval);
+        let mut previous: Option<HashMap<String, Ticket>> = None;
+        loop {
+            match self.cache.load_snapshot().await {
+                Ok(snapshot) => {
+                    let current: HashMap<String, Ticket> = snapshot.assigned
+                        .iter()
+                        .map(|ticket| (ticket.id.clone(), ticket.clone()))
+                        .collect();
+                    // Before storing: listeners drop cached tickets on these
+                    if let Some(previous) = &previous {
+                        self.publish_changes(previous, &current);
+                    }
+                    #[cfg(feature = "local-search")]
+                    if let Some(index) = &self.index {
+                        if let Err(e) = index.index_tickets(&self.provider, &snapshot.assigned) {
+                            warn!("Indexing {} tickets failed: {}", self.provider, e);
+                        }
+                    }
+                    if let Some(semantic) = &self.semantic {
+                        if let Err(e) = semantic.index_tickets(&self.provider, &snapshot.assigned).await {
+                            warn!("Embedding {} tickets failed: {}", self.provider, e);
+                        }
+                    }
+                    debug!("Prefetched {} assigned tickets from {}", current.len(), self.provider);
+                    // Entries outlive the interval so one failed pass does
+                    // not leave the cache cold
+                    self.cache.store_snapshot(snapshot, self.interval * 2);
+                    previous = Some(current);
+                }
+                Err(e) => warn!("Prefetching {} failed: {}", self.provider, e),
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+
+    fn publish_changes(&self, previous: &HashMap<String, Ticket>, current: &HashMap<String, Ticket>) {
+        let changed = current.values().filter_map(|ticket| match previous.get(&ticket.id) {
+            None => Some((ticket, WebhookAction::Created)),
+            Some(before) if before.updated_at < ticket.updated_at => Some((ticket, WebhookAction::Updated)),
+            Some(_) => None,
+        });
+        // Unassigned, closed elsewhere, or deleted: gone from this user's list
+        let removed = previous.values()
+            .filter(|ticket| !current.contains_key(&ticket.id))
+            .map(|ticket| (ticket, WebhookAction::Removed));
+
+        let events: Vec<WebhookEvent> = changed.chain(removed)
+            .map(|(ticket, action)| WebhookEvent {
+                provider: self.provider.clone(),
+                entity: WebhookEntity::Ticket,
+                action,
+                entity_id: ticket.id.clone(),
+                ticket_id: Some(ticket.id.clone()),
+                project_id: ticket.project_id.clone(),
+                occurred_at: Utc::now(),
+            })
+            .collect();
+        if events.is_empty() {
+            return;
+        }
+
+        info!("{} assigned tickets changed on {}", events.len(), self.provider);
+        for event in &events {
+            self.events.publish(event);
+        }
+    }
+}
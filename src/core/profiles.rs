@@ -0,0 +1,109 @@
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// What tickets created without explicit values fall back to.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TicketDefaults {
+    /// Team id or key.
+    #[serde(default)]
+    pub team: Option<String>,
+    /// Project id or name.
+    #[serde(default)]
+    pub project: Option<String>,
+    /// Assigns tickets created without an assignee to the current user.
+    #[serde(default)]
+    pub self_assign: Option<bool>,
+}
+
+impl TicketDefaults {
+    /// These defaults, with unset ones taken from `fallback`.
+    pub fn or(self, fallback: &TicketDefaults) -> Self {
+        Self {
+            team: self.team.or_else(|| fallback.team.clone()),
+            project: self.project.or_else(|| fallback.project.clone()),
+            self_assign: self.self_assign.or(fallback.self_assign),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.team.is_none() && self.project.is_none() && !self.self_assign.unwrap_or(false)
+    }
+}
+
+/// A named account to work against, e.g. `work-linear` or `client-jira`:
+/// which provider, how to authenticate, and its own defaults.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Profile {
+    /// Provider type, e.g. `linear`.
+    pub provider: String,
+    /// The token itself, or a `secret://` URI naming it in a secret
+    /// manager; `api_token_env` or the OS keychain also keep it out of the
+    /// file.
+    #[serde(default)]
+    pub api_token: Option<String>,
+    /// Environment variable holding the token.
+    #[serde(default)]
+    pub api_token_env: Option<String>,
+    #[serde(default)]
+    pub base_url: Option<String>,
+    #[serde(default)]
+    pub workspace_id: Option<String>,
+    /// Proxy for this profile's requests, overriding `MCP_PROXY`.
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// PEM files of extra root certificates, for self-hosted instances
+    /// behind an internal CA; added to `MCP_CA_CERTIFICATES`.
+    #[serde(default)]
+    pub ca_certificates: Vec<String>,
+    /// Skips certificate verification for this profile's requests.
+    #[serde(default)]
+    pub accept_invalid_certs: bool,
+    /// Override `MCP_CONNECT_TIMEOUT_SECS` and `MCP_REQUEST_TIMEOUT_SECS`,
+    /// e.g. for a slow self-hosted instance.
+    #[serde(default)]
+    pub connect_timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub request_timeout_secs: Option<u64>,
+    /// HTTP client library for this profile, overriding `MCP_HTTP_BACKEND`.
+    #[serde(default)]
+    pub http_backend: Option<String>,
+    /// WebAssembly component implementing the provider, for trackers
+    /// without a built-in adapter.
+    #[serde(default)]
+    pub plugin: Option<String>,
+    /// Settings passed to the plugin as they are, next to the token.
+    #[serde(default)]
+    pub plugin_config: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    pub defaults: TicketDefaults,
+}
+
+impl Profile {
+    /// The token from the file or its environment variable, if either is set.
+    pub fn api_token(&self) -> Result<Option<String>> {
+        if let Some(token) = &self.api_token {
+            return Ok(Some(token.clone()));
+        }
+        match &self.api_token_env {
+            Some(name) => std::env::var(name)
+                .map(Some)
+                .map_err(|_| anyhow!("{} is not set", name)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Loads profiles from a JSON file holding an object of profiles by name.
+pub fn load_profiles(path: impl AsRef<Path>) -> Result<BTreeMap<String, Profile>> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read profiles from {}", path.display()))?;
+    let profiles: BTreeMap<String, Profile> = serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid profiles in {}", path.display()))?;
+    if profiles.is_empty() {
+        return Err(anyhow!("{} defines no profiles", path.display()));
+    }
+    Ok(profiles)
+}
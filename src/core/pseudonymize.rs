@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::domain::Ticket;
+use crate::domain::User;
+
+const ADJECTIVES: [&str; 24] = [
+    "Amber", "Azure", "Brisk", "Calm", "Cobalt", "Crimson", "Dusky", "Eager",
+    "Fern", "Gentle", "Golden", "Hazel", "Indigo", "Jade", "Keen", "Lunar",
+    "Misty", "Nimble", "Olive", "Quiet", "Rustic", "Silver", "Teal", "Witty",
+];
+
+const ANIMALS: [&str; 24] = [
+    "Badger", "Beaver", "Crane", "Dolphin", "Falcon", "Ferret", "Gecko", "Heron",
+    "Ibex", "Jackal", "Koala", "Lemur", "Lynx", "Marten", "Newt", "Ocelot",
+    "Otter", "Panda", "Puffin", "Raven", "Salmon", "Tapir", "Walrus", "Wombat",
+];
+
+/// Replacement identity for a real user in externally shared output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserAlias {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+}
+
+/// Maps real users onto stable fake identities. The same real user id must
+/// always produce the same alias so shared reports stay internally consistent.
+pub trait Pseudonymizer {
+    fn alias(&self, user_id: &str) -> UserAlias;
+
+    /// Replaces the user references on a ticket. Free text such as the
+    /// description is left untouched.
+    fn pseudonymize_ticket(&self, ticket: &Ticket) -> Ticket {
+        let mut ticket = ticket.clone();
+        ticket.assignee_id = ticket.assignee_id.map(|id| self.alias(&id).id);
+        ticket.creator_id = self.alias(&ticket.creator_id).id;
+        ticket.watcher_ids = ticket.watcher_ids.iter().map(|id| self.alias(id).id).collect();
+        ticket
+    }
+
+    fn pseudonymize_user(&self, user: &User) -> User {
+        let alias = self.alias(&user.id);
+        let mut user = user.clone();
+        user.id = alias.id;
+        user.display_name = alias.name.clone();
+        user.name = alias.name;
+        user.email = alias.email;
+        user.avatar_url = None;
+        user.custom_fields.clear();
+        user
+    }
+}
+
+/// Derives aliases from a salted SHA-256 of the user id. Keep the salt secret
+/// (and fixed, if aliases should survive restarts), otherwise aliases can be
+/// reversed by hashing known user ids.
+pub struct HashPseudonymizer {
+    salt: String,
+}
+
+impl HashPseudonymizer {
+    pub fn new(salt: impl Into<String>) -> Self {
+        Self { salt: salt.into() }
+    }
+
+    /// Uses a random salt: aliases are stable for this process only.
+    pub fn random() -> Self {
+        Self::new(Uuid::new_v4().to_string())
+    }
+}
+
+impl Pseudonymizer for HashPseudonymizer {
+    fn alias(&self, user_id: &str) -> UserAlias {
+        let mut hasher = Sha256::new();
+        hasher.update(self.salt.as_bytes());
+        hasher.update([0u8]);
+        hasher.update(user_id.as_bytes());
+        let digest = hasher.finalize();
+
+        let adjective = ADJECTIVES[digest[0] as usize % ADJECTIVES.len()];
+        let animal = ANIMALS[digest[1] as usize % ANIMALS.len()];
+        let tag: String = digest[2..8].iter().map(|b| format!("{:02x}", b)).collect();
+
+        UserAlias {
+            id: format!("user-{}", tag),
+            name: format!("{} {}", adjective, animal),
+            email: format!("{}.{}.{}@example.invalid", adjective.to_lowercase(), animal.to_lowercase(), &tag[..4]),
+        }
+    }
+}
@@ -0,0 +1,345 @@
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+use crate::domain::{
+    Ticket, TicketFilter, CreateTicketRequest, UpdateTicketRequest,
+    Label, CreateLabelRequest, Project, ProjectMilestone, Workspace,
+    CreateProjectRequest, UpdateProjectRequest, CreateMilestoneRequest, Cycle,
+    TimeEntry, LogTimeRequest, TicketRef, Comment, CreateCommentRequest, TicketEvent,
+    WorkflowState, Reaction, ReactionTarget, Initiative, Notification,
+    CreateRelationRequest, Attachment, CreateAttachmentRequest, ProjectUpdate, Document,
+};
+use crate::domain::{Team, User};
+use crate::ports::{Capabilities, TicketService, TicketServiceMiddleware, TicketResult, Page, PageRequest};
+
+/// Provider calls allowed in flight at once.
+pub const DEFAULT_MAX_CONCURRENT_CALLS: usize = 8;
+
+/// Sustained provider calls per minute, with bursts of up to `burst`.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    pub per_minute: u32,
+    pub burst: u32,
+}
+
+/// Token bucket: holds up to `burst` tokens, refilled at `per_minute`.
+struct TokenBucket {
+    limit: RateLimit,
+    /// Tokens available and when they were last topped up.
+    state: Mutex<(f64, Instant)>,
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit) -> Self {
+        let limit = RateLimit {
+            per_minute: limit.per_minute.max(1),
+            burst: limit.burst.max(1),
+        };
+        Self {
+            limit,
+            state: Mutex::new((limit.burst as f64, Instant::now())),
+        }
+    }
+
+    /// Takes a token, sleeping until one is available.
+    async fn take(&self) {
+        let refill_per_sec = self.limit.per_minute as f64 / 60.0;
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                let (tokens, refilled_at) = &mut *state;
+                let now = Instant::now();
+                *tokens = (*tokens + now.duration_since(*refilled_at).as_secs_f64() * refill_per_sec)
+                    .min(self.limit.burst as f64);
+                *refilled_at = now;
+                if *tokens >= 1.0 {
+                    *tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - *tokens) / refill_per_sec)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Throttles calls to a `TicketService` so a runaway session cannot exhaust
+/// a provider's API quota or flood it with parallel requests. Limits apply
+/// per call; a call that pages through results counts once.
+pub struct RateLimitedTicketService {
+    inner: Arc<dyn TicketService + Send + Sync>,
+    rate: Option<TokenBucket>,
+    concurrency: Semaphore,
+}
+
+impl RateLimitedTicketService {
+    /// Without a `rate`, only concurrency is limited.
+    pub fn new(inner: Arc<dyn TicketService + Send + Sync>, rate: Option<RateLimit>, max_concurrent: usize) -> Self {
+        Self {
+            inner,
+            rate: rate.map(TokenBucket::new),
+            concurrency: Semaphore::new(max_concurrent.max(1)),
+        }
+    }
+
+    async fn acquire(&self) -> SemaphorePermit<'_> {
+        // The semaphore is never closed
+        let permit = self.concurrency.acquire().await.expect("rate limiter semaphore closed");
+        if let Some(rate) = &self.rate {
+            rate.take().await;
+        }
+        permit
+    }
+}
+
+/// Wraps providers in a `RateLimitedTicketService`.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitLayer {
+    pub rate: Option<RateLimit>,
+    pub max_concurrent: usize,
+}
+
+impl TicketServiceMiddleware for RateLimitLayer {
+    fn wrap(&self, inner: Arc<dyn TicketService + Send + Sync>) -> Arc<dyn TicketService + Send + Sync> {
+        Arc::new(RateLimitedTicketService::new(inner, self.rate, self.max_concurrent))
+    }
+}
+
+#[async_trait]
+impl TicketService for RateLimitedTicketService {
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+
+    async fn get_assigned_tickets(&self, user_id: &str, page: &PageRequest) -> TicketResult<Page<Ticket>> {
+        let _permit = self.acquire().await;
+        self.inner.get_assigned_tickets(user_id, page).await
+    }
+
+    async fn search_tickets(&self, filter: &TicketFilter, page: &PageRequest) -> TicketResult<Page<Ticket>> {
+        let _permit = self.acquire().await;
+        self.inner.search_tickets(filter, page).await
+    }
+
+    async fn get_all_assigned_tickets(&self, user_id: &str) -> TicketResult<Vec<Ticket>> {
+        let _permit = self.acquire().await;
+        self.inner.get_all_assigned_tickets(user_id).await
+    }
+
+    async fn get_ticket(&self, ticket_id: &str) -> TicketResult<Option<Ticket>> {
+        let _permit = self.acquire().await;
+        self.inner.get_ticket(ticket_id).await
+    }
+
+    async fn create_ticket(&self, request: &CreateTicketRequest) -> TicketResult<Ticket> {
+        let _permit = self.acquire().await;
+        self.inner.create_ticket(request).await
+    }
+
+    async fn update_ticket(&self, request: &UpdateTicketRequest) -> TicketResult<Ticket> {
+        let _permit = self.acquire().await;
+        self.inner.update_ticket(request).await
+    }
+
+    async fn archive_ticket(&self, ticket_id: &str) -> TicketResult<()> {
+        let _permit = self.acquire().await;
+        self.inner.archive_ticket(ticket_id).await
+    }
+
+    async fn delete_ticket(&self, ticket_id: &str) -> TicketResult<()> {
+        let _permit = self.acquire().await;
+        self.inner.delete_ticket(ticket_id).await
+    }
+
+    async fn watch_ticket(&self, ticket_id: &str, user_id: &str) -> TicketResult<()> {
+        let _permit = self.acquire().await;
+        self.inner.watch_ticket(ticket_id, user_id).await
+    }
+
+    async fn unwatch_ticket(&self, ticket_id: &str, user_id: &str) -> TicketResult<()> {
+        let _permit = self.acquire().await;
+        self.inner.unwatch_ticket(ticket_id, user_id).await
+    }
+
+    async fn find_ticket(&self, reference: &TicketRef) -> TicketResult<Option<Ticket>> {
+        let _permit = self.acquire().await;
+        self.inner.find_ticket(reference).await
+    }
+
+    async fn get_tickets(&self, references: &[TicketRef]) -> TicketResult<Vec<Option<Ticket>>> {
+        let _permit = self.acquire().await;
+        self.inner.get_tickets(references).await
+    }
+
+    async fn get_comments(&self, ticket_id: &str, page: &PageRequest) -> TicketResult<Page<Comment>> {
+        let _permit = self.acquire().await;
+        self.inner.get_comments(ticket_id, page).await
+    }
+
+    async fn add_comment(&self, request: &CreateCommentRequest) -> TicketResult<Comment> {
+        let _permit = self.acquire().await;
+        self.inner.add_comment(request).await
+    }
+
+    async fn get_ticket_history(&self, ticket_id: &str) -> TicketResult<Vec<TicketEvent>> {
+        let _permit = self.acquire().await;
+        self.inner.get_ticket_history(ticket_id).await
+    }
+
+    async fn add_relation(&self, request: &CreateRelationRequest) -> TicketResult<()> {
+        let _permit = self.acquire().await;
+        self.inner.add_relation(request).await
+    }
+
+    async fn set_parent(&self, ticket_id: &str, parent_id: Option<&str>) -> TicketResult<Ticket> {
+        let _permit = self.acquire().await;
+        self.inner.set_parent(ticket_id, parent_id).await
+    }
+
+    async fn get_attachments(&self, ticket_id: &str) -> TicketResult<Vec<Attachment>> {
+        let _permit = self.acquire().await;
+        self.inner.get_attachments(ticket_id).await
+    }
+
+    async fn add_attachment(&self, request: &CreateAttachmentRequest) -> TicketResult<Attachment> {
+        let _permit = self.acquire().await;
+        self.inner.add_attachment(request).await
+    }
+
+    async fn add_reaction(&self, target: &ReactionTarget, emoji: &str) -> TicketResult<Reaction> {
+        let _permit = self.acquire().await;
+        self.inner.add_reaction(target, emoji).await
+    }
+
+    async fn remove_reaction(&self, reaction_id: &str) -> TicketResult<()> {
+        let _permit = self.acquire().await;
+        self.inner.remove_reaction(reaction_id).await
+    }
+
+    async fn get_current_user(&self) -> TicketResult<User> {
+        let _permit = self.acquire().await;
+        self.inner.get_current_user().await
+    }
+
+    async fn get_user(&self, user_id: &str) -> TicketResult<Option<User>> {
+        let _permit = self.acquire().await;
+        self.inner.get_user(user_id).await
+    }
+
+    async fn get_notifications(&self, unread_only: bool, page: &PageRequest) -> TicketResult<Page<Notification>> {
+        let _permit = self.acquire().await;
+        self.inner.get_notifications(unread_only, page).await
+    }
+
+    async fn get_teams(&self) -> TicketResult<Vec<Team>> {
+        let _permit = self.acquire().await;
+        self.inner.get_teams().await
+    }
+
+    async fn get_team_members(&self, team_id: &str) -> TicketResult<Vec<User>> {
+        let _permit = self.acquire().await;
+        self.inner.get_team_members(team_id).await
+    }
+
+    async fn get_workflow_states(&self, team_id: &str) -> TicketResult<Vec<WorkflowState>> {
+        let _permit = self.acquire().await;
+        self.inner.get_workflow_states(team_id).await
+    }
+
+    async fn get_labels(&self) -> TicketResult<Vec<Label>> {
+        let _permit = self.acquire().await;
+        self.inner.get_labels().await
+    }
+
+    async fn create_label(&self, request: &CreateLabelRequest) -> TicketResult<Label> {
+        let _permit = self.acquire().await;
+        self.inner.create_label(request).await
+    }
+
+    async fn get_projects(&self, page: &PageRequest) -> TicketResult<Page<Project>> {
+        let _permit = self.acquire().await;
+        self.inner.get_projects(page).await
+    }
+
+    async fn get_project(&self, project_id: &str) -> TicketResult<Option<Project>> {
+        let _permit = self.acquire().await;
+        self.inner.get_project(project_id).await
+    }
+
+    async fn get_project_milestones(&self, project_id: &str) -> TicketResult<Vec<ProjectMilestone>> {
+        let _permit = self.acquire().await;
+        self.inner.get_project_milestones(project_id).await
+    }
+
+    async fn create_project(&self, request: &CreateProjectRequest) -> TicketResult<Project> {
+        let _permit = self.acquire().await;
+        self.inner.create_project(request).await
+    }
+
+    async fn update_project(&self, request: &UpdateProjectRequest) -> TicketResult<Project> {
+        let _permit = self.acquire().await;
+        self.inner.update_project(request).await
+    }
+
+    async fn create_milestone(&self, request: &CreateMilestoneRequest) -> TicketResult<ProjectMilestone> {
+        let _permit = self.acquire().await;
+        self.inner.create_milestone(request).await
+    }
+
+    async fn get_project_updates(&self, project_id: &str, page: &PageRequest) -> TicketResult<Page<ProjectUpdate>> {
+        let _permit = self.acquire().await;
+        self.inner.get_project_updates(project_id, page).await
+    }
+
+    async fn get_documents(&self, project_id: Option<&str>, page: &PageRequest) -> TicketResult<Page<Document>> {
+        let _permit = self.acquire().await;
+        self.inner.get_documents(project_id, page).await
+    }
+
+    async fn get_document(&self, document_id: &str) -> TicketResult<Option<Document>> {
+        let _permit = self.acquire().await;
+        self.inner.get_document(document_id).await
+    }
+
+    async fn get_initiatives(&self) -> TicketResult<Vec<Initiative>> {
+        let _permit = self.acquire().await;
+        self.inner.get_initiatives().await
+    }
+
+    async fn get_initiative_projects(&self, initiative_id: &str) -> TicketResult<Vec<Project>> {
+        let _permit = self.acquire().await;
+        self.inner.get_initiative_projects(initiative_id).await
+    }
+
+    async fn get_cycles(&self, team_id: &str) -> TicketResult<Vec<Cycle>> {
+        let _permit = self.acquire().await;
+        self.inner.get_cycles(team_id).await
+    }
+
+    async fn get_active_cycle(&self, team_id: &str) -> TicketResult<Option<Cycle>> {
+        let _permit = self.acquire().await;
+        self.inner.get_active_cycle(team_id).await
+    }
+
+    async fn assign_ticket_to_cycle(&self, ticket_id: &str, cycle_id: &str) -> TicketResult<Ticket> {
+        let _permit = self.acquire().await;
+        self.inner.assign_ticket_to_cycle(ticket_id, cycle_id).await
+    }
+
+    async fn log_time(&self, request: &LogTimeRequest) -> TicketResult<TimeEntry> {
+        let _permit = self.acquire().await;
+        self.inner.log_time(request).await
+    }
+
+    async fn get_time_entries(&self, ticket_id: &str) -> TicketResult<Vec<TimeEntry>> {
+        let _permit = self.acquire().await;
+        self.inner.get_time_entries(ticket_id).await
+    }
+
+    async fn get_workspace(&self) -> TicketResult<Workspace> {
+        let _permit = self.acquire().await;
+        self.inner.get_workspace().await
+    }
+}
@@ -0,0 +1,91 @@
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+use std::path::Path;
+
+/// Output fields filtered when the configuration names none: ticket and
+/// project descriptions, comment and update bodies, document content, and
+/// the text of resources and exports.
+pub const DEFAULT_REDACTED_FIELDS: &[&str] = &["description", "body", "content", "text", "markdown"];
+
+pub(crate) const EMAIL_PATTERN: &str = r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}";
+
+/// Three or four digit groups with an optional country code, so ISO dates
+/// and version numbers are left alone.
+const PHONE_PATTERN: &str = r"(?:\+\d{1,3}[\s.-]?)?(?:\(\d{2,4}\)|\b\d{2,4})[\s.-]\d{3,4}[\s.-]\d{3,4}\b";
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RedactionConfig {
+    pub emails: bool,
+    pub phone_numbers: bool,
+    /// Further regular expressions, e.g. customer or account ids.
+    pub patterns: Vec<String>,
+    /// Output fields whose text is filtered, at any depth; defaults to
+    /// `DEFAULT_REDACTED_FIELDS`.
+    pub fields: Option<Vec<String>>,
+}
+
+/// Loads a redaction configuration from a JSON file.
+pub fn load_redaction_config(path: impl AsRef<Path>) -> Result<RedactionConfig> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read redaction configuration from {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid redaction configuration in {}", path.display()))
+}
+
+/// Replaces personal data in tool output before it reaches the client.
+pub struct Redactor {
+    rules: Vec<(Regex, &'static str)>,
+    fields: Vec<String>,
+}
+
+impl Redactor {
+    pub fn new(config: &RedactionConfig) -> Result<Self> {
+        let mut rules = Vec::new();
+        if config.emails {
+            rules.push((Regex::new(EMAIL_PATTERN)?, "[redacted email]"));
+        }
+        if config.phone_numbers {
+            rules.push((Regex::new(PHONE_PATTERN)?, "[redacted phone]"));
+        }
+        for pattern in &config.patterns {
+            let regex = Regex::new(pattern)
+                .with_context(|| format!("Invalid redaction pattern {:?}", pattern))?;
+            rules.push((regex, "[redacted]"));
+        }
+
+        Ok(Self {
+            rules,
+            fields: config.fields.clone()
+                .unwrap_or_else(|| DEFAULT_REDACTED_FIELDS.iter().map(|f| f.to_string()).collect()),
+        })
+    }
+
+    pub fn redact_text(&self, text: &str) -> String {
+        self.rules.iter().fold(text.to_string(), |text, (regex, replacement)| {
+            regex.replace_all(&text, *replacement).into_owned()
+        })
+    }
+
+    /// Redacts every string inside the configured fields of `value`.
+    pub fn redact_value(&self, value: &mut Value) {
+        self.redact_within(value, false);
+    }
+
+    fn redact_within(&self, value: &mut Value, inside_field: bool) {
+        match value {
+            Value::String(text) if inside_field => *text = self.redact_text(text),
+            Value::Array(items) => items.iter_mut().for_each(|item| self.redact_within(item, inside_field)),
+            Value::Object(map) => {
+                for (key, item) in map.iter_mut() {
+                    let inside = inside_field || self.fields.iter().any(|field| field == key);
+                    self.redact_within(item, inside);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::core::Application;
+use crate::domain::Ticket;
+
+/// One `Application` per configured provider, by name (`linear`, `github`).
+/// Callers pick one by name and fall back to the default.
+#[derive(Clone)]
+pub struct ProviderRegistry {
+    applications: BTreeMap<String, Arc<Application>>,
+    default: String,
+}
+
+impl ProviderRegistry {
+    pub fn new(default: impl Into<String>, application: Arc<Application>) -> Self {
+        let default = default.into();
+        let mut applications = BTreeMap::new();
+        applications.insert(default.clone(), application);
+        Self { applications, default }
+    }
+
+    /// Adds another provider; a repeated name replaces the earlier one.
+    pub fn with_provider(mut self, name: impl Into<String>, application: Arc<Application>) -> Self {
+        self.applications.insert(name.into(), application);
+        self
+    }
+
+    /// The named provider's application, or the default one for `None`.
+    pub fn get(&self, name: Option<&str>) -> Result<Arc<Application>> {
+        let name = name.unwrap_or(&self.default);
+        self.applications
+            .get(name)
+            .cloned()
+            .ok_or_else(|| anyhow!(
+                "Unknown provider: {}. Configured providers: {}",
+                name, self.names().join(", ")
+            ))
+    }
+
+    /// A registry holding only the named provider, for a session that must
+    /// not reach the other providers' credentials.
+    pub fn only(&self, name: &str) -> Result<Self> {
+        Ok(Self::new(name, self.get(Some(name))?))
+    }
+
+    pub fn default_application(&self) -> Arc<Application> {
+        self.applications[&self.default].clone()
+    }
+
+    pub fn default_name(&self) -> &str {
+        &self.default
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.applications.keys().map(|name| name.as_str()).collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Arc<Application>)> {
+        self.applications.iter().map(|(name, application)| (name.as_str(), application))
+    }
+
+    pub fn len(&self) -> usize {
+        self.applications.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.applications.is_empty()
+    }
+}
+
+/// A ticket from a cross-provider search, tagged with where it came from.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderTicket {
+    pub provider: String,
+    #[serde(flatten)]
+    pub ticket: Ticket,
+}
+
+/// Merged results of `Application::search_all`. Providers that failed are
+/// listed instead of failing the whole search.
+#[derive(Debug, Clone, Serialize)]
+pub struct CrossProviderResults {
+    pub tickets: Vec<ProviderTicket>,
+    pub failed_providers: Vec<ProviderFailure>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProviderFailure {
+    pub provider: String,
+    pub error: String,
+}
@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::Ticket;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReleaseNoteCategory {
+    Features,
+    Fixes,
+    Chores,
+    Other,
+}
+
+impl ReleaseNoteCategory {
+    const ORDER: [ReleaseNoteCategory; 4] = [
+        ReleaseNoteCategory::Features,
+        ReleaseNoteCategory::Fixes,
+        ReleaseNoteCategory::Chores,
+        ReleaseNoteCategory::Other,
+    ];
+
+    /// Classifies a ticket by its label names. Fix labels win over feature
+    /// labels so a "bug" + "enhancement" ticket lands under fixes.
+    pub fn from_labels(labels: &[String]) -> Self {
+        let has = |needles: &[&str]| {
+            labels.iter().any(|label| {
+                let label = label.to_lowercase();
+                needles.iter().any(|needle| label.contains(needle))
+            })
+        };
+
+        if has(&["bug", "fix", "defect", "regression"]) {
+            ReleaseNoteCategory::Fixes
+        } else if has(&["feature", "feat", "enhancement", "improvement"]) {
+            ReleaseNoteCategory::Features
+        } else if has(&["chore", "maintenance", "refactor", "tech debt", "tech-debt", "docs", "documentation", "dependencies"]) {
+            ReleaseNoteCategory::Chores
+        } else {
+            ReleaseNoteCategory::Other
+        }
+    }
+
+    pub fn heading(&self) -> &'static str {
+        match self {
+            ReleaseNoteCategory::Features => "Features",
+            ReleaseNoteCategory::Fixes => "Fixes",
+            ReleaseNoteCategory::Chores => "Chores",
+            ReleaseNoteCategory::Other => "Other",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseNoteItem {
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    pub completed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseNoteSection {
+    pub category: ReleaseNoteCategory,
+    pub items: Vec<ReleaseNoteItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseNotes {
+    pub title: String,
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+    pub sections: Vec<ReleaseNoteSection>,
+}
+
+impl ReleaseNotes {
+    /// Builds release notes from completed tickets, keeping only those
+    /// completed inside `[since, until]`. Tickets without a completion time
+    /// fall back to their last update.
+    pub fn build(title: &str, since: DateTime<Utc>, until: DateTime<Utc>, tickets: &[Ticket]) -> Self {
+        let mut items: Vec<(ReleaseNoteCategory, ReleaseNoteItem)> = tickets
+            .iter()
+            .filter_map(|ticket| {
+                let completed_at = ticket.completed_at.unwrap_or(ticket.updated_at);
+                if completed_at < since || completed_at > until {
+                    return None;
+                }
+
+                Some((
+                    ReleaseNoteCategory::from_labels(&ticket.labels),
+                    ReleaseNoteItem {
+                        identifier: ticket.identifier.clone(),
+                        title: ticket.title.clone(),
+                        url: ticket.url.clone(),
+                        completed_at,
+                    },
+                ))
+            })
+            .collect();
+        items.sort_by_key(|(_, item)| item.completed_at);
+
+        let sections = ReleaseNoteCategory::ORDER
+            .iter()
+            .map(|category| ReleaseNoteSection {
+                category: *category,
+                items: items
+                    .iter()
+                    .filter(|(c, _)| c == category)
+                    .map(|(_, item)| item.clone())
+                    .collect(),
+            })
+            .filter(|section| !section.items.is_empty())
+            .collect();
+
+        Self {
+            title: title.to_string(),
+            since,
+            until,
+            sections,
+        }
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.sections.iter().map(|section| section.items.len()).sum()
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!(
+            "## {} ({} – {})\n",
+            self.title,
+            self.since.format("%Y-%m-%d"),
+            self.until.format("%Y-%m-%d")
+        );
+
+        if self.sections.is_empty() {
+            markdown.push_str("\n_No tickets were completed in this window._\n");
+            return markdown;
+        }
+
+        for section in &self.sections {
+            markdown.push_str(&format!("\n### {}\n\n", section.category.heading()));
+            for item in &section.items {
+                markdown.push_str(&format!("- {} ([{}]({}))\n", item.title, item.identifier, item.url));
+            }
+        }
+
+        markdown
+    }
+}
@@ -0,0 +1,122 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::core::ProviderRegistry;
+use crate::domain::Ticket;
+
+pub const DEFAULT_DUE_REMINDER_HOURS: i64 = 24;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DueStatus {
+    DueSoon,
+    Overdue,
+}
+
+/// An open ticket of the current user's whose due date is near or past.
+#[derive(Debug, Clone, Serialize)]
+pub struct DueReminder {
+    pub provider: String,
+    pub status: DueStatus,
+    pub ticket_id: String,
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    pub due_date: DateTime<Utc>,
+    /// Negative once overdue.
+    pub hours_left: i64,
+}
+
+impl DueReminder {
+    /// `None` for tickets without a due date.
+    pub fn for_ticket(provider: &str, ticket: &Ticket, now: DateTime<Utc>) -> Option<Self> {
+        let due_date = ticket.due_date?;
+        Some(Self {
+            provider: provider.to_string(),
+            status: if due_date <= now { DueStatus::Overdue } else { DueStatus::DueSoon },
+            ticket_id: ticket.id.clone(),
+            identifier: ticket.identifier.clone(),
+            title: ticket.title.clone(),
+            url: ticket.url.clone(),
+            due_date,
+            hours_left: (due_date - now).num_hours(),
+        })
+    }
+
+    pub fn message(&self) -> String {
+        match self.status {
+            DueStatus::DueSoon => format!("{} \"{}\" is due in {}h", self.identifier, self.title, self.hours_left),
+            DueStatus::Overdue => format!("{} \"{}\" is {}h overdue", self.identifier, self.title, -self.hours_left),
+        }
+    }
+}
+
+/// Receives reminders as the watcher finds them, e.g. to forward them to a
+/// chat.
+pub trait ReminderSink: Send + Sync {
+    fn remind(&self, reminder: &DueReminder);
+}
+
+/// Writes reminders to the server log.
+pub struct LogReminderSink;
+
+impl ReminderSink for LogReminderSink {
+    fn remind(&self, reminder: &DueReminder) {
+        info!("Due reminder: {}", reminder.message());
+    }
+}
+
+/// Periodically checks every provider for the current user's tickets due
+/// within `within` or overdue, and passes each to the sink once per status
+/// and due date: moving a due date or becoming overdue reminds again.
+pub struct DueDateWatcher {
+    registry: ProviderRegistry,
+    within: Duration,
+    interval: std::time::Duration,
+    sink: Arc<dyn ReminderSink>,
+}
+
+impl DueDateWatcher {
+    pub fn new(registry: ProviderRegistry, within: Duration, interval: std::time::Duration, sink: Arc<dyn ReminderSink>) -> Self {
+        Self {
+            registry,
+            within,
+            interval,
+            sink,
+        }
+    }
+
+    /// Checks until the task is dropped.
+    pub async fn run(self) {
+        info!("Checking for tickets due within {}h every {:?}", self.within.num_hours(), self.interval);
+        let mut reminded: HashSet<(String, String, DueStatus, DateTime<Utc>)> = HashSet::new();
+        loop {
+            let now = Utc::now();
+            let mut current = HashSet::new();
+            for (provider, application) in self.registry.iter() {
+                let tickets = match application.get_due_soon(self.within).await {
+                    Ok(tickets) => tickets,
+                    Err(e) => {
+                        warn!("Checking {} for due tickets failed: {}", provider, e);
+                        // Keep its reminders so a failed check does not repeat them
+                        current.extend(reminded.iter().filter(|key| key.0 == provider).cloned());
+                        continue;
+                    }
+                };
+                for reminder in tickets.iter().filter_map(|ticket| DueReminder::for_ticket(provider, ticket, now)) {
+                    let key = (reminder.provider.clone(), reminder.ticket_id.clone(), reminder.status, reminder.due_date);
+                    if !reminded.contains(&key) {
+                        self.sink.remind(&reminder);
+                    }
+                    current.insert(key);
+                }
+            }
+            // Forget tickets that were closed or rescheduled
+            reminded = current;
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+}
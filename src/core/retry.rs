@@ -0,0 +1,285 @@
+use async_trait::async_trait;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::warn;
+
+use crate::domain::{
+    Ticket, TicketFilter, CreateTicketRequest, UpdateTicketRequest,
+    Label, CreateLabelRequest, Project, ProjectMilestone, Workspace,
+    CreateProjectRequest, UpdateProjectRequest, CreateMilestoneRequest, Cycle,
+    TimeEntry, LogTimeRequest, TicketRef, Comment, CreateCommentRequest, TicketEvent,
+    WorkflowState, Reaction, ReactionTarget, Initiative, Notification,
+    CreateRelationRequest, Attachment, CreateAttachmentRequest, ProjectUpdate, Document,
+};
+use crate::domain::{Team, User};
+use crate::ports::{
+    Capabilities, Page, PageRequest, TicketService, TicketServiceError, TicketServiceMiddleware, TicketResult,
+};
+
+const BASE_RETRY_DELAY: Duration = Duration::from_millis(500);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Retries provider calls that failed in a way that may pass later: rate
+/// limits and provider outages. Writes are only retried after a rate limit,
+/// which rejects the request before it runs; after an outage a write may
+/// have been applied, and repeating it could duplicate it.
+pub struct RetryingTicketService {
+    inner: Arc<dyn TicketService + Send + Sync>,
+    max_retries: u32,
+}
+
+impl RetryingTicketService {
+    pub fn new(inner: Arc<dyn TicketService + Send + Sync>, max_retries: u32) -> Self {
+        Self { inner, max_retries }
+    }
+
+    async fn read<T, F, Fut>(&self, call: &str, f: F) -> TicketResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = TicketResult<T>>,
+    {
+        self.retry(call, TicketServiceError::is_retryable, f).await
+    }
+
+    async fn write<T, F, Fut>(&self, call: &str, f: F) -> TicketResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = TicketResult<T>>,
+    {
+        self.retry(call, |e| matches!(e, TicketServiceError::RateLimited { .. }), f).await
+    }
+
+    async fn retry<T, F, Fut>(&self, call: &str, retryable: fn(&TicketServiceError) -> bool, f: F) -> TicketResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = TicketResult<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match f().await {
+                Err(e) if attempt < self.max_retries && retryable(&e) => {
+                    let delay = match &e {
+                        TicketServiceError::RateLimited { retry_after: Some(delay), .. } => (*delay).min(MAX_RETRY_DELAY),
+                        _ => Self::backoff(attempt),
+                    };
+                    warn!("{} failed: {}; retrying in {:?} (attempt {} of {})", call, e, delay, attempt + 1, self.max_retries);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Exponential backoff with up to 50% jitter for the given zero-based
+    /// attempt.
+    fn backoff(attempt: u32) -> Duration {
+        let delay = BASE_RETRY_DELAY.saturating_mul(2u32.saturating_pow(attempt)).min(MAX_RETRY_DELAY);
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().subsec_nanos();
+        delay + delay.mul_f64(f64::from(nanos % 1000) / 2000.0)
+    }
+}
+
+/// Wraps providers in a `RetryingTicketService`.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryLayer {
+    pub max_retries: u32,
+}
+
+impl TicketServiceMiddleware for RetryLayer {
+    fn wrap(&self, inner: Arc<dyn TicketService + Send + Sync>) -> Arc<dyn TicketService + Send + Sync> {
+        Arc::new(RetryingTicketService::new(inner, self.max_retries))
+    }
+}
+
+#[async_trait]
+impl TicketService for RetryingTicketService {
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+
+    async fn get_assigned_tickets(&self, user_id: &str, page: &PageRequest) -> TicketResult<Page<Ticket>> {
+        self.read("get_assigned_tickets", || self.inner.get_assigned_tickets(user_id, page)).await
+    }
+
+    async fn search_tickets(&self, filter: &TicketFilter, page: &PageRequest) -> TicketResult<Page<Ticket>> {
+        self.read("search_tickets", || self.inner.search_tickets(filter, page)).await
+    }
+
+    async fn get_all_assigned_tickets(&self, user_id: &str) -> TicketResult<Vec<Ticket>> {
+        self.read("get_all_assigned_tickets", || self.inner.get_all_assigned_tickets(user_id)).await
+    }
+
+    async fn get_ticket(&self, ticket_id: &str) -> TicketResult<Option<Ticket>> {
+        self.read("get_ticket", || self.inner.get_ticket(ticket_id)).await
+    }
+
+    async fn create_ticket(&self, request: &CreateTicketRequest) -> TicketResult<Ticket> {
+        self.write("create_ticket", || self.inner.create_ticket(request)).await
+    }
+
+    async fn update_ticket(&self, request: &UpdateTicketRequest) -> TicketResult<Ticket> {
+        self.write("update_ticket", || self.inner.update_ticket(request)).await
+    }
+
+    async fn archive_ticket(&self, ticket_id: &str) -> TicketResult<()> {
+        self.write("archive_ticket", || self.inner.archive_ticket(ticket_id)).await
+    }
+
+    async fn delete_ticket(&self, ticket_id: &str) -> TicketResult<()> {
+        self.write("delete_ticket", || self.inner.delete_ticket(ticket_id)).await
+    }
+
+    async fn watch_ticket(&self, ticket_id: &str, user_id: &str) -> TicketResult<()> {
+        self.write("watch_ticket", || self.inner.watch_ticket(ticket_id, user_id)).await
+    }
+
+    async fn unwatch_ticket(&self, ticket_id: &str, user_id: &str) -> TicketResult<()> {
+        self.write("unwatch_ticket", || self.inner.unwatch_ticket(ticket_id, user_id)).await
+    }
+
+    async fn find_ticket(&self, reference: &TicketRef) -> TicketResult<Option<Ticket>> {
+        self.read("find_ticket", || self.inner.find_ticket(reference)).await
+    }
+
+    async fn get_tickets(&self, references: &[TicketRef]) -> TicketResult<Vec<Option<Ticket>>> {
+        self.read("get_tickets", || self.inner.get_tickets(references)).await
+    }
+
+    async fn get_comments(&self, ticket_id: &str, page: &PageRequest) -> TicketResult<Page<Comment>> {
+        self.read("get_comments", || self.inner.get_comments(ticket_id, page)).await
+    }
+
+    async fn add_comment(&self, request: &CreateCommentRequest) -> TicketResult<Comment> {
+        self.write("add_comment", || self.inner.add_comment(request)).await
+    }
+
+    async fn get_ticket_history(&self, ticket_id: &str) -> TicketResult<Vec<TicketEvent>> {
+        self.read("get_ticket_history", || self.inner.get_ticket_history(ticket_id)).await
+    }
+
+    async fn add_relation(&self, request: &CreateRelationRequest) -> TicketResult<()> {
+        self.write("add_relation", || self.inner.add_relation(request)).await
+    }
+
+    async fn set_parent(&self, ticket_id: &str, parent_id: Option<&str>) -> TicketResult<Ticket> {
+        self.write("set_parent", || self.inner.set_parent(ticket_id, parent_id)).await
+    }
+
+    async fn get_attachments(&self, ticket_id: &str) -> TicketResult<Vec<Attachment>> {
+        self.read("get_attachments", || self.inner.get_attachments(ticket_id)).await
+    }
+
+    async fn add_attachment(&self, request: &CreateAttachmentRequest) -> TicketResult<Attachment> {
+        self.write("add_attachment", || self.inner.add_attachment(request)).await
+    }
+
+    async fn add_reaction(&self, target: &ReactionTarget, emoji: &str) -> TicketResult<Reaction> {
+        self.write("add_reaction", || self.inner.add_reaction(target, emoji)).await
+    }
+
+    async fn remove_reaction(&self, reaction_id: &str) -> TicketResult<()> {
+        self.write("remove_reaction", || self.inner.remove_reaction(reaction_id)).await
+    }
+
+    async fn get_current_user(&self) -> TicketResult<User> {
+        self.read("get_current_user", || self.inner.get_current_user()).await
+    }
+
+    async fn get_user(&self, user_id: &str) -> TicketResult<Option<User>> {
+        self.read("get_user", || self.inner.get_user(user_id)).await
+    }
+
+    async fn get_notifications(&self, unread_only: bool, page: &PageRequest) -> TicketResult<Page<Notification>> {
+        self.read("get_notifications", || self.inner.get_notifications(unread_only, page)).await
+    }
+
+    async fn get_teams(&self) -> TicketResult<Vec<Team>> {
+        self.read("get_teams", || self.inner.get_teams()).await
+    }
+
+    async fn get_team_members(&self, team_id: &str) -> TicketResult<Vec<User>> {
+        self.read("get_team_members", || self.inner.get_team_members(team_id)).await
+    }
+
+    async fn get_workflow_states(&self, team_id: &str) -> TicketResult<Vec<WorkflowState>> {
+        self.read("get_workflow_states", || self.inner.get_workflow_states(team_id)).await
+    }
+
+    async fn get_labels(&self) -> TicketResult<Vec<Label>> {
+        self.read("get_labels", || self.inner.get_labels()).await
+    }
+
+    async fn create_label(&self, request: &CreateLabelRequest) -> TicketResult<Label> {
+        self.write("create_label", || self.inner.create_label(request)).await
+    }
+
+    async fn get_projects(&self, page: &PageRequest) -> TicketResult<Page<Project>> {
+        self.read("get_projects", || self.inner.get_projects(page)).await
+    }
+
+    async fn get_project(&self, project_id: &str) -> TicketResult<Option<Project>> {
+        self.read("get_project", || self.inner.get_project(project_id)).await
+    }
+
+    async fn get_project_milestones(&self, project_id: &str) -> TicketResult<Vec<ProjectMilestone>> {
+        self.read("get_project_milestones", || self.inner.get_project_milestones(project_id)).await
+    }
+
+    async fn create_project(&self, request: &CreateProjectRequest) -> TicketResult<Project> {
+        self.write("create_project", || self.inner.create_project(request)).await
+    }
+
+    async fn update_project(&self, request: &UpdateProjectRequest) -> TicketResult<Project> {
+        self.write("update_project", || self.inner.update_project(request)).await
+    }
+
+    async fn create_milestone(&self, request: &CreateMilestoneRequest) -> TicketResult<ProjectMilestone> {
+        self.write("create_milestone", || self.inner.create_milestone(request)).await
+    }
+
+    async fn get_project_updates(&self, project_id: &str, page: &PageRequest) -> TicketResult<Page<ProjectUpdate>> {
+        self.read("get_project_updates", || self.inner.get_project_updates(project_id, page)).await
+    }
+
+    async fn get_documents(&self, project_id: Option<&str>, page: &PageRequest) -> TicketResult<Page<Document>> {
+        self.read("get_documents", || self.inner.get_documents(project_id, page)).await
+    }
+
+    async fn get_document(&self, document_id: &str) -> TicketResult<Option<Document>> {
+        self.read("get_document", || self.inner.get_document(document_id)).await
+    }
+
+    async fn get_initiatives(&self) -> TicketResult<Vec<Initiative>> {
+        self.read("get_initiatives", || self.inner.get_initiatives()).await
+    }
+
+    async fn get_initiative_projects(&self, initiative_id: &str) -> TicketResult<Vec<Project>> {
+        self.read("get_initiative_projects", || self.inner.get_initiative_projects(initiative_id)).await
+    }
+
+    async fn get_cycles(&self, team_id: &str) -> TicketResult<Vec<Cycle>> {
+        self.read("get_cycles", || self.inner.get_cycles(team_id)).await
+    }
+
+    async fn get_active_cycle(&self, team_id: &str) -> TicketResult<Option<Cycle>> {
+        self.read("get_active_cycle", || self.inner.get_active_cycle(team_id)).await
+    }
+
+    async fn assign_ticket_to_cycle(&self, ticket_id: &str, cycle_id: &str) -> TicketResult<Ticket> {
+        self.write("assign_ticket_to_cycle", || self.inner.assign_ticket_to_cycle(ticket_id, cycle_id)).await
+    }
+
+    async fn log_time(&self, request: &LogTimeRequest) -> TicketResult<TimeEntry> {
+        self.write("log_time", || self.inner.log_time(request)).await
+    }
+
+    async fn get_time_entries(&self, ticket_id: &str) -> TicketResult<Vec<TimeEntry>> {
+        self.read("get_time_entries", || self.inner.get_time_entries(ticket_id)).await
+    }
+
+    async fn get_workspace(&self) -> TicketResult<Workspace> {
+        self.read("get_workspace", || self.inner.get_workspace()).await
+    }
+}
@@ -0,0 +1,51 @@
+use anyhow::{Context, Result};
+use std::cmp::Ordering;
+use std::path::Path;
+
+use crate::domain::{Priority, SavedView, SortField, Ticket, TicketSort};
+
+/// Loads saved views from a JSON file holding an array of views.
+pub fn load_saved_views(path: impl AsRef<Path>) -> Result<Vec<SavedView>> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read saved views from {}", path.display()))?;
+    let views: Vec<SavedView> = serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid saved views in {}", path.display()))?;
+    Ok(views)
+}
+
+/// Finds a view by name, ignoring case.
+pub fn find_saved_view<'a>(views: &'a [SavedView], name: &str) -> Option<&'a SavedView> {
+    views.iter().find(|view| view.name.eq_ignore_ascii_case(name))
+}
+
+/// Higher is more urgent; custom priorities sit between medium and low.
+pub(crate) fn priority_rank(priority: &Priority) -> u8 {
+    match priority {
+        Priority::Highest => 6,
+        Priority::High => 5,
+        Priority::Medium => 4,
+        Priority::Custom(_) => 3,
+        Priority::Low => 2,
+        Priority::Lowest => 1,
+        Priority::None => 0,
+    }
+}
+
+/// Sorts tickets in place. Tickets without a due date always sort last.
+pub fn sort_tickets(tickets: &mut [Ticket], sort: &TicketSort) {
+    let direction = |ordering: Ordering| if sort.descending { ordering.reverse() } else { ordering };
+
+    tickets.sort_by(|a, b| match sort.field {
+        SortField::Priority => direction(priority_rank(&a.priority).cmp(&priority_rank(&b.priority))),
+        SortField::CreatedAt => direction(a.created_at.cmp(&b.created_at)),
+        SortField::UpdatedAt => direction(a.updated_at.cmp(&b.updated_at)),
+        SortField::Title => direction(a.title.to_lowercase().cmp(&b.title.to_lowercase())),
+        SortField::DueDate => match (a.due_date, b.due_date) {
+            (Some(a), Some(b)) => direction(a.cmp(&b)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        },
+    });
+}
@@ -0,0 +1,341 @@
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+
+use crate::core::{ProviderRegistry, find_template};
+use crate::domain::TicketTemplate;
+
+/// How often the scheduler checks for due schedules.
+const SCHEDULER_TICK_SECS: u64 = 30;
+
+/// How far ahead `CronSchedule::next_after` looks before giving up on an
+/// expression that never matches, such as `0 0 31 2 *`.
+const CRON_SEARCH_DAYS: i64 = 366 * 5;
+
+/// A five-field cron expression (`minute hour day-of-month month
+/// day-of-week`) evaluated in UTC. Fields take `*`, numbers, lists
+/// (`1,15`), ranges (`1-5`), and steps (`*/15`); day-of-week counts from
+/// Sunday as 0 (7 is Sunday too). `@hourly`, `@daily`, `@weekly`,
+/// `@monthly`, and `@yearly` are shorthands.
+#[derive(Debug, Clone)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Whether day-of-month or day-of-week was restricted; when both are,
+    /// either one matching is enough, as in standard cron. A field starting
+    /// with `*`, such as `*/1` or `*/2`, does not restrict.
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Result<Self> {
+        let expanded = match expression.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            other => other,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let [minute, hour, day, month, weekday] = fields[..] else {
+            return Err(anyhow!("Cron expression {:?} must have five fields: minute hour day month weekday", expression));
+        };
+
+        let field = |spec: &str, min: u32, max: u32, name: &str| {
+            Self::parse_field(spec, min, max)
+                .with_context(|| format!("Invalid {} field {:?} in cron expression {:?}", name, spec, expression))
+        };
+        let mut weekdays = field(weekday, 0, 7, "weekday")?;
+        // 7 is Sunday too
+        if weekdays & (1 << 7) != 0 {
+            weekdays |= 1;
+        }
+        Ok(Self {
+            minutes: field(minute, 0, 59, "minute")?,
+            hours: field(hour, 0, 23, "hour")?,
+            days: field(day, 1, 31, "day")?,
+            months: field(month, 1, 12, "month")?,
+            weekdays,
+            days_restricted: !day.starts_with('*'),
+            weekdays_restricted: !weekday.starts_with('*'),
+        })
+    }
+
+    fn parse_field(spec: &str, min: u32, max: u32) -> Result<u64> {
+        let number = |s: &str| -> Result<u32> {
+            let n: u32 = s.parse().map_err(|_| anyhow!("{:?} is not a number", s))?;
+            if n < min || n > max {
+                return Err(anyhow!("{} is outside {}-{}", n, min, max));
+            }
+            Ok(n)
+        };
+
+        let mut bits = 0u64;
+        for part in spec.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => (range, Some(step.parse::<u32>().map_err(|_| anyhow!("Invalid step {:?}", step))?)),
+                None => (part, None),
+            };
+            let (start, end) = match range {
+                "*" => (min, max),
+                _ => match range.split_once('-') {
+                    Some((start, end)) => (number(start)?, number(end)?),
+                    // `5/15` runs from 5 to the end of the field
+                    None if step.is_some() => (number(range)?, max),
+                    None => (number(range)?, number(range)?),
+                },
+            };
+            let step = step.unwrap_or(1);
+            if step == 0 || start > end {
+                return Err(anyhow!("Empty range {:?}", part));
+            }
+            for value in (start..=end).step_by(step as usize) {
+                bits |= 1 << value;
+            }
+        }
+        Ok(bits)
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let day = self.days & (1 << date.day()) != 0;
+        let weekday = self.weekdays & (1 << date.weekday().num_days_from_sunday()) != 0;
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => day || weekday,
+            _ => day && weekday,
+        }
+    }
+
+    /// The first matching minute strictly after `after`, or `None` when the
+    /// expression never matches within the next five years.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut time = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let give_up = after + Duration::days(CRON_SEARCH_DAYS);
+
+        while time < give_up {
+            if self.months & (1 << time.month()) == 0 {
+                let (year, month) = if time.month() == 12 { (time.year() + 1, 1) } else { (time.year(), time.month() + 1) };
+                time = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?.and_utc();
+            } else if !self.matches_day(time.date_naive()) {
+                time = (time.date_naive() + Duration::days(1)).and_hms_opt(0, 0, 0)?.and_utc();
+            } else if self.hours & (1 << time.hour()) == 0 {
+                time = time.with_minute(0)? + Duration::hours(1);
+            } else if self.minutes & (1 << time.minute()) == 0 {
+                time += Duration::minutes(1);
+            } else {
+                return Some(time);
+            }
+        }
+        None
+    }
+}
+
+/// A ticket created from a template every time `cron` matches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringTicket {
+    pub name: String,
+    /// Name of a ticket template.
+    pub template: String,
+    pub cron: String,
+    /// Values for the template's placeholders; `date` is filled in.
+    #[serde(default)]
+    pub variables: HashMap<String, String>,
+    /// Team id or key; falls back to the template's, then the default team.
+    #[serde(default)]
+    pub team: Option<String>,
+    #[serde(default)]
+    pub assignee_id: Option<String>,
+    /// Registry provider; the default provider when unset.
+    #[serde(default)]
+    pub provider: Option<String>,
+    #[serde(default)]
+    pub paused: bool,
+    /// Written back after each run so restarts do not create it twice.
+    #[serde(default)]
+    pub last_run: Option<DateTime<Utc>>,
+}
+
+/// A schedule as reported by `list_schedules`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleStatus {
+    #[serde(flatten)]
+    pub schedule: RecurringTicket,
+    /// `None` while paused.
+    pub next_run: Option<DateTime<Utc>>,
+    pub last_ticket: Option<String>,
+    pub last_error: Option<String>,
+}
+
+struct Entry {
+    schedule: RecurringTicket,
+    cron: CronSchedule,
+    /// When counting toward the next run started: the last run, or for a
+    /// schedule that never ran, when it was loaded or resumed.
+    since: DateTime<Utc>,
+    last_ticket: Option<String>,
+    last_error: Option<String>,
+}
+
+/// Creates tickets from templates on cron schedules. Schedules live in a
+/// JSON file holding an array of `RecurringTicket`s; pausing and runs are
+/// written back to it. A schedule that came due several times while the
+/// server was down runs once when it comes back.
+pub struct Scheduler {
+    path: PathBuf,
+    registry: ProviderRegistry,
+    templates: Arc<Vec<TicketTemplate>>,
+    default_team: Option<String>,
+    entries: Mutex<Vec<Entry>>,
+}
+
+impl Scheduler {
+    pub fn load(
+        path: impl AsRef<Path>,
+        registry: ProviderRegistry,
+        templates: Arc<Vec<TicketTemplate>>,
+        default_team: Option<String>,
+    ) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read schedules from {}", path.display()))?;
+        let schedules: Vec<RecurringTicket> = serde_json::from_str(&contents)
+            .with_context(|| format!("Invalid schedules in {}", path.display()))?;
+
+        let now = Utc::now();
+        let entries = schedules
+            .into_iter()
+            .map(|schedule| {
+                if find_template(&templates, &schedule.template).is_none() {
+                    return Err(anyhow!("Schedule {} uses unknown template {}", schedule.name, schedule.template));
+                }
+                registry.get(schedule.provider.as_deref())?;
+                Ok(Entry {
+                    cron: CronSchedule::parse(&schedule.cron)?,
+                    since: schedule.last_run.unwrap_or(now),
+                    schedule,
+                    last_ticket: None,
+                    last_error: None,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            registry,
+            templates,
+            default_team,
+            entries: Mutex::new(entries),
+        })
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Vec<Entry>>> {
+        self.entries.lock().map_err(|_| anyhow!("Scheduler lock poisoned"))
+    }
+
+    fn status(entry: &Entry) -> ScheduleStatus {
+        ScheduleStatus {
+            schedule: entry.schedule.clone(),
+            next_run: if entry.schedule.paused { None } else { entry.cron.next_after(entry.since) },
+            last_ticket: entry.last_ticket.clone(),
+            last_error: entry.last_error.clone(),
+        }
+    }
+
+    pub fn list(&self) -> Result<Vec<ScheduleStatus>> {
+        Ok(self.lock()?.iter().map(Self::status).collect())
+    }
+
+    /// Pauses or resumes a schedule by name, ignoring case. A resumed
+    /// schedule next runs at its first match after now, not for the runs it
+    /// missed while paused.
+    pub fn set_paused(&self, name: &str, paused: bool) -> Result<ScheduleStatus> {
+        let mut entries = self.lock()?;
+        let entry = entries
+            .iter_mut()
+            .find(|entry| entry.schedule.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| anyhow!("No schedule named {}", name))?;
+        if entry.schedule.paused && !paused {
+            entry.since = Utc::now();
+        }
+        entry.schedule.paused = paused;
+        let status = Self::status(entry);
+        self.save(&entries)?;
+        info!("Schedule {} {}", status.schedule.name, if paused { "paused" } else { "resumed" });
+        Ok(status)
+    }
+
+    fn save(&self, entries: &[Entry]) -> Result<()> {
+        let schedules: Vec<&RecurringTicket> = entries.iter().map(|entry| &entry.schedule).collect();
+        std::fs::write(&self.path, serde_json::to_string_pretty(&schedules)?)
+            .with_context(|| format!("Failed to save schedules to {}", self.path.display()))
+    }
+
+    /// Creates a ticket for every unpaused schedule due at `now`.
+    pub async fn run_due(&self, now: DateTime<Utc>) -> Result<()> {
+        let due: Vec<RecurringTicket> = self.lock()?
+            .iter()
+            .filter(|entry| !entry.schedule.paused)
+            .filter(|entry| entry.cron.next_after(entry.since).is_some_and(|next| next <= now))
+            .map(|entry| entry.schedule.clone())
+            .collect();
+
+        for schedule in due {
+            let result = self.create(&schedule).await;
+            let mut entries = self.lock()?;
+            let Some(entry) = entries.iter_mut().find(|entry| entry.schedule.name == schedule.name) else {
+                continue;
+            };
+            // Failures are not retried until the next occurrence, so a
+            // broken template cannot create a ticket every tick
+            entry.since = now;
+            entry.schedule.last_run = Some(now);
+            match result {
+                Ok(identifier) => {
+                    info!("Schedule {} created {}", schedule.name, identifier);
+                    entry.last_ticket = Some(identifier);
+                    entry.last_error = None;
+                }
+                Err(e) => {
+                    warn!("Schedule {} failed: {}", schedule.name, e);
+                    entry.last_error = Some(e.to_string());
+                }
+            }
+            self.save(&entries)?;
+        }
+        Ok(())
+    }
+
+    async fn create(&self, schedule: &RecurringTicket) -> Result<String> {
+        let template = find_template(&self.templates, &schedule.template)
+            .ok_or_else(|| anyhow!("No ticket template named {}", schedule.template))?;
+        let application = self.registry.get(schedule.provider.as_deref())?;
+        let team = schedule.team.as_deref()
+            .or(template.team.as_deref())
+            .or(application.defaults().team.as_deref())
+            .or(self.default_team.as_deref())
+            .ok_or_else(|| anyhow!("Schedule {} has no team: set one on it, its template, or the default team", schedule.name))?;
+        let ticket = application
+            .create_from_template(template, &schedule.variables, team, schedule.assignee_id.as_deref())
+            .await?;
+        Ok(ticket.identifier)
+    }
+
+    /// Checks for due schedules until the task is dropped.
+    pub async fn run(self: Arc<Self>) {
+        info!("Scheduling {} recurring tickets from {}", self.entries.lock().map(|e| e.len()).unwrap_or(0), self.path.display());
+        loop {
+            if let Err(e) = self.run_due(Utc::now()).await {
+                warn!("Running schedules failed: {}", e);
+            }
+            tokio::time::sleep(std::time::Duration::from_secs(SCHEDULER_TICK_SECS)).await;
+        }
+    }
+}
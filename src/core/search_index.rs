@@ -0,0 +1,68 @@
+use anyhow::Result;
+use std::sync::{Arc, Mutex};
+
+use crate::core::{EventListener, ProviderTicket};
+use crate::domain::{Ticket, WebhookEntity, WebhookEvent};
+use crate::ports::FullTextIndex;
+
+/// Full-text index over tickets seen through prefetching and webhooks,
+/// searchable without calling any provider.
+pub struct TicketIndex {
+    index: Arc<dyn FullTextIndex>,
+    /// Tickets webhooks reported as changed, to fetch before the next search.
+    stale: Mutex<Vec<(String, String)>>,
+}
+
+impl TicketIndex {
+    pub fn new(index: Arc<dyn FullTextIndex>) -> Self {
+        Self {
+            index,
+            stale: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Adds or replaces `provider`'s tickets.
+    pub fn index_tickets(&self, provider: &str, tickets: &[Ticket]) -> Result<()> {
+        self.index.index_tickets(provider, tickets)?;
+
+        // Indexed just now, so no need to fetch them again
+        if let Ok(mut stale) = self.stale.lock() {
+            stale.retain(|(p, id)| p != provider || !tickets.iter().any(|t| &t.id == id));
+        }
+        Ok(())
+    }
+
+    /// Drops a ticket the provider no longer returns.
+    pub fn remove_ticket(&self, provider: &str, ticket_id: &str) -> Result<()> {
+        self.index.remove_ticket(provider, ticket_id)
+    }
+
+    /// `(provider, ticket id)` pairs changed since they were indexed. The
+    /// caller re-fetches them and passes them back to `index_tickets`.
+    pub fn take_stale(&self) -> Vec<(String, String)> {
+        self.stale.lock().map(|mut stale| std::mem::take(&mut *stale)).unwrap_or_default()
+    }
+
+    /// Tickets matching `query`, best match first; see `FullTextIndex::search`.
+    pub fn search(&self, query: &str, provider: Option<&str>, fuzzy: bool, limit: usize) -> Result<Vec<ProviderTicket>> {
+        Ok(self.index.search(query, provider, fuzzy, limit)?
+            .into_iter()
+            .map(|(provider, ticket)| ProviderTicket { provider, ticket })
+            .collect())
+    }
+}
+
+impl EventListener for TicketIndex {
+    fn on_event(&self, event: &WebhookEvent) {
+        if event.entity != WebhookEntity::Ticket {
+            return;
+        }
+        // Removals too: a ticket that left someone's list may still exist
+        if let (Some(ticket_id), Ok(mut stale)) = (&event.ticket_id, self.stale.lock()) {
+            let entry = (event.provider.clone(), ticket_id.clone());
+            if !stale.contains(&entry) {
+                stale.push(entry);
+            }
+        }
+    }
+}
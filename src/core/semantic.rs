@@ -0,0 +1,167 @@
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::core::EventListener;
+use crate::domain::{Ticket, WebhookEntity, WebhookEvent};
+use crate::ports::Embedder;
+
+/// Texts sent to the embedder per request.
+const EMBED_BATCH_SIZE: usize = 64;
+
+/// A ticket close in meaning to a `semantic_search` query.
+#[derive(Debug, Clone, Serialize)]
+pub struct SemanticMatch {
+    pub provider: String,
+    /// Cosine similarity to the query; higher is closer.
+    pub score: f32,
+    #[serde(flatten)]
+    pub ticket: Ticket,
+}
+
+struct Entry {
+    provider: String,
+    ticket: Ticket,
+    /// What `vector` was computed from, to skip re-embedding unchanged text.
+    text: String,
+    vector: Vec<f32>,
+}
+
+/// Embeddings of ticket titles and descriptions, searched by nearest
+/// neighbour. Filled like the local search index, from prefetching and
+/// webhooks; vectors live in memory and are recomputed after a restart.
+pub struct SemanticIndex {
+    embedder: Arc<dyn Embedder>,
+    entries: Mutex<HashMap<String, Entry>>,
+    /// Tickets webhooks reported as changed, to fetch before the next search.
+    stale: Mutex<Vec<(String, String)>>,
+}
+
+impl SemanticIndex {
+    pub fn new(embedder: Arc<dyn Embedder>) -> Self {
+        Self {
+            embedder,
+            entries: Mutex::new(HashMap::new()),
+            stale: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn model(&self) -> &str {
+        self.embedder.model()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().map(|entries| entries.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn key(provider: &str, ticket_id: &str) -> String {
+        format!("{}/{}", provider, ticket_id)
+    }
+
+    fn text(ticket: &Ticket) -> String {
+        match &ticket.description {
+            Some(description) if !description.is_empty() => format!("{}\n\n{}", ticket.title, description.to_markdown()),
+            _ => ticket.title.clone(),
+        }
+    }
+
+    /// Adds or replaces `provider`'s tickets, embedding only those whose
+    /// title or description changed.
+    pub async fn index_tickets(&self, provider: &str, tickets: &[Ticket]) -> Result<()> {
+        let mut to_embed = Vec::new();
+        {
+            let mut entries = self.entries.lock().map_err(|_| anyhow!("Semantic index lock poisoned"))?;
+            for ticket in tickets {
+                let text = Self::text(ticket);
+                match entries.get_mut(&Self::key(provider, &ticket.id)) {
+                    Some(entry) if entry.text == text => entry.ticket = ticket.clone(),
+                    _ => to_embed.push((ticket, text)),
+                }
+            }
+        }
+
+        for batch in to_embed.chunks(EMBED_BATCH_SIZE) {
+            let texts: Vec<String> = batch.iter().map(|(_, text)| text.clone()).collect();
+            let vectors = self.embedder.embed(&texts).await?;
+            let mut entries = self.entries.lock().map_err(|_| anyhow!("Semantic index lock poisoned"))?;
+            for ((ticket, text), vector) in batch.iter().zip(vectors) {
+                entries.insert(Self::key(provider, &ticket.id), Entry {
+                    provider: provider.to_string(),
+                    ticket: (*ticket).clone(),
+                    text: text.clone(),
+                    vector,
+                });
+            }
+        }
+
+        // Indexed just now, so no need to fetch them again
+        if let Ok(mut stale) = self.stale.lock() {
+            stale.retain(|(p, id)| p != provider || !tickets.iter().any(|t| &t.id == id));
+        }
+        Ok(())
+    }
+
+    /// Drops a ticket the provider no longer returns.
+    pub fn remove_ticket(&self, provider: &str, ticket_id: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(&Self::key(provider, ticket_id));
+        }
+    }
+
+    /// `(provider, ticket id)` pairs changed since they were indexed. The
+    /// caller re-fetches them and passes them back to `index_tickets`.
+    pub fn take_stale(&self) -> Vec<(String, String)> {
+        self.stale.lock().map(|mut stale| std::mem::take(&mut *stale)).unwrap_or_default()
+    }
+
+    /// The `limit` tickets closest in meaning to `query`, closest first.
+    pub async fn search(&self, query: &str, provider: Option<&str>, limit: usize) -> Result<Vec<SemanticMatch>> {
+        let query_vector = self.embedder.embed(&[query.to_string()]).await?
+            .pop()
+            .ok_or_else(|| anyhow!("Embedder returned no vector for the query"))?;
+
+        let entries = self.entries.lock().map_err(|_| anyhow!("Semantic index lock poisoned"))?;
+        let mut matches: Vec<SemanticMatch> = entries.values()
+            .filter(|entry| provider.is_none_or(|p| p == entry.provider))
+            .map(|entry| SemanticMatch {
+                provider: entry.provider.clone(),
+                score: cosine_similarity(&query_vector, &entry.vector),
+                ticket: entry.ticket.clone(),
+            })
+            .collect();
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+        matches.truncate(limit);
+        Ok(matches)
+    }
+}
+
+/// 0 for all-zero vectors and for vectors of different lengths, which
+/// cannot come from the same model.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norms = a.iter().map(|x| x * x).sum::<f32>().sqrt() * b.iter().map(|y| y * y).sum::<f32>().sqrt();
+    if norms == 0.0 { 0.0 } else { dot / norms }
+}
+
+impl EventListener for SemanticIndex {
+    fn on_event(&self, event: &WebhookEvent) {
+        if event.entity != WebhookEntity::Ticket {
+            return;
+        }
+        // Removals too: a ticket that left someone's list may still exist
+        if let (Some(ticket_id), Ok(mut stale)) = (&event.ticket_id, self.stale.lock()) {
+            let entry = (event.provider.clone(), ticket_id.clone());
+            if !stale.contains(&entry) {
+                stale.push(entry);
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::domain::{Comment, Label, Project, Ticket, Workspace};
+
+/// Format version written into snapshots; bumped when older snapshots can
+/// no longer be read as they are.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+/// Everything in one provider's workspace, in the provider-neutral domain
+/// model, so it can be kept as a backup or re-created in another provider.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceSnapshot {
+    pub version: u32,
+    /// Provider the snapshot was exported from.
+    pub provider: String,
+    pub exported_at: DateTime<Utc>,
+    /// Teams come with their members, which assignees and comment authors
+    /// refer to.
+    pub workspace: Workspace,
+    pub labels: Vec<Label>,
+    pub projects: Vec<Project>,
+    pub tickets: Vec<TicketSnapshot>,
+}
+
+/// One ticket, fetched in detail, with its comments.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketSnapshot {
+    /// Key of the ticket's team.
+    pub team: String,
+    pub ticket: Ticket,
+    #[serde(default)]
+    pub comments: Vec<Comment>,
+}
+
+/// Writes a snapshot as pretty-printed JSON.
+pub fn save_snapshot(path: impl AsRef<Path>, snapshot: &WorkspaceSnapshot) -> Result<()> {
+    let path = path.as_ref();
+    std::fs::write(path, serde_json::to_string_pretty(snapshot)?)
+        .with_context(|| format!("Failed to write workspace snapshot to {}", path.display()))
+}
+
+/// Reads a snapshot written by `save_snapshot`.
+pub fn load_snapshot(path: impl AsRef<Path>) -> Result<WorkspaceSnapshot> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read workspace snapshot from {}", path.display()))?;
+    let snapshot: WorkspaceSnapshot = serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid workspace snapshot in {}", path.display()))?;
+    if snapshot.version > SNAPSHOT_VERSION {
+        return Err(anyhow!(
+            "{} is a version {} snapshot; this build reads up to version {}",
+            path.display(), snapshot.version, SNAPSHOT_VERSION
+        ));
+    }
+    Ok(snapshot)
+}
+
+/// What an import created, and what it could not.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub labels_created: usize,
+    pub projects_created: usize,
+    pub tickets_created: usize,
+    pub comments_created: usize,
+    /// Parent links and relations between tickets.
+    pub links_created: usize,
+    /// Identifier of each ticket in the snapshot and of the ticket created
+    /// for it.
+    pub tickets: BTreeMap<String, String>,
+    pub failures: Vec<String>,
+}
+
+impl fmt::Display for ImportReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Created {} labels, {} projects, {} tickets, {} comments, and {} links",
+            self.labels_created, self.projects_created, self.tickets_created, self.comments_created, self.links_created
+        )?;
+        if !self.failures.is_empty() {
+            write!(f, "\n{} failures:", self.failures.len())?;
+            for failure in &self.failures {
+                write!(f, "\n  {}", failure)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A comment's body headed with who wrote it and when, since imported
+/// comments are posted by the importing user.
+pub fn attributed_comment(comment: &Comment, author: Option<&str>) -> String {
+    format!(
+        "*{} on {}:*\n\n{}",
+        author.unwrap_or("Someone"),
+        comment.created_at.format("%Y-%m-%d %H:%M UTC"),
+        comment.body
+    )
+}
@@ -0,0 +1,55 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// How long past its TTL cached data may still answer reads while the
+/// provider is unavailable.
+pub const DEFAULT_MAX_STALENESS: Duration = Duration::from_secs(3600);
+
+thread_local! {
+    /// The age of the oldest stale data served in the scope being polled.
+    static CURRENT: RefCell<Option<Arc<Mutex<Option<Duration>>>>> = const { RefCell::new(None) };
+}
+
+/// Notes that a read was answered with cached data `age` old because the
+/// provider could not be reached. Outside `track_stale_reads` it does nothing.
+pub fn record_stale_read(age: Duration) {
+    CURRENT.with(|current| {
+        if let Some(oldest) = &*current.borrow() {
+            if let Ok(mut oldest) = oldest.lock() {
+                *oldest = Some(oldest.map_or(age, |oldest| oldest.max(age)));
+            }
+        }
+    });
+}
+
+/// Runs `future`, returning its output and the age of the oldest stale data
+/// any read in it was answered with, if any was. Reads in tasks spawned by
+/// `future` are not seen.
+pub async fn track_stale_reads<F: Future>(future: F) -> (F::Output, Option<Duration>) {
+    let oldest = Arc::new(Mutex::new(None));
+    let output = StaleScope { future: Box::pin(future), oldest: oldest.clone() }.await;
+    let age = oldest.lock().ok().and_then(|oldest| *oldest);
+    (output, age)
+}
+
+/// Makes its scope current while its future is polled, so the scope
+/// follows the future across threads.
+struct StaleScope<F> {
+    future: Pin<Box<F>>,
+    oldest: Arc<Mutex<Option<Duration>>>,
+}
+
+impl<F: Future> Future for StaleScope<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        let previous = CURRENT.with(|current| current.replace(Some(self.oldest.clone())));
+        let poll = self.future.as_mut().poll(cx);
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+        poll
+    }
+}
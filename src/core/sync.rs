@@ -0,0 +1,379 @@
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info, warn};
+
+use crate::core::{Application, ProviderRegistry};
+use crate::domain::{CreateTicketRequest, Ticket, TicketFilter, UpdateTicketRequest, Workflow};
+
+/// One end of a sync: a provider from the registry and the team whose
+/// tickets are mirrored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncEndpoint {
+    pub provider: String,
+    /// Team id or key.
+    pub team: String,
+}
+
+/// Ticket fields copied between the two sides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncField {
+    Title,
+    Description,
+    Priority,
+    /// Matched by state type (open, in progress, ...), since state names
+    /// differ between providers.
+    State,
+}
+
+fn default_sync_fields() -> Vec<SyncField> {
+    vec![SyncField::Title, SyncField::Description, SyncField::Priority, SyncField::State]
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncConfig {
+    pub left: SyncEndpoint,
+    pub right: SyncEndpoint,
+    #[serde(default = "default_sync_fields")]
+    pub fields: Vec<SyncField>,
+    /// Where linked ticket pairs are kept between runs. Required, since
+    /// without it every restart would mirror each ticket again.
+    pub state_file: PathBuf,
+}
+
+/// Loads a sync configuration from a JSON file.
+pub fn load_sync_config(path: impl AsRef<Path>) -> Result<SyncConfig> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read sync configuration from {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid sync configuration in {}", path.display()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncSide {
+    Left,
+    Right,
+}
+
+/// A mirrored pair and each side's `updated_at` when it was last synced;
+/// a later `updated_at` means that side changed since.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncLink {
+    pub left_id: String,
+    pub right_id: String,
+    pub left_updated_at: DateTime<Utc>,
+    pub right_updated_at: DateTime<Utc>,
+    pub synced_at: DateTime<Utc>,
+}
+
+/// Both sides of a pair changed since the last sync and now disagree.
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncConflict {
+    pub left: String,
+    pub right: String,
+    pub fields: Vec<SyncField>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SyncReport {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub unchanged: usize,
+    pub conflicts: Vec<SyncConflict>,
+    pub failures: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncStatus {
+    pub left: SyncEndpoint,
+    pub right: SyncEndpoint,
+    pub fields: Vec<SyncField>,
+    pub linked: usize,
+    pub last_run: Option<DateTime<Utc>>,
+    pub last_report: Option<SyncReport>,
+}
+
+/// Mirrors tickets between two providers. Each run creates the tickets one
+/// side is missing, copies changes from whichever side changed, and reports
+/// pairs changed on both sides as conflicts instead of overwriting either.
+pub struct SyncEngine {
+    config: SyncConfig,
+    left: Arc<Application>,
+    right: Arc<Application>,
+    links: Mutex<Vec<SyncLink>>,
+    last_run: Mutex<Option<(DateTime<Utc>, SyncReport)>>,
+    /// Held for a whole run so two runs cannot mirror the same ticket twice.
+    running: futures::lock::Mutex<()>,
+}
+
+struct Side<'a> {
+    application: &'a Application,
+    team_id: String,
+    workflow: Workflow,
+    tickets: HashMap<String, Ticket>,
+}
+
+impl SyncEngine {
+    pub fn new(config: SyncConfig, registry: &ProviderRegistry) -> Result<Self> {
+        let left = registry.get(Some(&config.left.provider))?;
+        let right = registry.get(Some(&config.right.provider))?;
+
+        let path = &config.state_file;
+        let links = if path.exists() {
+            let contents = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read sync state from {}", path.display()))?;
+            serde_json::from_str(&contents)
+                .with_context(|| format!("Invalid sync state in {}", path.display()))?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            config,
+            left,
+            right,
+            links: Mutex::new(links),
+            last_run: Mutex::new(None),
+            running: futures::lock::Mutex::new(()),
+        })
+    }
+
+    pub fn status(&self) -> SyncStatus {
+        let linked = self.links.lock().map(|links| links.len()).unwrap_or(0);
+        let last_run = self.last_run.lock().ok().and_then(|last| last.clone());
+        SyncStatus {
+            left: self.config.left.clone(),
+            right: self.config.right.clone(),
+            fields: self.config.fields.clone(),
+            linked,
+            last_run: last_run.as_ref().map(|(at, _)| *at),
+            last_report: last_run.map(|(_, report)| report),
+        }
+    }
+
+    /// Runs one sync pass. `prefer` settles conflicts in favour of that side;
+    /// without it conflicting pairs are left alone and reported.
+    pub async fn run(&self, prefer: Option<SyncSide>) -> Result<SyncReport> {
+        let _running = self.running.lock().await;
+        debug!("Syncing {} <-> {}", self.config.left.provider, self.config.right.provider);
+
+        let mut left = Self::load_side(&self.left, &self.config.left).await?;
+        let mut right = Self::load_side(&self.right, &self.config.right).await?;
+        let mut links = self.links.lock().map_err(|_| anyhow!("Sync state lock poisoned"))?.clone();
+        let mut report = SyncReport::default();
+
+        for link in &mut links {
+            let pair = (left.tickets.remove(&link.left_id), right.tickets.remove(&link.right_id));
+            let (Some(l), Some(r)) = pair else {
+                // Out of scope now (moved team, archived, or deleted)
+                continue;
+            };
+            let left_changed = l.updated_at > link.left_updated_at;
+            let right_changed = r.updated_at > link.right_updated_at;
+            let differing = self.differing_fields(&l, &r);
+
+            let copy_from = match (left_changed, right_changed) {
+                _ if differing.is_empty() => None,
+                (true, true) => match prefer {
+                    Some(side) => Some(side),
+                    None => {
+                        report.conflicts.push(SyncConflict {
+                            left: l.identifier.clone(),
+                            right: r.identifier.clone(),
+                            fields: differing,
+                        });
+                        continue;
+                    }
+                },
+                (true, false) => Some(SyncSide::Left),
+                (false, true) => Some(SyncSide::Right),
+                (false, false) => None,
+            };
+
+            let (l, r) = match copy_from {
+                None => {
+                    report.unchanged += 1;
+                    (l, r)
+                }
+                Some(SyncSide::Left) => match self.copy_fields(&l, &r, &right).await {
+                    Ok(r) => {
+                        report.updated.push(r.identifier.clone());
+                        (l, r)
+                    }
+                    Err(e) => {
+                        report.failures.push(format!("{} -> {}: {}", l.identifier, r.identifier, e));
+                        continue;
+                    }
+                },
+                Some(SyncSide::Right) => match self.copy_fields(&r, &l, &left).await {
+                    Ok(l) => {
+                        report.updated.push(l.identifier.clone());
+                        (l, r)
+                    }
+                    Err(e) => {
+                        report.failures.push(format!("{} -> {}: {}", r.identifier, l.identifier, e));
+                        continue;
+                    }
+                },
+            };
+
+            link.left_updated_at = l.updated_at;
+            link.right_updated_at = r.updated_at;
+            link.synced_at = Utc::now();
+        }
+
+        // Linked tickets were taken out above; the rest have no counterpart yet
+
+        for (from, to, side) in [(&left, &right, SyncSide::Left), (&right, &left, SyncSide::Right)] {
+            for ticket in from.tickets.values() {
+                let mirror = match self.mirror(ticket, to).await {
+                    Ok(mirror) => mirror,
+                    Err(e) => {
+                        report.failures.push(format!("{}: {}", ticket.identifier, e));
+                        continue;
+                    }
+                };
+                report.created.push(mirror.identifier.clone());
+
+                // Saved before anything else can fail, so a later run never
+                // creates this counterpart again
+                let (l, r) = match side {
+                    SyncSide::Left => (ticket, &mirror),
+                    SyncSide::Right => (&mirror, ticket),
+                };
+                links.push(SyncLink {
+                    left_id: l.id.clone(),
+                    right_id: r.id.clone(),
+                    left_updated_at: l.updated_at,
+                    right_updated_at: r.updated_at,
+                    synced_at: Utc::now(),
+                });
+                self.save_links(links.clone())?;
+
+                // New tickets start in the team's default state
+                if !self.config.fields.contains(&SyncField::State) || mirror.state.type_ == ticket.state.type_ {
+                    continue;
+                }
+                let link = links.last_mut().expect("link pushed above");
+                match self.copy_fields(ticket, &mirror, to).await {
+                    Ok(mirror) => match side {
+                        SyncSide::Left => link.right_updated_at = mirror.updated_at,
+                        SyncSide::Right => link.left_updated_at = mirror.updated_at,
+                    },
+                    Err(e) => {
+                        report.failures.push(format!("{} -> {}: {}", ticket.identifier, mirror.identifier, e));
+                        // Marks the original as changed so the next run copies it again
+                        match side {
+                            SyncSide::Left => link.left_updated_at = DateTime::<Utc>::MIN_UTC,
+                            SyncSide::Right => link.right_updated_at = DateTime::<Utc>::MIN_UTC,
+                        }
+                    }
+                }
+            }
+        }
+
+        self.save_links(links)?;
+        for conflict in &report.conflicts {
+            warn!("Sync conflict between {} and {}: {:?}", conflict.left, conflict.right, conflict.fields);
+        }
+        info!(
+            "Sync created {}, updated {}, {} conflicts, {} failures",
+            report.created.len(), report.updated.len(), report.conflicts.len(), report.failures.len()
+        );
+        if let Ok(mut last_run) = self.last_run.lock() {
+            *last_run = Some((Utc::now(), report.clone()));
+        }
+        Ok(report)
+    }
+
+    async fn load_side<'a>(application: &'a Application, endpoint: &SyncEndpoint) -> Result<Side<'a>> {
+        let team = application.find_team(&endpoint.team).await
+            .with_context(|| format!("Sync endpoint {}", endpoint.provider))?;
+        let workflow = application.get_workflow(&team.id).await?;
+        let filter = TicketFilter {
+            team_id: Some(team.id.clone()),
+            ..Default::default()
+        };
+        let tickets = application.filter_tickets(&filter).await?
+            .into_iter()
+            .map(|ticket| (ticket.id.clone(), ticket))
+            .collect();
+        Ok(Side { application, team_id: team.id, workflow, tickets })
+    }
+
+    fn differing_fields(&self, a: &Ticket, b: &Ticket) -> Vec<SyncField> {
+        let description = |t: &Ticket| t.description.as_ref().map(|d| d.to_markdown()).unwrap_or_default();
+        self.config.fields
+            .iter()
+            .copied()
+            .filter(|field| match field {
+                SyncField::Title => a.title != b.title,
+                SyncField::Description => description(a).trim() != description(b).trim(),
+                SyncField::Priority => a.priority != b.priority,
+                SyncField::State => a.state.type_ != b.state.type_,
+            })
+            .collect()
+    }
+
+    /// Updates `to` with the synced fields of `from` and returns the result.
+    async fn copy_fields(&self, from: &Ticket, to: &Ticket, side: &Side<'_>) -> Result<Ticket> {
+        let differing = self.differing_fields(from, to);
+        let mut request = UpdateTicketRequest {
+            id: to.id.clone(),
+            title: None,
+            description: None,
+            priority: None,
+            assignee_id: None,
+            state_id: None,
+            label_ids: None,
+            due_date: None,
+            estimate: None,
+            custom_fields: None,
+        };
+        for field in differing {
+            match field {
+                SyncField::Title => request.title = Some(from.title.clone()),
+                SyncField::Description => request.description = from.description.clone(),
+                SyncField::Priority => request.priority = Some(from.priority.clone()),
+                SyncField::State => request.state_id = Some(Self::matching_state(from, side)?),
+            }
+        }
+        side.application.update_ticket(&request).await
+    }
+
+    /// Creates the counterpart of `ticket` on the other side, in the team's
+    /// default state; the run copies the state once the pair is linked.
+    async fn mirror(&self, ticket: &Ticket, side: &Side<'_>) -> Result<Ticket> {
+        let has = |field| self.config.fields.contains(&field);
+        let request = CreateTicketRequest {
+            title: ticket.title.clone(),
+            description: ticket.description.clone().filter(|_| has(SyncField::Description)),
+            priority: Some(ticket.priority.clone()).filter(|_| has(SyncField::Priority)),
+            team_id: Some(side.team_id.clone()),
+            ..Default::default()
+        };
+        side.application.create_ticket(&request, None).await
+    }
+
+    fn matching_state(from: &Ticket, side: &Side<'_>) -> Result<String> {
+        side.workflow.states
+            .iter()
+            .find(|state| state.type_ == from.state.type_)
+            .map(|state| state.id.clone())
+            .ok_or_else(|| anyhow!("No {:?} state in team {}", from.state.type_, side.workflow.team_id))
+    }
+
+    fn save_links(&self, links: Vec<SyncLink>) -> Result<()> {
+        let path = &self.config.state_file;
+        std::fs::write(path, serde_json::to_string_pretty(&links)?)
+            .with_context(|| format!("Failed to write sync state to {}", path.display()))?;
+        *self.links.lock().map_err(|_| anyhow!("Sync state lock poisoned"))? = links;
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use anyhow::{Result, anyhow};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+/// How a `Table` is printed: aligned for a terminal, or as JSON, CSV, or
+/// markdown for scripts and documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    Table,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl std::str::FromStr for TableFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.to_lowercase().as_str() {
+            "table" => Ok(TableFormat::Table),
+            "json" => Ok(TableFormat::Json),
+            "csv" => Ok(TableFormat::Csv),
+            "markdown" | "md" => Ok(TableFormat::Markdown),
+            other => Err(anyhow!("Unsupported format: {}. Expected table, json, csv, or markdown", other)),
+        }
+    }
+}
+
+/// Rows of named columns. Cells keep their JSON type for `Json` and are
+/// printed as text otherwise, with lists joined by `; `.
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    columns: Vec<String>,
+    rows: Vec<Vec<Value>>,
+}
+
+impl Table {
+    pub fn new(columns: &[&str]) -> Self {
+        Self {
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Adds a row with a cell for each column, in order.
+    pub fn push(&mut self, row: Vec<Value>) {
+        debug_assert_eq!(row.len(), self.columns.len());
+        self.rows.push(row);
+    }
+
+    /// Keeps only `columns`, in the order given.
+    pub fn select<S: AsRef<str>>(self, columns: &[S]) -> Result<Self> {
+        let indices = columns
+            .iter()
+            .map(|column| {
+                let column = column.as_ref().trim();
+                self.columns.iter().position(|name| name.eq_ignore_ascii_case(column)).ok_or_else(|| {
+                    anyhow!("Unknown column: {}. Available columns: {}", column, self.columns.join(", "))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            columns: indices.iter().map(|&index| self.columns[index].clone()).collect(),
+            rows: self.rows.iter().map(|row| indices.iter().map(|&index| row[index].clone()).collect()).collect(),
+        })
+    }
+
+    /// The text of one column's cells, e.g. ids for piping to other commands.
+    pub fn column_text(&self, column: &str) -> Result<Vec<String>> {
+        let index = self.columns.iter().position(|name| name == column)
+            .ok_or_else(|| anyhow!("Unknown column: {}", column))?;
+        Ok(self.rows.iter().map(|row| cell_text(&row[index])).collect())
+    }
+
+    pub fn render(&self, format: TableFormat) -> Result<String> {
+        match format {
+            TableFormat::Table => Ok(self.render_aligned()),
+            TableFormat::Json => {
+                let objects: Vec<JsonRow> = self.rows.iter().map(|row| JsonRow { columns: &self.columns, cells: row }).collect();
+                Ok(format!("{}\n", serde_json::to_string_pretty(&objects)?))
+            }
+            TableFormat::Csv => Ok(self.render_csv()),
+            TableFormat::Markdown => Ok(self.render_markdown()),
+        }
+    }
+
+    fn text_rows(&self) -> impl Iterator<Item = Vec<String>> + '_ {
+        self.rows.iter().map(|row| row.iter().map(cell_text).collect())
+    }
+
+    fn render_aligned(&self) -> String {
+        let header: Vec<String> = self.columns.iter().map(|column| column.to_uppercase()).collect();
+        let rows: Vec<Vec<String>> = std::iter::once(header).chain(self.text_rows()).collect();
+        let widths: Vec<usize> = (0..self.columns.len())
+            .map(|column| rows.iter().map(|row| row[column].chars().count()).max().unwrap_or(0))
+            .collect();
+        let mut text = String::new();
+        for row in &rows {
+            let cells: Vec<String> = row.iter().zip(&widths)
+                .map(|(cell, &width)| format!("{:<width$}", cell.replace(['\r', '\n'], " "), width = width))
+                .collect();
+            text.push_str(cells.join("  ").trim_end());
+            text.push('\n');
+        }
+        text
+    }
+
+    fn render_csv(&self) -> String {
+        let mut csv = self.columns.iter().map(|column| csv_field(column)).collect::<Vec<_>>().join(",");
+        csv.push_str("\r\n");
+        for row in self.text_rows() {
+            let fields: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&fields.join(","));
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+
+    fn render_markdown(&self) -> String {
+        let header: Vec<String> = self.columns.iter().map(|column| markdown_cell(column)).collect();
+        let mut markdown = format!("| {} |\n", header.join(" | "));
+        markdown.push_str(&format!("|{}\n", " --- |".repeat(self.columns.len())));
+        for row in self.text_rows() {
+            let cells: Vec<String> = row.iter().map(|cell| markdown_cell(cell)).collect();
+            markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+        markdown
+    }
+}
+
+/// A row as a JSON object whose keys keep the table's column order.
+struct JsonRow<'a> {
+    columns: &'a [String],
+    cells: &'a [Value],
+}
+
+impl Serialize for JsonRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.columns.len()))?;
+        for (column, cell) in self.columns.iter().zip(self.cells) {
+            map.serialize_entry(column, cell)?;
+        }
+        map.end()
+    }
+}
+
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) => items.iter().map(cell_text).collect::<Vec<_>>().join("; "),
+        other => other.to_string(),
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
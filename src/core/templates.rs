@@ -0,0 +1,73 @@
+use anyhow::{Context, Result, anyhow};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use crate::domain::TicketTemplate;
+
+/// Loads ticket templates from a JSON file holding an array of templates.
+pub fn load_templates(path: impl AsRef<Path>) -> Result<Vec<TicketTemplate>> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read ticket templates from {}", path.display()))?;
+    let templates: Vec<TicketTemplate> = serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid ticket templates in {}", path.display()))?;
+    Ok(templates)
+}
+
+/// Finds a template by name, ignoring case.
+pub fn find_template<'a>(templates: &'a [TicketTemplate], name: &str) -> Option<&'a TicketTemplate> {
+    templates.iter().find(|template| template.name.eq_ignore_ascii_case(name))
+}
+
+fn placeholders(text: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else { break };
+        names.push(rest[start + 2..start + 2 + end].trim().to_string());
+        rest = &rest[start + 2 + end + 2..];
+    }
+    names
+}
+
+/// Variables a caller has to supply; `date` is always filled in.
+pub fn template_variables(template: &TicketTemplate) -> Vec<String> {
+    let mut names: BTreeSet<String> = placeholders(&template.title).into_iter().collect();
+    if let Some(body) = &template.body {
+        names.extend(placeholders(body));
+    }
+    names.remove("date");
+    names.into_iter().collect()
+}
+
+/// Replaces `{{name}}` placeholders. `date` defaults to today's date; any
+/// other placeholder without a value is an error.
+pub fn render_template(text: &str, variables: &HashMap<String, String>, now: DateTime<Utc>) -> Result<String> {
+    let mut rendered = String::with_capacity(text.len());
+    let mut missing = BTreeSet::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else { break };
+        rendered.push_str(&rest[..start]);
+
+        let name = rest[start + 2..start + 2 + end].trim();
+        match variables.get(name) {
+            Some(value) => rendered.push_str(value),
+            None if name == "date" => rendered.push_str(&now.format("%Y-%m-%d").to_string()),
+            None => {
+                missing.insert(name.to_string());
+            }
+        }
+        rest = &rest[start + 2 + end + 2..];
+    }
+    rendered.push_str(rest);
+
+    if !missing.is_empty() {
+        let missing: Vec<String> = missing.into_iter().collect();
+        return Err(anyhow!("Missing template variables: {}", missing.join(", ")));
+    }
+
+    Ok(rendered)
+}
@@ -0,0 +1,138 @@
+use anyhow::{Context, Result, anyhow};
+use futures::future::BoxFuture;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::core::{Application, ProviderRegistry};
+use crate::ports::SecretStore;
+
+/// Where one user's token for one provider comes from.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UserToken {
+    #[serde(default)]
+    pub api_token: Option<String>,
+    /// Environment variable holding the token.
+    #[serde(default)]
+    pub api_token_env: Option<String>,
+}
+
+impl UserToken {
+    fn resolve(&self) -> Result<Option<String>> {
+        if let Some(token) = &self.api_token {
+            return Ok(Some(token.clone()));
+        }
+        match &self.api_token_env {
+            Some(name) => std::env::var(name)
+                .map(Some)
+                .map_err(|_| anyhow!("{} is not set", name)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Secret store entry holding a user's token for a provider or profile.
+pub fn user_token_key(name: &str, identity: &str) -> String {
+    format!("{}.api_token.{}", name, identity)
+}
+
+/// The provider tokens of authenticated MCP client identities: a table by
+/// identity and then by provider (or profile) name, falling back to tokens
+/// saved in a secret store under `user_token_key`.
+#[derive(Clone, Default)]
+pub struct UserTokens {
+    table: BTreeMap<String, BTreeMap<String, UserToken>>,
+    store: Option<Arc<dyn SecretStore>>,
+}
+
+impl UserTokens {
+    pub fn new(table: BTreeMap<String, BTreeMap<String, UserToken>>) -> Self {
+        Self { table, store: None }
+    }
+
+    pub fn with_secret_store(mut self, store: Arc<dyn SecretStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// `identity`'s token for the provider or profile `name`, if it has one.
+    pub fn token(&self, identity: &str, name: &str) -> Result<Option<String>> {
+        if let Some(token) = self.table.get(identity).and_then(|tokens| tokens.get(name)) {
+            if let Some(token) = token.resolve()? {
+                return Ok(Some(token));
+            }
+        }
+        match &self.store {
+            Some(store) => store.get(&user_token_key(name, identity)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Loads a JSON object of identities, each an object of tokens by provider
+/// or profile name.
+pub fn load_user_tokens(path: impl AsRef<Path>) -> Result<BTreeMap<String, BTreeMap<String, UserToken>>> {
+    let path = path.as_ref();
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read user tokens from {}", path.display()))?;
+    serde_json::from_str(&contents)
+        .with_context(|| format!("Invalid user tokens in {}", path.display()))
+}
+
+/// Builds the application for one provider connected with one user's
+/// token: `(identity, name, token)`.
+pub type ConnectUser = Arc<dyn Fn(String, String, String) -> BoxFuture<'static, Result<Arc<Application>>> + Send + Sync>;
+
+/// Runs an authenticated user's tool calls with their own provider tokens,
+/// so what they do is attributed to them rather than to a shared bot token.
+/// Each user's providers are connected on their first session and kept for
+/// later ones, with caches of their own so one user never sees another's
+/// cached responses.
+pub struct Impersonation {
+    tokens: UserTokens,
+    connect: ConnectUser,
+    applications: futures::lock::Mutex<HashMap<(String, String), Arc<Application>>>,
+}
+
+impl Impersonation {
+    pub fn new(tokens: UserTokens, connect: ConnectUser) -> Self {
+        Self {
+            tokens,
+            connect,
+            applications: futures::lock::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// `registry` narrowed to the providers `identity` has a token for, each
+    /// connected with that token. The default stays the default when the
+    /// user has a token for it. Fails when the user has no token at all
+    /// rather than falling back to the shared one.
+    pub async fn registry_for(&self, identity: &str, registry: &ProviderRegistry) -> Result<ProviderRegistry> {
+        let mut names = registry.names();
+        names.sort_by_key(|name| *name != registry.default_name());
+
+        let mut applications = self.applications.lock().await;
+        let mut user_registry: Option<ProviderRegistry> = None;
+        for name in names {
+            let key = (identity.to_string(), name.to_string());
+            let application = match applications.get(&key) {
+                Some(application) => application.clone(),
+                None => {
+                    let Some(token) = self.tokens.token(identity, name)? else {
+                        continue;
+                    };
+                    let application = (self.connect)(identity.to_string(), name.to_string(), token).await
+                        .with_context(|| format!("Failed to connect {} for {}", name, identity))?;
+                    applications.insert(key, application.clone());
+                    application
+                }
+            };
+            user_registry = Some(match user_registry {
+                Some(user_registry) => user_registry.with_provider(name, application),
+                None => ProviderRegistry::new(name, application),
+            });
+        }
+        user_registry.ok_or_else(|| anyhow!("No provider token is configured for {}", identity))
+    }
+}
@@ -0,0 +1,127 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::Ticket;
+
+pub const DEFAULT_WORKING_SET_CAPACITY: usize = 50;
+
+/// A ticket the agent has touched during the current session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkingSetEntry {
+    pub ticket_id: String,
+    pub identifier: String,
+    pub title: String,
+    pub url: String,
+    pub pinned: bool,
+    pub touch_count: u32,
+    pub last_touched: DateTime<Utc>,
+}
+
+/// Tickets touched during a session, so follow-up requests can refer back to
+/// them without searching again. Pinned entries are never evicted; unpinned
+/// entries are dropped least-recently-touched first once `capacity` is reached.
+#[derive(Debug, Clone)]
+pub struct WorkingSet {
+    capacity: usize,
+    entries: Vec<WorkingSetEntry>,
+}
+
+impl WorkingSet {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn touch(&mut self, ticket: &Ticket) {
+        let now = Utc::now();
+
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.ticket_id == ticket.id) {
+            entry.identifier = ticket.identifier.clone();
+            entry.title = ticket.title.clone();
+            entry.url = ticket.url.clone();
+            entry.touch_count += 1;
+            entry.last_touched = now;
+            return;
+        }
+
+        self.entries.push(WorkingSetEntry {
+            ticket_id: ticket.id.clone(),
+            identifier: ticket.identifier.clone(),
+            title: ticket.title.clone(),
+            url: ticket.url.clone(),
+            pinned: false,
+            touch_count: 1,
+            last_touched: now,
+        });
+        self.evict();
+    }
+
+    pub fn touch_all<'a>(&mut self, tickets: impl IntoIterator<Item = &'a Ticket>) {
+        for ticket in tickets {
+            self.touch(ticket);
+        }
+    }
+
+    /// Sets the pinned flag on an entry matched by ticket id or identifier.
+    /// Returns `false` if the ticket is not in the working set.
+    pub fn set_pinned(&mut self, id_or_identifier: &str, pinned: bool) -> bool {
+        match self.entries.iter_mut().find(|e| e.matches(id_or_identifier)) {
+            Some(entry) => {
+                entry.pinned = pinned;
+                if !pinned {
+                    self.evict();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Entries with pinned tickets first, then most recently touched.
+    pub fn entries(&self) -> Vec<WorkingSetEntry> {
+        let mut entries = self.entries.clone();
+        entries.sort_by(|a, b| {
+            b.pinned
+                .cmp(&a.pinned)
+                .then_with(|| b.last_touched.cmp(&a.last_touched))
+        });
+        entries
+    }
+
+    fn evict(&mut self) {
+        while self.entries.len() > self.capacity {
+            let oldest = self
+                .entries
+                .iter()
+                .enumerate()
+                .filter(|(_, e)| !e.pinned)
+                .min_by_key(|(_, e)| e.last_touched)
+                .map(|(i, _)| i);
+
+            match oldest {
+                Some(index) => {
+                    self.entries.remove(index);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+impl Default for WorkingSet {
+    fn default() -> Self {
+        Self::new(DEFAULT_WORKING_SET_CAPACITY)
+    }
+}
+
+impl WorkingSetEntry {
+    fn matches(&self, id_or_identifier: &str) -> bool {
+        self.ticket_id == id_or_identifier || self.identifier.eq_ignore_ascii_case(id_or_identifier)
+    }
+}
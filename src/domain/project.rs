@@ -1,34 +0,0 @@
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Project {
-    pub id: String,
-    pub name: String,
-    pub description: Option<String>,
-    pub key: String,
-    pub state: ProjectState,
-    pub target_date: Option<DateTime<Utc>>,
-    pub lead_id: Option<String>,
-    pub created_at: DateTime<Utc>,
-    pub updated_at: DateTime<Utc>,
-    pub progress: f32,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum ProjectState {
-    Planned,
-    Started,
-    Completed,
-    Canceled,
-    Paused,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProjectMilestone {
-    pub id: String,
-    pub name: String,
-    pub description: Option<String>,
-    pub target_date: Option<DateTime<Utc>>,
-    pub project_id: String,
-}
\ No newline at end of file
@@ -1,20 +0,0 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct User {
-    pub id: String,
-    pub name: String,
-    pub email: String,
-    pub avatar_url: Option<String>,
-    pub display_name: String,
-    pub active: bool,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Team {
-    pub id: String,
-    pub name: String,
-    pub key: String,
-    pub description: Option<String>,
-    pub members: Vec<User>,
-}
\ No newline at end of file
@@ -1,6 +1,8 @@
-pub mod domain;
+// Domain types and ports live in their own crate so they can be reused
+// without the async/HTTP stack; re-export them under their usual paths.
+pub use generic_mcp_domain::{domain, ports};
+
 pub mod core;
-pub mod ports;
 pub mod adapters;
 pub mod providers;
 
@@ -8,4 +10,8 @@ pub use domain::*;
 pub use core::*;
 pub use ports::*;
 pub use adapters::*;
-pub use providers::*;
\ No newline at end of file
+pub use providers::*;
+
+// For `register_provider!` in crates that do not depend on linkme
+#[doc(hidden)]
+pub use linkme;
\ No newline at end of file
@@ -1,59 +1,835 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use clap::{Args, Parser, Subcommand};
 use dotenv::dotenv;
+use std::collections::BTreeMap;
 use std::env;
+use std::io::IsTerminal;
 use std::sync::Arc;
-use tracing::{info, error};
-use tracing_subscriber::{fmt, EnvFilter};
+use serde_json::json;
+use tracing::info;
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
 
 use generic_mcp::{
     Application,
     McpServerImpl,
     McpServer,
+    EventBus,
+    LinearWebhookDecoder,
+    GithubWebhookDecoder,
+    WebhookDecoder,
+    WebhookServer,
+    HealthServer,
+    ReadinessCheck,
+    DEFAULT_READINESS_TTL,
+    IdempotencyStore,
+    RateLimit,
+    RateLimitLayer,
+    DEFAULT_MAX_CONCURRENT_CALLS,
+    MiddlewareStack,
+    RetryLayer,
+    AuditLog,
+    ToolPolicy,
+    SyncEngine,
+    load_sync_config,
+    CachingTicketService,
+    Prefetcher,
+    OutputLimits,
+    Redactor,
+    load_redaction_config,
+    DueDateWatcher,
+    LogReminderSink,
+    AlertWatcher,
+    load_alerts_config,
+    DEFAULT_ALERT_INTERVAL,
+    SlackWebhook,
+    DEFAULT_DUE_REMINDER_HOURS,
+    CsvField,
+    SecretResolver,
+    SECRET_URI_SCHEME,
+    DEFAULT_IMPORT_CONCURRENCY,
+    Scheduler,
+    SemanticIndex,
+    Embedder,
+    HashingEmbedder,
+    OpenAiEmbedder,
+    OPENAI_EMBEDDINGS_URL,
+    DEFAULT_EMBEDDINGS_MODEL,
+    DEFAULT_REFERENCE_CACHE_TTL,
+    DEFAULT_TICKET_CACHE_TTL,
+    DEFAULT_MAX_STALENESS,
+    ProviderRegistry,
     ProviderConfig,
+    HashPseudonymizer,
+    load_saved_views,
+    run_checks,
+    load_templates,
+    load_profiles,
+    load_user_tokens,
+    load_snapshot,
+    save_snapshot,
+    load_field_mapping,
+    load_migration_config,
+    run_migration,
+    FieldMapping,
+    MigrationOutcome,
+    user_token_key,
+    Profile,
+    UserTokens,
+    Impersonation,
+    ConnectUser,
+    TicketDefaults,
+    DEFAULT_WORKING_SET_CAPACITY,
+    FileSecretStore,
+    OAuthClient,
+    OAuthConfig,
+    SecretStore,
+    CacheStore,
+    HttpSettings,
+    FixtureMode,
+    FixtureSettings,
+    https_client,
+    CreateTicketRequest,
+    UpdateTicketRequest,
+    TicketFilter,
+    PageRequest,
+    Priority,
+    RichText,
+    StateType,
+    Table,
+    TableFormat,
 };
 
-#[cfg(feature = "linear")]
-use generic_mcp::providers::LinearAdapter;
+#[cfg(feature = "local-search")]
+use generic_mcp::{TantivyIndex, TicketIndex};
+#[cfg(feature = "persistent-cache")]
+use generic_mcp::SqliteCacheStore;
+#[cfg(feature = "keyring")]
+use generic_mcp::KeyringSecretStore;
+use generic_mcp::providers::{ProviderContext, provider_factory, provider_names};
+#[cfg(feature = "wasm")]
+use generic_mcp::{http_transport, providers::WasmProvider};
+
+/// A Model Context Protocol server for issue trackers, and a command line
+/// for the same providers. Without a command it serves MCP.
+#[derive(Parser)]
+#[command(name = "generic-mcp", version)]
+struct Cli {
+    /// Provider to use instead of MCP_PROVIDER or MCP_PROVIDERS
+    #[arg(long, global = true)]
+    provider: Option<String>,
+    /// Profile from MCP_PROFILES_FILE to use instead of MCP_PROFILE
+    #[arg(long, global = true)]
+    profile: Option<String>,
+    /// Same as `doctor`
+    #[arg(long, hide = true)]
+    check: bool,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Serve MCP to an AI assistant (the default)
+    Serve,
+    /// Save or remove provider API tokens and OAuth authorizations
+    Auth {
+        #[command(subcommand)]
+        command: AuthCommand,
+    },
+    /// Check every configured provider and exit
+    Doctor,
+    /// Write a snapshot of the provider's workspace to a file
+    ExportWorkspace { file: String },
+    /// Re-create a workspace snapshot in the provider
+    ImportWorkspace {
+        file: String,
+        /// Field mapping file translating the snapshot's people, teams, and fields
+        #[arg(long)]
+        mapping: Option<String>,
+        /// Team for tickets whose original team has no match
+        #[arg(long)]
+        team: Option<String>,
+        /// Print what would be created instead
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Run the migration a configuration file describes
+    Migrate {
+        file: String,
+        /// Print the plan instead
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// List, create, and update tickets
+    Tickets {
+        #[command(subcommand)]
+        command: TicketsCommand,
+    },
+    /// List teams
+    Teams {
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// List projects
+    Projects {
+        /// Maximum number of projects
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// List labels
+    Labels {
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+}
+
+/// How listing commands print their results.
+#[derive(Args)]
+struct OutputArgs {
+    /// table, json, csv, or markdown
+    #[arg(long, default_value = "table")]
+    format: TableFormat,
+    /// Comma-separated columns to print, e.g. identifier,title,url; an
+    /// unknown name lists the available ones
+    #[arg(long, value_delimiter = ',')]
+    columns: Vec<String>,
+    /// Print only ids, one per line
+    #[arg(long, short)]
+    quiet: bool,
+}
+
+#[derive(Subcommand)]
+enum AuthCommand {
+    /// Authorize the provider's OAuth application and save its tokens
+    Login {
+        provider: String,
+        /// Use the device code flow, for hosts without a browser
+        #[arg(long)]
+        device: bool,
+    },
+    /// Forget the provider's OAuth tokens
+    Logout { provider: String },
+    /// Save an API token to the OS keychain, prompting for it without echo
+    Set {
+        provider: String,
+        /// Save this client identity's own token instead
+        #[arg(long)]
+        user: Option<String>,
+    },
+    /// Remove an API token from the OS keychain
+    Delete {
+        provider: String,
+        /// Remove this client identity's own token instead
+        #[arg(long)]
+        user: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum TicketsCommand {
+    /// List tickets, most recently updated first
+    List {
+        /// Team key, name, or id
+        #[arg(long)]
+        team: Option<String>,
+        /// State category: open, in_progress, closed, or cancelled
+        #[arg(long)]
+        state: Option<String>,
+        /// Assignee id, or `me`
+        #[arg(long)]
+        assignee: Option<String>,
+        /// Text to search for
+        #[arg(long)]
+        query: Option<String>,
+        /// Maximum number of tickets
+        #[arg(long, default_value_t = 50)]
+        limit: usize,
+        #[command(flatten)]
+        output: OutputArgs,
+    },
+    /// Create a ticket
+    Create {
+        /// Title
+        #[arg(long)]
+        title: String,
+        /// Team key, name, or id; defaults to MCP_DEFAULT_TEAM
+        #[arg(long)]
+        team: Option<String>,
+        /// Markdown description
+        #[arg(long)]
+        description: Option<String>,
+        /// none, low, medium, high, or urgent
+        #[arg(long)]
+        priority: Option<String>,
+        /// Assignee id
+        #[arg(long)]
+        assignee: Option<String>,
+        /// Project id
+        #[arg(long)]
+        project: Option<String>,
+        /// Parent ticket id
+        #[arg(long)]
+        parent: Option<String>,
+        /// Label ids; repeat for several
+        #[arg(long = "label")]
+        labels: Vec<String>,
+    },
+    /// Update a ticket's fields or move it to another state
+    Update {
+        /// Ticket id or identifier such as METAL-37
+        ticket: String,
+        /// New title
+        #[arg(long)]
+        title: Option<String>,
+        /// Markdown description
+        #[arg(long)]
+        description: Option<String>,
+        /// none, low, medium, high, or urgent
+        #[arg(long)]
+        priority: Option<String>,
+        /// State name or category, e.g. "In Review" or done
+        #[arg(long)]
+        state: Option<String>,
+        /// Assignee id
+        #[arg(long)]
+        assignee: Option<String>,
+    },
+    /// Create tickets from a CSV file whose header names each column's field
+    Import {
+        /// CSV file; the header needs a title column and may have description,
+        /// priority, team, assignee, project, labels, due_date, and estimate
+        file: String,
+        /// Map a column to a field, e.g. `Story=title`; repeat for several
+        #[arg(long = "column", value_name = "COLUMN=FIELD", value_parser = parse_column_mapping)]
+        columns: Vec<(String, CsvField)>,
+        /// Team key, name, or id for rows without a team column
+        #[arg(long)]
+        team: Option<String>,
+        /// Only validate the rows and print what would be created
+        #[arg(long)]
+        dry_run: bool,
+        /// Tickets created at once
+        #[arg(long, default_value_t = DEFAULT_IMPORT_CONCURRENCY)]
+        concurrency: usize,
+    },
+}
+
+fn parse_column_mapping(value: &str) -> Result<(String, CsvField)> {
+    let (column, field) = value.split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("expected COLUMN=FIELD, got {}", value))?;
+    Ok((column.trim().to_string(), CsvField::parse(field)?))
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
     dotenv().ok();
+
+    let cli = Cli::parse();
+    let command = match cli.command {
+        // Managing saved tokens needs no provider
+        Some(Command::Auth { command }) => return run_auth_command(command).await,
+        Some(command) => command,
+        None if cli.check => Command::Doctor,
+        None => Command::Serve,
+    };
     
-    tracing_subscriber::fmt()
+    // Logs go to stderr, or MCP_LOG_FILE, since stdout may carry the stdio
+    // transport; MCP_LOG_FORMAT=json writes one JSON object per line
+    let log_file = env::var("MCP_LOG_FILE");
+    let ansi = log_file.is_err() && std::io::stderr().is_terminal();
+    let writer = match log_file {
+        Ok(path) => {
+            let file = std::fs::OpenOptions::new().create(true).append(true).open(&path)
+                .map_err(|e| anyhow::anyhow!("Failed to open MCP_LOG_FILE {}: {}", path, e))?;
+            BoxMakeWriter::new(std::sync::Mutex::new(file))
+        }
+        Err(_) => BoxMakeWriter::new(std::io::stderr),
+    };
+    let logs = tracing_subscriber::fmt()
         .with_env_filter(EnvFilter::from_default_env())
-        .init();
+        .with_ansi(ansi)
+        .with_writer(writer);
+    match env::var("MCP_LOG_FORMAT").as_deref() {
+        Ok("json") => logs.json().flatten_event(true).with_current_span(true).with_span_list(false).init(),
+        Ok("text") | Err(_) => logs.init(),
+        Ok(other) => return Err(anyhow::anyhow!("MCP_LOG_FORMAT must be text or json, got: {}", other)),
+    }
 
     info!("Starting generic-mcp server...");
 
-    // Default to Linear provider for now
-    let provider = env::var("MCP_PROVIDER").unwrap_or_else(|_| "linear".to_string());
-    
-    let ticket_service = match provider.as_str() {
-        #[cfg(feature = "linear")]
-        "linear" => {
-            let linear_api_token = env::var("LINEAR_API_TOKEN")
-                .map_err(|_| anyhow::anyhow!("LINEAR_API_TOKEN environment variable is required for Linear provider"))?;
-            
-            let config = ProviderConfig {
-                provider_type: "linear".to_string(),
-                api_token: linear_api_token,
-                base_url: None,
-                workspace_id: None,
-            };
-            
-            info!("Creating Linear provider adapter...");
-            Arc::new(LinearAdapter::new(config)?) as Arc<dyn generic_mcp::TicketService + Send + Sync>
-        },
-        _ => {
-            return Err(anyhow::anyhow!("Unsupported provider: {}. Available providers: linear", provider));
+    // MCP_PROFILES_FILE serves named accounts, --profile or MCP_PROFILE
+    // picking the default
+    let selected_profile = cli.profile.or_else(|| env::var("MCP_PROFILE").ok());
+    let profiles = match env::var("MCP_PROFILES_FILE") {
+        Ok(path) => {
+            let profiles = load_profiles(&path)?;
+            info!("Loaded {} profiles from {}", profiles.len(), path);
+            profiles
         }
+        Err(_) if selected_profile.is_some() => {
+            return Err(anyhow::anyhow!("--profile and MCP_PROFILE need MCP_PROFILES_FILE to define the profiles"));
+        }
+        Err(_) => BTreeMap::new(),
+    };
+
+    // Otherwise --provider serves one provider, and MCP_PROVIDERS several at
+    // once; the first is the default
+    let providers: Vec<String> = if profiles.is_empty() {
+        match (cli.provider, env::var("MCP_PROVIDERS")) {
+            (Some(provider), _) => vec![provider],
+            (None, Ok(value)) => value.split(',').map(|p| p.trim().to_string()).filter(|p| !p.is_empty()).collect(),
+            (None, Err(_)) => vec![env::var("MCP_PROVIDER").unwrap_or_else(|_| "linear".to_string())],
+        }
+    } else if cli.provider.is_some() {
+        return Err(anyhow::anyhow!("--provider does not apply with MCP_PROFILES_FILE; choose a profile with --profile"));
+    } else {
+        let names = profiles.keys().cloned().collect::<Vec<_>>();
+        let default = match selected_profile {
+            Some(name) if profiles.contains_key(&name) => name,
+            Some(name) => return Err(anyhow::anyhow!("Unknown profile: {}. Configured profiles: {}", name, names.join(", "))),
+            None if names.len() == 1 => names[0].clone(),
+            None => return Err(anyhow::anyhow!("Set MCP_PROFILE, or pass --profile, to the default profile: one of {}", names.join(", "))),
+        };
+        std::iter::once(default.clone())
+            .chain(names.into_iter().filter(|name| *name != default))
+            .collect()
+    };
+    let (default_provider, other_providers) = providers.split_first()
+        .ok_or_else(|| anyhow::anyhow!("MCP_PROVIDERS must name at least one provider"))?;
+
+    // Response cache in front of every provider; a zero TTL disables it
+    let reference_ttl = match env::var("MCP_CACHE_TTL_SECS") {
+        Ok(value) => value.parse::<u64>()
+            .map(std::time::Duration::from_secs)
+            .map_err(|_| anyhow::anyhow!("MCP_CACHE_TTL_SECS must be a non-negative integer, got: {}", value))?,
+        Err(_) => DEFAULT_REFERENCE_CACHE_TTL,
+    };
+    let ticket_ttl = match env::var("MCP_TICKET_CACHE_TTL_SECS") {
+        Ok(value) => value.parse::<u64>()
+            .map(std::time::Duration::from_secs)
+            .map_err(|_| anyhow::anyhow!("MCP_TICKET_CACHE_TTL_SECS must be a non-negative integer, got: {}", value))?,
+        Err(_) => DEFAULT_TICKET_CACHE_TTL,
+    };
+    // How long past their TTL cached reads answer while a provider is down
+    let max_staleness = match env::var("MCP_MAX_STALENESS_SECS") {
+        Ok(value) => value.parse::<u64>()
+            .map(std::time::Duration::from_secs)
+            .map_err(|_| anyhow::anyhow!("MCP_MAX_STALENESS_SECS must be a non-negative integer, got: {}", value))?,
+        Err(_) => DEFAULT_MAX_STALENESS,
+    };
+    // Cached responses survive restarts when given a file, so a stdio server
+    // spawned per session starts warm
+    let cache_store: Option<Arc<dyn CacheStore>> = match env::var("MCP_CACHE_FILE") {
+        #[cfg(feature = "persistent-cache")]
+        Ok(path) => {
+            info!("Keeping the response cache in {}", path);
+            Some(Arc::new(SqliteCacheStore::open(&path)?))
+        }
+        #[cfg(not(feature = "persistent-cache"))]
+        Ok(_) => return Err(anyhow::anyhow!("MCP_CACHE_FILE needs generic-mcp built with the persistent-cache feature")),
+        Err(_) => None,
+    };
+    // Idempotency keys for create_ticket survive restarts when given a file
+    let idempotency = match env::var("MCP_IDEMPOTENCY_FILE") {
+        Ok(path) => Arc::new(IdempotencyStore::load(path)?),
+        Err(_) => Arc::new(IdempotencyStore::in_memory()),
+    };
+
+    // Webhook events invalidate caches and mark resources as updated
+    let events = EventBus::default();
+
+    // Background refresh of each provider's cache; unset or 0 disables it
+    let prefetch_interval = match env::var("MCP_PREFETCH_INTERVAL_SECS") {
+        Ok(value) => value.parse::<u64>()
+            .map(std::time::Duration::from_secs)
+            .map_err(|_| anyhow::anyhow!("MCP_PREFETCH_INTERVAL_SECS must be a non-negative integer, got: {}", value))?,
+        Err(_) => std::time::Duration::ZERO,
+    };
+    // Retries of rate-limited calls and provider outages on top of any the
+    // provider client makes itself; 0 (the default) disables them
+    let provider_retries = match env::var("MCP_PROVIDER_RETRIES") {
+        Ok(value) => value.parse::<u32>()
+            .map_err(|_| anyhow::anyhow!("MCP_PROVIDER_RETRIES must be a non-negative integer, got: {}", value))?,
+        Err(_) => 0,
+    };
+    let mut caches = Vec::new();
+    // Uncached services the readiness check probes
+    let mut probes = Vec::new();
+
+    // What new tickets fall back to; a profile's own defaults come first
+    let defaults = TicketDefaults {
+        team: env::var("MCP_DEFAULT_TEAM").ok(),
+        project: env::var("MCP_DEFAULT_PROJECT").ok(),
+        self_assign: env::var("MCP_SELF_ASSIGN")
+            .ok()
+            .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes")),
+    };
+    if !defaults.is_empty() {
+        info!("Defaults for new tickets: {:?}", defaults);
+    }
+
+    // Cache hits skip the provider limits, so the cache wraps the limiter,
+    // and each retry waits its turn like any other call. The cache is not a
+    // stack layer because webhooks and prefetch need its concrete handle.
+    // Limits and webhooks go by provider type, the rest by profile name.
+    // Idempotency keys and persisted cache entries are scoped by `scope`,
+    // so users never share them.
+    let build_application = {
+        let profiles = Arc::new(profiles.clone());
+        let (defaults, idempotency, events, cache_store) = (defaults.clone(), idempotency.clone(), events.clone(), cache_store.clone());
+        Arc::new(move |name: &str, scope: &str, service| -> Result<_> {
+            let profile = profiles.get(name);
+            let provider = profile.map_or(name, |profile| profile.provider.as_str());
+            let (rate, max_concurrent) = provider_limits(provider)?;
+            let mut stack = MiddlewareStack::new();
+            if provider_retries > 0 {
+                stack = stack.layer(RetryLayer { max_retries: provider_retries });
+            }
+            let limited = stack
+                .layer(RateLimitLayer { rate, max_concurrent })
+                .wrap(service);
+            let mut cache = CachingTicketService::with_ttls(limited.clone(), reference_ttl, ticket_ttl)
+                .with_max_staleness(max_staleness);
+            if let Some(store) = &cache_store {
+                cache = cache.with_store(store.clone(), scope);
+            }
+            let cached = Arc::new(cache);
+            events.subscribe(Some(provider), cached.clone());
+            let application = Application::new(cached.clone())
+                .with_idempotency_store(idempotency.clone(), scope)
+                .with_defaults(match profile {
+                    Some(profile) => profile.defaults.clone().or(&defaults),
+                    None => defaults.clone(),
+                });
+            Ok((limited, cached, Arc::new(application)))
+        })
+    };
+    let mut create_application = |name: &str, service| -> Result<Arc<Application>> {
+        let (limited, cached, application) = build_application(name, name, service)?;
+        caches.push((name.to_string(), cached));
+        probes.push((name.to_string(), limited));
+        Ok(application)
     };
 
     info!("Creating application...");
-    let application = Arc::new(Application::new(ticket_service));
+    let application = create_application(default_provider, create_ticket_service(default_provider, profiles.get(default_provider), None).await?)?;
+    let mut registry = ProviderRegistry::new(default_provider.clone(), application);
+    for provider in other_providers {
+        info!("Creating application for provider {}...", provider);
+        let application = create_application(provider, create_ticket_service(provider, profiles.get(provider), None).await?)?;
+        registry = registry.with_provider(provider.clone(), application);
+    }
+
+    // `generic-mcp doctor` (or `--check`) reports on each provider and exits;
+    // MCP_STARTUP_CHECK runs the same checks before serving
+    match command {
+        Command::Serve | Command::Auth { .. } => {}
+        Command::Doctor => {
+            let report = run_checks(&registry).await;
+            println!("{}", report);
+            std::process::exit(if report.passed() { 0 } else { 1 });
+        }
+        Command::ExportWorkspace { file } => return export_workspace(&registry, &file).await,
+        Command::ImportWorkspace { file, mapping, team, dry_run } => {
+            return import_workspace(&registry, &file, mapping.as_deref(), team, dry_run).await;
+        }
+        Command::Migrate { file, dry_run } => return migrate(&registry, &file, dry_run).await,
+        Command::Tickets { command } => return run_tickets_command(&registry.default_application(), command).await,
+        Command::Teams { output } => return list_teams(&registry.default_application(), &output).await,
+        Command::Projects { limit, output } => return list_projects(&registry.default_application(), limit, &output).await,
+        Command::Labels { output } => return list_labels(&registry.default_application(), &output).await,
+    }
+    if env::var("MCP_STARTUP_CHECK").is_ok_and(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes")) {
+        info!("Checking providers...");
+        let report = run_checks(&registry).await;
+        if !report.passed() {
+            return Err(anyhow::anyhow!("Provider checks failed:\n{}", report));
+        }
+        info!("{}", report);
+    }
+
+    let sync = match env::var("MCP_SYNC_FILE") {
+        Ok(path) => {
+            let config = load_sync_config(&path)?;
+            info!("Syncing {} and {} per {}", config.left.provider, config.right.provider, path);
+            Some(Arc::new(SyncEngine::new(config, &registry)?))
+        }
+        Err(_) => None,
+    };
 
     info!("Creating MCP server...");
-    let mcp_server = McpServerImpl::new(application.clone());
+    let mut mcp_server = McpServerImpl::with_registry(registry.clone());
+    if !profiles.is_empty() {
+        mcp_server = mcp_server.with_profiles();
+    }
+
+    // MCP_USER_TOKENS_FILE runs each authenticated client's calls with its
+    // own tokens, from the file or saved by `auth set <provider> --user`
+    if let Ok(path) = env::var("MCP_USER_TOKENS_FILE") {
+        let table = load_user_tokens(&path)?;
+        info!("Loaded provider tokens for {} users from {}", table.len(), path);
+        let mut tokens = UserTokens::new(table);
+        if let Some(store) = keychain() {
+            tokens = tokens.with_secret_store(store);
+        }
+        let profiles = Arc::new(profiles.clone());
+        let connect: ConnectUser = Arc::new(move |identity, name, token| {
+            let build_application = build_application.clone();
+            let profile = profiles.get(&name).cloned();
+            Box::pin(async move {
+                info!("Connecting {} for {} with their own token", name, identity);
+                let service = create_ticket_service(&name, profile.as_ref(), Some(token)).await?;
+                let (_, _, application) = build_application(&name, &format!("{}:{}", name, identity), service)?;
+                Ok(application)
+            })
+        });
+        mcp_server = mcp_server.with_impersonation(Arc::new(Impersonation::new(tokens, connect)));
+    }
+    if let Some(sync) = sync {
+        mcp_server = mcp_server.with_sync(sync);
+    }
+    if let Ok(path) = env::var("MCP_MIGRATION_FILE") {
+        let config = load_migration_config(&path)?;
+        // Both ends must be configured providers
+        registry.get(Some(&config.source))?;
+        registry.get(Some(&config.target))?;
+        info!("Migrating {} to {} per {}", config.source, config.target, path);
+        mcp_server = mcp_server.with_migration(config);
+    }
+
+    // Session working set: MCP_WORKING_SET_SIZE=0 disables it
+    let working_set_size = match env::var("MCP_WORKING_SET_SIZE") {
+        Ok(value) => value.parse::<usize>()
+            .map_err(|_| anyhow::anyhow!("MCP_WORKING_SET_SIZE must be a non-negative integer, got: {}", value))?,
+        Err(_) => DEFAULT_WORKING_SET_CAPACITY,
+    };
+    if working_set_size > 0 {
+        info!("Enabling session working set (capacity {})", working_set_size);
+        mcp_server = mcp_server.with_working_set(working_set_size);
+    }
+
+    if let Ok(prefix) = env::var("MCP_BRANCH_PREFIX") {
+        mcp_server = mcp_server.with_branch_prefix(prefix);
+    }
+
+    // A fixed salt keeps user aliases stable across restarts
+    if let Ok(salt) = env::var("MCP_PSEUDONYM_SALT") {
+        mcp_server = mcp_server.with_pseudonymizer(Arc::new(HashPseudonymizer::new(salt)));
+    }
+    let pseudonymize_reports = env::var("MCP_PSEUDONYMIZE_REPORTS")
+        .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false);
+    if pseudonymize_reports {
+        info!("Pseudonymizing user identities in exports and resources");
+    }
+    mcp_server = mcp_server.with_pseudonymized_reports(pseudonymize_reports);
+
+    if let Ok(path) = env::var("MCP_AUDIT_LOG") {
+        let audit = AuditLog::new(&path);
+        info!("Auditing tool calls to {} (session {})", path, audit.session_id());
+        mcp_server = mcp_server.with_audit_log(Arc::new(audit));
+    }
+
+    if let Ok(path) = env::var("MCP_REDACTION_FILE") {
+        let redactor = Redactor::new(&load_redaction_config(&path)?)?;
+        info!("Redacting tool output per {}", path);
+        mcp_server = mcp_server.with_redactor(Arc::new(redactor));
+    }
+
+    let limits = OutputLimits {
+        max_tickets: env::var("MCP_MAX_TICKETS_PER_RESPONSE").ok().and_then(|v| v.parse().ok()).filter(|n| *n > 0),
+        max_description_chars: env::var("MCP_MAX_DESCRIPTION_CHARS").ok().and_then(|v| v.parse().ok()),
+        summary_only: env::var("MCP_SUMMARY_ONLY")
+            .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false),
+    };
+    if !limits.is_unlimited() {
+        info!("Limiting tool output: {:?}", limits);
+    }
+    mcp_server = mcp_server.with_output_limits(limits);
+
+    let policy = ToolPolicy {
+        read_only: env::var("MCP_READ_ONLY")
+            .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false),
+        allow: env::var("MCP_ALLOWED_TOOLS").map(|v| ToolPolicy::parse_patterns(&v)).unwrap_or_default(),
+        deny: env::var("MCP_DENIED_TOOLS").map(|v| ToolPolicy::parse_patterns(&v)).unwrap_or_default(),
+    };
+    if policy.read_only {
+        info!("Read-only mode: tools that write to providers are disabled");
+    }
+    mcp_server = mcp_server.with_policy(policy);
+
+    if let Ok(path) = env::var("MCP_SAVED_VIEWS_FILE") {
+        let views = load_saved_views(&path)?;
+        info!("Loaded {} saved views from {}", views.len(), path);
+        mcp_server = mcp_server.with_saved_views(views);
+    }
+
+    let templates = match env::var("MCP_TEMPLATES_FILE") {
+        Ok(path) => {
+            let templates = load_templates(&path)?;
+            info!("Loaded {} ticket templates from {}", templates.len(), path);
+            templates
+        }
+        Err(_) => Vec::new(),
+    };
+    mcp_server = mcp_server.with_templates(templates.clone());
+
+    // Recurring tickets created from those templates
+    let scheduler = match env::var("MCP_SCHEDULES_FILE") {
+        Ok(path) => Some(Arc::new(Scheduler::load(
+            &path,
+            registry.clone(),
+            Arc::new(templates),
+            defaults.team.clone(),
+        )?)),
+        Err(_) => None,
+    };
+    if let Some(scheduler) = &scheduler {
+        mcp_server = mcp_server.with_scheduler(scheduler.clone());
+        tokio::spawn(scheduler.clone().run());
+    }
+
+    // Tickets seen through prefetching and webhooks, for local_search
+    #[cfg(feature = "local-search")]
+    let search_index = match env::var("MCP_LOCAL_SEARCH").as_deref() {
+        Ok("memory") => Some(Arc::new(TicketIndex::new(Arc::new(TantivyIndex::in_memory()?)))),
+        Ok(dir) => Some(Arc::new(TicketIndex::new(Arc::new(TantivyIndex::open(dir)?)))),
+        Err(_) => None,
+    };
+    #[cfg(feature = "local-search")]
+    if let Some(index) = &search_index {
+        events.subscribe(None, index.clone());
+        mcp_server = mcp_server.with_search_index(index.clone());
+    }
+
+    // Embeddings for semantic_search: built-in hashing, or an
+    // OpenAI-compatible endpoint (OpenAI, or a local Ollama/llama.cpp server)
+    let embedder: Option<Arc<dyn Embedder>> = match env::var("MCP_SEMANTIC_SEARCH").as_deref() {
+        Ok("hashing") => Some(Arc::new(HashingEmbedder::default())),
+        Ok("api") => Some(Arc::new(OpenAiEmbedder::new(
+            env::var("MCP_EMBEDDINGS_URL").unwrap_or_else(|_| OPENAI_EMBEDDINGS_URL.to_string()),
+            env::var("MCP_EMBEDDINGS_MODEL").unwrap_or_else(|_| DEFAULT_EMBEDDINGS_MODEL.to_string()),
+            env::var("MCP_EMBEDDINGS_API_KEY").ok(),
+        ).with_http_client(https_client(&http_settings(None)?)?))),
+        Ok(other) => return Err(anyhow::anyhow!("MCP_SEMANTIC_SEARCH must be hashing or api, got: {}", other)),
+        Err(_) => None,
+    };
+    let semantic = embedder.map(|embedder| Arc::new(SemanticIndex::new(embedder)));
+    if let Some(semantic) = &semantic {
+        info!("Semantic search enabled with {}", semantic.model());
+        events.subscribe(None, semantic.clone());
+        mcp_server = mcp_server.with_semantic_index(semantic.clone());
+    }
+
+    // Due-date reminders, written to the server log
+    if let Ok(value) = env::var("MCP_DUE_REMINDER_INTERVAL_SECS") {
+        let interval = value.parse::<u64>()
+            .map_err(|_| anyhow::anyhow!("MCP_DUE_REMINDER_INTERVAL_SECS must be a positive integer, got: {}", value))?;
+        let hours = match env::var("MCP_DUE_REMINDER_HOURS") {
+            Ok(value) => value.parse::<i64>()
+                .map_err(|_| anyhow::anyhow!("MCP_DUE_REMINDER_HOURS must be an integer, got: {}", value))?,
+            Err(_) => DEFAULT_DUE_REMINDER_HOURS,
+        };
+        if interval > 0 {
+            let watcher = DueDateWatcher::new(
+                registry.clone(),
+                chrono::Duration::hours(hours),
+                std::time::Duration::from_secs(interval),
+                Arc::new(LogReminderSink),
+            );
+            tokio::spawn(watcher.run());
+        }
+    }
+
+    // Alerts posted to Slack as webhooks report ticket changes
+    if let Ok(path) = env::var("MCP_ALERTS_FILE") {
+        let config = load_alerts_config(&path)?;
+        let interval = config.interval_secs.map_or(DEFAULT_ALERT_INTERVAL, std::time::Duration::from_secs);
+        let mut watcher = AlertWatcher::new(registry.clone(), interval);
+        for slack in config.slack {
+            let url = resolve_secret(&slack.webhook_url, None).await?;
+            let webhook = SlackWebhook::new(url).with_http_client(https_client(&http_settings(None)?)?);
+            watcher = watcher.with_route(slack.events, slack.providers, Arc::new(webhook));
+        }
+        if env::var("MCP_WEBHOOK_LISTEN").is_err() && prefetch_interval.is_zero() {
+            tracing::warn!("Alerts need ticket changes from MCP_WEBHOOK_LISTEN or MCP_PREFETCH_INTERVAL_SECS; only SLA breaches of your tickets will alert");
+        }
+        let watcher = Arc::new(watcher);
+        events.subscribe(None, watcher.clone());
+        tokio::spawn(watcher.run());
+    }
+
+    events.subscribe(None, Arc::new(mcp_server.clone()));
+    if let Ok(listen) = env::var("MCP_WEBHOOK_LISTEN") {
+        let addr = listen.parse()
+            .map_err(|_| anyhow::anyhow!("MCP_WEBHOOK_LISTEN must be a host:port address, got: {}", listen))?;
+        let allow_unsigned = env::var("MCP_WEBHOOK_ALLOW_UNSIGNED")
+            .is_ok_and(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"));
+        let decoders: Vec<Arc<dyn WebhookDecoder>> = [
+            Arc::new(LinearWebhookDecoder::new(env::var("LINEAR_WEBHOOK_SECRET").ok())) as Arc<dyn WebhookDecoder>,
+            Arc::new(GithubWebhookDecoder::new(env::var("GITHUB_WEBHOOK_SECRET").ok())),
+        ]
+        .into_iter()
+        // Providers without a secret get no route unless explicitly allowed
+        .filter(|decoder| decoder.signed() || allow_unsigned)
+        .collect();
+        if decoders.is_empty() {
+            return Err(anyhow::anyhow!(
+                "MCP_WEBHOOK_LISTEN needs LINEAR_WEBHOOK_SECRET or GITHUB_WEBHOOK_SECRET; \
+                 set MCP_WEBHOOK_ALLOW_UNSIGNED=true to accept unsigned deliveries instead"
+            ));
+        }
+        if allow_unsigned {
+            tracing::warn!("MCP_WEBHOOK_ALLOW_UNSIGNED is set; webhooks without a *_WEBHOOK_SECRET are accepted unsigned");
+        }
+        let webhooks = decoders.into_iter().fold(
+            WebhookServer::new(addr, events.clone()).allow_unsigned(allow_unsigned),
+            |webhooks, decoder| webhooks.with_decoder(decoder),
+        );
+        tokio::spawn(async move {
+            if let Err(e) = webhooks.run().await {
+                tracing::error!("Webhook listener stopped: {}", e);
+            }
+        });
+    }
+
+    if let Ok(listen) = env::var("MCP_HEALTH_LISTEN") {
+        let addr = listen.parse()
+            .map_err(|_| anyhow::anyhow!("MCP_HEALTH_LISTEN must be a host:port address, got: {}", listen))?;
+        let ttl = match env::var("MCP_READINESS_TTL_SECS") {
+            Ok(value) => value.parse::<u64>()
+                .map(std::time::Duration::from_secs)
+                .map_err(|_| anyhow::anyhow!("MCP_READINESS_TTL_SECS must be a non-negative integer, got: {}", value))?,
+            Err(_) => DEFAULT_READINESS_TTL,
+        };
+        let health = HealthServer::new(addr, mcp_server.clone(), Arc::new(ReadinessCheck::new(probes, ttl)));
+        tokio::spawn(async move {
+            if let Err(e) = health.run().await {
+                tracing::error!("Health check listener stopped: {}", e);
+            }
+        });
+    }
+
+    if !prefetch_interval.is_zero() {
+        for (provider, cache) in caches {
+            let prefetcher = Prefetcher::new(provider, cache, events.clone(), prefetch_interval);
+            #[cfg(feature = "local-search")]
+            let prefetcher = match &search_index {
+                Some(index) => prefetcher.with_index(index.clone()),
+                None => prefetcher,
+            };
+            let prefetcher = match &semantic {
+                Some(semantic) => prefetcher.with_semantic_index(semantic.clone()),
+                None => prefetcher,
+            };
+            tokio::spawn(prefetcher.run());
+        }
+    }
 
     info!("Starting MCP server...");
     mcp_server.start_server().await?;
@@ -67,4 +843,576 @@ async fn main() -> Result<()> {
     info!("MCP server stopped");
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Builds the ticket service for one provider, or one profile, with the
+/// provider's registered factory: resolves its token and OAuth client, then
+/// leaves the rest of its settings to the factory. A given `api_token`, such
+/// as an impersonated user's, replaces both.
+async fn create_ticket_service(
+    name: &str,
+    profile: Option<&Profile>,
+    api_token: Option<String>,
+) -> Result<Arc<dyn generic_mcp::TicketService + Send + Sync>> {
+    let provider = profile.map_or(name, |profile| profile.provider.as_str());
+    let Some(factory) = provider_factory(provider) else {
+        #[cfg(feature = "wasm")]
+        if let Some(path) = profile.and_then(|profile| profile.plugin.clone())
+            .or_else(|| env::var(format!("{}_PLUGIN", provider.to_uppercase())).ok())
+        {
+            return load_plugin(name, provider, &path, profile, api_token).await;
+        }
+        return Err(anyhow::anyhow!(
+            "Unsupported provider: {}. Available providers: {}, or a WebAssembly plugin given by {}_PLUGIN or a profile's plugin",
+            provider, provider_names().join(", "), provider.to_uppercase()
+        ));
+    };
+    let prefix = provider.to_uppercase();
+
+    // An OAuth application replaces the personal API token; profiles bring
+    // their own token instead
+    let oauth = match oauth_config(provider)?.filter(|_| profile.is_none() && api_token.is_none()) {
+        Some(config) => {
+            let oauth = OAuthClient::new(config, oauth_secret_store()?)?
+                .with_http_client(https_client(&http_settings(profile)?)?);
+            if !oauth.is_authorized() {
+                match env::var(format!("{}_OAUTH_CODE", prefix)) {
+                    Ok(code) => oauth.exchange_code(&code).await?,
+                    Err(_) => return Err(anyhow::anyhow!(
+                        "{} OAuth is not authorized. Run `generic-mcp auth login {}`, or open {} and set {}_OAUTH_CODE to the code it redirects with",
+                        provider, provider, oauth.authorize_url(&uuid::Uuid::new_v4().to_string())?, prefix
+                    )),
+                }
+            }
+            Some(Arc::new(oauth))
+        }
+        None => None,
+    };
+
+    let api_token = match (api_token, profile) {
+        (Some(api_token), _) => api_token,
+        (None, Some(profile)) => profile.api_token()?.or_else(|| saved_api_token(name)).ok_or_else(|| anyhow::anyhow!(
+            "Profile {} has no API token: set api_token_env, or save one with `generic-mcp auth set {}`", name, name
+        ))?,
+        (None, None) => match env::var(format!("{}_API_TOKEN", prefix)).ok().or_else(|| saved_api_token(provider)) {
+            Some(token) => token,
+            None if oauth.is_some() => String::new(),
+            None => return Err(anyhow::anyhow!(
+                "{}_API_TOKEN (or {}_OAUTH_CLIENT_ID) is required for the {} provider; `generic-mcp auth set {}` saves one to the OS keychain",
+                prefix, prefix, provider, provider
+            )),
+        },
+    };
+
+    let api_token = resolve_secret(&api_token, profile).await?;
+    let context = ProviderContext {
+        name,
+        profile,
+        config: ProviderConfig {
+            provider_type: provider.to_string(),
+            api_token,
+            base_url: profile.and_then(|profile| profile.base_url.clone()),
+            workspace_id: profile.and_then(|profile| profile.workspace_id.clone()),
+            http: http_settings(profile)?,
+        },
+        oauth,
+    };
+    factory.create(&context).await
+}
+
+/// Loads a provider plugin, handing it the token and base URL from the
+/// profile or `<PROVIDER>_API_TOKEN` and `<PROVIDER>_BASE_URL`, and the
+/// profile's `plugin_config`; a given `api_token` wins.
+#[cfg(feature = "wasm")]
+async fn load_plugin(
+    name: &str,
+    provider: &str,
+    path: &str,
+    profile: Option<&Profile>,
+    api_token: Option<String>,
+) -> Result<Arc<dyn generic_mcp::TicketService + Send + Sync>> {
+    let prefix = provider.to_uppercase();
+    let mut config = profile.map(|profile| profile.plugin_config.clone()).unwrap_or_default();
+    let api_token = match (api_token, profile) {
+        (Some(api_token), _) => Some(api_token),
+        (None, Some(profile)) => profile.api_token()?,
+        (None, None) => env::var(format!("{}_API_TOKEN", prefix)).ok(),
+    };
+    if let Some(api_token) = api_token.or_else(|| saved_api_token(name)) {
+        config.insert("api_token".to_string(), resolve_secret(&api_token, profile).await?.into());
+    }
+    let base_url = match profile {
+        Some(profile) => profile.base_url.clone(),
+        None => env::var(format!("{}_BASE_URL", prefix)).ok(),
+    };
+    if let Some(base_url) = base_url {
+        config.insert("base_url".to_string(), base_url.into());
+    }
+    if let Some(workspace_id) = profile.and_then(|profile| profile.workspace_id.clone()) {
+        config.insert("workspace_id".to_string(), workspace_id.into());
+    }
+
+    let transport = http_transport(&http_settings(profile)?)?;
+    let plugin = WasmProvider::load(name, path, serde_json::Value::Object(config), transport).await?;
+    Ok(Arc::new(plugin))
+}
+
+/// A token given as a `secret://` URI, fetched from its secret manager
+/// through the profile's proxy settings; any other value as it is.
+async fn resolve_secret(value: &str, profile: Option<&Profile>) -> Result<String> {
+    if !value.starts_with(SECRET_URI_SCHEME) {
+        return Ok(value.to_string());
+    }
+    SecretResolver::new()
+        .with_http_client(https_client(&http_settings(profile)?)?)
+        .resolve(value)
+        .await
+}
+
+/// Keychain entry holding a provider's API token.
+fn api_token_key(provider: &str) -> String {
+    format!("{}.api_token", provider)
+}
+
+/// The OS keychain API tokens are saved to, when built with it.
+#[cfg(feature = "keyring")]
+fn keychain() -> Option<Arc<dyn SecretStore>> {
+    Some(Arc::new(KeyringSecretStore::default()))
+}
+
+#[cfg(not(feature = "keyring"))]
+fn keychain() -> Option<Arc<dyn SecretStore>> {
+    None
+}
+
+/// A provider's API token saved by `generic-mcp auth set`, if the keychain
+/// is reachable.
+fn saved_api_token(provider: &str) -> Option<String> {
+    match keychain()?.get(&api_token_key(provider)) {
+        Ok(token) => token,
+        Err(e) => {
+            tracing::warn!("Could not read the {} API token from the keychain: {}", provider, e);
+            None
+        }
+    }
+}
+
+/// Proxy settings: a profile's `proxy`, then `MCP_PROXY` and `MCP_NO_PROXY`,
+/// then the standard proxy environment variables. Extra CA certificates
+/// come from `MCP_CA_CERTIFICATES` and the profile. Timeouts and connection
+/// limits come from `MCP_*_SECS` and `MCP_MAX_IN_FLIGHT_REQUESTS`, with the
+/// profile's timeouts taking precedence.
+fn http_settings(profile: Option<&Profile>) -> Result<HttpSettings> {
+    let number = |name: &str| match env::var(name) {
+        Ok(value) => value.parse::<u64>()
+            .map(Some)
+            .map_err(|_| anyhow::anyhow!("{} must be a non-negative integer, got: {}", name, value)),
+        Err(_) => Ok(None),
+    };
+    let seconds = |name: &str| number(name).map(|secs| secs.map(std::time::Duration::from_secs));
+    let connect_timeout = profile.and_then(|profile| profile.connect_timeout_secs).map(std::time::Duration::from_secs);
+    let request_timeout = profile.and_then(|profile| profile.request_timeout_secs).map(std::time::Duration::from_secs);
+
+    let mut ca_certificates: Vec<String> = env::var("MCP_CA_CERTIFICATES")
+        .map(|paths| paths.split(',').map(str::trim).filter(|path| !path.is_empty()).map(String::from).collect())
+        .unwrap_or_default();
+    ca_certificates.extend(profile.iter().flat_map(|profile| profile.ca_certificates.iter().cloned()));
+    let accept_invalid_certs = profile.is_some_and(|profile| profile.accept_invalid_certs)
+        || env::var("MCP_ACCEPT_INVALID_CERTS").is_ok_and(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"));
+    if accept_invalid_certs {
+        tracing::warn!("TLS certificate verification is disabled");
+    }
+    Ok(HttpSettings {
+        proxy: profile.and_then(|profile| profile.proxy.clone()).or_else(|| env::var("MCP_PROXY").ok()),
+        no_proxy: env::var("MCP_NO_PROXY").ok(),
+        ca_certificates,
+        accept_invalid_certs,
+        connect_timeout: connect_timeout.or(seconds("MCP_CONNECT_TIMEOUT_SECS")?),
+        request_timeout: request_timeout.or(seconds("MCP_REQUEST_TIMEOUT_SECS")?),
+        pool_idle_timeout: seconds("MCP_POOL_IDLE_TIMEOUT_SECS")?,
+        tcp_keepalive: seconds("MCP_TCP_KEEPALIVE_SECS")?,
+        max_in_flight: number("MCP_MAX_IN_FLIGHT_REQUESTS")?.map(|max| max as usize),
+        backend: profile.and_then(|profile| profile.http_backend.clone()).or_else(|| env::var("MCP_HTTP_BACKEND").ok()),
+        conditional_cache_entries: number("MCP_CONDITIONAL_CACHE_ENTRIES")?.map(|entries| entries as usize),
+        fixtures: fixture_settings()?,
+    })
+}
+
+/// `MCP_HTTP_FIXTURES=record` writes provider responses to
+/// `MCP_HTTP_FIXTURES_DIR` (default `tests/fixtures/recorded`), and
+/// `replay` answers from them instead of the network.
+fn fixture_settings() -> Result<Option<FixtureSettings>> {
+    let mode = match env::var("MCP_HTTP_FIXTURES").as_deref() {
+        Err(_) | Ok("") | Ok("off") => return Ok(None),
+        Ok("record") => FixtureMode::Record,
+        Ok("replay") => FixtureMode::Replay,
+        Ok(other) => return Err(anyhow::anyhow!("MCP_HTTP_FIXTURES must be record, replay, or off, got: {}", other)),
+    };
+    let dir = env::var("MCP_HTTP_FIXTURES_DIR").unwrap_or_else(|_| "tests/fixtures/recorded".to_string());
+    if mode == FixtureMode::Record {
+        tracing::warn!("Recording provider responses to {}; review them before committing", dir);
+    }
+    Ok(Some(FixtureSettings { mode, dir }))
+}
+
+/// Where OAuth tokens are kept: `MCP_SECRETS_FILE`, or the default secrets
+/// file.
+fn oauth_secret_store() -> Result<Arc<dyn SecretStore>> {
+    let path = match env::var("MCP_SECRETS_FILE") {
+        Ok(path) => path.into(),
+        Err(_) => FileSecretStore::default_path()
+            .ok_or_else(|| anyhow::anyhow!("Set MCP_SECRETS_FILE to store OAuth tokens"))?,
+    };
+    Ok(Arc::new(FileSecretStore::new(path)))
+}
+
+/// A provider's OAuth application from `<PROVIDER>_OAUTH_CLIENT_ID`,
+/// `_CLIENT_SECRET`, `_REDIRECT_URI`, and `_SCOPE`; `None` without a client id.
+fn oauth_config(provider: &str) -> Result<Option<OAuthConfig>> {
+    let prefix = provider.to_uppercase();
+    let var = |suffix: &str| env::var(format!("{}_OAUTH_{}", prefix, suffix)).ok();
+    let required = |suffix: &str| var(suffix)
+        .ok_or_else(|| anyhow::anyhow!("{}_OAUTH_{} is required with {}_OAUTH_CLIENT_ID", prefix, suffix, prefix));
+    let Some(client_id) = var("CLIENT_ID") else {
+        return Ok(None);
+    };
+
+    let mut config = match provider {
+        "linear" => OAuthConfig::linear(client_id, required("CLIENT_SECRET")?, required("REDIRECT_URI")?),
+        "github" => OAuthConfig::github(client_id, var("CLIENT_SECRET")),
+        "jira" => OAuthConfig::jira(client_id, required("CLIENT_SECRET")?, required("REDIRECT_URI")?),
+        _ => return Err(anyhow::anyhow!("OAuth is not supported for {}; available: linear, github, jira", provider)),
+    };
+    if let Some(redirect_uri) = var("REDIRECT_URI") {
+        config.redirect_uri = Some(redirect_uri);
+    }
+    if let Some(scope) = var("SCOPE") {
+        config = config.with_scope(scope);
+    }
+    Ok(Some(config))
+}
+
+/// `auth login` authorizes the provider's OAuth application in the browser,
+/// or with a device code, and saves the tokens; `auth logout` forgets them.
+/// `auth set` saves an API token to the OS keychain, prompting for it
+/// without echo (or reading it from piped stdin); `auth delete` removes it.
+/// With `--user <identity>`, `set` and `delete` manage that client's own
+/// token.
+async fn run_auth_command(command: AuthCommand) -> Result<()> {
+    let token_key = |provider: &str, user: Option<&str>| match user {
+        Some(identity) => user_token_key(provider, identity),
+        None => api_token_key(provider),
+    };
+    match command {
+        AuthCommand::Login { provider, device } => {
+            let config = oauth_config(&provider)?.ok_or_else(|| anyhow::anyhow!(
+                "Set {}_OAUTH_CLIENT_ID and the rest of its OAuth application settings to log in",
+                provider.to_uppercase()
+            ))?;
+            // Without a redirect URI the device flow is the only option
+            let device = device || config.redirect_uri.is_none();
+            let oauth = OAuthClient::new(config, oauth_secret_store()?)?
+                .with_http_client(https_client(&http_settings(None)?)?);
+            if device {
+                oauth.authorize_with_device_code(|device| {
+                    let url = device.verification_uri_complete.as_ref().unwrap_or(&device.verification_uri);
+                    println!("Open {} and enter the code {}", url, device.user_code);
+                }).await?;
+            } else {
+                oauth.authorize_with_browser(|url| {
+                    println!("Open this URL to authorize {}:\n\n  {}\n", provider, url);
+                }).await?;
+            }
+            println!("Authorized {}; its tokens are saved and refreshed automatically", provider);
+        }
+        AuthCommand::Logout { provider } => {
+            let config = oauth_config(&provider)?
+                .ok_or_else(|| anyhow::anyhow!("{}_OAUTH_CLIENT_ID is not set", provider.to_uppercase()))?;
+            OAuthClient::new(config, oauth_secret_store()?)?.sign_out()?;
+            println!("Removed the saved {} OAuth tokens", provider);
+        }
+        AuthCommand::Set { provider, user } => {
+            let key = token_key(&provider, user.as_deref());
+            let store = keychain().ok_or_else(|| anyhow::anyhow!(
+                "generic-mcp was built without the keyring feature; set the provider's API token in the environment"
+            ))?;
+            let token = if std::io::stdin().is_terminal() {
+                rpassword::prompt_password(format!("{} API token: ", provider))?
+            } else {
+                let mut line = String::new();
+                std::io::stdin().read_line(&mut line)?;
+                line
+            };
+            let token = token.trim();
+            if token.is_empty() {
+                return Err(anyhow::anyhow!("No token given"));
+            }
+            store.set(&key, token)?;
+            println!("Saved the {} API token to the OS keychain", provider);
+        }
+        AuthCommand::Delete { provider, user } => {
+            let key = token_key(&provider, user.as_deref());
+            let store = keychain().ok_or_else(|| anyhow::anyhow!("generic-mcp was built without the keyring feature"))?;
+            store.delete(&key)?;
+            println!("Removed the {} API token from the OS keychain", provider);
+        }
+    }
+    Ok(())
+}
+
+/// `export-workspace <file>` writes a snapshot of the provider's workspace.
+async fn export_workspace(registry: &ProviderRegistry, path: &str) -> Result<()> {
+    let name = registry.default_name();
+    let snapshot = registry.default_application().export_workspace(name).await?;
+    save_snapshot(path, &snapshot)?;
+    println!(
+        "Exported {} tickets, {} projects, and {} labels from {} to {}",
+        snapshot.tickets.len(), snapshot.projects.len(), snapshot.labels.len(), name, path
+    );
+    Ok(())
+}
+
+/// `import-workspace <file>` re-creates a snapshot in the provider,
+/// translated per `--mapping <file>`. `--team` names the team that tickets
+/// go to when the target has no team with their original key.
+async fn import_workspace(
+    registry: &ProviderRegistry,
+    path: &str,
+    mapping_file: Option<&str>,
+    team: Option<String>,
+    dry_run: bool,
+) -> Result<()> {
+    let mut mapping = match mapping_file {
+        Some(file) => load_field_mapping(file)?,
+        None => FieldMapping::default(),
+    };
+    if team.is_some() {
+        mapping.fallback_team = team;
+    }
+    let snapshot = load_snapshot(path)?;
+    let application = registry.default_application();
+    let outcome = if dry_run {
+        MigrationOutcome::Plan(application.plan_import(&snapshot, &mapping).await?)
+    } else {
+        MigrationOutcome::Report(application.import_workspace(&snapshot, &mapping, &print_progress).await?)
+    };
+    finish_migration(outcome)
+}
+
+/// `migrate <file>` runs the migration a configuration file describes.
+async fn migrate(registry: &ProviderRegistry, path: &str, dry_run: bool) -> Result<()> {
+    let outcome = run_migration(registry, &load_migration_config(path)?, dry_run, &print_progress).await?;
+    finish_migration(outcome)
+}
+
+/// Progress goes to stderr, leaving stdout to the result.
+fn print_progress(done: usize, total: usize, message: &str) {
+    if total > 0 {
+        eprintln!("[{}/{}] {}", done, total, message);
+    } else {
+        eprintln!("{}", message);
+    }
+}
+
+/// Prints a migration's plan or report, exiting with status 1 if anything
+/// failed.
+fn finish_migration(outcome: MigrationOutcome) -> Result<()> {
+    println!("{}", outcome);
+    if matches!(&outcome, MigrationOutcome::Report(report) if !report.failures.is_empty()) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+async fn run_tickets_command(application: &Application, command: TicketsCommand) -> Result<()> {
+    match command {
+        TicketsCommand::List { team, state, assignee, query, limit, output } => {
+            let filter = TicketFilter {
+                team_id: match team {
+                    Some(team) => Some(application.find_team(&team).await?.id),
+                    None => None,
+                },
+                state_type: state.as_deref().map(StateType::parse),
+                assignee_id: match assignee.as_deref() {
+                    Some("me") => Some(application.get_current_user().await?.id),
+                    other => other.map(str::to_string),
+                },
+                search_query: query,
+                ..Default::default()
+            };
+            let page = application.filter_tickets_page(&filter, &PageRequest::first(limit)).await?;
+            let mut table = Table::new(&[
+                "id", "identifier", "title", "state", "state_type", "priority", "assignee_id", "project_id",
+                "labels", "created_at", "updated_at", "due_date", "estimate", "url",
+            ]);
+            for ticket in &page.items {
+                table.push(vec![
+                    json!(ticket.id),
+                    json!(ticket.identifier),
+                    json!(ticket.title),
+                    json!(ticket.state.name),
+                    json!(ticket.state.type_.name()),
+                    json!(ticket.priority.name()),
+                    json!(ticket.assignee_id),
+                    json!(ticket.project_id),
+                    json!(ticket.labels),
+                    json!(ticket.created_at.to_rfc3339()),
+                    json!(ticket.updated_at.to_rfc3339()),
+                    json!(ticket.due_date.map(|date| date.to_rfc3339())),
+                    json!(ticket.estimate),
+                    json!(ticket.url),
+                ]);
+            }
+            print_listing(table, &["identifier", "state", "priority", "title"], &output)?;
+        }
+        TicketsCommand::Create { title, team, description, priority, assignee, project, parent, labels } => {
+            let team_id = match team {
+                Some(team) => Some(application.find_team(&team).await?.id),
+                None => None,
+            };
+            let ticket = application
+                .create_ticket(&CreateTicketRequest {
+                    title,
+                    description: description.as_deref().map(RichText::markdown),
+                    priority: priority.as_deref().map(Priority::parse),
+                    assignee_id: assignee,
+                    team_id,
+                    project_id: project,
+                    parent_id: parent,
+                    label_ids: (!labels.is_empty()).then_some(labels),
+                    ..Default::default()
+                }, None)
+                .await?;
+            println!("Created {}: {}\n{}", ticket.identifier, ticket.title, ticket.url);
+        }
+        TicketsCommand::Update { ticket, title, description, priority, state, assignee } => {
+            let current = application.get_ticket(&ticket).await?
+                .ok_or_else(|| anyhow::anyhow!("Ticket not found: {}", ticket))?;
+            let state_id = match state {
+                Some(state) => {
+                    let workflow = application.ticket_workflow(&current).await?;
+                    let target = workflow.resolve(&state)
+                        .ok_or_else(|| anyhow::anyhow!("{}'s workflow has no state {}", current.identifier, state))?;
+                    Some(target.id.clone())
+                }
+                None => None,
+            };
+            let updated = application
+                .update_ticket(&UpdateTicketRequest {
+                    id: current.id,
+                    title,
+                    description: description.as_deref().map(RichText::markdown),
+                    priority: priority.as_deref().map(Priority::parse),
+                    assignee_id: assignee,
+                    state_id,
+                    label_ids: None,
+                    due_date: None,
+                    estimate: None,
+                    custom_fields: None,
+                })
+                .await?;
+            println!("Updated {}: {} ({})", updated.identifier, updated.title, updated.state.name);
+        }
+        TicketsCommand::Import { file, columns, team, dry_run, concurrency } => {
+            let csv = std::fs::read_to_string(&file).with_context(|| format!("Failed to read {}", file))?;
+            let plan = application.plan_csv_import(&csv, &columns.into_iter().collect(), team.as_deref()).await?;
+            if dry_run || !plan.is_valid() {
+                print!("{}", plan);
+                if !plan.is_valid() {
+                    if !dry_run {
+                        eprintln!("Nothing was created; fix the invalid rows and run again");
+                    }
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+            let report = application.import_csv(&plan, concurrency, &print_progress).await;
+            print!("{}", report);
+            if !report.failed.is_empty() {
+                std::process::exit(1);
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn list_teams(application: &Application, output: &OutputArgs) -> Result<()> {
+    let mut table = Table::new(&["id", "key", "name", "description", "members"]);
+    for team in application.get_teams().await? {
+        table.push(vec![
+            json!(team.id),
+            json!(team.key),
+            json!(team.name),
+            json!(team.description),
+            json!(team.members.len()),
+        ]);
+    }
+    print_listing(table, &["key", "name", "id"], output)
+}
+
+async fn list_projects(application: &Application, limit: usize, output: &OutputArgs) -> Result<()> {
+    let mut table = Table::new(&["id", "key", "name", "state", "progress", "target_date", "lead_id", "updated_at"]);
+    for project in application.get_projects(&PageRequest::first(limit)).await?.items {
+        table.push(vec![
+            json!(project.id),
+            json!(project.key),
+            json!(project.name),
+            json!(format!("{:?}", project.state).to_lowercase()),
+            json!(format!("{:.0}%", project.progress * 100.0)),
+            json!(project.target_date.map(|date| date.to_rfc3339())),
+            json!(project.lead_id),
+            json!(project.updated_at.to_rfc3339()),
+        ]);
+    }
+    print_listing(table, &["name", "state", "progress", "id"], output)
+}
+
+async fn list_labels(application: &Application, output: &OutputArgs) -> Result<()> {
+    let mut table = Table::new(&["id", "name", "color", "description"]);
+    for label in application.get_labels().await? {
+        table.push(vec![json!(label.id), json!(label.name), json!(label.color), json!(label.description)]);
+    }
+    print_listing(table, &["name", "color", "id"], output)
+}
+
+/// Prints a listing's `defaults` columns, or the ones asked for, in the
+/// format asked for; `--quiet` prints only the `id` column.
+fn print_listing(table: Table, defaults: &[&str], output: &OutputArgs) -> Result<()> {
+    if output.quiet {
+        for id in table.column_text("id")? {
+            println!("{}", id);
+        }
+        return Ok(());
+    }
+    let table = if output.columns.is_empty() { table.select(defaults)? } else { table.select(&output.columns)? };
+    print!("{}", table.render(output.format)?);
+    Ok(())
+}
+
+/// Rate and concurrency limits for one provider, from `<PROVIDER>_RATE_LIMIT_PER_MINUTE`,
+/// `<PROVIDER>_RATE_LIMIT_BURST`, and `<PROVIDER>_MAX_CONCURRENT_CALLS`.
+fn provider_limits(provider: &str) -> Result<(Option<RateLimit>, usize)> {
+    let prefix = provider.to_uppercase();
+    let number = |suffix: &str| -> Result<Option<u32>> {
+        let name = format!("{}_{}", prefix, suffix);
+        match env::var(&name) {
+            Ok(value) => value.parse::<u32>()
+                .map(Some)
+                .map_err(|_| anyhow::anyhow!("{} must be a non-negative integer, got: {}", name, value)),
+            Err(_) => Ok(None),
+        }
+    };
+
+    let rate = match number("RATE_LIMIT_PER_MINUTE")? {
+        Some(per_minute) if per_minute > 0 => {
+            let burst = number("RATE_LIMIT_BURST")?.unwrap_or(per_minute.div_ceil(6));
+            info!("Limiting {} to {} calls per minute (bursts of {})", provider, per_minute, burst);
+            Some(RateLimit { per_minute, burst })
+        }
+        _ => None,
+    };
+    let max_concurrent = number("MAX_CONCURRENT_CALLS")?
+        .map(|max| max as usize)
+        .unwrap_or(DEFAULT_MAX_CONCURRENT_CALLS);
+    Ok((rate, max_concurrent))
+}
@@ -1,10 +0,0 @@
-// Generic service interfaces
-pub mod ticket_service;
-pub mod mcp_server;
-
-pub use ticket_service::*;
-pub use mcp_server::*;
-
-// Legacy Linear-specific interface (for backward compatibility)
-pub mod linear_service;
-pub use linear_service::*;
\ No newline at end of file
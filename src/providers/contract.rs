@@ -0,0 +1,252 @@
+use anyhow::{Context, Result, anyhow, ensure};
+
+use crate::domain::{
+    CreateTicketRequest, Priority, RichText, StateType, Ticket, TicketFilter, TicketRef, UpdateTicketRequest, WorkflowState,
+};
+use crate::ports::{PageRequest, TicketService, TicketServiceError};
+
+/// Where `check_ticket_service` works and what it leaves behind.
+#[derive(Debug, Clone)]
+pub struct ContractOptions {
+    /// Team the ticket is created in; unset takes the first team.
+    pub team_id: Option<String>,
+    /// Start of the ticket's title, so leftovers are easy to find.
+    pub title_prefix: String,
+    /// Deletes the ticket at the end.
+    pub cleanup: bool,
+    /// An id no ticket has, checked to come back as `None` rather than an
+    /// error; unset skips the check, for providers where a made-up id is
+    /// malformed.
+    pub missing_ticket_id: Option<String>,
+}
+
+impl Default for ContractOptions {
+    fn default() -> Self {
+        Self {
+            team_id: None,
+            title_prefix: "[contract]".to_string(),
+            cleanup: true,
+            missing_ticket_id: None,
+        }
+    }
+}
+
+/// Runs one ticket through what every provider must agree on: it is
+/// created with what was asked for, reads back the same by id and by
+/// identifier, keeps untouched fields through an update, turns up in
+/// searches by team, text, and state, and moves through its team's
+/// workflow. Fails with the first broken expectation. Against a real
+/// provider this creates a ticket; point it at a test workspace.
+pub async fn check_ticket_service<S>(service: &S, options: &ContractOptions) -> Result<()>
+where
+    S: TicketService + Sync + ?Sized,
+{
+    let teams = service.get_teams().await.context("get_teams")?;
+    let team = match &options.team_id {
+        Some(team_id) => teams.iter().find(|team| &team.id == team_id || &team.key == team_id)
+            .ok_or_else(|| anyhow!("get_teams: no team {}", team_id))?,
+        None => teams.first().ok_or_else(|| anyhow!("get_teams: no teams to create a ticket in"))?,
+    };
+    let states = service.get_workflow_states(&team.id).await.context("get_workflow_states")?;
+    let state_of = |type_: StateType| states.iter().find(|state| state.type_ == type_)
+        .ok_or_else(|| anyhow!("get_workflow_states: team {} has no {:?} state", team.key, type_));
+    let (open, in_progress, closed) = (state_of(StateType::Open)?, state_of(StateType::InProgress)?, state_of(StateType::Closed)?);
+
+    if let Some(missing) = &options.missing_ticket_id {
+        let found = service.get_ticket(missing).await.context("get_ticket of a missing ticket")?;
+        ensure!(found.is_none(), "get_ticket: found {} for the missing id {}", ticket_name(found.as_ref()), missing);
+    }
+
+    // A marker unique to this run, for searching
+    let marker = uuid::Uuid::new_v4().simple().to_string()[..12].to_string();
+    let description = format!("Created by the TicketService contract ({})", marker);
+    let created = service
+        .create_ticket(&CreateTicketRequest {
+            title: format!("{} {}", options.title_prefix, marker),
+            description: Some(RichText::markdown(&description)),
+            priority: Some(Priority::High),
+            team_id: Some(team.id.clone()),
+            ..Default::default()
+        })
+        .await
+        .context("create_ticket")?;
+    let result = round_trip(service, options, &team.id, &created, &marker, &description, [open, in_progress, closed]).await;
+    if options.cleanup {
+        let deleted = service.delete_ticket(&created.id).await.context("delete_ticket");
+        // The round trip's failure says more than a failed cleanup
+        result?;
+        deleted?;
+        let after = service.get_ticket(&created.id).await.context("get_ticket after delete_ticket")?;
+        ensure!(
+            after.as_ref().is_none_or(|ticket| ticket.archived_at.is_some()),
+            "get_ticket: {} is still there, unarchived, after delete_ticket", created.identifier
+        );
+        return Ok(());
+    }
+    result
+}
+
+async fn round_trip<S>(
+    service: &S,
+    options: &ContractOptions,
+    team_id: &str,
+    created: &Ticket,
+    marker: &str,
+    description: &str,
+    [open, in_progress, closed]: [&WorkflowState; 3],
+) -> Result<()>
+where
+    S: TicketService + Sync + ?Sized,
+{
+    let title = format!("{} {}", options.title_prefix, marker);
+    ensure!(!created.id.is_empty() && !created.identifier.is_empty(), "create_ticket: no id or identifier");
+    ensure!(created.title == title, "create_ticket: title {:?}, asked for {:?}", created.title, title);
+    ensure!(created.priority == Priority::High, "create_ticket: priority {:?}, asked for High", created.priority);
+    ensure!(
+        !matches!(created.state.type_, StateType::Closed | StateType::Cancelled),
+        "create_ticket: new ticket is already {:?}", created.state.type_
+    );
+    ensure!(created.archived_at.is_none(), "create_ticket: new ticket is archived");
+
+    let fetched = fetch(service, &created.id, "get_ticket after create_ticket").await?;
+    ensure!(fetched.id == created.id, "get_ticket: asked for {}, got {}", created.id, fetched.id);
+    ensure!(fetched.identifier == created.identifier, "get_ticket: identifier {}, created as {}", fetched.identifier, created.identifier);
+    ensure!(fetched.title == title, "get_ticket: title {:?}, created as {:?}", fetched.title, title);
+    ensure!(plain_text(&fetched) == description, "get_ticket: description {:?}, created as {:?}", plain_text(&fetched), description);
+
+    match service.find_ticket(&TicketRef::parse(&created.identifier)).await {
+        Ok(found) => ensure!(
+            found.as_ref().is_some_and(|ticket| ticket.id == created.id),
+            "find_ticket: {} found {}", created.identifier, ticket_name(found.as_ref())
+        ),
+        // Providers without human identifiers may only look up by id
+        Err(TicketServiceError::Unsupported(_)) => {}
+        Err(error) => return Err(anyhow::Error::new(error).context("find_ticket")),
+    }
+
+    let renamed = format!("{} {} renamed", options.title_prefix, marker);
+    let updated = service
+        .update_ticket(&UpdateTicketRequest {
+            title: Some(renamed.clone()),
+            priority: Some(Priority::Low),
+            ..update(&created.id)
+        })
+        .await
+        .context("update_ticket")?;
+    for (step, ticket) in [("update_ticket", updated), ("get_ticket after update_ticket", fetch(service, &created.id, "get_ticket after update_ticket").await?)] {
+        ensure!(ticket.title == renamed, "{}: title {:?}, updated to {:?}", step, ticket.title, renamed);
+        ensure!(ticket.priority == Priority::Low, "{}: priority {:?}, updated to Low", step, ticket.priority);
+        ensure!(plain_text(&ticket) == description, "{}: description changed to {:?} though the update left it alone", step, plain_text(&ticket));
+    }
+
+    let by_text = TicketFilter {
+        team_id: Some(team_id.to_string()),
+        search_query: Some(marker.to_string()),
+        ..Default::default()
+    };
+    ensure_found(service, &by_text, created, "search_tickets by team and text").await?;
+
+    for target in [in_progress, closed] {
+        let moved = service
+            .update_ticket(&UpdateTicketRequest { state_id: Some(target.id.clone()), ..update(&created.id) })
+            .await
+            .with_context(|| format!("update_ticket to {}", target.name))?;
+        let refetched = fetch(service, &created.id, "get_ticket after a state change").await?;
+        for (step, ticket) in [("update_ticket", &moved), ("get_ticket", &refetched)] {
+            ensure!(
+                ticket.state.id == target.id && ticket.state.type_ == target.type_,
+                "{}: state {} ({:?}) after moving to {} ({:?})",
+                step, ticket.state.name, ticket.state.type_, target.name, target.type_
+            );
+        }
+    }
+    let closed_filter = TicketFilter { state_type: Some(StateType::Closed), ..by_text.clone() };
+    ensure_found(service, &closed_filter, created, "search_tickets by closed state").await?;
+    let open_filter = TicketFilter { state_type: Some(StateType::Open), ..by_text };
+    let page = service.search_tickets(&open_filter, &PageRequest::default()).await.context("search_tickets by open state")?;
+    ensure!(
+        page.items.iter().all(|ticket| ticket.id != created.id),
+        "search_tickets: closed ticket {} matched a search for open tickets", created.identifier
+    );
+
+    let reopened = service
+        .update_ticket(&UpdateTicketRequest { state_id: Some(open.id.clone()), ..update(&created.id) })
+        .await
+        .context("update_ticket to reopen")?;
+    ensure!(
+        reopened.state.type_ == StateType::Open,
+        "update_ticket: state {:?} after reopening into {}", reopened.state.type_, open.name
+    );
+    Ok(())
+}
+
+/// An update of `id` that changes nothing.
+fn update(id: &str) -> UpdateTicketRequest {
+    UpdateTicketRequest {
+        id: id.to_string(),
+        title: None,
+        description: None,
+        priority: None,
+        assignee_id: None,
+        state_id: None,
+        label_ids: None,
+        due_date: None,
+        estimate: None,
+        custom_fields: None,
+    }
+}
+
+async fn fetch<S>(service: &S, id: &str, step: &str) -> Result<Ticket>
+where
+    S: TicketService + Sync + ?Sized,
+{
+    service.get_ticket(id).await.context(step.to_string())?
+        .ok_or_else(|| anyhow!("{}: {} not found", step, id))
+}
+
+async fn ensure_found<S>(service: &S, filter: &TicketFilter, ticket: &Ticket, step: &str) -> Result<()>
+where
+    S: TicketService + Sync + ?Sized,
+{
+    let page = service.search_tickets(filter, &PageRequest::default()).await.context(step.to_string())?;
+    ensure!(
+        page.items.iter().any(|found| found.id == ticket.id),
+        "{}: {} not among {} results", step, ticket.identifier, page.items.len()
+    );
+    Ok(())
+}
+
+fn plain_text(ticket: &Ticket) -> String {
+    ticket.description.as_ref().map(|description| description.to_plain_text().trim().to_string()).unwrap_or_default()
+}
+
+fn ticket_name(ticket: Option<&Ticket>) -> String {
+    ticket.map_or_else(|| "nothing".to_string(), |ticket| ticket.identifier.clone())
+}
+
+/// Declares a `#[tokio::test]` that runs `check_ticket_service` against
+/// the service an expression builds, which may `.await`:
+///
+/// ```ignore
+/// generic_mcp::ticket_service_contract!(memory_contract, MemoryTicketService::new());
+/// generic_mcp::ticket_service_contract!(
+///     acme_contract,
+///     AcmeService::connect(&token()).await.unwrap(),
+///     ContractOptions { team_id: Some("QA".into()), ..Default::default() }
+/// );
+/// ```
+#[macro_export]
+macro_rules! ticket_service_contract {
+    ($name:ident, $service:expr) => {
+        $crate::ticket_service_contract!($name, $service, $crate::providers::ContractOptions::default());
+    };
+    ($name:ident, $service:expr, $options:expr) => {
+        #[tokio::test]
+        async fn $name() {
+            let service = $service;
+            if let Err(error) = $crate::providers::check_ticket_service(&service, &$options).await {
+                panic!("{:#}", error);
+            }
+        }
+    };
+}
@@ -0,0 +1,96 @@
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use linkme::distributed_slice;
+use std::env;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::adapters::OAuthClient;
+use crate::core::Profile;
+use crate::ports::{ProviderConfig, TicketService};
+
+/// Builds a provider's `TicketService`. Factories register themselves with
+/// `register_provider!`, so a crate that links this one can add a provider
+/// without the server knowing about it.
+#[async_trait]
+pub trait ProviderFactory: Send + Sync {
+    /// The provider type profiles and `MCP_PROVIDER` name, e.g. `linear`;
+    /// also the prefix of its environment settings.
+    fn name(&self) -> &'static str;
+
+    async fn create(&self, context: &ProviderContext<'_>) -> Result<Arc<dyn TicketService + Send + Sync>>;
+}
+
+/// What the server has resolved for a provider before its factory runs.
+pub struct ProviderContext<'a> {
+    /// The profile name, or the provider type without profiles.
+    pub name: &'a str,
+    pub profile: Option<&'a Profile>,
+    /// The token, base URL, and HTTP settings. The token is empty when the
+    /// provider authenticates with `oauth` instead.
+    pub config: ProviderConfig,
+    /// An authorized client of the provider's OAuth application, when
+    /// `<PROVIDER>_OAUTH_CLIENT_ID` is set.
+    pub oauth: Option<Arc<OAuthClient>>,
+}
+
+impl ProviderContext<'_> {
+    /// The environment variable `<PROVIDER>_<suffix>`, e.g. `LINEAR_PAGE_SIZE`.
+    pub fn setting(&self, suffix: &str) -> Option<String> {
+        env::var(self.setting_name(suffix)).ok()
+    }
+
+    /// A setting parsed as `T`; `expected` describes valid values for the
+    /// error, e.g. "a positive integer".
+    pub fn parsed_setting<T: FromStr>(&self, suffix: &str, expected: &str) -> Result<Option<T>> {
+        match self.setting(suffix) {
+            Some(value) => value.parse::<T>()
+                .map(Some)
+                .map_err(|_| anyhow!("{} must be {}, got: {}", self.setting_name(suffix), expected, value)),
+            None => Ok(None),
+        }
+    }
+
+    /// A setting read as a boolean: `1`, `true`, or `yes`.
+    pub fn flag(&self, suffix: &str) -> bool {
+        self.setting(suffix).is_some_and(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+    }
+
+    fn setting_name(&self, suffix: &str) -> String {
+        format!("{}_{}", self.config.provider_type.to_uppercase(), suffix)
+    }
+}
+
+/// Every factory linked into the binary.
+#[distributed_slice]
+pub static PROVIDER_FACTORIES: [&'static dyn ProviderFactory];
+
+/// The factory registered for a provider type.
+pub fn provider_factory(provider: &str) -> Option<&'static dyn ProviderFactory> {
+    PROVIDER_FACTORIES.iter().copied().find(|factory| factory.name() == provider)
+}
+
+/// The registered provider types, sorted.
+pub fn provider_names() -> Vec<&'static str> {
+    let mut names: Vec<_> = PROVIDER_FACTORIES.iter().map(|factory| factory.name()).collect();
+    names.sort_unstable();
+    names
+}
+
+/// Registers a `ProviderFactory` value at link time:
+///
+/// ```ignore
+/// struct AcmeFactory;
+/// // impl ProviderFactory for AcmeFactory { ... }
+/// generic_mcp::register_provider!(AcmeFactory);
+/// ```
+#[macro_export]
+macro_rules! register_provider {
+    ($factory:expr) => {
+        const _: () = {
+            #[$crate::linkme::distributed_slice($crate::providers::PROVIDER_FACTORIES)]
+            #[linkme(crate = $crate::linkme)]
+            static FACTORY: &'static dyn $crate::providers::ProviderFactory = &$factory;
+        };
+    };
+}
@@ -1,21 +1,37 @@
 use async_trait::async_trait;
 use anyhow::{Result, anyhow};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
 
 use crate::domain::{
     Ticket, TicketFilter, CreateTicketRequest, UpdateTicketRequest,
     Label, CreateLabelRequest, Project, ProjectMilestone, Workspace,
-    Priority, State, StateType,
-    // Legacy Linear types for mapping
-    Issue, IssuePriority, IssueState, IssueStateType
+    CreateProjectRequest, UpdateProjectRequest, CreateMilestoneRequest, Cycle,
+    TicketRef, TicketEvent, WorkflowState,
+    Reaction, ReactionTarget, Initiative, Notification, RichText,
+    Comment, CreateCommentRequest, CreateRelationRequest, Attachment, CreateAttachmentRequest,
+    Webhook, CreateWebhookRequest, ProjectUpdate, Document,
 };
-use crate::domain::workspace::Team;
-use crate::domain::workspace::User;
-use crate::ports::{TicketService, ProviderConfig, LinearService};
-use crate::adapters::LinearClient;
+use crate::domain::{Team, User};
+use crate::ports::{Capabilities, TicketService, TicketResult, TicketServiceError, ProviderConfig, LinearService, Page, PageRequest, UnsupportedOperation};
+use crate::adapters::{LinearClient, OAuthClient, http_transport};
+use crate::adapters::linear_client::{FieldSelection, WEBHOOK_RESOURCE_TYPES};
+use crate::core::cache::TtlCache;
+use crate::providers::linear::mapping::{issue_to_ticket, priority_to_linear, state_type_to_linear};
+
+/// How long teams, workflow states, labels, and members are reused before
+/// being fetched again.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
 
 pub struct LinearAdapter {
     client: LinearClient,
+    // Name-to-id lookups behind most writes; these change rarely
+    teams: TtlCache<Vec<Team>>,
+    workflow_states: TtlCache<Vec<WorkflowState>>,
+    labels: TtlCache<Vec<Label>>,
+    team_members: TtlCache<Vec<User>>,
 }
 
 impl LinearAdapter {
@@ -24,129 +40,262 @@ impl LinearAdapter {
             return Err(anyhow!("Invalid provider type for LinearAdapter: {}", config.provider_type));
         }
         
-        let client = LinearClient::new(config.api_token)?;
-        Ok(Self { client })
-    }
-
-    fn map_issue_to_ticket(&self, issue: Issue) -> Ticket {
-        Ticket {
-            id: issue.id,
-            identifier: issue.identifier,
-            title: issue.title,
-            description: issue.description,
-            priority: self.map_issue_priority_to_priority(issue.priority),
-            state: self.map_issue_state_to_state(issue.state),
-            assignee_id: issue.assignee_id,
-            creator_id: issue.creator_id,
-            project_id: issue.project_id,
-            labels: issue.labels,
-            created_at: issue.created_at,
-            updated_at: issue.updated_at,
-            due_date: issue.due_date,
-            estimate: issue.estimate,
-            url: issue.url,
-            custom_fields: HashMap::new(),
+        let mut client = LinearClient::new(config.api_token)?
+            .with_transport(http_transport(&config.http)?);
+        if let Some(base_url) = config.base_url {
+            client = client.with_base_url(base_url);
         }
+        Ok(Self {
+            client,
+            teams: TtlCache::new(DEFAULT_CACHE_TTL),
+            workflow_states: TtlCache::new(DEFAULT_CACHE_TTL),
+            labels: TtlCache::new(DEFAULT_CACHE_TTL),
+            team_members: TtlCache::new(DEFAULT_CACHE_TTL),
+        })
     }
 
-    fn map_issue_priority_to_priority(&self, priority: IssuePriority) -> Priority {
-        match priority {
-            IssuePriority::NoPriority => Priority::None,
-            IssuePriority::Urgent => Priority::Highest,
-            IssuePriority::High => Priority::High,
-            IssuePriority::Medium => Priority::Medium,
-            IssuePriority::Low => Priority::Low,
-        }
+    /// How long team metadata is cached; zero disables caching.
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.teams = TtlCache::new(ttl);
+        self.workflow_states = TtlCache::new(ttl);
+        self.labels = TtlCache::new(ttl);
+        self.team_members = TtlCache::new(ttl);
+        self
     }
 
-    fn map_issue_state_to_state(&self, state: IssueState) -> State {
-        State {
-            id: state.id,
-            name: state.name,
-            type_: self.map_issue_state_type_to_state_type(state.type_),
-            position: state.position,
-        }
+    /// Authenticates with OAuth tokens instead of the configured API token.
+    pub fn with_oauth(mut self, oauth: Arc<OAuthClient>) -> Self {
+        self.client = self.client.with_oauth(oauth);
+        self
     }
 
-    fn map_issue_state_type_to_state_type(&self, state_type: IssueStateType) -> StateType {
-        match state_type {
-            IssueStateType::Unstarted => StateType::Open,
-            IssueStateType::Started => StateType::InProgress,
-            IssueStateType::Completed => StateType::Closed,
-            IssueStateType::Canceled => StateType::Cancelled,
-        }
+    /// Retries for rate limits and transient failures; 0 disables them.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.client = self.client.with_max_retries(max_retries);
+        self
     }
 
-    fn map_priority_to_issue_priority(&self, priority: Priority) -> IssuePriority {
-        match priority {
-            Priority::None => IssuePriority::NoPriority,
-            Priority::Lowest | Priority::Low => IssuePriority::Low,
-            Priority::Medium => IssuePriority::Medium,
-            Priority::High => IssuePriority::High,
-            Priority::Highest => IssuePriority::Urgent,
-            Priority::Custom(_) => IssuePriority::Medium,
+    /// How much of each ticket listings fetch; `Summary` skips descriptions
+    /// and relations.
+    pub fn with_list_fields(mut self, list_fields: FieldSelection) -> Self {
+        self.client = self.client.with_list_fields(list_fields);
+        self
+    }
+
+    /// Default page size and page limit for exhaustive listings.
+    pub fn with_paging(mut self, page_size: usize, max_pages: usize) -> Self {
+        self.client = self.client.with_page_size(page_size).with_max_pages(max_pages);
+        self
+    }
+
+    /// Makes sure a webhook delivers to `url`, creating one if none does.
+    /// Safe to call on every startup.
+    pub async fn ensure_webhook(&self, url: &str, secret: Option<String>) -> Result<Webhook> {
+        let webhooks = self.client.get_webhooks().await?;
+        if let Some(existing) = webhooks.into_iter().find(|webhook| webhook.url == url) {
+            if !existing.enabled {
+                warn!("Linear webhook {} for {} exists but is disabled", existing.id, url);
+            }
+            return Ok(existing);
         }
+
+        let request = CreateWebhookRequest {
+            url: url.to_string(),
+            label: Some("generic-mcp".to_string()),
+            resource_types: WEBHOOK_RESOURCE_TYPES.iter().map(|s| s.to_string()).collect(),
+            team_id: None,
+            secret,
+        };
+        let webhook = self.client.create_webhook(&request).await?;
+        info!("Registered Linear webhook {} for {}", webhook.id, url);
+        Ok(webhook)
+    }
+
+    pub async fn get_webhooks(&self) -> Result<Vec<Webhook>> {
+        self.client.get_webhooks().await
+    }
+
+    pub async fn delete_webhook(&self, webhook_id: &str) -> Result<()> {
+        self.client.delete_webhook(webhook_id).await
     }
 }
 
 #[async_trait]
 impl TicketService for LinearAdapter {
-    async fn get_assigned_tickets(&self, user_id: &str) -> Result<Vec<Ticket>> {
-        let issues = self.client.get_assigned_issues(user_id).await?;
-        Ok(issues.into_iter().map(|issue| self.map_issue_to_ticket(issue)).collect())
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            ticket_updates: true,
+            label_creation: true,
+            comments: true,
+            ticket_history: true,
+            relations: true,
+            sub_tickets: true,
+            attachments: true,
+            reactions: true,
+            notifications: true,
+            project_updates: true,
+            documents: true,
+            initiatives: true,
+            cycles: true,
+            // Linear has no worklogs, and the client has no user query yet
+            time_tracking: false,
+            user_lookup: false,
+        }
+    }
+
+    async fn get_assigned_tickets(&self, user_id: &str, page: &PageRequest) -> TicketResult<Page<Ticket>> {
+        let issues = self.client.get_assigned_issues(user_id, page).await?;
+        Ok(issues.map(issue_to_ticket))
+    }
+
+    async fn get_all_assigned_tickets(&self, user_id: &str) -> TicketResult<Vec<Ticket>> {
+        let issues = self.client.get_all_assigned_issues(user_id).await?;
+        Ok(issues.into_iter().map(issue_to_ticket).collect())
     }
 
-    async fn search_tickets(&self, filter: &TicketFilter) -> Result<Vec<Ticket>> {
+    async fn search_tickets(&self, filter: &TicketFilter, page: &PageRequest) -> TicketResult<Page<Ticket>> {
         // Map generic filter to Linear-specific filter
         let linear_filter = crate::domain::IssueFilter {
             assignee_id: filter.assignee_id.clone(),
             project_id: filter.project_id.clone(),
-            state_type: filter.state_type.as_ref().map(|st| match st {
-                StateType::Open => IssueStateType::Unstarted,
-                StateType::InProgress => IssueStateType::Started,
-                StateType::Closed => IssueStateType::Completed,
-                StateType::Cancelled => IssueStateType::Canceled,
-                StateType::Custom(_) => IssueStateType::Unstarted, // Default mapping
-            }),
-            priority: filter.priority.as_ref().map(|p| self.map_priority_to_issue_priority(p.clone())),
+            team_id: filter.team_id.clone(),
+            cycle_id: filter.cycle_id.clone(),
+            blocked: filter.blocked,
+            state_type: filter.state_type.as_ref().map(state_type_to_linear),
+            priority: filter.priority.as_ref().map(priority_to_linear),
             labels: filter.labels.clone(),
             search_query: filter.search_query.clone(),
+            created_after: filter.created_after,
+            created_before: filter.created_before,
+            updated_after: filter.updated_after,
+            updated_before: filter.updated_before,
+            due_before: filter.due_before,
+            include_archived: filter.include_archived,
+            triage: filter.triage,
+            snoozed: filter.snoozed,
         };
 
-        let issues = self.client.search_issues(&linear_filter).await?;
-        Ok(issues.into_iter().map(|issue| self.map_issue_to_ticket(issue)).collect())
+        let issues = self.client.search_issues(&linear_filter, page).await?;
+        Ok(issues.map(issue_to_ticket))
     }
 
-    async fn get_ticket(&self, ticket_id: &str) -> Result<Option<Ticket>> {
+    async fn get_ticket(&self, ticket_id: &str) -> TicketResult<Option<Ticket>> {
         let issue_opt = self.client.get_issue(ticket_id).await?;
-        Ok(issue_opt.map(|issue| self.map_issue_to_ticket(issue)))
+        Ok(issue_opt.map(issue_to_ticket))
+    }
+
+    async fn find_ticket(&self, reference: &TicketRef) -> TicketResult<Option<Ticket>> {
+        match reference {
+            TicketRef::Id(id) => self.get_ticket(id).await,
+            TicketRef::Key { prefix, number } => {
+                let issue_opt = self.client.get_issue_by_identifier(prefix, *number).await?;
+                Ok(issue_opt.map(issue_to_ticket))
+            }
+            TicketRef::Number(number) => {
+                // Numbers are only unique per team, so a bare number may be ambiguous
+                let mut issues = self.client.get_issues_by_number(*number).await?;
+                match issues.len() {
+                    0 => Ok(None),
+                    1 => Ok(issues.pop().map(issue_to_ticket)),
+                    _ => {
+                        let identifiers: Vec<String> = issues.into_iter().map(|i| i.identifier).collect();
+                        Err(TicketServiceError::Validation(format!(
+                            "#{} is ambiguous across teams ({}); use the full identifier",
+                            number, identifiers.join(", ")
+                        )))
+                    }
+                }
+            }
+        }
+    }
+
+    async fn get_tickets(&self, references: &[TicketRef]) -> TicketResult<Vec<Option<Ticket>>> {
+        // Ids and keys go out in one aliased query; bare numbers need a search
+        let batched: Vec<(usize, String)> = references
+            .iter()
+            .enumerate()
+            .filter(|(_, reference)| !matches!(reference, TicketRef::Number(_)))
+            .map(|(index, reference)| (index, reference.to_string()))
+            .collect();
+        let ids: Vec<String> = batched.iter().map(|(_, id)| id.clone()).collect();
+        let issues = self.client.get_issues(&ids).await?;
+
+        let mut tickets: Vec<Option<Ticket>> = vec![None; references.len()];
+        for ((index, _), issue) in batched.into_iter().zip(issues) {
+            tickets[index] = issue.map(issue_to_ticket);
+        }
+        for (index, reference) in references.iter().enumerate() {
+            if matches!(reference, TicketRef::Number(_)) {
+                tickets[index] = self.find_ticket(reference).await?;
+            }
+        }
+
+        Ok(tickets)
+    }
+
+    async fn add_relation(&self, request: &CreateRelationRequest) -> TicketResult<()> {
+        Ok(self.client.create_issue_relation(request).await?)
+    }
+
+    async fn set_parent(&self, ticket_id: &str, parent_id: Option<&str>) -> TicketResult<Ticket> {
+        let issue = self.client.set_issue_parent(ticket_id, parent_id).await?;
+        Ok(issue_to_ticket(issue))
+    }
+
+    async fn get_attachments(&self, ticket_id: &str) -> TicketResult<Vec<Attachment>> {
+        Ok(self.client.get_attachments(ticket_id).await?)
+    }
+
+    async fn add_attachment(&self, request: &CreateAttachmentRequest) -> TicketResult<Attachment> {
+        Ok(self.client.create_attachment(request).await?)
+    }
+
+    async fn add_reaction(&self, target: &ReactionTarget, emoji: &str) -> TicketResult<Reaction> {
+        Ok(self.client.add_reaction(target, emoji).await?)
+    }
+
+    async fn remove_reaction(&self, reaction_id: &str) -> TicketResult<()> {
+        Ok(self.client.remove_reaction(reaction_id).await?)
+    }
+
+    async fn get_comments(&self, ticket_id: &str, page: &PageRequest) -> TicketResult<Page<Comment>> {
+        Ok(self.client.get_comments(ticket_id, page).await?)
     }
 
-    async fn create_ticket(&self, request: &CreateTicketRequest) -> Result<Ticket> {
+    async fn add_comment(&self, request: &CreateCommentRequest) -> TicketResult<Comment> {
+        Ok(self.client.create_comment(request).await?)
+    }
+
+    async fn get_ticket_history(&self, ticket_id: &str) -> TicketResult<Vec<TicketEvent>> {
+        Ok(self.client.get_issue_history(ticket_id).await?)
+    }
+
+    async fn create_ticket(&self, request: &CreateTicketRequest) -> TicketResult<Ticket> {
         // Map generic request to Linear-specific request
         let linear_request = crate::domain::CreateIssueRequest {
             title: request.title.clone(),
-            description: request.description.clone(),
-            priority: request.priority.as_ref().map(|p| self.map_priority_to_issue_priority(p.clone())),
+            description: request.description.as_ref().map(RichText::to_markdown),
+            priority: request.priority.as_ref().map(priority_to_linear),
             assignee_id: request.assignee_id.clone(),
             team_id: request.team_id.clone(),
             project_id: request.project_id.clone(),
+            parent_id: request.parent_id.clone(),
             label_ids: request.label_ids.clone(),
             due_date: request.due_date,
             estimate: request.estimate,
         };
 
         let issue = self.client.create_issue(&linear_request).await?;
-        Ok(self.map_issue_to_ticket(issue))
+        Ok(issue_to_ticket(issue))
     }
 
-    async fn update_ticket(&self, request: &UpdateTicketRequest) -> Result<Ticket> {
+    async fn update_ticket(&self, request: &UpdateTicketRequest) -> TicketResult<Ticket> {
         // Map generic request to Linear-specific request
         let linear_request = crate::domain::UpdateIssueRequest {
             id: request.id.clone(),
             title: request.title.clone(),
-            description: request.description.clone(),
-            priority: request.priority.as_ref().map(|p| self.map_priority_to_issue_priority(p.clone())),
+            description: request.description.as_ref().map(RichText::to_markdown),
+            priority: request.priority.as_ref().map(priority_to_linear),
             assignee_id: request.assignee_id.clone(),
             state_id: request.state_id.clone(),
             label_ids: request.label_ids.clone(),
@@ -155,47 +304,118 @@ impl TicketService for LinearAdapter {
         };
 
         let issue = self.client.update_issue(&linear_request).await?;
-        Ok(self.map_issue_to_ticket(issue))
+        Ok(issue_to_ticket(issue))
+    }
+
+    async fn archive_ticket(&self, ticket_id: &str) -> TicketResult<()> {
+        Ok(self.client.archive_issue(ticket_id).await?)
+    }
+
+    async fn delete_ticket(&self, ticket_id: &str) -> TicketResult<()> {
+        Ok(self.client.delete_issue(ticket_id).await?)
     }
 
-    async fn get_current_user(&self) -> Result<User> {
-        self.client.get_current_user().await
+    async fn watch_ticket(&self, ticket_id: &str, user_id: &str) -> TicketResult<()> {
+        Ok(self.client.subscribe_to_issue(ticket_id, user_id).await?)
     }
 
-    async fn get_user(&self, user_id: &str) -> Result<Option<User>> {
+    async fn unwatch_ticket(&self, ticket_id: &str, user_id: &str) -> TicketResult<()> {
+        Ok(self.client.unsubscribe_from_issue(ticket_id, user_id).await?)
+    }
+
+    async fn get_current_user(&self) -> TicketResult<User> {
+        Ok(self.client.get_current_user().await?)
+    }
+
+    async fn get_user(&self, _user_id: &str) -> TicketResult<Option<User>> {
         // Linear client doesn't have get_user method yet
-        todo!("Implement get_user in LinearClient first")
+        Err(UnsupportedOperation::new("User lookup").into())
+    }
+
+    async fn get_teams(&self) -> TicketResult<Vec<Team>> {
+        Ok(self.teams.get_or_fetch("", || self.client.get_teams()).await?)
+    }
+
+    async fn get_team_members(&self, team_id: &str) -> TicketResult<Vec<User>> {
+        Ok(self.team_members.get_or_fetch(team_id, || self.client.get_team_members(team_id)).await?)
+    }
+
+    async fn get_notifications(&self, unread_only: bool, page: &PageRequest) -> TicketResult<Page<Notification>> {
+        Ok(self.client.get_notifications(unread_only, page).await?)
+    }
+
+    async fn get_workflow_states(&self, team_id: &str) -> TicketResult<Vec<WorkflowState>> {
+        Ok(self.workflow_states.get_or_fetch(team_id, || self.client.get_workflow_states(team_id)).await?)
+    }
+
+    async fn get_labels(&self) -> TicketResult<Vec<Label>> {
+        Ok(self.labels.get_or_fetch("", || self.client.get_labels()).await?)
+    }
+
+    async fn create_label(&self, request: &CreateLabelRequest) -> TicketResult<Label> {
+        let label = self.client.create_label(request).await?;
+        self.labels.invalidate("");
+        Ok(label)
+    }
+
+    async fn get_projects(&self, page: &PageRequest) -> TicketResult<Page<Project>> {
+        Ok(self.client.get_projects(page).await?)
+    }
+
+    async fn get_project(&self, project_id: &str) -> TicketResult<Option<Project>> {
+        Ok(self.client.get_project(project_id).await?)
+    }
+
+    async fn get_project_updates(&self, project_id: &str, page: &PageRequest) -> TicketResult<Page<ProjectUpdate>> {
+        Ok(self.client.get_project_updates(project_id, page).await?)
+    }
+
+    async fn get_documents(&self, project_id: Option<&str>, page: &PageRequest) -> TicketResult<Page<Document>> {
+        Ok(self.client.get_documents(project_id, page).await?)
+    }
+
+    async fn get_document(&self, document_id: &str) -> TicketResult<Option<Document>> {
+        Ok(self.client.get_document(document_id).await?)
+    }
+
+    async fn get_project_milestones(&self, project_id: &str) -> TicketResult<Vec<ProjectMilestone>> {
+        Ok(self.client.get_project_milestones(project_id).await?)
+    }
+
+    async fn create_project(&self, request: &CreateProjectRequest) -> TicketResult<Project> {
+        Ok(self.client.create_project(request).await?)
     }
 
-    async fn get_teams(&self) -> Result<Vec<Team>> {
-        self.client.get_teams().await
+    async fn update_project(&self, request: &UpdateProjectRequest) -> TicketResult<Project> {
+        Ok(self.client.update_project(request).await?)
     }
 
-    async fn get_team_members(&self, team_id: &str) -> Result<Vec<User>> {
-        self.client.get_team_members(team_id).await
+    async fn create_milestone(&self, request: &CreateMilestoneRequest) -> TicketResult<ProjectMilestone> {
+        Ok(self.client.create_milestone(request).await?)
     }
 
-    async fn get_labels(&self) -> Result<Vec<Label>> {
-        self.client.get_labels().await
+    async fn get_initiatives(&self) -> TicketResult<Vec<Initiative>> {
+        Ok(self.client.get_initiatives().await?)
     }
 
-    async fn create_label(&self, request: &CreateLabelRequest) -> Result<Label> {
-        self.client.create_label(request).await
+    async fn get_initiative_projects(&self, initiative_id: &str) -> TicketResult<Vec<Project>> {
+        Ok(self.client.get_initiative_projects(initiative_id).await?)
     }
 
-    async fn get_projects(&self) -> Result<Vec<Project>> {
-        self.client.get_projects().await
+    async fn get_cycles(&self, team_id: &str) -> TicketResult<Vec<Cycle>> {
+        Ok(self.client.get_cycles(team_id).await?)
     }
 
-    async fn get_project(&self, project_id: &str) -> Result<Option<Project>> {
-        self.client.get_project(project_id).await
+    async fn get_active_cycle(&self, team_id: &str) -> TicketResult<Option<Cycle>> {
+        Ok(self.client.get_active_cycle(team_id).await?)
     }
 
-    async fn get_project_milestones(&self, project_id: &str) -> Result<Vec<ProjectMilestone>> {
-        self.client.get_project_milestones(project_id).await
+    async fn assign_ticket_to_cycle(&self, ticket_id: &str, cycle_id: &str) -> TicketResult<Ticket> {
+        let issue = self.client.set_issue_cycle(ticket_id, cycle_id).await?;
+        Ok(issue_to_ticket(issue))
     }
 
-    async fn get_workspace(&self) -> Result<Workspace> {
+    async fn get_workspace(&self) -> TicketResult<Workspace> {
         // Linear doesn't have a direct workspace concept, so we'll construct one
         let user = self.get_current_user().await?;
         let teams = self.get_teams().await?;
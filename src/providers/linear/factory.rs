@@ -0,0 +1,55 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+
+use crate::adapters::linear_client::{DEFAULT_MAX_PAGES, DEFAULT_MAX_RETRIES, DEFAULT_PAGE_SIZE, FieldSelection};
+use crate::ports::TicketService;
+use crate::providers::{DEFAULT_CACHE_TTL, LinearAdapter, ProviderContext, ProviderFactory};
+
+/// Builds the Linear adapter from the `LINEAR_*` settings.
+pub struct LinearFactory;
+
+#[async_trait]
+impl ProviderFactory for LinearFactory {
+    fn name(&self) -> &'static str {
+        "linear"
+    }
+
+    async fn create(&self, context: &ProviderContext<'_>) -> Result<Arc<dyn TicketService + Send + Sync>> {
+        let page_size = context.parsed_setting("PAGE_SIZE", "a positive integer")?.unwrap_or(DEFAULT_PAGE_SIZE);
+        let max_pages = context.parsed_setting("MAX_PAGES", "a positive integer")?.unwrap_or(DEFAULT_MAX_PAGES);
+        let max_retries = context.parsed_setting("MAX_RETRIES", "a non-negative integer")?.unwrap_or(DEFAULT_MAX_RETRIES);
+        let list_fields = match context.setting("LIST_FIELDS") {
+            Some(value) => value.parse::<FieldSelection>()?,
+            None => FieldSelection::default(),
+        };
+        let cache_ttl = context.parsed_setting("CACHE_TTL_SECS", "a non-negative integer")?
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_CACHE_TTL);
+
+        info!("Creating Linear provider adapter...");
+        let mut adapter = LinearAdapter::new(context.config.clone())?
+            .with_paging(page_size, max_pages)
+            .with_list_fields(list_fields)
+            .with_cache_ttl(cache_ttl)
+            .with_max_retries(max_retries);
+        if let Some(oauth) = &context.oauth {
+            info!("Authenticating to Linear with OAuth");
+            adapter = adapter.with_oauth(oauth.clone());
+        }
+
+        // Self-provision the webhook real-time updates are delivered through
+        if context.flag("REGISTER_WEBHOOK") {
+            let url = context.setting("WEBHOOK_URL")
+                .ok_or_else(|| anyhow::anyhow!("LINEAR_WEBHOOK_URL is required when LINEAR_REGISTER_WEBHOOK is set"))?;
+            let webhook = adapter.ensure_webhook(&url, context.setting("WEBHOOK_SECRET")).await?;
+            info!("Linear webhook {} delivers to {}", webhook.id, webhook.url);
+        }
+
+        Ok(Arc::new(adapter))
+    }
+}
+
+crate::register_provider!(LinearFactory);
@@ -0,0 +1,106 @@
+//! Conversions between Linear's issue model and the generic ticket model.
+
+use std::collections::HashMap;
+
+use crate::domain::{Issue, IssuePriority, IssueState, IssueStateType, Priority, RichText, State, StateType, Ticket};
+
+pub fn issue_to_ticket(issue: Issue) -> Ticket {
+    Ticket {
+        id: issue.id,
+        identifier: issue.identifier,
+        title: issue.title,
+        description: issue.description.map(RichText::Markdown),
+        priority: priority_from_linear(issue.priority),
+        state: state_from_linear(issue.state),
+        assignee_id: issue.assignee_id,
+        creator_id: issue.creator_id,
+        project_id: issue.project_id,
+        cycle_id: issue.cycle_id,
+        labels: issue.labels,
+        watcher_ids: issue.subscriber_ids,
+        attachments: issue.attachments,
+        relations: issue.relations,
+        reactions: issue.reactions,
+        created_at: issue.created_at,
+        updated_at: issue.updated_at,
+        due_date: issue.due_date,
+        completed_at: issue.completed_at,
+        archived_at: issue.archived_at,
+        snoozed_until: issue.snoozed_until,
+        estimate: issue.estimate,
+        sla: issue.sla,
+        url: issue.url,
+        custom_fields: HashMap::new(),
+    }
+}
+
+pub fn priority_from_linear(priority: IssuePriority) -> Priority {
+    match priority {
+        IssuePriority::NoPriority => Priority::None,
+        IssuePriority::Urgent => Priority::Highest,
+        IssuePriority::High => Priority::High,
+        IssuePriority::Medium => Priority::Medium,
+        IssuePriority::Low => Priority::Low,
+    }
+}
+
+/// Linear has four levels and no priority, so `Lowest` shares `Low`.
+/// Custom priorities, e.g. from another tracker, are matched by name,
+/// including Jira's; names that match nothing become `Medium`.
+pub fn priority_to_linear(priority: &Priority) -> IssuePriority {
+    match priority {
+        Priority::None => IssuePriority::NoPriority,
+        Priority::Lowest | Priority::Low => IssuePriority::Low,
+        Priority::Medium => IssuePriority::Medium,
+        Priority::High => IssuePriority::High,
+        Priority::Highest => IssuePriority::Urgent,
+        Priority::Custom(name) => match normalized(name).as_str() {
+            "none" | "no_priority" => IssuePriority::NoPriority,
+            "urgent" | "highest" | "critical" | "blocker" => IssuePriority::Urgent,
+            "high" | "major" => IssuePriority::High,
+            "low" | "lowest" | "minor" | "trivial" => IssuePriority::Low,
+            _ => IssuePriority::Medium,
+        },
+    }
+}
+
+pub fn state_from_linear(state: IssueState) -> State {
+    State {
+        id: state.id,
+        name: state.name,
+        type_: state_type_from_linear(state.type_),
+        position: state.position,
+    }
+}
+
+pub fn state_type_from_linear(state_type: IssueStateType) -> StateType {
+    match state_type {
+        IssueStateType::Unstarted => StateType::Open,
+        IssueStateType::Started => StateType::InProgress,
+        IssueStateType::Completed => StateType::Closed,
+        IssueStateType::Canceled => StateType::Cancelled,
+    }
+}
+
+/// Custom state types are matched by name, e.g. Linear's own `triage` or
+/// a `done` from another tracker; names that match nothing are treated as
+/// not started, so a search never mistakes them for finished work.
+pub fn state_type_to_linear(state_type: &StateType) -> IssueStateType {
+    match state_type {
+        StateType::Open => IssueStateType::Unstarted,
+        StateType::InProgress => IssueStateType::Started,
+        StateType::Closed => IssueStateType::Completed,
+        StateType::Cancelled => IssueStateType::Canceled,
+        StateType::Custom(name) => match normalized(name).as_str() {
+            "started" | "in_progress" | "in_review" => IssueStateType::Started,
+            "completed" | "closed" | "done" | "resolved" => IssueStateType::Completed,
+            "canceled" | "cancelled" => IssueStateType::Canceled,
+            _ => IssueStateType::Unstarted,
+        },
+    }
+}
+
+/// Lowercase, trimmed, with spaces and dashes as underscores.
+fn normalized(name: &str) -> String {
+    name.trim().to_lowercase().replace([' ', '-'], "_")
+}
@@ -1,5 +1,9 @@
 pub mod client;
 pub mod adapter;
+pub mod factory;
+pub mod mapping;
 
 pub use client::*;
-pub use adapter::*;
\ No newline at end of file
+pub use adapter::*;
+pub use factory::*;
+pub use mapping::*;
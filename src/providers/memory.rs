@@ -0,0 +1,620 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+use crate::domain::{
+    Comment, CreateCommentRequest, CreateLabelRequest, CreateMilestoneRequest, CreateProjectRequest,
+    CreateTicketRequest, Cycle, Label, Priority, Project, ProjectMilestone, ProjectState, RelatedTicket,
+    State, StateType, Team, Ticket, TicketFilter, TicketRef, TicketRelations, UpdateProjectRequest,
+    UpdateTicketRequest, User, WorkflowState, Workspace,
+};
+use crate::ports::{
+    Capabilities, Page, PageRequest, TicketResult as Result, TicketService, TicketServiceError, UnsupportedOperation,
+};
+
+/// Tickets per page when a request does not say.
+const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// A ticket and the team it belongs to, which `Ticket` does not carry.
+struct StoredTicket {
+    team_id: String,
+    number: u32,
+    ticket: Ticket,
+}
+
+struct MemoryState {
+    current_user: String,
+    users: Vec<User>,
+    teams: Vec<Team>,
+    /// Every team shares one workflow.
+    states: Vec<WorkflowState>,
+    labels: Vec<Label>,
+    projects: Vec<Project>,
+    tickets: Vec<StoredTicket>,
+    comments: HashMap<String, Vec<Comment>>,
+    /// Last ticket number handed out per team.
+    numbers: HashMap<String, u32>,
+    /// Last id handed out, shared by every kind of entity.
+    next_id: u64,
+}
+
+impl MemoryState {
+    fn id(&mut self, kind: &str) -> String {
+        self.next_id += 1;
+        format!("{}-{}", kind, self.next_id)
+    }
+
+    fn ticket(&self, reference: &str) -> Option<&StoredTicket> {
+        self.tickets.iter().find(|stored| stored.ticket.id == reference || stored.ticket.identifier == reference)
+    }
+
+    fn ticket_mut(&mut self, reference: &str) -> Result<&mut StoredTicket> {
+        self.tickets
+            .iter_mut()
+            .find(|stored| stored.ticket.id == reference || stored.ticket.identifier == reference)
+            .ok_or_else(|| TicketServiceError::NotFound(format!("Ticket {} not found", reference)))
+    }
+
+    fn user(&self, id: &str) -> Result<&User> {
+        self.users.iter().find(|user| user.id == id)
+            .ok_or_else(|| TicketServiceError::Validation(format!("Unknown user {}", id)))
+    }
+
+    fn label_names(&self, ids: &[String]) -> Result<Vec<String>> {
+        ids.iter()
+            .map(|id| {
+                self.labels.iter().find(|label| &label.id == id).map(|label| label.name.clone())
+                    .ok_or_else(|| TicketServiceError::Validation(format!("Unknown label {}", id)))
+            })
+            .collect()
+    }
+
+    fn state(&self, id: &str) -> Result<State> {
+        let state = self.states.iter().find(|state| state.id == id)
+            .ok_or_else(|| TicketServiceError::Validation(format!("Unknown workflow state {}", id)))?;
+        Ok(State {
+            id: state.id.clone(),
+            name: state.name.clone(),
+            type_: state.type_.clone(),
+            position: state.position,
+        })
+    }
+}
+
+/// A provider that keeps a small workspace in memory: one team, the
+/// current user and a teammate, a five-state workflow, and whatever
+/// tickets, labels, projects, and comments are created. It needs no
+/// account or network, so tests and client developers can run the server
+/// against it; everything is lost when it is dropped.
+pub struct MemoryTicketService {
+    state: Mutex<MemoryState>,
+    capabilities: Capabilities,
+}
+
+impl Default for MemoryTicketService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MemoryTicketService {
+    pub fn new() -> Self {
+        let user = |id: &str, name: &str, email: &str| User {
+            id: id.to_string(),
+            name: name.to_string(),
+            email: email.to_string(),
+            avatar_url: None,
+            display_name: name.split(' ').next().unwrap_or(name).to_lowercase(),
+            active: true,
+            custom_fields: HashMap::new(),
+        };
+        let users = vec![
+            user("user-ann", "Ann Example", "ann@example.com"),
+            user("user-ben", "Ben Example", "ben@example.com"),
+        ];
+        let team = Team {
+            id: "team-demo".to_string(),
+            name: "Demo".to_string(),
+            key: "DEMO".to_string(),
+            description: None,
+            members: users.clone(),
+            custom_fields: HashMap::new(),
+        };
+        let states = [
+            ("Backlog", StateType::Open),
+            ("Todo", StateType::Open),
+            ("In Progress", StateType::InProgress),
+            ("Done", StateType::Closed),
+            ("Canceled", StateType::Cancelled),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(position, (name, type_))| WorkflowState {
+            id: format!("state-{}", name.to_lowercase().replace(' ', "-")),
+            name: name.to_string(),
+            type_,
+            position: position as f32,
+            color: None,
+        })
+        .collect();
+
+        Self {
+            capabilities: Capabilities {
+                ticket_updates: true,
+                label_creation: true,
+                comments: true,
+                sub_tickets: true,
+                user_lookup: true,
+                ..Capabilities::default()
+            },
+            state: Mutex::new(MemoryState {
+                current_user: "user-ann".to_string(),
+                users,
+                teams: vec![team],
+                states,
+                labels: Vec::new(),
+                projects: Vec::new(),
+                tickets: Vec::new(),
+                comments: HashMap::new(),
+                numbers: HashMap::new(),
+                next_id: 0,
+            }),
+        }
+    }
+
+    /// Reports `capabilities` instead, standing in for a provider with fewer
+    /// features; ticket updates and label creation are refused when off.
+    pub fn with_capabilities(mut self, capabilities: Capabilities) -> Self {
+        self.capabilities = capabilities;
+        self
+    }
+
+    fn state(&self) -> Result<MutexGuard<'_, MemoryState>> {
+        self.state.lock().map_err(|_| TicketServiceError::Other(anyhow::anyhow!("In-memory workspace lock poisoned")))
+    }
+}
+
+/// The page of `items` a request asks for; the cursor is an offset.
+fn page<T>(items: Vec<T>, request: &PageRequest) -> Result<Page<T>> {
+    let offset = match &request.cursor {
+        Some(cursor) => cursor.parse::<usize>()
+            .map_err(|_| TicketServiceError::Validation(format!("Invalid cursor {}", cursor)))?,
+        None => 0,
+    };
+    let limit = request.limit.unwrap_or(DEFAULT_PAGE_SIZE).max(1);
+    let total = items.len();
+    let items: Vec<T> = items.into_iter().skip(offset).take(limit).collect();
+    let next_cursor = (offset + items.len() < total).then(|| (offset + items.len()).to_string());
+    Ok(Page { items, next_cursor, total: Some(total) })
+}
+
+fn matches(stored: &StoredTicket, filter: &TicketFilter) -> bool {
+    let ticket = &stored.ticket;
+    let contains = |text: &str, query: &str| text.to_lowercase().contains(&query.to_lowercase());
+    (filter.include_archived || ticket.archived_at.is_none())
+        && filter.assignee_id.as_ref().is_none_or(|id| ticket.assignee_id.as_ref() == Some(id))
+        && filter.project_id.as_ref().is_none_or(|id| ticket.project_id.as_ref() == Some(id))
+        && filter.team_id.as_ref().is_none_or(|id| &stored.team_id == id)
+        && filter.cycle_id.as_ref().is_none_or(|id| ticket.cycle_id.as_ref() == Some(id))
+        && filter.blocked.is_none_or(|blocked| ticket.relations.is_blocked() == blocked)
+        && filter.state_type.as_ref().is_none_or(|type_| &ticket.state.type_ == type_)
+        && filter.priority.as_ref().is_none_or(|priority| &ticket.priority == priority)
+        && filter.labels.as_ref().is_none_or(|labels| {
+            labels.iter().all(|name| ticket.labels.iter().any(|label| label.eq_ignore_ascii_case(name)))
+        })
+        && filter.search_query.as_ref().is_none_or(|query| {
+            contains(&ticket.title, query)
+                || contains(&ticket.identifier, query)
+                || ticket.description.as_ref().is_some_and(|description| contains(&description.to_plain_text(), query))
+        })
+        && filter.created_after.is_none_or(|after| ticket.created_at >= after)
+        && filter.created_before.is_none_or(|before| ticket.created_at < before)
+        && filter.updated_after.is_none_or(|after| ticket.updated_at >= after)
+        && filter.updated_before.is_none_or(|before| ticket.updated_at < before)
+        && filter.due_before.is_none_or(|before| ticket.due_date.is_some_and(|due| due < before))
+        // Nothing here waits in triage or is snoozed
+        && filter.triage != Some(true)
+        && filter.snoozed != Some(true)
+}
+
+fn related(ticket: &Ticket) -> RelatedTicket {
+    RelatedTicket {
+        id: ticket.id.clone(),
+        identifier: ticket.identifier.clone(),
+    }
+}
+
+#[async_trait]
+impl TicketService for MemoryTicketService {
+    fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    async fn get_assigned_tickets(&self, user_id: &str, request: &PageRequest) -> Result<Page<Ticket>> {
+        let filter = TicketFilter { assignee_id: Some(user_id.to_string()), ..Default::default() };
+        self.search_tickets(&filter, request).await
+    }
+
+    async fn search_tickets(&self, filter: &TicketFilter, request: &PageRequest) -> Result<Page<Ticket>> {
+        let state = self.state()?;
+        let tickets = state.tickets
+            .iter()
+            .filter(|stored| matches(stored, filter))
+            .map(|stored| stored.ticket.clone())
+            .collect();
+        page(tickets, request)
+    }
+
+    async fn get_ticket(&self, ticket_id: &str) -> Result<Option<Ticket>> {
+        Ok(self.state()?.ticket(ticket_id).map(|stored| stored.ticket.clone()))
+    }
+
+    async fn find_ticket(&self, reference: &TicketRef) -> Result<Option<Ticket>> {
+        let state = self.state()?;
+        let mut found = state.tickets.iter().filter(|stored| match reference {
+            TicketRef::Id(id) => &stored.ticket.id == id || &stored.ticket.identifier == id,
+            TicketRef::Key { prefix, number } => stored.ticket.identifier.eq_ignore_ascii_case(&format!("{}-{}", prefix, number)),
+            TicketRef::Number(number) => stored.number == *number,
+        });
+        Ok(found.next().map(|stored| stored.ticket.clone()))
+    }
+
+    async fn create_ticket(&self, request: &CreateTicketRequest) -> Result<Ticket> {
+        if request.title.trim().is_empty() {
+            return Err(TicketServiceError::Validation("A ticket needs a title".to_string()));
+        }
+        let mut state = self.state()?;
+        let team = match &request.team_id {
+            Some(team_id) => state.teams.iter().find(|team| &team.id == team_id || team.key.eq_ignore_ascii_case(team_id))
+                .ok_or_else(|| TicketServiceError::Validation(format!("Unknown team {}", team_id)))?,
+            None => &state.teams[0],
+        };
+        let (team_id, team_key) = (team.id.clone(), team.key.clone());
+        if let Some(assignee_id) = &request.assignee_id {
+            state.user(assignee_id)?;
+        }
+        if let Some(project_id) = &request.project_id {
+            if !state.projects.iter().any(|project| &project.id == project_id) {
+                return Err(TicketServiceError::Validation(format!("Unknown project {}", project_id)));
+            }
+        }
+        let labels = state.label_names(request.label_ids.as_deref().unwrap_or_default())?;
+        let parent = match &request.parent_id {
+            Some(parent_id) => Some(related(&state.ticket(parent_id)
+                .ok_or_else(|| TicketServiceError::Validation(format!("Unknown parent ticket {}", parent_id)))?
+                .ticket)),
+            None => None,
+        };
+        let initial = state.states[0].id.clone();
+
+        let number = {
+            let number = state.numbers.entry(team_id.clone()).or_insert(0);
+            *number += 1;
+            *number
+        };
+        let identifier = format!("{}-{}", team_key, number);
+        let now = Utc::now();
+        let ticket = Ticket {
+            id: state.id("ticket"),
+            identifier: identifier.clone(),
+            title: request.title.clone(),
+            description: request.description.clone(),
+            priority: request.priority.clone().unwrap_or(Priority::None),
+            state: state.state(&initial)?,
+            assignee_id: request.assignee_id.clone(),
+            creator_id: state.current_user.clone(),
+            project_id: request.project_id.clone(),
+            cycle_id: None,
+            labels,
+            watcher_ids: vec![state.current_user.clone()],
+            attachments: Vec::new(),
+            relations: TicketRelations { parent: parent.clone(), ..Default::default() },
+            reactions: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            due_date: request.due_date,
+            completed_at: None,
+            archived_at: None,
+            snoozed_until: None,
+            estimate: request.estimate,
+            sla: None,
+            url: format!("memory://tickets/{}", identifier),
+            custom_fields: request.custom_fields.clone().unwrap_or_default(),
+        };
+        if let Some(parent) = parent {
+            state.ticket_mut(&parent.id)?.ticket.relations.children.push(related(&ticket));
+        }
+        state.tickets.push(StoredTicket { team_id, number, ticket: ticket.clone() });
+        Ok(ticket)
+    }
+
+    async fn update_ticket(&self, request: &UpdateTicketRequest) -> Result<Ticket> {
+        if !self.capabilities.ticket_updates {
+            return Err(UnsupportedOperation::new("Ticket updates").into());
+        }
+        let mut state = self.state()?;
+        if let Some(assignee_id) = &request.assignee_id {
+            state.user(assignee_id)?;
+        }
+        let labels = request.label_ids.as_deref().map(|ids| state.label_names(ids)).transpose()?;
+        let new_state = request.state_id.as_deref().map(|id| state.state(id)).transpose()?;
+
+        let ticket = &mut state.ticket_mut(&request.id)?.ticket;
+        if let Some(title) = &request.title {
+            ticket.title = title.clone();
+        }
+        if let Some(description) = &request.description {
+            ticket.description = Some(description.clone());
+        }
+        if let Some(priority) = &request.priority {
+            ticket.priority = priority.clone();
+        }
+        if let Some(assignee_id) = &request.assignee_id {
+            ticket.assignee_id = Some(assignee_id.clone());
+        }
+        if let Some(labels) = labels {
+            ticket.labels = labels;
+        }
+        if let Some(due_date) = request.due_date {
+            ticket.due_date = Some(due_date);
+        }
+        if let Some(estimate) = request.estimate {
+            ticket.estimate = Some(estimate);
+        }
+        if let Some(custom_fields) = &request.custom_fields {
+            ticket.custom_fields.extend(custom_fields.clone());
+        }
+        let now = Utc::now();
+        if let Some(new_state) = new_state {
+            ticket.completed_at = match new_state.type_ {
+                StateType::Closed | StateType::Cancelled => ticket.completed_at.or(Some(now)),
+                _ => None,
+            };
+            ticket.state = new_state;
+        }
+        ticket.updated_at = now;
+        Ok(ticket.clone())
+    }
+
+    async fn archive_ticket(&self, ticket_id: &str) -> Result<()> {
+        let mut state = self.state()?;
+        let ticket = &mut state.ticket_mut(ticket_id)?.ticket;
+        ticket.archived_at = Some(Utc::now());
+        Ok(())
+    }
+
+    async fn delete_ticket(&self, ticket_id: &str) -> Result<()> {
+        let mut state = self.state()?;
+        let id = state.ticket_mut(ticket_id)?.ticket.id.clone();
+        state.tickets.retain(|stored| stored.ticket.id != id);
+        state.comments.remove(&id);
+        for stored in &mut state.tickets {
+            let relations = &mut stored.ticket.relations;
+            if relations.parent.as_ref().is_some_and(|parent| parent.id == id) {
+                relations.parent = None;
+            }
+            relations.children.retain(|child| child.id != id);
+        }
+        Ok(())
+    }
+
+    async fn watch_ticket(&self, ticket_id: &str, user_id: &str) -> Result<()> {
+        let mut state = self.state()?;
+        state.user(user_id)?;
+        let watchers = &mut state.ticket_mut(ticket_id)?.ticket.watcher_ids;
+        if !watchers.iter().any(|id| id == user_id) {
+            watchers.push(user_id.to_string());
+        }
+        Ok(())
+    }
+
+    async fn unwatch_ticket(&self, ticket_id: &str, user_id: &str) -> Result<()> {
+        let mut state = self.state()?;
+        state.ticket_mut(ticket_id)?.ticket.watcher_ids.retain(|id| id != user_id);
+        Ok(())
+    }
+
+    async fn get_comments(&self, ticket_id: &str, request: &PageRequest) -> Result<Page<Comment>> {
+        let state = self.state()?;
+        let id = &state.ticket(ticket_id)
+            .ok_or_else(|| TicketServiceError::NotFound(format!("Ticket {} not found", ticket_id)))?
+            .ticket
+            .id;
+        page(state.comments.get(id).cloned().unwrap_or_default(), request)
+    }
+
+    async fn add_comment(&self, request: &CreateCommentRequest) -> Result<Comment> {
+        if request.body.trim().is_empty() {
+            return Err(TicketServiceError::Validation("A comment needs a body".to_string()));
+        }
+        let mut state = self.state()?;
+        let ticket_id = state.ticket_mut(&request.ticket_id)?.ticket.id.clone();
+        let now = Utc::now();
+        let comment = Comment {
+            id: state.id("comment"),
+            body: request.body.clone(),
+            author_id: Some(state.current_user.clone()),
+            created_at: now,
+            updated_at: now,
+            parent_comment_id: request.parent_comment_id.clone(),
+            reactions: Vec::new(),
+        };
+        state.comments.entry(ticket_id).or_default().push(comment.clone());
+        Ok(comment)
+    }
+
+    async fn set_parent(&self, ticket_id: &str, parent_id: Option<&str>) -> Result<Ticket> {
+        let mut state = self.state()?;
+        let child = related(&state.ticket_mut(ticket_id)?.ticket);
+        let parent = match parent_id {
+            Some(parent_id) if parent_id == child.id || parent_id == child.identifier => {
+                return Err(TicketServiceError::Validation("A ticket cannot be its own parent".to_string()));
+            }
+            Some(parent_id) => Some(related(&state.ticket_mut(parent_id)?.ticket)),
+            None => None,
+        };
+        for stored in &mut state.tickets {
+            stored.ticket.relations.children.retain(|existing| existing.id != child.id);
+            if parent.as_ref().is_some_and(|parent| parent.id == stored.ticket.id) {
+                stored.ticket.relations.children.push(child.clone());
+            }
+        }
+        let ticket = &mut state.ticket_mut(ticket_id)?.ticket;
+        ticket.relations.parent = parent;
+        ticket.updated_at = Utc::now();
+        Ok(ticket.clone())
+    }
+
+    async fn get_current_user(&self) -> Result<User> {
+        let state = self.state()?;
+        Ok(state.user(&state.current_user)?.clone())
+    }
+
+    async fn get_user(&self, user_id: &str) -> Result<Option<User>> {
+        Ok(self.state()?.users.iter().find(|user| user.id == user_id).cloned())
+    }
+
+    async fn get_teams(&self) -> Result<Vec<Team>> {
+        Ok(self.state()?.teams.clone())
+    }
+
+    async fn get_team_members(&self, team_id: &str) -> Result<Vec<User>> {
+        let state = self.state()?;
+        let team = state.teams.iter().find(|team| team.id == team_id || team.key.eq_ignore_ascii_case(team_id))
+            .ok_or_else(|| TicketServiceError::NotFound(format!("Team {} not found", team_id)))?;
+        Ok(team.members.clone())
+    }
+
+    async fn get_workflow_states(&self, team_id: &str) -> Result<Vec<WorkflowState>> {
+        let state = self.state()?;
+        if !state.teams.iter().any(|team| team.id == team_id || team.key.eq_ignore_ascii_case(team_id)) {
+            return Err(TicketServiceError::NotFound(format!("Team {} not found", team_id)));
+        }
+        Ok(state.states.clone())
+    }
+
+    async fn get_labels(&self) -> Result<Vec<Label>> {
+        Ok(self.state()?.labels.clone())
+    }
+
+    async fn create_label(&self, request: &CreateLabelRequest) -> Result<Label> {
+        if !self.capabilities.label_creation {
+            return Err(UnsupportedOperation::new("Label creation").into());
+        }
+        let mut state = self.state()?;
+        if state.labels.iter().any(|label| label.name.eq_ignore_ascii_case(&request.name)) {
+            return Err(TicketServiceError::Validation(format!("Label {} already exists", request.name)));
+        }
+        let label = Label {
+            id: state.id("label"),
+            name: request.name.clone(),
+            color: request.color.clone(),
+            description: request.description.clone(),
+        };
+        state.labels.push(label.clone());
+        Ok(label)
+    }
+
+    async fn get_projects(&self, request: &PageRequest) -> Result<Page<Project>> {
+        let projects = self.state()?.projects
+            .iter()
+            .map(|project| Project { milestones: Vec::new(), ..project.clone() })
+            .collect();
+        page(projects, request)
+    }
+
+    async fn get_project(&self, project_id: &str) -> Result<Option<Project>> {
+        Ok(self.state()?.projects.iter().find(|project| project.id == project_id).cloned())
+    }
+
+    async fn get_project_milestones(&self, project_id: &str) -> Result<Vec<ProjectMilestone>> {
+        let state = self.state()?;
+        let project = state.projects.iter().find(|project| project.id == project_id)
+            .ok_or_else(|| TicketServiceError::NotFound(format!("Project {} not found", project_id)))?;
+        Ok(project.milestones.clone())
+    }
+
+    async fn create_project(&self, request: &CreateProjectRequest) -> Result<Project> {
+        let mut state = self.state()?;
+        let now = Utc::now();
+        let project = Project {
+            id: state.id("project"),
+            name: request.name.clone(),
+            description: request.description.clone(),
+            key: request.name.split_whitespace().filter_map(|word| word.chars().next()).collect::<String>().to_uppercase(),
+            state: ProjectState::Planned,
+            target_date: request.target_date,
+            lead_id: request.lead_id.clone(),
+            created_at: now,
+            updated_at: now,
+            progress: 0.0,
+            milestones: Vec::new(),
+        };
+        state.projects.push(project.clone());
+        Ok(project)
+    }
+
+    async fn update_project(&self, request: &UpdateProjectRequest) -> Result<Project> {
+        let mut state = self.state()?;
+        let project = state.projects.iter_mut().find(|project| project.id == request.id)
+            .ok_or_else(|| TicketServiceError::NotFound(format!("Project {} not found", request.id)))?;
+        if let Some(name) = &request.name {
+            project.name = name.clone();
+        }
+        if let Some(description) = &request.description {
+            project.description = Some(description.clone());
+        }
+        if let Some(project_state) = &request.state {
+            project.state = project_state.clone();
+        }
+        if let Some(lead_id) = &request.lead_id {
+            project.lead_id = Some(lead_id.clone());
+        }
+        if let Some(target_date) = request.target_date {
+            project.target_date = Some(target_date);
+        }
+        project.updated_at = Utc::now();
+        Ok(project.clone())
+    }
+
+    async fn create_milestone(&self, request: &CreateMilestoneRequest) -> Result<ProjectMilestone> {
+        let mut state = self.state()?;
+        let id = state.id("milestone");
+        let project = state.projects.iter_mut().find(|project| project.id == request.project_id)
+            .ok_or_else(|| TicketServiceError::NotFound(format!("Project {} not found", request.project_id)))?;
+        let milestone = ProjectMilestone {
+            id,
+            name: request.name.clone(),
+            description: request.description.clone(),
+            target_date: request.target_date,
+            project_id: project.id.clone(),
+        };
+        project.milestones.push(milestone.clone());
+        Ok(milestone)
+    }
+
+    async fn get_cycles(&self, _team_id: &str) -> Result<Vec<Cycle>> {
+        Ok(Vec::new())
+    }
+
+    async fn get_active_cycle(&self, _team_id: &str) -> Result<Option<Cycle>> {
+        Ok(None)
+    }
+
+    async fn assign_ticket_to_cycle(&self, _ticket_id: &str, cycle_id: &str) -> Result<Ticket> {
+        Err(TicketServiceError::NotFound(format!("Cycle {} not found", cycle_id)))
+    }
+
+    async fn get_workspace(&self) -> Result<Workspace> {
+        Ok(Workspace {
+            id: "workspace-memory".to_string(),
+            name: "In-memory workspace".to_string(),
+            description: None,
+            url: "memory://".to_string(),
+            teams: self.state()?.teams.clone(),
+            custom_fields: HashMap::new(),
+        })
+    }
+}
+
@@ -1,5 +1,15 @@
+pub mod contract;
+pub mod factory;
 #[cfg(feature = "linear")]
 pub mod linear;
+pub mod memory;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
+pub use contract::*;
+pub use factory::*;
 #[cfg(feature = "linear")]
-pub use linear::*;
\ No newline at end of file
+pub use linear::*;
+pub use memory::*;
+#[cfg(feature = "wasm")]
+pub use wasm::*;
@@ -0,0 +1,357 @@
+//! Providers compiled to WebAssembly components, so a proprietary tracker
+//! can be added without forking the crate. Plugins implement the
+//! `ticket-provider` world in `wit/ticket-provider.wit` and run sandboxed:
+//! their only way out is the host's `http-send`, which goes through the
+//! server's own HTTP transport.
+
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::runtime::Handle;
+use tracing::{debug, error, info, warn};
+use wasmtime::component::{Component, HasSelf, Linker};
+use wasmtime::{Engine, Store};
+
+use crate::domain::{
+    Ticket, TicketFilter, CreateTicketRequest, UpdateTicketRequest,
+    Label, CreateLabelRequest, Project, ProjectMilestone, Workspace,
+    CreateProjectRequest, UpdateProjectRequest, CreateMilestoneRequest, Cycle,
+    TimeEntry, LogTimeRequest, Comment, CreateCommentRequest, TicketEvent,
+    WorkflowState, Reaction, ReactionTarget, Initiative, Notification,
+    CreateRelationRequest, Attachment, CreateAttachmentRequest, ProjectUpdate, Document,
+};
+use crate::domain::{Team, User};
+use crate::ports::{
+    Capabilities, HttpTransport, Page, PageRequest, TicketResult, TicketService, TicketServiceError,
+    UnsupportedOperation,
+};
+
+mod bindings {
+    wasmtime::component::bindgen!({
+        path: "wit/ticket-provider.wit",
+        world: "ticket-provider",
+    });
+}
+
+use bindings::TicketProvider;
+use bindings::exports::generic_mcp::provider::provider::ProviderError;
+use bindings::generic_mcp::provider::host::{self, HttpRequest, HttpResponse, LogLevel};
+
+/// What plugin calls into the host can reach.
+struct HostState {
+    plugin: String,
+    transport: Arc<dyn HttpTransport>,
+    runtime: Handle,
+}
+
+impl host::Host for HostState {
+    fn http_send(&mut self, request: HttpRequest) -> std::result::Result<HttpResponse, String> {
+        let mut builder = hyper::Request::builder().method(request.method.as_str()).uri(request.url.as_str());
+        for (name, value) in &request.headers {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        let request = builder.body(Bytes::from(request.body)).map_err(|e| e.to_string())?;
+        // Plugin calls run on a blocking thread, so waiting here is fine
+        let response = self.runtime.block_on(self.transport.execute(request)).map_err(|e| format!("{:#}", e))?;
+        Ok(HttpResponse {
+            status: response.status().as_u16(),
+            headers: response.headers().iter()
+                .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+                .collect(),
+            body: response.into_body().to_vec(),
+        })
+    }
+
+    fn log(&mut self, level: LogLevel, message: String) {
+        match level {
+            LogLevel::Error => error!("[{}] {}", self.plugin, message),
+            LogLevel::Warn => warn!("[{}] {}", self.plugin, message),
+            LogLevel::Info => info!("[{}] {}", self.plugin, message),
+            LogLevel::Debug => debug!("[{}] {}", self.plugin, message),
+        }
+    }
+}
+
+struct Instance {
+    store: Store<HostState>,
+    bindings: TicketProvider,
+}
+
+/// A `TicketService` backed by a WebAssembly plugin. Calls into one plugin
+/// run one at a time, since an instance is single-threaded.
+pub struct WasmProvider {
+    name: String,
+    capabilities: Capabilities,
+    instance: Arc<Mutex<Instance>>,
+}
+
+impl WasmProvider {
+    /// Compiles the component at `path`, then initializes it with `config`,
+    /// a JSON object of the plugin's settings.
+    pub async fn load(name: &str, path: impl AsRef<Path>, config: Value, transport: Arc<dyn HttpTransport>) -> Result<Self> {
+        let path = path.as_ref();
+        let engine = Engine::default();
+        let component = Component::from_file(&engine, path)
+            .map_err(|e| anyhow!("Failed to load plugin {}: {:#}", path.display(), e))?;
+        let mut linker = Linker::new(&engine);
+        TicketProvider::add_to_linker::<_, HasSelf<_>>(&mut linker, |state| state)?;
+        let state = HostState { plugin: name.to_string(), transport, runtime: Handle::current() };
+        let mut store = Store::new(&engine, state);
+        let bindings = TicketProvider::instantiate(&mut store, &component, &linker)
+            .map_err(|e| anyhow!("Plugin {} does not implement the ticket-provider world: {:#}", path.display(), e))?;
+
+        let name = name.to_string();
+        let instance = Arc::new(Mutex::new(Instance { store, bindings }));
+        let capabilities = {
+            let plugin = name.clone();
+            let instance = instance.clone();
+            tokio::task::spawn_blocking(move || -> Result<Capabilities> {
+                let mut instance = instance.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+                let Instance { store, bindings } = &mut *instance;
+                let provider = bindings.generic_mcp_provider_provider();
+                provider.call_init(&mut *store, &config.to_string())?
+                    .map_err(|e| anyhow!("Plugin {} failed to start: {}", plugin, provider_error("init", e)))?;
+                let c = provider.call_capabilities(&mut *store)?;
+                Ok(Capabilities {
+                    // The plugin interface has no flags for these; a plugin
+                    // without them reports each call as unsupported
+                    ticket_updates: true,
+                    label_creation: true,
+                    comments: c.comments,
+                    ticket_history: c.ticket_history,
+                    relations: c.relations,
+                    sub_tickets: c.sub_tickets,
+                    attachments: c.attachments,
+                    reactions: c.reactions,
+                    notifications: c.notifications,
+                    project_updates: c.project_updates,
+                    documents: c.documents,
+                    initiatives: c.initiatives,
+                    cycles: c.cycles,
+                    time_tracking: c.time_tracking,
+                    user_lookup: c.user_lookup,
+                })
+            }).await??
+        };
+        info!("Loaded provider plugin {} from {}", name, path.display());
+        Ok(Self { name, capabilities, instance })
+    }
+
+    async fn call<T: DeserializeOwned + Send + 'static>(&self, method: &'static str, arguments: Value) -> TicketResult<T> {
+        let instance = self.instance.clone();
+        let plugin = self.name.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut instance = instance.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let Instance { store, bindings } = &mut *instance;
+            let result = bindings.generic_mcp_provider_provider()
+                .call_call(&mut *store, method, &arguments.to_string())
+                // A trap is a bug in the plugin, not a provider failure
+                .map_err(|e| TicketServiceError::Other(anyhow!("Plugin {} crashed in {}: {:#}", plugin, method, e)))?
+                .map_err(|e| provider_error(method, e))?;
+            serde_json::from_str(&result).map_err(|e| {
+                TicketServiceError::Other(anyhow!("Plugin {} returned an invalid {} result: {}", plugin, method, e))
+            })
+        })
+        .await
+        .map_err(|e| TicketServiceError::Other(e.into()))?
+    }
+}
+
+fn provider_error(method: &'static str, error: ProviderError) -> TicketServiceError {
+    match error {
+        ProviderError::NotFound(message) => TicketServiceError::NotFound(message),
+        ProviderError::Unauthorized(message) => TicketServiceError::Unauthorized(message),
+        ProviderError::RateLimited(limit) => TicketServiceError::RateLimited {
+            message: limit.message,
+            retry_after: limit.retry_after_secs.map(Duration::from_secs),
+        },
+        ProviderError::Validation(message) => TicketServiceError::Validation(message),
+        ProviderError::Unavailable(message) => TicketServiceError::ProviderUnavailable(message),
+        ProviderError::Unsupported => UnsupportedOperation::new(method).into(),
+        ProviderError::Other(message) => TicketServiceError::Other(anyhow!(message)),
+    }
+}
+
+#[async_trait]
+impl TicketService for WasmProvider {
+    fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
+    async fn get_assigned_tickets(&self, user_id: &str, page: &PageRequest) -> TicketResult<Page<Ticket>> {
+        self.call("get_assigned_tickets", json!({ "user_id": user_id, "page": page })).await
+    }
+
+    async fn search_tickets(&self, filter: &TicketFilter, page: &PageRequest) -> TicketResult<Page<Ticket>> {
+        self.call("search_tickets", json!({ "filter": filter, "page": page })).await
+    }
+
+    async fn get_ticket(&self, ticket_id: &str) -> TicketResult<Option<Ticket>> {
+        self.call("get_ticket", json!({ "ticket_id": ticket_id })).await
+    }
+
+    async fn create_ticket(&self, request: &CreateTicketRequest) -> TicketResult<Ticket> {
+        self.call("create_ticket", json!({ "request": request })).await
+    }
+
+    async fn update_ticket(&self, request: &UpdateTicketRequest) -> TicketResult<Ticket> {
+        self.call("update_ticket", json!({ "request": request })).await
+    }
+
+    async fn archive_ticket(&self, ticket_id: &str) -> TicketResult<()> {
+        self.call("archive_ticket", json!({ "ticket_id": ticket_id })).await
+    }
+
+    async fn delete_ticket(&self, ticket_id: &str) -> TicketResult<()> {
+        self.call("delete_ticket", json!({ "ticket_id": ticket_id })).await
+    }
+
+    async fn watch_ticket(&self, ticket_id: &str, user_id: &str) -> TicketResult<()> {
+        self.call("watch_ticket", json!({ "ticket_id": ticket_id, "user_id": user_id })).await
+    }
+
+    async fn unwatch_ticket(&self, ticket_id: &str, user_id: &str) -> TicketResult<()> {
+        self.call("unwatch_ticket", json!({ "ticket_id": ticket_id, "user_id": user_id })).await
+    }
+
+    async fn get_comments(&self, ticket_id: &str, page: &PageRequest) -> TicketResult<Page<Comment>> {
+        self.call("get_comments", json!({ "ticket_id": ticket_id, "page": page })).await
+    }
+
+    async fn add_comment(&self, request: &CreateCommentRequest) -> TicketResult<Comment> {
+        self.call("add_comment", json!({ "request": request })).await
+    }
+
+    async fn get_ticket_history(&self, ticket_id: &str) -> TicketResult<Vec<TicketEvent>> {
+        self.call("get_ticket_history", json!({ "ticket_id": ticket_id })).await
+    }
+
+    async fn add_relation(&self, request: &CreateRelationRequest) -> TicketResult<()> {
+        self.call("add_relation", json!({ "request": request })).await
+    }
+
+    async fn set_parent(&self, ticket_id: &str, parent_id: Option<&str>) -> TicketResult<Ticket> {
+        self.call("set_parent", json!({ "ticket_id": ticket_id, "parent_id": parent_id })).await
+    }
+
+    async fn get_attachments(&self, ticket_id: &str) -> TicketResult<Vec<Attachment>> {
+        self.call("get_attachments", json!({ "ticket_id": ticket_id })).await
+    }
+
+    async fn add_attachment(&self, request: &CreateAttachmentRequest) -> TicketResult<Attachment> {
+        self.call("add_attachment", json!({ "request": request })).await
+    }
+
+    async fn add_reaction(&self, target: &ReactionTarget, emoji: &str) -> TicketResult<Reaction> {
+        self.call("add_reaction", json!({ "target": target, "emoji": emoji })).await
+    }
+
+    async fn remove_reaction(&self, reaction_id: &str) -> TicketResult<()> {
+        self.call("remove_reaction", json!({ "reaction_id": reaction_id })).await
+    }
+
+    async fn get_current_user(&self) -> TicketResult<User> {
+        self.call("get_current_user", json!({})).await
+    }
+
+    async fn get_user(&self, user_id: &str) -> TicketResult<Option<User>> {
+        self.call("get_user", json!({ "user_id": user_id })).await
+    }
+
+    async fn get_notifications(&self, unread_only: bool, page: &PageRequest) -> TicketResult<Page<Notification>> {
+        self.call("get_notifications", json!({ "unread_only": unread_only, "page": page })).await
+    }
+
+    async fn get_teams(&self) -> TicketResult<Vec<Team>> {
+        self.call("get_teams", json!({})).await
+    }
+
+    async fn get_team_members(&self, team_id: &str) -> TicketResult<Vec<User>> {
+        self.call("get_team_members", json!({ "team_id": team_id })).await
+    }
+
+    async fn get_workflow_states(&self, team_id: &str) -> TicketResult<Vec<WorkflowState>> {
+        self.call("get_workflow_states", json!({ "team_id": team_id })).await
+    }
+
+    async fn get_labels(&self) -> TicketResult<Vec<Label>> {
+        self.call("get_labels", json!({})).await
+    }
+
+    async fn create_label(&self, request: &CreateLabelRequest) -> TicketResult<Label> {
+        self.call("create_label", json!({ "request": request })).await
+    }
+
+    async fn get_projects(&self, page: &PageRequest) -> TicketResult<Page<Project>> {
+        self.call("get_projects", json!({ "page": page })).await
+    }
+
+    async fn get_project(&self, project_id: &str) -> TicketResult<Option<Project>> {
+        self.call("get_project", json!({ "project_id": project_id })).await
+    }
+
+    async fn get_project_milestones(&self, project_id: &str) -> TicketResult<Vec<ProjectMilestone>> {
+        self.call("get_project_milestones", json!({ "project_id": project_id })).await
+    }
+
+    async fn create_project(&self, request: &CreateProjectRequest) -> TicketResult<Project> {
+        self.call("create_project", json!({ "request": request })).await
+    }
+
+    async fn update_project(&self, request: &UpdateProjectRequest) -> TicketResult<Project> {
+        self.call("update_project", json!({ "request": request })).await
+    }
+
+    async fn create_milestone(&self, request: &CreateMilestoneRequest) -> TicketResult<ProjectMilestone> {
+        self.call("create_milestone", json!({ "request": request })).await
+    }
+
+    async fn get_project_updates(&self, project_id: &str, page: &PageRequest) -> TicketResult<Page<ProjectUpdate>> {
+        self.call("get_project_updates", json!({ "project_id": project_id, "page": page })).await
+    }
+
+    async fn get_documents(&self, project_id: Option<&str>, page: &PageRequest) -> TicketResult<Page<Document>> {
+        self.call("get_documents", json!({ "project_id": project_id, "page": page })).await
+    }
+
+    async fn get_document(&self, document_id: &str) -> TicketResult<Option<Document>> {
+        self.call("get_document", json!({ "document_id": document_id })).await
+    }
+
+    async fn get_initiatives(&self) -> TicketResult<Vec<Initiative>> {
+        self.call("get_initiatives", json!({})).await
+    }
+
+    async fn get_initiative_projects(&self, initiative_id: &str) -> TicketResult<Vec<Project>> {
+        self.call("get_initiative_projects", json!({ "initiative_id": initiative_id })).await
+    }
+
+    async fn get_cycles(&self, team_id: &str) -> TicketResult<Vec<Cycle>> {
+        self.call("get_cycles", json!({ "team_id": team_id })).await
+    }
+
+    async fn get_active_cycle(&self, team_id: &str) -> TicketResult<Option<Cycle>> {
+        self.call("get_active_cycle", json!({ "team_id": team_id })).await
+    }
+
+    async fn assign_ticket_to_cycle(&self, ticket_id: &str, cycle_id: &str) -> TicketResult<Ticket> {
+        self.call("assign_ticket_to_cycle", json!({ "ticket_id": ticket_id, "cycle_id": cycle_id })).await
+    }
+
+    async fn log_time(&self, request: &LogTimeRequest) -> TicketResult<TimeEntry> {
+        self.call("log_time", json!({ "request": request })).await
+    }
+
+    async fn get_time_entries(&self, ticket_id: &str) -> TicketResult<Vec<TimeEntry>> {
+        self.call("get_time_entries", json!({ "ticket_id": ticket_id })).await
+    }
+
+    async fn get_workspace(&self) -> TicketResult<Workspace> {
+        self.call("get_workspace", json!({})).await
+    }
+}
@@ -0,0 +1,102 @@
+//! Cron expressions of recurring tickets: each field's syntax, how
+//! day-of-month and day-of-week combine, and the expressions refused.
+
+use chrono::{DateTime, Utc};
+use generic_mcp::CronSchedule;
+
+fn at(time: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(time).unwrap().with_timezone(&Utc)
+}
+
+/// The next run of `expression` after `after`.
+fn next(expression: &str, after: &str) -> DateTime<Utc> {
+    CronSchedule::parse(expression).unwrap().next_after(at(after)).unwrap()
+}
+
+#[test]
+fn steps_through_the_whole_field() {
+    assert_eq!(next("*/15 * * * *", "2026-10-01T10:07:00Z"), at("2026-10-01T10:15:00Z"));
+    assert_eq!(next("*/15 * * * *", "2026-10-01T10:45:00Z"), at("2026-10-01T11:00:00Z"));
+    assert_eq!(next("0 */6 * * *", "2026-10-01T13:00:00Z"), at("2026-10-01T18:00:00Z"));
+}
+
+#[test]
+fn runs_strictly_after_the_given_time() {
+    assert_eq!(next("30 9 * * *", "2026-10-01T09:30:00Z"), at("2026-10-02T09:30:00Z"));
+    assert_eq!(next("30 9 * * *", "2026-10-01T09:29:59Z"), at("2026-10-01T09:30:00Z"));
+}
+
+#[test]
+fn takes_ranges_lists_and_stepped_ranges() {
+    assert_eq!(next("0 9-17 * * *", "2026-10-01T17:30:00Z"), at("2026-10-02T09:00:00Z"));
+    assert_eq!(next("0 0 1,15 * *", "2026-10-02T00:00:00Z"), at("2026-10-15T00:00:00Z"));
+    assert_eq!(next("10-30/10 * * * *", "2026-10-01T10:21:00Z"), at("2026-10-01T10:30:00Z"));
+    assert_eq!(next("10-30/10 * * * *", "2026-10-01T10:31:00Z"), at("2026-10-01T11:10:00Z"));
+    // A start with a step runs to the end of the field
+    assert_eq!(next("50/5 * * * *", "2026-10-01T10:56:00Z"), at("2026-10-01T11:50:00Z"));
+    assert_eq!(next("0 0 1 1,7 *", "2026-10-01T00:00:00Z"), at("2027-01-01T00:00:00Z"));
+}
+
+#[test]
+fn matches_either_day_when_both_are_restricted() {
+    // The 13th, or any Friday
+    assert_eq!(next("0 0 13 * 5", "2026-10-01T00:00:00Z"), at("2026-10-02T00:00:00Z"));
+    assert_eq!(next("0 0 13 * 5", "2026-10-10T00:00:00Z"), at("2026-10-13T00:00:00Z"));
+}
+
+#[test]
+fn matches_the_restricted_day_when_the_other_is_a_star() {
+    assert_eq!(next("0 0 * * 1", "2026-10-01T00:00:00Z"), at("2026-10-05T00:00:00Z"));
+    assert_eq!(next("0 0 13 * *", "2026-10-01T00:00:00Z"), at("2026-10-13T00:00:00Z"));
+}
+
+#[test]
+fn treats_stepped_stars_as_unrestricted_days() {
+    // Mondays only, not every day
+    assert_eq!(next("0 0 */1 * 1", "2026-10-01T00:00:00Z"), at("2026-10-05T00:00:00Z"));
+    // Mondays falling on odd days of the month
+    assert_eq!(next("0 0 */2 * 1", "2026-10-01T00:00:00Z"), at("2026-10-05T00:00:00Z"));
+    assert_eq!(next("0 0 */2 * 1", "2026-10-05T00:00:00Z"), at("2026-10-19T00:00:00Z"));
+    // The 13th only, whatever the weekday
+    assert_eq!(next("0 0 13 * */1", "2026-10-01T00:00:00Z"), at("2026-10-13T00:00:00Z"));
+}
+
+#[test]
+fn counts_seven_as_sunday() {
+    assert_eq!(next("0 0 * * 7", "2026-10-01T00:00:00Z"), at("2026-10-04T00:00:00Z"));
+    assert_eq!(next("0 0 * * 0", "2026-10-01T00:00:00Z"), at("2026-10-04T00:00:00Z"));
+}
+
+#[test]
+fn expands_shorthands() {
+    assert_eq!(next("@hourly", "2026-10-01T10:07:00Z"), at("2026-10-01T11:00:00Z"));
+    assert_eq!(next("@weekly", "2026-10-01T00:00:00Z"), at("2026-10-04T00:00:00Z"));
+    assert_eq!(next("@monthly", "2026-10-01T00:00:00Z"), at("2026-11-01T00:00:00Z"));
+    assert_eq!(next("@yearly", "2026-10-01T00:00:00Z"), at("2027-01-01T00:00:00Z"));
+}
+
+#[test]
+fn never_matches_an_impossible_date() {
+    let schedule = CronSchedule::parse("0 0 31 2 *").unwrap();
+    assert_eq!(schedule.next_after(at("2026-10-01T00:00:00Z")), None);
+}
+
+#[test]
+fn refuses_malformed_expressions() {
+    for expression in [
+        "* * * *",
+        "* * * * * *",
+        "60 * * * *",
+        "* 24 * * *",
+        "* * 0 * *",
+        "* * * 13 *",
+        "* * * * 8",
+        "*/0 * * * *",
+        "5-1 * * * *",
+        "a * * * *",
+        "1,,2 * * * *",
+        "*/x * * * *",
+    ] {
+        assert!(CronSchedule::parse(expression).is_err(), "{} was accepted", expression);
+    }
+}
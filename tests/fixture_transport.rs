@@ -0,0 +1,87 @@
+//! Recording provider responses to sanitized fixtures and replaying them.
+
+#![cfg(all(feature = "linear", feature = "tokio"))]
+
+use generic_mcp::{FixtureTransport, LinearClient, LinearService, default_https_client};
+use serde_json::json;
+use std::path::Path;
+use std::sync::Arc;
+use wiremock::matchers::method;
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TOKEN: &str = "lin_api_9f8e7d6c5b4a";
+
+fn client(server: &MockServer, transport: FixtureTransport) -> LinearClient {
+    LinearClient::new(TOKEN.to_string())
+        .unwrap()
+        .with_base_url(format!("{}/graphql", server.uri()))
+        .with_max_retries(0)
+        .with_transport(Arc::new(transport))
+}
+
+fn recorded(dir: &Path) -> String {
+    let mut contents = String::new();
+    for host in std::fs::read_dir(dir).unwrap() {
+        for fixture in std::fs::read_dir(host.unwrap().path()).unwrap() {
+            contents.push_str(&std::fs::read_to_string(fixture.unwrap().path()).unwrap());
+        }
+    }
+    contents
+}
+
+#[tokio::test]
+async fn records_sanitized_fixtures_and_replays_them_offline() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("set-cookie", "session=abc123456789")
+                .set_body_json(json!({
+                    "data": {
+                        "viewer": {
+                            "id": "user-1",
+                            "name": "Ann Example",
+                            "email": "ann@corp.example",
+                            "displayName": format!("ann ({})", TOKEN),
+                            "active": true,
+                            "apiKey": "not-for-fixtures"
+                        }
+                    }
+                })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let recording = client(&server, FixtureTransport::record(Arc::new(default_https_client()), dir.path()));
+    let live = recording.get_current_user().await.unwrap();
+    assert_eq!(live.email, "ann@corp.example");
+
+    let fixtures = recorded(dir.path());
+    assert!(!fixtures.contains(TOKEN), "{}", fixtures);
+    assert!(!fixtures.contains("ann@corp.example"), "{}", fixtures);
+    assert!(!fixtures.contains("not-for-fixtures"), "{}", fixtures);
+    assert!(!fixtures.contains("session="), "{}", fixtures);
+
+    // Replaying needs neither the server nor the real token
+    let replaying = LinearClient::new("lin_api_another_token".to_string())
+        .unwrap()
+        .with_base_url(format!("{}/graphql", server.uri()))
+        .with_transport(Arc::new(FixtureTransport::replay(dir.path())));
+    let replayed = replaying.get_current_user().await.unwrap();
+    assert_eq!(replayed.id, "user-1");
+    assert_eq!(replayed.name, "Ann Example");
+    assert!(replayed.email.ends_with("@example.com"));
+    assert_eq!(replayed.display_name, "ann (REDACTED)");
+}
+
+#[tokio::test]
+async fn replay_fails_for_requests_that_were_never_recorded() {
+    let dir = tempfile::tempdir().unwrap();
+    let server = MockServer::start().await;
+
+    let error = client(&server, FixtureTransport::replay(dir.path())).get_teams().await.unwrap_err();
+    assert!(format!("{:#}", error).contains("MCP_HTTP_FIXTURES=record"), "{:#}", error);
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
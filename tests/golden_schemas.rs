@@ -0,0 +1,81 @@
+//! The tools and resources the server offers, checked against golden files
+//! in `tests/golden/`, since agents are built against these schemas and
+//! break when they drift. After an intended change, rerun with
+//! `UPDATE_GOLDEN=1` and review the files' diff.
+
+#![cfg(all(feature = "linear", feature = "tokio"))]
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use generic_mcp::{Application, HttpSettings, LinearAdapter, McpServer, McpServerImpl, ProviderConfig, ProviderRegistry};
+use serde_json::{Value, json};
+use similar::TextDiff;
+
+/// The server as a Linear deployment with no optional features sees it.
+fn linear_server() -> McpServerImpl {
+    let adapter = LinearAdapter::new(ProviderConfig {
+        provider_type: "linear".to_string(),
+        api_token: "lin_api_golden".to_string(),
+        base_url: None,
+        workspace_id: None,
+        http: HttpSettings::default(),
+    })
+    .unwrap();
+    McpServerImpl::with_registry(ProviderRegistry::new("linear", Arc::new(Application::new(Arc::new(adapter)))))
+}
+
+/// Fails with a unified diff when `actual` differs from `tests/golden/{name}.json`.
+fn assert_golden(name: &str, actual: &Value) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(format!("{}.json", name));
+    let actual = format!("{}\n", serde_json::to_string_pretty(actual).unwrap());
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("{}: {}; create it with UPDATE_GOLDEN=1", path.display(), e));
+    if expected != actual {
+        let diff = TextDiff::from_lines(&expected, &actual);
+        panic!(
+            "{} changed; rerun with UPDATE_GOLDEN=1 if this is intended\n\n{}",
+            name,
+            diff.unified_diff().context_radius(3).header("golden", "actual")
+        );
+    }
+}
+
+#[tokio::test]
+async fn tools_match_golden() {
+    let tools: Vec<Value> = linear_server()
+        .list_tools()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|tool| json!({
+            "name": tool.name,
+            "description": tool.description,
+            "inputSchema": tool.input_schema
+        }))
+        .collect();
+    assert_golden("linear/tools", &json!(tools));
+}
+
+#[tokio::test]
+async fn resources_match_golden() {
+    let resources: Vec<Value> = linear_server()
+        .list_resources()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|resource| json!({
+            "uri": resource.uri,
+            "name": resource.name,
+            "description": resource.description,
+            "mimeType": resource.mime_type
+        }))
+        .collect();
+    assert_golden("linear/resources", &json!(resources));
+}
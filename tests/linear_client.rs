@@ -0,0 +1,449 @@
+//! `LinearClient` against a mock GraphQL endpoint serving recorded Linear
+//! responses from `tests/fixtures/linear`.
+
+#![cfg(all(feature = "linear", feature = "tokio"))]
+
+use generic_mcp::{
+    CreateLabelRequest, IssueFilter, IssuePriority, IssueStateType, LinearClient, LinearService, PageRequest,
+    TicketEventKind, TicketServiceError, UpdateIssueRequest,
+};
+use serde_json::{Value, json};
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, body_string_contains, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TOKEN: &str = "lin_api_test";
+
+fn fixture(name: &str) -> Value {
+    let path = format!("{}/tests/fixtures/linear/{}.json", env!("CARGO_MANIFEST_DIR"), name);
+    let contents = std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    serde_json::from_str(&contents).unwrap()
+}
+
+fn client(server: &MockServer) -> LinearClient {
+    LinearClient::new(TOKEN.to_string())
+        .unwrap()
+        .with_base_url(format!("{}/graphql", server.uri()))
+        .with_max_retries(0)
+}
+
+fn service_error(error: &anyhow::Error) -> &TicketServiceError {
+    error.downcast_ref::<TicketServiceError>()
+        .unwrap_or_else(|| panic!("not a TicketServiceError: {:#}", error))
+}
+
+#[tokio::test]
+async fn sends_the_api_key_and_parses_the_viewer() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(header("authorization", TOKEN))
+        .and(header("content-type", "application/json"))
+        .and(body_string_contains("viewer"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("viewer")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let user = client(&server).get_current_user().await.unwrap();
+    assert_eq!(user.email, "ann@example.com");
+    assert_eq!(user.display_name, "ann");
+    assert!(user.active);
+}
+
+#[tokio::test]
+async fn parses_issues_and_follows_cursors_to_the_last_page() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "variables": { "userId": "ann", "after": null } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("assigned_issues_page1")))
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "variables": { "userId": "ann", "after": "cursor-1" } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("assigned_issues_page2")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let page = client.get_assigned_issues("ann", &PageRequest::default()).await.unwrap();
+    assert_eq!(page.next_cursor.as_deref(), Some("cursor-1"));
+    let issue = &page.items[0];
+    assert_eq!(issue.identifier, "ENG-12");
+    assert!(matches!(issue.state.type_, IssueStateType::Started));
+    assert_eq!(issue.labels, ["Bug", "CI"]);
+    assert_eq!(issue.project_id.as_deref(), Some("project-ci"));
+    assert_eq!(issue.estimate, Some(3.0));
+    assert!(issue.due_date.is_some());
+
+    let all = client.get_all_assigned_issues("ann").await.unwrap();
+    let identifiers: Vec<&str> = all.iter().map(|issue| issue.identifier.as_str()).collect();
+    assert_eq!(identifiers, ["ENG-12", "ENG-15"]);
+    // Backlog folds into unstarted
+    assert!(matches!(all[1].state.type_, IssueStateType::Unstarted));
+}
+
+#[tokio::test]
+async fn stops_at_the_page_limit() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("assigned_issues_page1")))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let issues = client(&server).with_max_pages(2).get_all_assigned_issues("ann").await.unwrap();
+    assert_eq!(issues.len(), 2);
+}
+
+#[tokio::test]
+async fn sends_page_size_and_search_filters() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_string_contains("SearchIssues"))
+        .and(body_partial_json(json!({
+            "variables": {
+                "first": 25,
+                "includeArchived": true,
+                "filter": { "team": { "id": { "eq": "team-eng" } } }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "issues": { "pageInfo": { "hasNextPage": false, "endCursor": null }, "nodes": [] } }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let filter = IssueFilter {
+        team_id: Some("team-eng".to_string()),
+        include_archived: true,
+        ..Default::default()
+    };
+    let page = client(&server).search_issues(&filter, &PageRequest::first(25)).await.unwrap();
+    assert!(page.items.is_empty());
+    assert!(page.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn batches_issues_and_returns_none_for_missing_ones() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_string_contains("query GetIssues"))
+        .and(body_partial_json(json!({ "variables": { "b0_id": "ENG-12", "b1_id": "ENG-404" } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("issues_batch")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let issues = client(&server)
+        .get_issues(&["ENG-12".to_string(), "ENG-404".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(issues.len(), 2);
+    let issue = issues[0].as_ref().unwrap();
+    assert_eq!(issue.relations.parent.as_ref().unwrap().identifier, "ENG-10");
+    assert_eq!(issue.relations.blocks[0].identifier, "ENG-15");
+    assert!(issues[1].is_none());
+}
+
+#[tokio::test]
+async fn lists_teams() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_string_contains("GetTeams"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("teams")))
+        .mount(&server)
+        .await;
+
+    let teams = client(&server).get_teams().await.unwrap();
+    let keys: Vec<&str> = teams.iter().map(|team| team.key.as_str()).collect();
+    assert_eq!(keys, ["ENG", "OPS"]);
+    assert_eq!(teams[1].description, None);
+}
+
+#[tokio::test]
+async fn refuses_a_team_missing_a_field() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_string_contains("GetTeams"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "teams": { "nodes": [{ "id": "team-eng", "name": "Engineering", "description": null }] } }
+        })))
+        .mount(&server)
+        .await;
+
+    let error = client(&server).get_teams().await.unwrap_err();
+    assert!(format!("{:#}", error).contains("missing field `key`"), "{:#}", error);
+}
+
+#[tokio::test]
+async fn splits_history_entries_and_merges_comments() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_string_contains("GetIssueHistory"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("issue_history")))
+        .mount(&server)
+        .await;
+
+    let events = client(&server).get_issue_history("ENG-12").await.unwrap();
+    let ids: Vec<&str> = events.iter().map(|event| event.id.as_str()).collect();
+    assert_eq!(ids, ["comment-1", "history-1:state", "history-1:priority", "history-1:cycle", "history-1:labels"]);
+    assert!(events.iter().all(|event| event.ticket_id == "issue-12"));
+    assert_eq!(events[0].actor_id.as_deref(), Some("bob"));
+    assert!(matches!(
+        &events[2].kind,
+        TicketEventKind::FieldChanged { from: Some(from), to: Some(to), .. } if from == "Medium" && to == "Urgent"
+    ));
+    assert!(matches!(&events[3].kind, TicketEventKind::FieldChanged { to: Some(to), .. } if to == "42"));
+    assert!(matches!(
+        &events[4].kind,
+        TicketEventKind::FieldChanged { from: None, to: Some(to), .. } if to == "Bug, CI"
+    ));
+}
+
+#[tokio::test]
+async fn pages_through_history_after_comments_run_out() {
+    let server = MockServer::start().await;
+    let mut first = fixture("issue_history");
+    first["data"]["issue"]["history"]["pageInfo"] = json!({ "hasNextPage": true, "endCursor": "history-cursor" });
+    first["data"]["issue"]["comments"]["pageInfo"] = json!({ "hasNextPage": false, "endCursor": null });
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "variables": { "historyAfter": null, "withComments": true } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(first))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut second = fixture("issue_history");
+    let entry = &mut second["data"]["issue"]["history"]["nodes"][0];
+    entry["id"] = json!("history-2");
+    entry["createdAt"] = json!("2026-10-02T09:00:00.000Z");
+    second["data"]["issue"].as_object_mut().unwrap().remove("comments");
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "variables": { "historyAfter": "history-cursor", "withHistory": true, "withComments": false }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(second))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let events = client(&server).get_issue_history("ENG-12").await.unwrap();
+    let comments = events.iter().filter(|event| matches!(event.kind, TicketEventKind::Comment { .. })).count();
+    assert_eq!(comments, 1);
+    assert_eq!(events.last().unwrap().id, "history-2:labels");
+}
+
+#[tokio::test]
+async fn refuses_a_history_entry_with_a_malformed_field() {
+    let server = MockServer::start().await;
+    let mut history = fixture("issue_history");
+    history["data"]["issue"]["history"]["nodes"][0]["createdAt"] = json!("yesterday");
+    Mock::given(method("POST"))
+        .and(body_string_contains("GetIssueHistory"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(history))
+        .mount(&server)
+        .await;
+
+    let error = client(&server).get_issue_history("ENG-12").await.unwrap_err();
+    assert!(format!("{:#}", error).contains("issue history"), "{:#}", error);
+}
+
+#[tokio::test]
+async fn updates_only_the_fields_given() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_string_contains("issueUpdate"))
+        .and(body_partial_json(json!({
+            "variables": { "id": "ENG-12", "input": { "stateId": "state-started", "priority": 1 } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("issue_update")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = UpdateIssueRequest {
+        id: "ENG-12".to_string(),
+        title: None,
+        description: None,
+        priority: Some(IssuePriority::Urgent),
+        assignee_id: None,
+        state_id: Some("state-started".to_string()),
+        label_ids: None,
+        due_date: None,
+        estimate: None,
+    };
+    let issue = client(&server).update_issue(&request).await.unwrap();
+    assert_eq!(issue.identifier, "ENG-12");
+    assert_eq!(issue.state.name, "In Progress");
+
+    // Fields left unset must not be sent as nulls, which would clear them
+    let requests = server.received_requests().await.unwrap();
+    let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+    let input = body["variables"]["input"].as_object().unwrap();
+    let mut keys: Vec<&str> = input.keys().map(String::as_str).collect();
+    keys.sort();
+    assert_eq!(keys, ["priority", "stateId"]);
+}
+
+#[tokio::test]
+async fn creates_labels() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_string_contains("issueLabelCreate"))
+        .and(body_partial_json(json!({ "variables": { "input": { "name": "Firmware", "color": "#4ea7fc" } } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "issueLabelCreate": {
+                    "success": true,
+                    "issueLabel": { "id": "label-firmware", "name": "Firmware", "color": "#4ea7fc", "description": null }
+                }
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = CreateLabelRequest {
+        name: "Firmware".to_string(),
+        color: "#4ea7fc".to_string(),
+        description: None,
+    };
+    let label = client(&server).create_label(&request).await.unwrap();
+    assert_eq!(label.id, "label-firmware");
+    assert_eq!(label.description, None);
+}
+
+#[tokio::test]
+async fn refuses_a_label_creation_without_a_label() {
+    let server = MockServer::start().await;
+    for (name, success) in [("Firmware", false), ("Network", true)] {
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({ "variables": { "input": { "name": name } } })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { "issueLabelCreate": { "success": success, "issueLabel": null } }
+            })))
+            .mount(&server)
+            .await;
+    }
+
+    let client = client(&server);
+    for name in ["Firmware", "Network"] {
+        let request = CreateLabelRequest {
+            name: name.to_string(),
+            color: "#4ea7fc".to_string(),
+            description: None,
+        };
+        assert!(client.create_label(&request).await.is_err(), "{}", name);
+    }
+}
+
+#[tokio::test]
+async fn maps_a_rejected_token_to_unauthorized() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(fixture("authentication_error")))
+        .mount(&server)
+        .await;
+
+    let error = client(&server).get_current_user().await.unwrap_err();
+    assert!(matches!(service_error(&error), TicketServiceError::Unauthorized(_)), "{:#}", error);
+}
+
+#[tokio::test]
+async fn maps_graphql_error_codes_to_typed_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_string_contains("GetTeams"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(fixture("invalid_input")))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(body_string_contains("viewer"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("authentication_error")))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let error = client.get_teams().await.unwrap_err();
+    assert!(matches!(service_error(&error), TicketServiceError::Validation(_)), "{:#}", error);
+    let error = client.get_current_user().await.unwrap_err();
+    assert!(matches!(service_error(&error), TicketServiceError::Unauthorized(_)), "{:#}", error);
+}
+
+#[tokio::test]
+async fn maps_server_errors_to_provider_unavailable() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(503).set_body_string("upstream connect error"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let error = client(&server).get_teams().await.unwrap_err();
+    assert!(matches!(service_error(&error), TicketServiceError::ProviderUnavailable(_)), "{:#}", error);
+    assert!(service_error(&error).is_retryable());
+}
+
+#[tokio::test]
+async fn rejects_a_response_that_is_not_json() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html>captive portal</html>"))
+        .mount(&server)
+        .await;
+
+    let error = client(&server).get_teams().await.unwrap_err();
+    assert!(error.to_string().contains("not JSON"), "{:#}", error);
+}
+
+#[tokio::test]
+async fn retries_after_a_rate_limit_then_succeeds() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0").set_body_json(fixture("rate_limited")))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("viewer")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let user = client(&server).with_max_retries(1).get_current_user().await.unwrap();
+    assert_eq!(user.name, "Ann Example");
+}
+
+#[tokio::test]
+async fn reports_the_retry_delay_once_retries_run_out() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "30").set_body_json(fixture("rate_limited")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let error = client(&server).get_current_user().await.unwrap_err();
+    match service_error(&error) {
+        TicketServiceError::RateLimited { retry_after, .. } => assert_eq!(*retry_after, Some(Duration::from_secs(30))),
+        other => panic!("expected a rate limit, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn treats_a_ratelimited_graphql_error_as_a_rate_limit() {
+    let server = MockServer::start().await;
+    // Linear can answer 200 or 400 with a RATELIMITED error instead of a 429
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(fixture("rate_limited")))
+        .mount(&server)
+        .await;
+
+    let error = client(&server).get_teams().await.unwrap_err();
+    assert!(matches!(service_error(&error), TicketServiceError::RateLimited { .. }), "{:#}", error);
+}
@@ -0,0 +1,213 @@
+//! Properties of the conversions between Linear issues and generic tickets.
+
+#![cfg(feature = "linear")]
+
+use chrono::{DateTime, TimeZone, Utc};
+use generic_mcp::domain::{Issue, IssuePriority, IssueState, IssueStateType, Priority, RichText, StateType, TicketRelations};
+use generic_mcp::providers::{
+    issue_to_ticket, priority_from_linear, priority_to_linear, state_type_from_linear, state_type_to_linear,
+};
+use proptest::prelude::*;
+
+fn issue_priority() -> impl Strategy<Value = IssuePriority> {
+    prop_oneof![
+        Just(IssuePriority::NoPriority),
+        Just(IssuePriority::Urgent),
+        Just(IssuePriority::High),
+        Just(IssuePriority::Medium),
+        Just(IssuePriority::Low),
+    ]
+}
+
+fn issue_state_type() -> impl Strategy<Value = IssueStateType> {
+    prop_oneof![
+        Just(IssueStateType::Unstarted),
+        Just(IssueStateType::Started),
+        Just(IssueStateType::Completed),
+        Just(IssueStateType::Canceled),
+    ]
+}
+
+/// The generic priorities other than `Custom`, with the name a tracker
+/// might give each.
+fn named_priority() -> impl Strategy<Value = (Priority, &'static str)> {
+    prop_oneof![
+        Just((Priority::None, "No priority")),
+        Just((Priority::Lowest, "Lowest")),
+        Just((Priority::Low, "Low")),
+        Just((Priority::Medium, "Medium")),
+        Just((Priority::High, "High")),
+        Just((Priority::Highest, "Highest")),
+    ]
+}
+
+fn named_state_type() -> impl Strategy<Value = (StateType, &'static str)> {
+    prop_oneof![
+        Just((StateType::Open, "Unstarted")),
+        Just((StateType::InProgress, "In Progress")),
+        Just((StateType::Closed, "Completed")),
+        Just((StateType::Cancelled, "Canceled")),
+    ]
+}
+
+/// `name` in random case, with stray whitespace around it.
+fn spelled(name: &'static str) -> impl Strategy<Value = String> {
+    (prop::collection::vec(any::<bool>(), name.len()), "[ \t]{0,2}", "[ \t]{0,2}").prop_map(move |(upper, before, after)| {
+        let word: String = name
+            .chars()
+            .zip(upper)
+            .map(|(c, upper)| if upper { c.to_ascii_uppercase() } else { c.to_ascii_lowercase() })
+            .collect();
+        format!("{}{}{}", before, word, after)
+    })
+}
+
+fn timestamp() -> impl Strategy<Value = DateTime<Utc>> {
+    (0i64..4_102_444_800).prop_map(|seconds| Utc.timestamp_opt(seconds, 0).unwrap())
+}
+
+fn issue() -> impl Strategy<Value = Issue> {
+    let identity = ("[a-f0-9-]{1,36}", "[A-Z]{1,5}-[0-9]{1,5}", ".*", prop::option::of(".*"), "https://linear\\.app/[a-z/0-9-]{0,40}");
+    let people = (
+        prop::option::of("[a-z0-9-]{1,12}"),
+        "[a-z0-9-]{1,12}",
+        prop::option::of("[a-z0-9-]{1,12}"),
+        prop::option::of("[a-z0-9-]{1,12}"),
+        prop::collection::vec(".{0,12}", 0..4),
+        prop::collection::vec("[a-z0-9-]{1,12}", 0..4),
+    );
+    let state = (issue_priority(), "[a-z0-9-]{1,12}", ".{0,20}", issue_state_type(), -1000.0f32..1000.0);
+    let dates = (
+        timestamp(),
+        timestamp(),
+        prop::option::of(timestamp()),
+        prop::option::of(timestamp()),
+        prop::option::of(timestamp()),
+        prop::option::of(timestamp()),
+        prop::option::of(0.0f32..100.0),
+    );
+    (identity, people, state, dates).prop_map(
+        |(
+            (id, identifier, title, description, url),
+            (assignee_id, creator_id, project_id, cycle_id, labels, subscriber_ids),
+            (priority, state_id, state_name, state_type, position),
+            (created_at, updated_at, due_date, completed_at, archived_at, snoozed_until, estimate),
+        )| Issue {
+            id,
+            identifier,
+            title,
+            description,
+            priority,
+            state: IssueState { id: state_id, name: state_name, type_: state_type, position },
+            assignee_id,
+            creator_id,
+            project_id,
+            cycle_id,
+            labels,
+            subscriber_ids,
+            attachments: Vec::new(),
+            relations: TicketRelations::default(),
+            reactions: Vec::new(),
+            created_at,
+            updated_at,
+            due_date,
+            completed_at,
+            archived_at,
+            snoozed_until,
+            estimate,
+            sla: None,
+            url,
+        },
+    )
+}
+
+proptest! {
+    #[test]
+    fn issues_keep_every_field_as_tickets(issue in issue()) {
+        let ticket = issue_to_ticket(issue.clone());
+        prop_assert_eq!(&ticket.id, &issue.id);
+        prop_assert_eq!(&ticket.identifier, &issue.identifier);
+        prop_assert_eq!(&ticket.title, &issue.title);
+        match (&ticket.description, &issue.description) {
+            (Some(RichText::Markdown(converted)), Some(original)) => prop_assert_eq!(converted, original),
+            (None, None) => {}
+            (converted, original) => prop_assert!(false, "description {:?} became {:?}", original, converted),
+        }
+        prop_assert_eq!(priority_to_linear(&ticket.priority), issue.priority);
+        prop_assert_eq!(&ticket.state.id, &issue.state.id);
+        prop_assert_eq!(&ticket.state.name, &issue.state.name);
+        prop_assert_eq!(state_type_to_linear(&ticket.state.type_), issue.state.type_);
+        prop_assert_eq!(ticket.state.position, issue.state.position);
+        prop_assert_eq!(&ticket.assignee_id, &issue.assignee_id);
+        prop_assert_eq!(&ticket.creator_id, &issue.creator_id);
+        prop_assert_eq!(&ticket.project_id, &issue.project_id);
+        prop_assert_eq!(&ticket.cycle_id, &issue.cycle_id);
+        prop_assert_eq!(&ticket.labels, &issue.labels);
+        prop_assert_eq!(&ticket.watcher_ids, &issue.subscriber_ids);
+        prop_assert_eq!(ticket.created_at, issue.created_at);
+        prop_assert_eq!(ticket.updated_at, issue.updated_at);
+        prop_assert_eq!(ticket.due_date, issue.due_date);
+        prop_assert_eq!(ticket.completed_at, issue.completed_at);
+        prop_assert_eq!(ticket.archived_at, issue.archived_at);
+        prop_assert_eq!(ticket.snoozed_until, issue.snoozed_until);
+        prop_assert_eq!(ticket.estimate, issue.estimate);
+        prop_assert_eq!(&ticket.url, &issue.url);
+    }
+
+    #[test]
+    fn linear_priorities_survive_a_round_trip(priority in issue_priority()) {
+        prop_assert_eq!(priority_to_linear(&priority_from_linear(priority.clone())), priority);
+    }
+
+    #[test]
+    fn linear_state_types_survive_a_round_trip(state_type in issue_state_type()) {
+        prop_assert_eq!(state_type_to_linear(&state_type_from_linear(state_type.clone())), state_type);
+    }
+
+    #[test]
+    fn generic_priorities_keep_their_level((priority, _) in named_priority()) {
+        let expected = if priority == Priority::Lowest { Priority::Low } else { priority.clone() };
+        prop_assert_eq!(priority_from_linear(priority_to_linear(&priority)), expected);
+    }
+
+    #[test]
+    fn generic_state_types_keep_their_category((state_type, _) in named_state_type()) {
+        prop_assert_eq!(state_type_from_linear(state_type_to_linear(&state_type)), state_type);
+    }
+
+    #[test]
+    fn custom_priorities_named_like_a_level_map_to_it(
+        (priority, name) in named_priority().prop_flat_map(|(priority, name)| (Just(priority), spelled(name)))
+    ) {
+        prop_assert_eq!(priority_to_linear(&Priority::Custom(name)), priority_to_linear(&priority));
+    }
+
+    #[test]
+    fn custom_state_types_named_like_a_category_map_to_it(
+        (state_type, name) in named_state_type().prop_flat_map(|(state_type, name)| (Just(state_type), spelled(name)))
+    ) {
+        prop_assert_eq!(state_type_to_linear(&StateType::Custom(name)), state_type_to_linear(&state_type));
+    }
+
+    #[test]
+    fn unrecognized_custom_priorities_fall_back_to_medium(name in "\\PC*") {
+        let known = [
+            "none", "no priority", "no_priority", "no-priority", "urgent", "highest", "critical", "blocker",
+            "high", "major", "low", "lowest", "minor", "trivial",
+        ];
+        prop_assume!(!known.iter().any(|known| name.trim().eq_ignore_ascii_case(known)));
+        prop_assert_eq!(priority_to_linear(&Priority::Custom(name)), IssuePriority::Medium);
+    }
+
+    #[test]
+    fn unrecognized_custom_state_types_are_never_finished(name in "\\PC*") {
+        let state_type = state_type_to_linear(&StateType::Custom(name.clone()));
+        let finished = ["completed", "closed", "done", "resolved", "canceled", "cancelled"];
+        if !finished.iter().any(|finished| name.trim().eq_ignore_ascii_case(finished)) {
+            prop_assert!(
+                matches!(state_type, IssueStateType::Unstarted | IssueStateType::Started),
+                "{:?} mapped to {:?}", name, state_type
+            );
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Local search over the tantivy index: query syntax, the provider filter,
+//! and tickets webhooks report as changed.
+
+#![cfg(all(feature = "local-search", feature = "tokio"))]
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use generic_mcp::{
+    Application, CreateTicketRequest, EventListener, MemoryTicketService, RichText, TantivyIndex, Ticket,
+    TicketIndex, WebhookAction, WebhookEntity, WebhookEvent,
+};
+
+async fn tickets() -> Vec<Ticket> {
+    let application = Application::new(Arc::new(MemoryTicketService::new()));
+    let mut tickets = Vec::new();
+    for (title, description) in [
+        ("Fix IPMI auth on the GPU nodes", "BMC rejects the rotated password"),
+        ("Flaky CI on arm64", "The firmware build times out"),
+    ] {
+        let ticket = application
+            .create_ticket(&CreateTicketRequest {
+                title: title.to_string(),
+                description: Some(RichText::markdown(description)),
+                team_id: Some("team-demo".to_string()),
+                ..Default::default()
+            }, None)
+            .await
+            .unwrap();
+        tickets.push(ticket);
+    }
+    tickets
+}
+
+fn titles(index: &TicketIndex, query: &str, provider: Option<&str>, fuzzy: bool) -> Vec<String> {
+    index.search(query, provider, fuzzy, 10).unwrap().into_iter().map(|hit| hit.ticket.title).collect()
+}
+
+#[tokio::test]
+async fn searches_terms_phrases_and_fields() {
+    let index = TicketIndex::new(Arc::new(TantivyIndex::in_memory().unwrap()));
+    index.index_tickets("linear", &tickets().await).unwrap();
+
+    assert_eq!(titles(&index, "ipmi gpu", None, false), ["Fix IPMI auth on the GPU nodes"]);
+    assert_eq!(titles(&index, "\"firmware build\"", None, false), ["Flaky CI on arm64"]);
+    assert_eq!(titles(&index, "description:password", None, false), ["Fix IPMI auth on the GPU nodes"]);
+    assert!(titles(&index, "ipmi arm64", None, false).is_empty());
+    assert!(titles(&index, "flakey", None, false).is_empty());
+    assert_eq!(titles(&index, "flakey", None, true), ["Flaky CI on arm64"]);
+    assert!(index.search("title:(", None, false, 10).is_err());
+}
+
+#[tokio::test]
+async fn filters_by_provider_and_replaces_reindexed_tickets() {
+    let index = TicketIndex::new(Arc::new(TantivyIndex::in_memory().unwrap()));
+    let tickets = tickets().await;
+    index.index_tickets("linear", &tickets).unwrap();
+    index.index_tickets("github", &tickets[..1]).unwrap();
+    index.index_tickets("github", &tickets[..1]).unwrap();
+
+    let hits = index.search("ipmi", Some("github"), false, 10).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].provider, "github");
+
+    index.remove_ticket("linear", &tickets[0].id).unwrap();
+    let hits = index.search("ipmi", None, false, 10).unwrap();
+    assert_eq!(hits.iter().map(|hit| hit.provider.as_str()).collect::<Vec<_>>(), ["github"]);
+}
+
+#[tokio::test]
+async fn tracks_tickets_webhooks_report_until_reindexed() {
+    let index = TicketIndex::new(Arc::new(TantivyIndex::in_memory().unwrap()));
+    let tickets = tickets().await;
+    let event = |ticket: &Ticket| WebhookEvent {
+        provider: "linear".to_string(),
+        entity: WebhookEntity::Ticket,
+        action: WebhookAction::Updated,
+        entity_id: ticket.id.clone(),
+        ticket_id: Some(ticket.id.clone()),
+        project_id: None,
+        occurred_at: Utc::now(),
+    };
+    index.on_event(&event(&tickets[0]));
+    index.on_event(&event(&tickets[0]));
+    index.on_event(&event(&tickets[1]));
+
+    index.index_tickets("linear", &tickets[1..]).unwrap();
+    assert_eq!(index.take_stale(), [("linear".to_string(), tickets[0].id.clone())]);
+    assert!(index.take_stale().is_empty());
+}
@@ -0,0 +1,23 @@
+//! Durations the `log_time` tool refuses before calling the provider.
+
+#![cfg(feature = "tokio")]
+
+use std::sync::Arc;
+
+use generic_mcp::{Application, Capabilities, McpServer, McpServerImpl, MemoryTicketService, TicketService};
+use serde_json::json;
+
+#[tokio::test]
+async fn refuses_durations_too_long_to_count() {
+    let service = MemoryTicketService::new().with_capabilities(Capabilities {
+        time_tracking: true,
+        ..MemoryTicketService::new().capabilities()
+    });
+    let server = McpServerImpl::new(Arc::new(Application::new(Arc::new(service))));
+
+    for duration in ["99999999h", "71582789h", "71582788h 60m", "4294967295m 1m", "", "1x"] {
+        let result = server.call_tool("log_time", json!({ "ticket_id": "DEMO-1", "duration": duration })).await;
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("Invalid duration"), "{}: {}", duration, error);
+    }
+}
@@ -0,0 +1,146 @@
+//! What an MCP client relies on from the server, checked against the
+//! in-memory provider: the tools it lists, the results and JSON-RPC error
+//! codes of calling them, and the resources it lists and reads.
+
+#![cfg(feature = "tokio")]
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use generic_mcp::{Application, McpServer, McpServerImpl, MemoryTicketService};
+use serde_json::{Value, json};
+
+fn server() -> McpServerImpl {
+    McpServerImpl::new(Arc::new(Application::new(Arc::new(MemoryTicketService::new())))).with_default_team("DEMO")
+}
+
+/// The JSON-RPC error a transport would send for a failed call.
+async fn call_error(server: &McpServerImpl, tool: &str, arguments: Value) -> Value {
+    match server.call_tool(tool, arguments).await {
+        Ok(result) => panic!("{} succeeded with {}", tool, result),
+        Err(error) => McpServerImpl::json_rpc_error(&error),
+    }
+}
+
+#[tokio::test]
+async fn starts_and_stops() {
+    let server = server();
+    assert!(!server.is_running());
+    server.start_server().await.unwrap();
+    assert!(server.is_running());
+    server.stop_server().await.unwrap();
+    assert!(!server.is_running());
+}
+
+#[tokio::test]
+async fn lists_tools_with_unique_names_and_object_schemas() {
+    let tools = server().list_tools().await.unwrap();
+    assert!(!tools.is_empty());
+
+    let mut names = HashSet::new();
+    for tool in &tools {
+        assert!(names.insert(tool.name.as_str()), "{} is listed twice", tool.name);
+        assert!(
+            tool.name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'),
+            "{} is not a valid tool name", tool.name
+        );
+        assert!(!tool.description.trim().is_empty(), "{} has no description", tool.name);
+        assert_eq!(tool.input_schema["type"], "object", "{}'s input schema is not an object", tool.name);
+    }
+}
+
+#[tokio::test]
+async fn every_listed_tool_can_be_called() {
+    let server = server();
+    for tool in server.list_tools().await.unwrap() {
+        // Empty arguments may be rejected, but never as an unknown tool
+        if let Err(error) = server.call_tool(&tool.name, json!({})).await {
+            let error = McpServerImpl::json_rpc_error(&error);
+            assert_ne!(error["code"], -32601, "{} is listed but not dispatched: {}", tool.name, error);
+        }
+    }
+}
+
+#[tokio::test]
+async fn calls_tools_against_the_provider() {
+    let server = server();
+
+    let user = server.call_tool("linear_get_current_user", Value::Null).await.unwrap();
+    assert_eq!(user["user"]["email"], "ann@example.com", "{}", user);
+
+    let created = server
+        .call_tool("create_ticket", json!({ "title": "Conformance ticket", "priority": "high" }))
+        .await
+        .unwrap();
+    let identifier = created["ticket"]["identifier"].as_str().unwrap_or_else(|| panic!("no identifier in {}", created));
+    assert_eq!(created["ticket"]["title"], "Conformance ticket");
+
+    let fetched = server.call_tool("linear_get_issue", json!({ "issue_id": identifier })).await.unwrap();
+    assert_eq!(fetched["issue"]["id"], created["ticket"]["id"]);
+}
+
+#[tokio::test]
+async fn unknown_tools_are_method_not_found() {
+    let error = call_error(&server(), "no_such_tool", json!({})).await;
+    assert_eq!(error["code"], -32601, "{}", error);
+    assert!(error["message"].as_str().unwrap().contains("no_such_tool"), "{}", error);
+}
+
+#[tokio::test]
+async fn malformed_arguments_are_invalid_params() {
+    let server = server();
+
+    let error = call_error(&server, "create_ticket", json!({ "title": 5 })).await;
+    assert_eq!(error["code"], -32602, "{}", error);
+    assert_eq!(error["data"]["kind"], "validation");
+    assert_eq!(error["data"]["retryable"], false);
+
+    let error = call_error(&server, "linear_get_issue", json!({})).await;
+    assert_eq!(error["code"], -32602, "{}", error);
+}
+
+#[tokio::test]
+async fn provider_failures_are_internal_errors_with_their_kind() {
+    let error = call_error(&server(), "list_comments", json!({ "ticket_id": "ticket-404" })).await;
+    assert_eq!(error["code"], -32603, "{}", error);
+    assert_eq!(error["data"]["kind"], "not_found", "{}", error);
+}
+
+#[tokio::test]
+async fn lists_resources_with_unique_uris() {
+    let resources = server().list_resources().await.unwrap();
+    assert!(!resources.is_empty());
+
+    let mut uris = HashSet::new();
+    for resource in &resources {
+        assert!(uris.insert(resource.uri.as_str()), "{} is listed twice", resource.uri);
+        assert!(resource.uri.contains("://"), "{} is not a URI", resource.uri);
+        assert!(!resource.name.trim().is_empty(), "{} has no name", resource.uri);
+        assert!(resource.mime_type.is_some(), "{} has no MIME type", resource.uri);
+    }
+}
+
+#[tokio::test]
+async fn reads_listed_resources_as_their_mime_type() {
+    let server = server();
+    for resource in server.list_resources().await.unwrap() {
+        // Templates need an id substituted
+        if resource.uri.contains('{') {
+            continue;
+        }
+        let contents = server.read_resource(&resource.uri).await.unwrap();
+        assert_eq!(contents["uri"], resource.uri.as_str());
+        assert_eq!(contents["mimeType"].as_str(), resource.mime_type.as_deref(), "{}", resource.uri);
+        let text = contents["text"].as_str().unwrap_or_else(|| panic!("{} has no text", resource.uri));
+        if resource.mime_type.as_deref() == Some("application/json") {
+            serde_json::from_str::<Value>(text).unwrap_or_else(|e| panic!("{} is not JSON: {}", resource.uri, e));
+        }
+    }
+}
+
+#[tokio::test]
+async fn unknown_resources_are_resource_not_found() {
+    let error = server().read_resource("linear://nowhere").await.unwrap_err();
+    let error = McpServerImpl::json_rpc_error(&error);
+    assert_eq!(error["code"], -32002, "{}", error);
+}
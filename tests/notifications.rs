@@ -0,0 +1,75 @@
+//! Notifications held for a transport to send: resource updates from
+//! webhooks and progress of long-running tools, bounded however long they
+//! go untaken.
+
+#![cfg(feature = "tokio")]
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use generic_mcp::{
+    Application, EventListener, MAX_PENDING_NOTIFICATIONS, McpServer, McpServerImpl, MemoryTicketService,
+    WebhookAction, WebhookEntity, WebhookEvent,
+};
+use serde_json::json;
+
+fn server() -> McpServerImpl {
+    McpServerImpl::new(Arc::new(Application::new(Arc::new(MemoryTicketService::new())))).with_default_team("DEMO")
+}
+
+fn project_event(project_id: &str) -> WebhookEvent {
+    WebhookEvent {
+        provider: "linear".to_string(),
+        entity: WebhookEntity::Project,
+        action: WebhookAction::Updated,
+        entity_id: project_id.to_string(),
+        ticket_id: None,
+        project_id: Some(project_id.to_string()),
+        occurred_at: Utc::now(),
+    }
+}
+
+#[test]
+fn announces_each_updated_resource_once() {
+    let server = server();
+    server.on_event(&project_event("p1"));
+    server.on_event(&project_event("p2"));
+    server.on_event(&project_event("p1"));
+
+    assert_eq!(server.take_resource_updates(), ["linear://projects/p1/status", "linear://projects/p2/status"]);
+    assert!(server.take_resource_updates().is_empty());
+}
+
+#[test]
+fn keeps_only_the_latest_resource_updates_when_none_are_taken() {
+    let server = server();
+    for project in 0..MAX_PENDING_NOTIFICATIONS + 10 {
+        server.on_event(&project_event(&format!("p{}", project)));
+    }
+
+    let updates = server.take_resource_updates();
+    assert_eq!(updates.len(), MAX_PENDING_NOTIFICATIONS);
+    assert_eq!(updates[0], "linear://projects/p10/status");
+    assert_eq!(updates.last().unwrap(), &format!("linear://projects/p{}/status", MAX_PENDING_NOTIFICATIONS + 9));
+}
+
+#[tokio::test]
+async fn keeps_only_the_latest_progress_of_a_request() {
+    let server = server();
+    let csv = "title\nFix IPMI auth\nRotate keys\nFlaky CI on arm64\n";
+    let request = server.for_progress_token(json!("import-1"));
+    request.call_tool("import_tickets_csv", json!({ "csv": csv, "dry_run": false })).await.unwrap();
+    let other = server.for_progress_token(json!(7));
+    other.call_tool("import_tickets_csv", json!({ "csv": csv, "dry_run": false })).await.unwrap();
+
+    let progress = server.take_progress_notifications();
+    assert_eq!(progress.len(), 2, "{:?}", progress);
+    assert_eq!(progress[0]["progressToken"], json!("import-1"));
+    assert_eq!((&progress[0]["progress"], &progress[0]["total"]), (&json!(3), &json!(3)));
+    assert_eq!(progress[1]["progressToken"], json!(7));
+    assert!(server.take_progress_notifications().is_empty());
+
+    // Requests without a token report nothing
+    server.call_tool("import_tickets_csv", json!({ "csv": csv, "dry_run": false })).await.unwrap();
+    assert!(server.take_progress_notifications().is_empty());
+}
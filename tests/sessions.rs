@@ -0,0 +1,168 @@
+//! Client sessions as a transport opens them: each with its own working set,
+//! pinned to the profile the client asked for, and run with the
+//! authenticated user's own tokens.
+
+#![cfg(feature = "tokio")]
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+use futures::FutureExt;
+use generic_mcp::{
+    Application, ConnectUser, Impersonation, McpServer, McpServerImpl, MemoryTicketService, PROFILE_HEADER,
+    PageRequest, ProviderRegistry, SessionRequest, TicketFilter, TicketService, UserToken, UserTokens,
+};
+use serde_json::json;
+
+struct Profiles {
+    server: McpServerImpl,
+    ops: Arc<MemoryTicketService>,
+    eng: Arc<MemoryTicketService>,
+}
+
+fn profiles() -> Profiles {
+    let ops = Arc::new(MemoryTicketService::new());
+    let eng = Arc::new(MemoryTicketService::new());
+    let registry = ProviderRegistry::new("ops", Arc::new(Application::new(ops.clone())))
+        .with_provider("eng", Arc::new(Application::new(eng.clone())));
+    let server = McpServerImpl::with_registry(registry).with_profiles().with_default_team("DEMO");
+    Profiles { server, ops, eng }
+}
+
+async fn ticket_count(service: &MemoryTicketService) -> usize {
+    service.search_tickets(&TicketFilter::default(), &PageRequest::default()).await.unwrap().items.len()
+}
+
+#[tokio::test]
+async fn pins_a_session_to_the_profile_header() {
+    let profiles = profiles();
+    let session = profiles.server
+        .open_session(&SessionRequest {
+            headers: [(PROFILE_HEADER.to_string(), "eng".to_string())].into(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    session.call_tool("create_ticket", json!({ "title": "Fix IPMI auth" })).await.unwrap();
+    assert_eq!(ticket_count(&profiles.eng).await, 1);
+    assert_eq!(ticket_count(&profiles.ops).await, 0);
+
+    let other = session.call_tool("create_ticket", json!({ "title": "Rotate keys", "profile": "ops" })).await;
+    assert!(other.is_err());
+    assert_eq!(ticket_count(&profiles.ops).await, 0);
+}
+
+#[tokio::test]
+async fn pins_a_session_to_the_profile_in_initialize_params() {
+    let profiles = profiles();
+    let session = profiles.server
+        .open_session(&SessionRequest {
+            initialize_params: Some(json!({ "protocolVersion": "2025-03-26", "_meta": { "profile": "eng" } })),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    session.call_tool("create_ticket", json!({ "title": "Fix IPMI auth" })).await.unwrap();
+    assert_eq!(ticket_count(&profiles.eng).await, 1);
+}
+
+#[tokio::test]
+async fn leaves_every_profile_reachable_without_one() {
+    let profiles = profiles();
+    let session = profiles.server.open_session(&SessionRequest::default()).await.unwrap();
+
+    session.call_tool("create_ticket", json!({ "title": "Fix IPMI auth", "profile": "eng" })).await.unwrap();
+    session.call_tool("create_ticket", json!({ "title": "Rotate keys" })).await.unwrap();
+    assert_eq!(ticket_count(&profiles.eng).await, 1);
+    assert_eq!(ticket_count(&profiles.ops).await, 1);
+}
+
+#[tokio::test]
+async fn gives_each_session_its_own_working_set() {
+    let profiles = profiles();
+    let server = profiles.server.clone().with_working_set(10);
+    let first = server.open_session(&SessionRequest::default()).await.unwrap();
+    let second = server.open_session(&SessionRequest::default()).await.unwrap();
+
+    first.call_tool("create_ticket", json!({ "title": "Fix IPMI auth" })).await.unwrap();
+    let tickets = profiles.ops.search_tickets(&TicketFilter::default(), &PageRequest::default()).await.unwrap();
+    first.call_tool("working_set_pin", json!({ "ticket_id": tickets.items[0].id })).await.unwrap();
+
+    let count = |list: serde_json::Value| list["count"].as_u64().unwrap();
+    assert_eq!(count(first.call_tool("working_set_list", json!({})).await.unwrap()), 1);
+    assert_eq!(count(second.call_tool("working_set_list", json!({})).await.unwrap()), 0);
+    assert_eq!(count(server.call_tool("working_set_list", json!({})).await.unwrap()), 0);
+}
+
+#[tokio::test]
+async fn refuses_an_unknown_profile() {
+    let profiles = profiles();
+    let session = profiles.server
+        .open_session(&SessionRequest {
+            headers: [(PROFILE_HEADER.to_string(), "sales".to_string())].into(),
+            ..Default::default()
+        })
+        .await;
+    assert!(session.is_err());
+}
+
+/// The workspace each user was connected to, by identity.
+type Connected = Arc<Mutex<HashMap<String, Arc<MemoryTicketService>>>>;
+
+/// Serves `profiles` with per-user tokens for alice on eng only.
+fn impersonating(profiles: &Profiles) -> (McpServerImpl, Connected) {
+    let token = UserToken {
+        api_token: Some("alice-token".to_string()),
+        api_token_env: None,
+    };
+    let tokens = UserTokens::new(BTreeMap::from([
+        ("alice@example.com".to_string(), BTreeMap::from([("eng".to_string(), token)])),
+    ]));
+    let connected = Arc::new(Mutex::new(HashMap::new()));
+    let connections = connected.clone();
+    let connect: ConnectUser = Arc::new(move |identity, _name, _token| {
+        let service = Arc::new(MemoryTicketService::new());
+        connections.lock().unwrap().insert(identity, service.clone());
+        async move { Ok(Arc::new(Application::new(service))) }.boxed()
+    });
+    let server = profiles.server.clone().with_impersonation(Arc::new(Impersonation::new(tokens, connect)));
+    (server, connected)
+}
+
+#[tokio::test]
+async fn runs_an_authenticated_session_with_the_users_token() {
+    let profiles = profiles();
+    let (server, connected) = impersonating(&profiles);
+    let session = server
+        .open_session(&SessionRequest {
+            identity: Some("alice@example.com".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    session.call_tool("create_ticket", json!({ "title": "Fix IPMI auth" })).await.unwrap();
+    let alice = connected.lock().unwrap()["alice@example.com"].clone();
+    assert_eq!(ticket_count(&alice).await, 1);
+    assert_eq!(ticket_count(&profiles.eng).await, 0);
+    assert_eq!(ticket_count(&profiles.ops).await, 0);
+
+    // Alice has no token for ops
+    let other = session.call_tool("create_ticket", json!({ "title": "Rotate keys", "profile": "ops" })).await;
+    assert!(other.is_err());
+}
+
+#[tokio::test]
+async fn refuses_a_user_without_tokens() {
+    let profiles = profiles();
+    let (server, _) = impersonating(&profiles);
+    let session = server
+        .open_session(&SessionRequest {
+            identity: Some("bob@example.com".to_string()),
+            ..Default::default()
+        })
+        .await;
+    assert!(session.is_err());
+}
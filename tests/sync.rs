@@ -0,0 +1,79 @@
+//! Two-way sync when a step after creating a counterpart fails: the pair is
+//! linked anyway, so later runs never create it a second time.
+
+#![cfg(feature = "tokio")]
+
+use std::path::Path;
+use std::sync::Arc;
+
+use generic_mcp::{
+    Application, Capabilities, CreateTicketRequest, MemoryTicketService, PageRequest, ProviderRegistry, SyncConfig,
+    SyncEndpoint, SyncEngine, TicketFilter, TicketService, load_sync_config,
+};
+
+fn config(state_file: &Path) -> SyncConfig {
+    let endpoint = |provider: &str| SyncEndpoint {
+        provider: provider.to_string(),
+        team: "DEMO".to_string(),
+    };
+    serde_json::from_value(serde_json::json!({
+        "left": endpoint("left"),
+        "right": endpoint("right"),
+        "state_file": state_file,
+    }))
+    .unwrap()
+}
+
+async fn ticket_count(service: &MemoryTicketService) -> usize {
+    service.search_tickets(&TicketFilter::default(), &PageRequest::default()).await.unwrap().items.len()
+}
+
+#[tokio::test]
+async fn links_a_mirrored_ticket_even_when_its_state_cannot_be_copied() {
+    let dir = tempfile::tempdir().unwrap();
+    let state_file = dir.path().join("sync-state.json");
+
+    let left = Arc::new(Application::new(Arc::new(MemoryTicketService::new())));
+    let ticket = left
+        .create_ticket(&CreateTicketRequest {
+            title: "Fix IPMI auth".to_string(),
+            team_id: Some("team-demo".to_string()),
+            ..Default::default()
+        }, None)
+        .await
+        .unwrap();
+    left.start_ticket(&ticket.identifier, None).await.unwrap();
+
+    // The right side can create tickets but not move them out of Backlog
+    let right_service = Arc::new(MemoryTicketService::new().with_capabilities(Capabilities {
+        ticket_updates: false,
+        ..MemoryTicketService::new().capabilities()
+    }));
+    let right = Arc::new(Application::new(right_service.clone()));
+    let registry = ProviderRegistry::new("left", left).with_provider("right", right);
+
+    let engine = SyncEngine::new(config(&state_file), &registry).unwrap();
+    let report = engine.run(None).await.unwrap();
+    assert_eq!(report.created.len(), 1);
+    assert_eq!(report.failures.len(), 1, "{:?}", report.failures);
+    assert_eq!(ticket_count(&right_service).await, 1);
+
+    // A fresh engine, as after a restart, reads the link back
+    let engine = SyncEngine::new(config(&state_file), &registry).unwrap();
+    assert_eq!(engine.status().linked, 1);
+    let report = engine.run(None).await.unwrap();
+    assert!(report.created.is_empty(), "{:?}", report.created);
+    assert_eq!(ticket_count(&right_service).await, 1);
+}
+
+#[test]
+fn refuses_a_configuration_without_a_state_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("sync.json");
+    std::fs::write(&path, r#"{
+        "left": { "provider": "linear", "team": "ENG" },
+        "right": { "provider": "github", "team": "my-org/my-repo" }
+    }"#).unwrap();
+    let error = load_sync_config(&path).unwrap_err();
+    assert!(format!("{:#}", error).contains("state_file"), "{:#}", error);
+}
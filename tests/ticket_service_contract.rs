@@ -0,0 +1,17 @@
+//! Providers in this crate held to the `TicketService` contract.
+
+#![cfg(feature = "tokio")]
+
+use generic_mcp::{ContractOptions, MemoryTicketService};
+
+generic_mcp::ticket_service_contract!(
+    memory_provider_meets_the_contract,
+    MemoryTicketService::new(),
+    ContractOptions { missing_ticket_id: Some("ticket-missing".to_string()), ..Default::default() }
+);
+
+generic_mcp::ticket_service_contract!(
+    memory_provider_meets_the_contract_without_cleanup,
+    MemoryTicketService::new(),
+    ContractOptions { team_id: Some("DEMO".to_string()), cleanup: false, ..Default::default() }
+);
@@ -0,0 +1,37 @@
+//! The webhook listener refuses providers it could only serve unsigned,
+//! unless unsigned deliveries are explicitly allowed.
+
+#![cfg(feature = "tokio")]
+
+use std::sync::Arc;
+
+use generic_mcp::{EventBus, GithubWebhookDecoder, LinearWebhookDecoder, WebhookServer};
+
+fn server() -> WebhookServer {
+    WebhookServer::new("127.0.0.1:0".parse().unwrap(), EventBus::default())
+}
+
+#[test]
+fn serves_providers_with_secrets() {
+    let server = server()
+        .with_decoder(Arc::new(LinearWebhookDecoder::new(Some("secret".to_string()))))
+        .with_decoder(Arc::new(GithubWebhookDecoder::new(Some("secret".to_string()))));
+    server.check().unwrap();
+}
+
+#[tokio::test]
+async fn refuses_to_start_with_an_unsigned_provider() {
+    let server = server()
+        .with_decoder(Arc::new(LinearWebhookDecoder::new(Some("secret".to_string()))))
+        .with_decoder(Arc::new(GithubWebhookDecoder::new(None)));
+    let error = server.run().await.unwrap_err();
+    assert!(error.to_string().contains("github"), "{}", error);
+}
+
+#[test]
+fn serves_unsigned_providers_when_allowed() {
+    let server = server()
+        .allow_unsigned(true)
+        .with_decoder(Arc::new(GithubWebhookDecoder::new(None)));
+    server.check().unwrap();
+}
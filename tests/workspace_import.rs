@@ -0,0 +1,90 @@
+//! Importing a workspace snapshot into providers that lack some of the
+//! operations an import needs: the import is refused before anything is
+//! created.
+
+#![cfg(feature = "tokio")]
+
+use std::sync::Arc;
+
+use generic_mcp::{
+    Application, Capabilities, CreateLabelRequest, CreateTicketRequest, FieldMapping, MemoryTicketService, PageRequest,
+    TicketFilter, TicketService, WorkspaceSnapshot, unsupported_operation,
+};
+
+/// A snapshot of one ticket in progress, labelled with a label the target
+/// does not have.
+async fn snapshot() -> WorkspaceSnapshot {
+    let source = Arc::new(MemoryTicketService::new());
+    let label = source
+        .create_label(&CreateLabelRequest {
+            name: "Firmware".to_string(),
+            color: "#4ea7fc".to_string(),
+            description: None,
+        })
+        .await
+        .unwrap();
+    let application = Application::new(source.clone());
+    let ticket = application
+        .create_ticket(&CreateTicketRequest {
+            title: "Fix IPMI auth".to_string(),
+            team_id: Some("team-demo".to_string()),
+            label_ids: Some(vec![label.id]),
+            ..Default::default()
+        }, None)
+        .await
+        .unwrap();
+    application.start_ticket(&ticket.identifier, None).await.unwrap();
+    application.export_workspace("source").await.unwrap()
+}
+
+fn target(capabilities: Capabilities) -> Arc<MemoryTicketService> {
+    Arc::new(MemoryTicketService::new().with_capabilities(capabilities))
+}
+
+async fn ticket_count(service: &MemoryTicketService) -> usize {
+    service.search_tickets(&TicketFilter::default(), &PageRequest::default()).await.unwrap().items.len()
+}
+
+#[tokio::test]
+async fn imports_into_a_provider_with_every_operation() {
+    let snapshot = snapshot().await;
+    let service = target(MemoryTicketService::new().capabilities());
+    let report = Application::new(service.clone())
+        .import_workspace(&snapshot, &FieldMapping::default(), &|_, _, _| {})
+        .await
+        .unwrap();
+    assert!(report.failures.is_empty(), "{:?}", report.failures);
+    assert_eq!(report.tickets_created, 1);
+    assert_eq!(report.labels_created, 1);
+}
+
+#[tokio::test]
+async fn refuses_a_provider_without_ticket_updates() {
+    let snapshot = snapshot().await;
+    let service = target(Capabilities {
+        ticket_updates: false,
+        ..MemoryTicketService::new().capabilities()
+    });
+    let error = Application::new(service.clone())
+        .import_workspace(&snapshot, &FieldMapping::default(), &|_, _, _| {})
+        .await
+        .unwrap_err();
+    assert_eq!(unsupported_operation(&error).unwrap().operation, "Ticket updates");
+    assert_eq!(ticket_count(&service).await, 0);
+    assert!(service.get_labels().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn refuses_a_provider_without_label_creation_when_labels_are_missing() {
+    let snapshot = snapshot().await;
+    let service = target(Capabilities {
+        label_creation: false,
+        ..MemoryTicketService::new().capabilities()
+    });
+    let error = Application::new(service.clone())
+        .import_workspace(&snapshot, &FieldMapping::default(), &|_, _, _| {})
+        .await
+        .unwrap_err();
+    assert_eq!(unsupported_operation(&error).unwrap().operation, "Label creation");
+    assert_eq!(ticket_count(&service).await, 0);
+}